@@ -27,6 +27,10 @@ pub struct IntrospectCommand {
     /// Configuration file location
     #[arg(short, long, default_value = "./grafbase-postgres.toml")]
     pub config: PathBuf,
+    /// Directory to write the subgraph schemas into, one file per subgraph. Required if the
+    /// configuration defines subgraphs.
+    #[arg(short, long)]
+    pub output_dir: Option<PathBuf>,
 }
 
 pub fn parse() -> Args {
@@ -1,5 +1,6 @@
 use anyhow::Context;
 use args::IntrospectCommand;
+use grafbase_postgres_introspection::config::Config;
 use sqlx::{Connection, PgConnection};
 
 mod args;
@@ -26,10 +27,30 @@ async fn introspect(conn: &mut PgConnection, cmd: IntrospectCommand) -> anyhow::
         "with --config, or make sure file ./grafbase-postgres.toml exists."
     ))?;
 
-    let config = toml::from_str(&config)?;
-    let sdl = grafbase_postgres_introspection::introspect(conn, config).await?;
+    let config: Config = toml::from_str(&config)?;
 
-    println!("{sdl}");
+    if config.subgraphs.is_empty() {
+        let sdl = grafbase_postgres_introspection::introspect(conn, config).await?;
+        println!("{sdl}");
+
+        return Ok(());
+    }
+
+    let output_dir = cmd.output_dir.context(concat!(
+        "The configuration defines subgraphs. Please provide a directory ",
+        "for the subgraph schemas with --output-dir."
+    ))?;
+
+    std::fs::create_dir_all(&output_dir)
+        .with_context(|| format!("Could not create directory {}", output_dir.display()))?;
+
+    let subgraphs = grafbase_postgres_introspection::introspect_subgraphs(conn, config).await?;
+
+    for (name, sdl) in subgraphs {
+        let path = output_dir.join(format!("{name}.graphql"));
+
+        std::fs::write(&path, sdl).with_context(|| format!("Could not write file {}", path.display()))?;
+    }
 
     Ok(())
 }
@@ -33,3 +33,17 @@ pub(crate) fn grpc_path_to_graphql_name(path: &str) -> impl fmt::Display {
         Ok(())
     })
 }
+
+pub(crate) fn snake_case_to_pascal_case(name: &str) -> impl fmt::Display {
+    display_fn(move |f| {
+        for segment in name.split('_').filter(|segment| !segment.is_empty()) {
+            let mut chars = segment.chars();
+
+            if let Some(first) = chars.next() {
+                write!(f, "{}{}", first.to_ascii_uppercase(), chars.as_str())?;
+            }
+        }
+
+        Ok(())
+    })
+}
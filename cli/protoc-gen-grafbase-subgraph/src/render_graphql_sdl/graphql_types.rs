@@ -1,5 +1,5 @@
 use super::*;
-use crate::schema::{ProtoMessage, ScalarType, View};
+use crate::schema::{ProtoField, ProtoFieldId, ProtoMessage, ScalarType, View};
 
 pub(super) fn render_graphql_types(
     schema: &GrpcSchema,
@@ -10,11 +10,16 @@ pub(super) fn render_graphql_types(
         messages_to_render_as_input,
         messages_to_render_as_output,
         enums_to_render,
+        json_scalar,
     } = types_to_render;
 
     f.write_str("\"64 bit signed integer\" scalar I64\n")?;
     f.write_str("\"64 bit unsigned integer\" scalar U64\n")?;
 
+    if *json_scalar {
+        f.write_str("\"Arbitrary JSON value\" scalar JSON\n")?;
+    }
+
     for message_id in messages_to_render_as_input {
         render_message(schema, *message_id, true, f)?;
     }
@@ -90,33 +95,30 @@ fn render_message(
 
     f.write_str("{\n")?;
 
+    let mut oneofs: Vec<&str> = Vec::new();
+
     for field in message_id.fields(schema) {
-        if let Some(description) = field.description.as_deref() {
-            render_description(f, description)?;
-        }
+        // A oneof group is rendered where its first member is. In inputs, it is an `@oneOf` input
+        // object with the members as fields. In outputs, it holds the name of the member that is set.
+        if let Some(oneof) = field.record.oneof.as_deref()
+            && !oneofs.contains(&oneof)
+        {
+            oneofs.push(oneof);
 
-        f.write_str(INDENT)?;
-        f.write_str(&field.name)?;
-        f.write_str(": ")?;
+            f.write_str(INDENT)?;
 
-        if input {
-            render_input_field_type(schema, &field.r#type, field.repeated, f)?;
-        } else {
-            render_output_field_type(schema, &field.r#type, field.repeated, field.optional, f)?;
+            if input {
+                writeln!(f, "{oneof}: {}", message.graphql_oneof_input_name(oneof))?;
+            } else {
+                writeln!(f, "{oneof}: String")?;
+            }
         }
 
-        let field_directives = if input {
-            field.input_field_directives.as_deref()
-        } else {
-            field.output_field_directives.as_deref()
-        };
-
-        if let Some(directives) = field_directives {
-            f.write_str(" ")?;
-            f.write_str(directives)?;
+        if input && field.oneof.is_some() {
+            continue;
         }
 
-        f.write_str("\n")?;
+        render_field(schema, &field, input, f)?;
     }
 
     if !input {
@@ -124,7 +126,60 @@ fn render_message(
         render_join_fields(schema, &message, f)?;
     }
 
-    f.write_str("}\n")
+    f.write_str("}\n")?;
+
+    if input {
+        for oneof in oneofs {
+            write!(f, "\ninput {} @oneOf {{\n", message.graphql_oneof_input_name(oneof))?;
+
+            for field in message_id
+                .fields(schema)
+                .filter(|field| field.oneof.as_deref() == Some(oneof))
+            {
+                render_field(schema, &field, input, f)?;
+            }
+
+            f.write_str("}\n")?;
+        }
+    }
+
+    Ok(())
+}
+
+fn render_field(
+    schema: &GrpcSchema,
+    field: &View<'_, ProtoFieldId, ProtoField>,
+    input: bool,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    if let Some(description) = field.description.as_deref() {
+        render_description(f, description)?;
+    }
+
+    f.write_str(INDENT)?;
+    f.write_str(&field.name)?;
+    f.write_str(": ")?;
+
+    if input {
+        render_input_field_type(schema, &field.r#type, field.repeated, f)?;
+    } else {
+        // At most one member of a oneof is set, so none of them can be non-null.
+        let optional = field.optional || field.oneof.is_some();
+        render_output_field_type(schema, &field.r#type, field.repeated, optional, f)?;
+    }
+
+    let field_directives = if input {
+        field.input_field_directives.as_deref()
+    } else {
+        field.output_field_directives.as_deref()
+    };
+
+    if let Some(directives) = field_directives {
+        f.write_str(" ")?;
+        f.write_str(directives)?;
+    }
+
+    f.write_str("\n")
 }
 
 fn render_derive_fields(message: &View<'_, ProtoMessageId, ProtoMessage>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -343,6 +398,7 @@ fn render_message_type_name(
         ".google.protobuf.StringValue" => ScalarType::String.render_graphql_type(f),
         ".google.protobuf.UInt32Value" => ScalarType::UInt32.render_graphql_type(f),
         ".google.protobuf.UInt64Value" => ScalarType::UInt64.render_graphql_type(f),
+        _ if message.is_json() => f.write_str("JSON"),
 
         _ => {
             if is_input {
@@ -177,6 +177,12 @@ fn render_proto_services_filtered(
                     "{INDENT}{INDENT}{INDENT}{INDENT}{INDENT}{INDENT}serverStreaming: true",
                 )?;
             }
+            if method.client_streaming {
+                writeln!(
+                    f,
+                    "{INDENT}{INDENT}{INDENT}{INDENT}{INDENT}{INDENT}clientStreaming: true",
+                )?;
+            }
             writeln!(f, "{INDENT}{INDENT}{INDENT}{INDENT}{INDENT}}}")?;
         }
 
@@ -245,6 +251,10 @@ fn render_proto_messages(
                 field.r#type.proto_name(schema)
             )?;
 
+            if let Some(oneof) = &field.oneof {
+                writeln!(f, "{INDENT}{INDENT}{INDENT}{INDENT}{INDENT}{INDENT}oneof: \"{oneof}\"")?;
+            }
+
             writeln!(f, "{INDENT}{INDENT}{INDENT}{INDENT}{INDENT}}}")?;
         }
 
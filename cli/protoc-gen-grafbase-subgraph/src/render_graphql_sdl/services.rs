@@ -90,6 +90,7 @@ pub(super) fn collect_types_to_render_filtered(
             &method.input_type,
             &mut types_to_render.messages_to_render_as_input,
             &mut types_to_render.enums_to_render,
+            &mut types_to_render.json_scalar,
         );
 
         collect_message_id_and_enum_ids_recursively(
@@ -97,6 +98,7 @@ pub(super) fn collect_types_to_render_filtered(
             &method.output_type,
             &mut types_to_render.messages_to_render_as_output,
             &mut types_to_render.enums_to_render,
+            &mut types_to_render.json_scalar,
         );
     }
 
@@ -117,6 +119,8 @@ fn render_method_field(
     f.write_str(INDENT)?;
     write!(f, "{}_{}(input: ", service.graphql_name(), method.name)?;
 
+    // The input stays a single message for client streaming methods, as the gRPC extension sends
+    // one message per call.
     render_input_field_type(schema, &method.input_type, false, f)?;
 
     if let Some(directives) = method.argument_directives.as_deref() {
@@ -155,6 +159,8 @@ pub(crate) struct TypesToRender {
     pub(super) messages_to_render_as_input: BTreeSet<ProtoMessageId>,
     pub(super) messages_to_render_as_output: BTreeSet<ProtoMessageId>,
     pub(super) enums_to_render: BTreeSet<ProtoEnumId>,
+    /// Whether a message holding arbitrary JSON is used, so the `JSON` scalar has to be declared.
+    pub(super) json_scalar: bool,
 }
 
 fn collect_message_id_and_enum_ids_recursively(
@@ -162,16 +168,27 @@ fn collect_message_id_and_enum_ids_recursively(
     field_type: &FieldType,
     message_ids: &mut BTreeSet<ProtoMessageId>,
     enum_ids: &mut BTreeSet<ProtoEnumId>,
+    json_scalar: &mut bool,
 ) {
     match field_type {
         FieldType::Scalar(_scalar_type) => (),
         FieldType::Enum(proto_enum_id) => {
             enum_ids.insert(*proto_enum_id);
         }
+        // The JSON scalar stands for the whole message, its fields are never rendered.
+        FieldType::Message(proto_message_id) if schema[*proto_message_id].is_json() => {
+            *json_scalar = true;
+        }
         FieldType::Message(proto_message_id) => {
             if message_ids.insert(*proto_message_id) {
                 for field in proto_message_id.fields(schema) {
-                    collect_message_id_and_enum_ids_recursively(schema, &field.r#type, message_ids, enum_ids);
+                    collect_message_id_and_enum_ids_recursively(
+                        schema,
+                        &field.r#type,
+                        message_ids,
+                        enum_ids,
+                        json_scalar,
+                    );
                 }
             }
         }
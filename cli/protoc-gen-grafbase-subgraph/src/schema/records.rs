@@ -40,6 +40,26 @@ impl ProtoMessage {
             f.write_str("Input")
         })
     }
+
+    /// Struct, Value, ListValue and Any hold arbitrary JSON, and are rendered as the `JSON` scalar.
+    pub(crate) fn is_json(&self) -> bool {
+        matches!(
+            self.name.as_str(),
+            ".google.protobuf.Struct"
+                | ".google.protobuf.Value"
+                | ".google.protobuf.ListValue"
+                | ".google.protobuf.Any"
+        )
+    }
+
+    /// The name of the `@oneOf` input object holding the members of a oneof group.
+    pub(crate) fn graphql_oneof_input_name(&self, oneof: &str) -> impl fmt::Display {
+        display_fn(move |f| {
+            self.graphql_output_name().fmt(f)?;
+            display_utils::snake_case_to_pascal_case(oneof).fmt(f)?;
+            f.write_str("Input")
+        })
+    }
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -73,6 +93,8 @@ pub(crate) struct ProtoField {
     pub(crate) number: u16,
     pub(crate) repeated: bool,
     pub(crate) optional: bool,
+    /// The name of the oneof the field is a member of. Proto3 optional fields are not members.
+    pub(crate) oneof: Option<String>,
     pub(crate) description: Option<String>,
     pub(crate) input_field_directives: Option<String>,
     pub(crate) output_field_directives: Option<String>,
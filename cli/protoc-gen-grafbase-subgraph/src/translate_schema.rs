@@ -339,6 +339,13 @@ fn translate_fields(
         let repeated = field.label.unwrap_or_default().enum_value_or_default() == Label::LABEL_REPEATED;
         let optional = field.proto3_optional();
 
+        // Proto3 optional fields are wrapped in a synthetic oneof, which is not a group of its own.
+        let oneof = field
+            .oneof_index
+            .filter(|_| !optional)
+            .and_then(|index| message.oneof_decl.get(usize::try_from(index).ok()?))
+            .map(|oneof| oneof.name().to_owned());
+
         let mut proto_field = ProtoField {
             message_id,
             name: field.name().to_owned(),
@@ -346,6 +353,7 @@ fn translate_fields(
             number,
             repeated,
             optional,
+            oneof,
             description,
             input_field_directives: None,
             output_field_directives: None,
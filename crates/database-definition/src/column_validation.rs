@@ -0,0 +1,50 @@
+/// Input validation rules of a column, derived from simple CHECK constraints and the
+/// maximum length of character types.
+///
+/// Several constraints on the same column are merged together, keeping the tightest bounds.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ColumnValidation {
+    /// The smallest allowed value, inclusive.
+    pub min: Option<f64>,
+    /// The largest allowed value, inclusive.
+    pub max: Option<f64>,
+    /// The value must be greater than this.
+    pub exclusive_min: Option<f64>,
+    /// The value must be less than this.
+    pub exclusive_max: Option<f64>,
+    /// The minimum length of a string value.
+    pub min_length: Option<u32>,
+    /// The maximum length of a string value.
+    pub max_length: Option<u32>,
+    /// The value must be one of these strings.
+    pub one_of: Option<Vec<String>>,
+}
+
+impl ColumnValidation {
+    /// True, if the column has no validation rules.
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    /// Combines the rules from another constraint to this one, keeping the tightest bounds.
+    pub fn merge(&mut self, other: ColumnValidation) {
+        self.min = merge_with(self.min, other.min, f64::max);
+        self.max = merge_with(self.max, other.max, f64::min);
+        self.exclusive_min = merge_with(self.exclusive_min, other.exclusive_min, f64::max);
+        self.exclusive_max = merge_with(self.exclusive_max, other.exclusive_max, f64::min);
+        self.min_length = merge_with(self.min_length, other.min_length, u32::max);
+        self.max_length = merge_with(self.max_length, other.max_length, u32::min);
+
+        self.one_of = match (self.one_of.take(), other.one_of) {
+            (Some(a), Some(b)) => Some(a.into_iter().filter(|value| b.contains(value)).collect()),
+            (a, b) => a.or(b),
+        };
+    }
+}
+
+fn merge_with<T>(a: Option<T>, b: Option<T>, f: impl FnOnce(T, T) -> T) -> Option<T> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(f(a, b)),
+        (a, b) => a.or(b),
+    }
+}
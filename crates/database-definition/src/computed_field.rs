@@ -0,0 +1,43 @@
+use super::{StringId, TableId};
+
+/// A field of a table, resolved with an SQL expression over the row instead of a column.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ComputedField<T> {
+    pub(super) table_id: TableId,
+    pub(super) client_name: T,
+    pub(super) sql: T,
+}
+
+impl<T> ComputedField<T> {
+    pub(crate) fn table_id(&self) -> TableId {
+        self.table_id
+    }
+}
+
+impl ComputedField<String> {
+    pub fn new(table_id: TableId, client_name: String, sql: String) -> Self {
+        Self {
+            table_id,
+            client_name,
+            sql,
+        }
+    }
+
+    pub(crate) fn client_name(&self) -> &str {
+        &self.client_name
+    }
+
+    pub(crate) fn sql(&self) -> &str {
+        &self.sql
+    }
+}
+
+impl ComputedField<StringId> {
+    pub(crate) fn client_name(&self) -> StringId {
+        self.client_name
+    }
+
+    pub(crate) fn sql(&self) -> StringId {
+        self.sql
+    }
+}
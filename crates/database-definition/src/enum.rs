@@ -4,7 +4,7 @@ use crate::StringId;
 
 use super::SchemaId;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct Enum<T> {
     pub(super) schema_id: SchemaId,
     pub(super) database_name: T,
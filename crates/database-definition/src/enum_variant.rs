@@ -2,7 +2,7 @@ use inflector::Inflector;
 
 use super::{EnumId, StringId};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct EnumVariant<T> {
     pub(super) enum_id: EnumId,
     pub(super) database_name: T,
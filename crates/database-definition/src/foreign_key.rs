@@ -1,6 +1,6 @@
 use super::{SchemaId, StringId, TableId};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ForeignKey<T> {
     pub(super) constraint_name: T,
     pub(super) schema_id: SchemaId,
@@ -1,6 +1,6 @@
 use super::{ForeignKeyId, TableColumnId};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ForeignKeyColumn {
     foreign_key_id: ForeignKeyId,
     constrained_column_id: TableColumnId,
@@ -4,43 +4,101 @@ use grafbase_sdk::types::DefinitionId;
 
 use crate::{Operation, StringId};
 
-use super::{EnumId, EnumVariantId, ForeignKeyId, KeyId, RelationId, SchemaId, TableColumnId, TableId};
+use super::{
+    ComputedFieldId, EnumId, EnumVariantId, ForeignKeyId, KeyId, RelationId, SchemaId, TableColumnId, TableId,
+};
 
 /// Various indices used to quickly look up items within the schema.
-#[derive(Default, Debug, Clone)]
+///
+/// The indices keyed by `DefinitionId` are not serialized. The ids are assigned by the gateway,
+/// so they are bound again from the subgraph schema after loading a serialized definition.
+#[derive(Default, Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub(super) struct Indices {
     /// Provides a fast lookup for a table by its schema ID and name.
+    #[serde(with = "sorted")]
     pub(super) tables: HashMap<(SchemaId, StringId), TableId>,
     /// Provides a fast lookup for a table column by its table ID and column name.
+    #[serde(with = "sorted")]
     pub(super) table_columns: HashMap<(TableId, StringId), TableColumnId>,
     /// Provides a fast lookup for a table column by its table ID and *client* field name.
     /// Note: This might be redundant with `client_fields`.
+    #[serde(with = "sorted")]
     pub(super) table_fields: HashMap<(TableId, StringId), TableColumnId>,
     /// Provides a fast lookup for an enum by its schema ID and name.
+    #[serde(with = "sorted")]
     pub(super) enums: HashMap<(SchemaId, StringId), EnumId>,
     /// Provides a fast lookup for an enum variant by its enum ID and variant name.
+    #[serde(with = "sorted")]
     pub(super) enum_variants: HashMap<(EnumId, StringId), EnumVariantId>,
     /// Provides a fast lookup for a foreign key by its schema ID and name.
+    #[serde(with = "sorted")]
     pub(super) foreign_keys: HashMap<(SchemaId, StringId), ForeignKeyId>,
     /// Provides a fast lookup for a key (e.g., primary, unique) by its table ID and name.
+    #[serde(with = "sorted")]
     pub(super) keys: HashMap<(TableId, StringId), KeyId>,
     /// Provides a fast lookup for a table ID by its client-facing type name.
+    #[serde(with = "sorted")]
     pub(super) client_types: HashMap<StringId, TableId>,
     /// Provides a fast lookup for a table column ID by its table ID and client-facing field name.
+    #[serde(with = "sorted")]
     pub(super) client_fields: HashMap<(TableId, StringId), TableColumnId>,
     /// Provides a fast lookup for a key ID by its table ID and client-facing unique constraint name.
+    #[serde(with = "sorted")]
     pub(super) client_unique_constraints: HashMap<(TableId, StringId), KeyId>,
     /// Provides a fast lookup for a relation ID by the `DefinitionId` of the client-facing relation field.
+    #[serde(skip)]
     pub(super) client_relations: HashMap<DefinitionId, RelationId>,
     /// Provides a fast lookup for a relation ID by its table ID and client-facing relation field name.
+    #[serde(with = "sorted")]
     pub(super) client_name_relations: HashMap<(TableId, StringId), RelationId>,
+    /// Provides a fast lookup for a computed field ID by its table ID and client-facing field name.
+    #[serde(with = "sorted")]
+    pub(super) client_computed_fields: HashMap<(TableId, StringId), ComputedFieldId>,
     /// Maps the `DefinitionId` of a client-facing scalar field to its corresponding `TableColumnId`.
+    #[serde(skip)]
     pub(super) field_definition_to_column: HashMap<DefinitionId, TableColumnId>,
+    /// Maps the `DefinitionId` of a client-facing computed field to its corresponding `ComputedFieldId`.
+    #[serde(skip)]
+    pub(super) field_definition_to_computed_field: HashMap<DefinitionId, ComputedFieldId>,
     /// Maps a `DefinitionId` (e.g., of a field) to its client-facing name (`StringId`).
+    #[serde(skip)]
     pub(super) definition_to_field_name: HashMap<DefinitionId, StringId>,
     /// Maps the `DefinitionId` of a client-facing field to the `DefinitionId` of its return type.
+    #[serde(skip)]
     pub(super) field_definition_to_return_type_definition_id: HashMap<DefinitionId, DefinitionId>,
     /// A mapping from a field definition ID to a full SQL operation. Mapped from
     /// query and mutation definitions.
+    #[serde(skip)]
     pub(super) operations: HashMap<DefinitionId, Operation>,
 }
+
+/// Serializes a map as a list of entries ordered by key, so the serialized definition is the
+/// same on every run.
+mod sorted {
+    use std::{collections::HashMap, hash::Hash};
+
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub(super) fn serialize<K, V, S>(map: &HashMap<K, V>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        K: Serialize + Ord,
+        V: Serialize,
+        S: Serializer,
+    {
+        let mut entries: Vec<_> = map.iter().collect();
+        entries.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+
+        entries.serialize(serializer)
+    }
+
+    pub(super) fn deserialize<'de, K, V, D>(deserializer: D) -> Result<HashMap<K, V>, D::Error>
+    where
+        K: Deserialize<'de> + Eq + Hash,
+        V: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let entries = Vec::<(K, V)>::deserialize(deserializer)?;
+
+        Ok(entries.into_iter().collect())
+    }
+}
@@ -1,13 +1,13 @@
 use super::{StringId, TableId};
 
-#[derive(serde::Deserialize, Debug, Clone, Copy, PartialEq)]
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Copy, PartialEq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum KeyType {
     Primary,
     Unique,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct Key<T> {
     pub(super) table_id: TableId,
     pub(super) r#type: KeyType,
@@ -1,6 +1,6 @@
 use super::{KeyId, TableColumnId};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct KeyColumn {
     key_id: KeyId,
     column_id: TableColumnId,
@@ -1,3 +1,5 @@
+mod column_validation;
+mod computed_field;
 mod r#enum;
 mod enum_variant;
 mod foreign_key;
@@ -8,6 +10,7 @@ mod interner;
 mod key;
 mod key_column;
 mod relations;
+mod serialization;
 mod table;
 mod table_column;
 mod r#type;
@@ -15,14 +18,16 @@ mod walkers;
 
 use std::collections::HashMap;
 
+pub use column_validation::ColumnValidation;
+pub use computed_field::ComputedField;
 pub use r#enum::Enum;
 pub use enum_variant::EnumVariant;
 pub use foreign_key::ForeignKey;
 pub use foreign_key_column::ForeignKeyColumn;
 use grafbase_sdk::types::DefinitionId;
 pub use ids::{
-    BackRelationId, EnumId, EnumVariantId, ForeignKeyColumnId, ForeignKeyId, ForwardRelationId, KeyColumnId, KeyId,
-    RelationId, SchemaId, TableColumnId, TableId,
+    BackRelationId, ComputedFieldId, EnumId, EnumVariantId, ForeignKeyColumnId, ForeignKeyId, ForwardRelationId,
+    KeyColumnId, KeyId, RelationId, SchemaId, TableColumnId, TableId,
 };
 use indices::Indices;
 use inflector::Inflector;
@@ -31,10 +36,11 @@ use interner::StringInterner;
 pub use key::{Key, KeyType};
 pub use key_column::KeyColumn;
 use relations::Relations;
+pub use serialization::{DeserializeError, FORMAT_VERSION};
 pub use table::{RelationKind, Table};
 pub use table_column::{IdentityGeneration, TableColumn};
 pub use r#type::{ColumnType, DatabaseType, EnumType, ScalarKind, ScalarType};
-pub use walkers::{EnumWalker, KeyWalker, RelationWalker, TableColumnWalker, TableWalker, Walker};
+pub use walkers::{ComputedFieldWalker, EnumWalker, KeyWalker, RelationWalker, TableColumnWalker, TableWalker, Walker};
 
 /// Definition of a PostgreSQL database. Contains all the
 /// tables, enums, columns, constraints etc. for us to render
@@ -47,11 +53,11 @@ pub use walkers::{EnumWalker, KeyWalker, RelationWalker, TableColumnWalker, Tabl
 /// the important call points are the table and enum iterators,
 /// and the find methods with string slices.
 ///
-/// Be aware that this structure is serialized in a cache for
-/// fast worker startup. Any changes here must be backwards-compatible.
-///
-/// There will be a test failure if something changes to alert you.
-#[derive(Debug)]
+/// Be aware that this structure is serialized for fast extension
+/// startup, see [`DatabaseDefinition::to_bytes`]. Any change to the
+/// serialized data must bump [`FORMAT_VERSION`]. There will be a test
+/// failure if something changes.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub struct DatabaseDefinition {
     /// Used for deduplicating strings in the definition.
     interner: StringInterner,
@@ -63,6 +69,8 @@ pub struct DatabaseDefinition {
     tables: Vec<Table<StringId>>,
     /// Ordered by schema id, table id and then column position.
     table_columns: Vec<TableColumn<StringId>>,
+    /// Ordered by table id, then field name.
+    computed_fields: Vec<ComputedField<StringId>>,
     /// Ordered by schema id, then enum name.
     enums: Vec<Enum<StringId>>,
     /// Ordered by schema id, enum id and finally the variant position.
@@ -94,6 +102,31 @@ pub enum Operation {
     UpdateMany(TableId),
 }
 
+impl Operation {
+    /// Whether the operation modifies data.
+    pub fn is_mutation(self) -> bool {
+        !matches!(
+            self,
+            Operation::FindOne(_) | Operation::FindMany(_) | Operation::Lookup(_)
+        )
+    }
+
+    /// The table the operation reads or modifies.
+    pub fn table_id(self) -> TableId {
+        match self {
+            Operation::FindOne(table_id)
+            | Operation::FindMany(table_id)
+            | Operation::Lookup(table_id)
+            | Operation::DeleteOne(table_id)
+            | Operation::DeleteMany(table_id)
+            | Operation::CreateOne(table_id)
+            | Operation::CreateMany(table_id)
+            | Operation::UpdateOne(table_id)
+            | Operation::UpdateMany(table_id) => table_id,
+        }
+    }
+}
+
 impl DatabaseDefinition {
     /// Creates a new database definition with the given name.
     ///
@@ -110,6 +143,7 @@ impl DatabaseDefinition {
             schemas: Vec::new(),
             tables: Vec::new(),
             table_columns: Vec::new(),
+            computed_fields: Vec::new(),
             enums: Vec::new(),
             enum_variants: Vec::new(),
             foreign_keys: Vec::new(),
@@ -167,6 +201,18 @@ impl DatabaseDefinition {
             .map(|id| self.walk(id))
     }
 
+    /// Retrieves a ComputedFieldWalker for a given definition ID.
+    pub fn computed_field_for_field_definition(
+        &self,
+        field_definition_id: DefinitionId,
+    ) -> Option<ComputedFieldWalker<'_>> {
+        self.indices
+            .field_definition_to_computed_field
+            .get(&field_definition_id)
+            .copied()
+            .map(|id| self.walk(id))
+    }
+
     /// Adds a schema to the definition.
     pub fn push_schema(&mut self, schema: String) -> SchemaId {
         let id = self.next_schema_id();
@@ -238,17 +284,62 @@ impl DatabaseDefinition {
             nullable: column.nullable,
             has_default: column.has_default,
             identity_generation: column.identity_generation,
+            generated: column.generated,
+            validation: column.validation,
             description: column.description.map(|d| self.interner.intern(&d)),
         });
 
         id
     }
 
+    /// Adds validation rules to a column, merging them with the existing rules.
+    pub fn push_column_validation(&mut self, column_id: TableColumnId, validation: ColumnValidation) {
+        self.table_columns[column_id.0 as usize].validation.merge(validation);
+    }
+
     /// Associates a column with a field definition in the GraphQL schema.
     pub fn push_column_to_definition(&mut self, definition_id: DefinitionId, column_id: TableColumnId) {
         self.indices.field_definition_to_column.insert(definition_id, column_id);
     }
 
+    /// Adds a computed field to the definition, associating it with a field definition in the
+    /// GraphQL schema.
+    pub fn push_computed_field(
+        &mut self,
+        computed_field: ComputedField<String>,
+        definition_id: Option<DefinitionId>,
+    ) -> ComputedFieldId {
+        let id = self.next_computed_field_id();
+
+        let string_id = self.interner.intern(computed_field.client_name());
+        self.indices
+            .client_computed_fields
+            .insert((computed_field.table_id(), string_id), id);
+
+        if let Some(definition_id) = definition_id {
+            self.push_computed_field_to_definition(definition_id, id);
+        }
+
+        self.computed_fields.push(ComputedField {
+            table_id: computed_field.table_id(),
+            client_name: self.interner.intern(computed_field.client_name()),
+            sql: self.interner.intern(computed_field.sql()),
+        });
+
+        id
+    }
+
+    /// Associates a computed field with a field definition in the GraphQL schema.
+    pub fn push_computed_field_to_definition(
+        &mut self,
+        definition_id: DefinitionId,
+        computed_field_id: ComputedFieldId,
+    ) {
+        self.indices
+            .field_definition_to_computed_field
+            .insert(definition_id, computed_field_id);
+    }
+
     /// Adds an enum to the definition.
     pub fn push_enum(&mut self, r#enum: Enum<String>) -> EnumId {
         let id = self.next_enum_id();
@@ -460,6 +551,14 @@ impl DatabaseDefinition {
             .copied()
     }
 
+    /// Finds the id of a computed field in a table by its client field name, if existing.
+    pub fn get_computed_field_id_for_field(&self, table_id: TableId, field_name: &str) -> Option<ComputedFieldId> {
+        self.interner
+            .lookup(field_name)
+            .and_then(|string_id| self.indices.client_computed_fields.get(&(table_id, string_id)))
+            .copied()
+    }
+
     /// Finds the column walker for a given client field name and table ID.
     pub fn find_column_for_client_field(&self, field_name: &str, id: TableId) -> Option<TableColumnWalker<'_>> {
         self.get_table_column_id_for_field(id, field_name)
@@ -518,7 +617,7 @@ impl DatabaseDefinition {
             *counter += 1;
         }
 
-        for table in &mut self.tables {
+        for (id, table) in self.tables.iter_mut().enumerate() {
             if names.get(&table.client_name()).copied().unwrap_or(0) < 2 {
                 continue;
             }
@@ -535,6 +634,9 @@ impl DatabaseDefinition {
             table.set_client_name(client_name);
             table.set_client_field_name(new_client_field_name);
             table.set_client_field_name_plural(new_client_field_name_plural);
+
+            // The SDL is rendered with the new name.
+            self.indices.client_types.insert(client_name, TableId(id as u32));
         }
 
         names.clear();
@@ -572,6 +674,10 @@ impl DatabaseDefinition {
         TableColumnId(self.table_columns.len() as u32)
     }
 
+    fn next_computed_field_id(&self) -> ComputedFieldId {
+        ComputedFieldId(self.computed_fields.len() as u32)
+    }
+
     fn next_enum_id(&self) -> EnumId {
         EnumId(self.enums.len() as u32)
     }
@@ -1,6 +1,6 @@
 use super::{BackRelationId, ForeignKey, ForeignKeyId, ForwardRelationId, TableId};
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
 pub(super) struct Relations {
     /// Ordered by table id
     pub(super) from: Vec<(TableId, ForeignKeyId)>,
@@ -0,0 +1,219 @@
+use std::fmt;
+
+use crate::DatabaseDefinition;
+
+/// The first bytes of a serialized definition.
+const MAGIC: &[u8; 4] = b"PGDD";
+
+/// The version of the serialized format. Bump this on every change to the serialized data, so
+/// definitions written by an older introspection are rejected instead of misread. The layout is
+/// snapshotted in the tests of this module, so a change fails until the snapshot is updated.
+pub const FORMAT_VERSION: u32 = 1;
+
+const HEADER_LENGTH: usize = MAGIC.len() + size_of::<u32>();
+
+/// An error loading a serialized definition.
+#[derive(Debug)]
+pub enum DeserializeError {
+    /// The data does not start with the header of a serialized definition.
+    InvalidHeader,
+    /// The definition was serialized with another version of the format.
+    VersionMismatch { found: u32, expected: u32 },
+    /// The data after the header is not a valid definition.
+    Malformed(postcard::Error),
+}
+
+impl fmt::Display for DeserializeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeserializeError::InvalidHeader => f.write_str("the data is not a serialized database definition"),
+            DeserializeError::VersionMismatch { found, expected } => {
+                write!(f, "the definition has format version {found}, expected {expected}")
+            }
+            DeserializeError::Malformed(error) => write!(f, "the definition is malformed: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for DeserializeError {}
+
+impl DatabaseDefinition {
+    /// Serializes the definition into a compact binary format, prefixed with a header holding
+    /// the [`FORMAT_VERSION`].
+    ///
+    /// The mappings from GraphQL field definitions are not included, the ids of the definitions
+    /// are only known when the gateway loads the schema.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(HEADER_LENGTH);
+
+        bytes.extend_from_slice(MAGIC);
+        bytes.extend_from_slice(&FORMAT_VERSION.to_le_bytes());
+
+        postcard::to_io(self, &mut bytes).expect("serializing into a vector cannot fail");
+
+        bytes
+    }
+
+    /// Loads a definition serialized with [`DatabaseDefinition::to_bytes`]. Fails if the
+    /// definition was written with another version of the format.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DeserializeError> {
+        if bytes.len() < HEADER_LENGTH || !bytes.starts_with(MAGIC) {
+            return Err(DeserializeError::InvalidHeader);
+        }
+
+        let (version, body) = bytes[MAGIC.len()..].split_at(size_of::<u32>());
+        let version = u32::from_le_bytes(version.try_into().expect("the header has four version bytes"));
+
+        if version != FORMAT_VERSION {
+            return Err(DeserializeError::VersionMismatch {
+                found: version,
+                expected: FORMAT_VERSION,
+            });
+        }
+
+        postcard::from_bytes(body).map_err(DeserializeError::Malformed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Write;
+
+    use crate::{
+        ColumnType, ColumnValidation, ComputedField, DatabaseDefinition, Enum, EnumType, EnumVariant, ForeignKey,
+        ForeignKeyColumn, IdentityGeneration, Key, KeyColumn, KeyType, RelationKind, ScalarKind, ScalarType, Table,
+        TableColumn,
+    };
+
+    /// A definition using every serialized part of the structure.
+    fn definition() -> DatabaseDefinition {
+        let mut definition = DatabaseDefinition::new("default".to_owned());
+        let schema_id = definition.push_schema("public".to_owned());
+
+        let enum_id = definition.push_enum(Enum::new(schema_id, "status".to_owned(), None));
+        definition.push_enum_variant(EnumVariant::new(enum_id, "active".to_owned(), None));
+        definition.push_enum_variant(EnumVariant::new(
+            enum_id,
+            "inactive".to_owned(),
+            Some("INACTIVE".to_owned()),
+        ));
+
+        let mut users = Table::new(schema_id, "users".to_owned(), RelationKind::Relation, None);
+        users.set_description("The users.".to_owned());
+        let users_id = definition.push_table(users);
+
+        let mut user_id = TableColumn::new(
+            users_id,
+            ColumnType::Scalar(ScalarType::new(ScalarKind::Int, false)),
+            "id".to_owned(),
+            None,
+        );
+        user_id.set_identity_generation(IdentityGeneration::Always);
+        let user_id = definition.push_table_column(user_id, None);
+
+        let mut name = TableColumn::new(
+            users_id,
+            ColumnType::Scalar(ScalarType::new(ScalarKind::Varchar, false)),
+            "user_name".to_owned(),
+            None,
+        );
+        name.set_validation(ColumnValidation {
+            min_length: Some(1),
+            max_length: Some(255),
+            ..Default::default()
+        });
+        name.set_description("The name of the user.".to_owned());
+        definition.push_table_column(name, None);
+
+        let mut status = TableColumn::new(
+            users_id,
+            ColumnType::Enum(EnumType::new(enum_id, false)),
+            "status".to_owned(),
+            None,
+        );
+        status.set_has_default(true);
+        definition.push_table_column(status, None);
+
+        let mut tags = TableColumn::new(
+            users_id,
+            ColumnType::Scalar(ScalarType::new(ScalarKind::Text, true)),
+            "tags".to_owned(),
+            None,
+        );
+        tags.set_nullable(true);
+        definition.push_table_column(tags, None);
+
+        let key_id = definition.push_key(Key::new(users_id, "users_pkey".to_owned(), KeyType::Primary));
+        definition.push_key_column(KeyColumn::new(key_id, user_id));
+
+        definition.push_computed_field(
+            ComputedField::new(users_id, "nameLength".to_owned(), "length(user_name)".to_owned()),
+            None,
+        );
+
+        let posts_id = definition.push_table(Table::new(schema_id, "posts".to_owned(), RelationKind::Relation, None));
+
+        let post_id = definition.push_table_column(
+            TableColumn::new(
+                posts_id,
+                ColumnType::Scalar(ScalarType::new(ScalarKind::Bigint, false)),
+                "id".to_owned(),
+                None,
+            ),
+            None,
+        );
+
+        let author_id = definition.push_table_column(
+            TableColumn::new(
+                posts_id,
+                ColumnType::Scalar(ScalarType::new(ScalarKind::Int, false)),
+                "author_id".to_owned(),
+                None,
+            ),
+            None,
+        );
+
+        let key_id = definition.push_key(Key::new(posts_id, "posts_pkey".to_owned(), KeyType::Primary));
+        definition.push_key_column(KeyColumn::new(key_id, post_id));
+
+        let (foreign_key_id, _, _) = definition.push_foreign_key(ForeignKey::new(
+            "posts_author_id_fkey".to_owned(),
+            schema_id,
+            posts_id,
+            users_id,
+        ));
+
+        definition.push_foreign_key_column(ForeignKeyColumn::new(foreign_key_id, author_id, user_id));
+
+        definition.finalize();
+        definition
+    }
+
+    fn hex_lines(bytes: &[u8]) -> String {
+        bytes.chunks(32).fold(String::new(), |mut out, chunk| {
+            for byte in chunk {
+                write!(out, "{byte:02x}").unwrap();
+            }
+
+            out.push('\n');
+            out
+        })
+    }
+
+    /// Fails on any change to the serialized layout. Bump `FORMAT_VERSION` before accepting a new
+    /// snapshot.
+    #[test]
+    fn serialized_layout() {
+        let bytes = definition().to_bytes();
+
+        insta::assert_snapshot!(hex_lines(&bytes));
+    }
+
+    #[test]
+    fn round_trip() {
+        let bytes = definition().to_bytes();
+        let definition = DatabaseDefinition::from_bytes(&bytes).unwrap();
+
+        assert_eq!(bytes, definition.to_bytes());
+    }
+}
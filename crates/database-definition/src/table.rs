@@ -2,7 +2,7 @@ use inflector::Inflector;
 
 use super::{SchemaId, StringId};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct Table<T> {
     pub(super) schema_id: SchemaId,
     pub(super) database_name: T,
@@ -13,13 +13,15 @@ pub struct Table<T> {
     pub(super) description: Option<T>,
 }
 
-#[derive(serde::Deserialize, Debug, Clone, Copy, Default, PartialEq)]
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Copy, Default, PartialEq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum RelationKind {
     #[default]
     Relation,
     View,
     MaterializedView,
+    /// A table from a foreign data wrapper, such as `postgres_fdw`.
+    ForeignTable,
 }
 
 impl RelationKind {
@@ -28,12 +30,19 @@ impl RelationKind {
             RelationKind::Relation => "RELATION",
             RelationKind::View => "VIEW",
             RelationKind::MaterializedView => "MATERIALIZED_VIEW",
+            RelationKind::ForeignTable => "FOREIGN_TABLE",
         }
     }
 
+    /// True for relations which are read-only and configured like views: views, materialized views
+    /// and foreign tables.
     pub fn is_view(self) -> bool {
         !matches!(self, RelationKind::Relation)
     }
+
+    pub fn is_foreign_table(self) -> bool {
+        matches!(self, RelationKind::ForeignTable)
+    }
 }
 
 impl<T> Copy for Table<T> where T: Copy {}
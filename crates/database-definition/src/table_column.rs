@@ -3,9 +3,9 @@ use std::str::FromStr;
 use grafbase_sdk::SdkError;
 use inflector::Inflector;
 
-use super::{ColumnType, StringId, TableId};
+use super::{ColumnType, ColumnValidation, StringId, TableId};
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize)]
 pub enum IdentityGeneration {
     /// Cannot insert a custom value to the column, always generated.
     Always,
@@ -25,7 +25,7 @@ impl FromStr for IdentityGeneration {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct TableColumn<T> {
     pub(super) table_id: TableId,
     pub(super) database_name: T,
@@ -34,6 +34,8 @@ pub struct TableColumn<T> {
     pub(super) nullable: bool,
     pub(super) has_default: bool,
     pub(super) identity_generation: Option<IdentityGeneration>,
+    pub(super) generated: bool,
+    pub(super) validation: ColumnValidation,
     pub(super) description: Option<T>,
 }
 
@@ -66,6 +68,16 @@ impl<T> TableColumn<T> {
         self.identity_generation = Some(value);
     }
 
+    /// Marks the column as generated (`GENERATED ALWAYS AS (expr)`). Generated columns
+    /// cannot be written to.
+    pub fn set_generated(&mut self, value: bool) {
+        self.generated = value;
+    }
+
+    pub fn set_validation(&mut self, value: ColumnValidation) {
+        self.validation = value;
+    }
+
     pub fn set_description(&mut self, value: T) {
         self.description = Some(value);
     }
@@ -88,6 +100,8 @@ impl TableColumn<String> {
             nullable: false,
             has_default: false,
             identity_generation: None,
+            generated: false,
+            validation: ColumnValidation::default(),
             description: None,
         }
     }
@@ -199,7 +199,7 @@ impl From<DatabaseType<'_>> for PgType {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum ColumnType {
     Scalar(ScalarType),
     Enum(EnumType),
@@ -214,7 +214,7 @@ impl ColumnType {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct EnumType {
     pub id: EnumId,
     pub is_array: bool,
@@ -226,7 +226,7 @@ impl EnumType {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct ScalarType {
     pub kind: ScalarKind,
     pub is_array: bool,
@@ -305,7 +305,7 @@ impl ScalarType {
 }
 
 /// Postgres data types supported for column definitions
-#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum ScalarKind {
     /// 2-byte signed integer, range: -32768 to +32767
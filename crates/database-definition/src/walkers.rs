@@ -1,4 +1,5 @@
 mod back_relation;
+mod computed_field;
 mod r#enum;
 mod enum_variant;
 mod foreign_key;
@@ -16,6 +17,7 @@ use crate::DatabaseDefinition;
 pub(crate) use foreign_key::ForeignKeyWalker;
 pub(crate) use foreign_key_column::ForeignKeyColumnWalker;
 
+pub use computed_field::ComputedFieldWalker;
 pub use r#enum::EnumWalker;
 pub use key::KeyWalker;
 pub use relation::RelationWalker;
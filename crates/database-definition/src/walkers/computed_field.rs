@@ -0,0 +1,26 @@
+use super::{TableWalker, Walker};
+use crate::{ComputedField, ComputedFieldId, StringId};
+
+/// A field of a table, resolved with an SQL expression over the row.
+pub type ComputedFieldWalker<'a> = Walker<'a, ComputedFieldId>;
+
+impl<'a> ComputedFieldWalker<'a> {
+    /// The table this field belongs to.
+    pub fn table(self) -> TableWalker<'a> {
+        self.walk(self.get().table_id())
+    }
+
+    /// The name of the field in the GraphQL schema.
+    pub fn client_name(self) -> &'a str {
+        self.get_name(self.get().client_name())
+    }
+
+    /// The SQL expression, evaluated with the row of the table in scope.
+    pub fn sql(self) -> &'a str {
+        self.get_name(self.get().sql())
+    }
+
+    fn get(self) -> &'a ComputedField<StringId> {
+        &self.database_definition.computed_fields[self.id.0 as usize]
+    }
+}
@@ -51,8 +51,8 @@ impl<'a> TableWalker<'a> {
     }
 
     /// Check if mutations are allowed for this table. Mutations are typically
-    /// allowed only for standard tables (`RelationKind::Relation`), not views or
-    /// materialized views.
+    /// allowed only for standard and partitioned tables (`RelationKind::Relation`), not
+    /// views, materialized views or foreign tables.
     pub fn mutations_allowed(self) -> bool {
         matches!(self.relation_kind(), RelationKind::Relation)
     }
@@ -138,7 +138,7 @@ impl<'a> TableWalker<'a> {
         self.get().description().map(|id| self.get_name(id))
     }
 
-    /// The kind of the relation: table, view, materialized view.
+    /// The kind of the relation: table, view, materialized view or foreign table.
     pub fn relation_kind(&self) -> RelationKind {
         self.get().kind()
     }
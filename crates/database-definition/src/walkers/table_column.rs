@@ -3,7 +3,7 @@ use std::borrow::Cow;
 use inflector::Inflector;
 
 use super::{TableWalker, Walker};
-use crate::{ColumnType, DatabaseType, IdentityGeneration, StringId, TableColumn, TableColumnId};
+use crate::{ColumnType, ColumnValidation, DatabaseType, IdentityGeneration, StringId, TableColumn, TableColumnId};
 
 /// Definition of a column located in a table.
 pub type TableColumnWalker<'a> = Walker<'a, TableColumnId>;
@@ -101,12 +101,27 @@ impl<'a> TableColumnWalker<'a> {
 
     /// True, if the column allows null input.
     pub fn allows_null_input(self) -> bool {
-        self.is_nullable() || self.get().has_default || self.identity_generation().is_some()
+        self.is_nullable() || self.has_default() || self.identity_generation().is_some()
     }
 
     /// True, if user can define the column value manually.
     pub fn allows_user_input(self) -> bool {
-        !matches!(self.identity_generation(), Some(IdentityGeneration::Always))
+        !self.is_generated() && !matches!(self.identity_generation(), Some(IdentityGeneration::Always))
+    }
+
+    /// True, if the column has a default value defined in the database.
+    pub fn has_default(self) -> bool {
+        self.get().has_default
+    }
+
+    /// True, if the column value is computed from other columns (`GENERATED ALWAYS AS (expr)`).
+    pub fn is_generated(self) -> bool {
+        self.get().generated
+    }
+
+    /// Input validation rules for the column, from the CHECK constraints and the column length.
+    pub fn validation(self) -> &'a ColumnValidation {
+        &self.get().validation
     }
 
     /// True, if the column is part of any key in the table (primary, unique, foreign).
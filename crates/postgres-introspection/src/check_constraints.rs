@@ -0,0 +1,360 @@
+//! Introspects single-column CHECK constraints, turning the simple ones into input validation rules.
+//!
+//! We parse the normalized output of `pg_get_constraintdef`, which looks like this:
+//!
+//! - `CHECK ((price > 0))`
+//! - `CHECK (((price >= (0)::numeric) AND (price <= (100)::numeric)))`
+//! - `CHECK ((char_length((name)::text) <= 64))`
+//! - `CHECK ((status = ANY (ARRAY['active'::text, 'inactive'::text])))`
+//!
+//! Conjunctions are split, and every part we understand adds a rule. Everything else is ignored, and
+//! left for the database to validate.
+
+use grafbase_database_definition::{ColumnValidation, DatabaseDefinition};
+use sqlx::{PgConnection, Row};
+
+pub(crate) async fn introspect_database(
+    conn: &mut PgConnection,
+    database_definition: &mut DatabaseDefinition,
+) -> anyhow::Result<()> {
+    let query = indoc::indoc! {r#"
+        SELECT pg_namespace.nspname                          AS schema,
+               pg_class.relname                              AS table_name,
+               pg_attribute.attname                          AS column_name,
+               pg_get_constraintdef(pg_constraint.oid)       AS definition
+        FROM pg_constraint
+        INNER JOIN pg_class ON pg_class.oid = pg_constraint.conrelid
+        INNER JOIN pg_namespace ON pg_namespace.oid = pg_class.relnamespace
+        INNER JOIN pg_attribute
+            ON pg_attribute.attrelid = pg_constraint.conrelid
+            AND pg_attribute.attnum = pg_constraint.conkey[1]
+        WHERE pg_constraint.contype = 'c' -- c = check constraint
+          AND cardinality(pg_constraint.conkey) = 1
+          AND pg_namespace.nspname <> ALL ( $1 )
+        ORDER BY schema, table_name, pg_constraint.conname;
+    "#};
+
+    let rows = sqlx::query(query)
+        .bind(super::blocked_schemas())
+        .fetch_all(conn)
+        .await?;
+
+    for row in rows {
+        let Some(table) = database_definition.get_table(row.get(0), row.get(1)) else {
+            continue;
+        };
+
+        let Some(column) = table.find_database_column(row.get(2)) else {
+            continue;
+        };
+
+        if column.is_array() {
+            continue;
+        }
+
+        let mut validation = parse(row.get(3), column.database_name());
+
+        // Enums are already validated by their type.
+        if column.client_base_type() != Some("String") {
+            validation.one_of = None;
+        }
+
+        if validation.is_empty() {
+            continue;
+        }
+
+        // `price > 0` on an integer column is better expressed as `min: 1`.
+        if matches!(column.client_base_type(), Some("Int" | "BigInt")) {
+            if let Some(value) = validation.exclusive_min.take_if(|value| value.fract() == 0.0) {
+                validation.merge(ColumnValidation {
+                    min: Some(value + 1.0),
+                    ..Default::default()
+                });
+            }
+
+            if let Some(value) = validation.exclusive_max.take_if(|value| value.fract() == 0.0) {
+                validation.merge(ColumnValidation {
+                    max: Some(value - 1.0),
+                    ..Default::default()
+                });
+            }
+        }
+
+        let column_id = column.id();
+        database_definition.push_column_validation(column_id, validation);
+    }
+
+    Ok(())
+}
+
+/// What a side of a comparison refers to.
+enum Operand {
+    /// The constrained column.
+    Column,
+    /// The length of the constrained column.
+    Length,
+    Number(f64),
+}
+
+/// Parses a CHECK constraint definition of the given column into validation rules.
+fn parse(definition: &str, column: &str) -> ColumnValidation {
+    let mut validation = ColumnValidation::default();
+
+    let Some(expression) = definition.trim().strip_prefix("CHECK") else {
+        return validation;
+    };
+
+    let expression = expression.trim();
+    let expression = expression.strip_suffix("NOT VALID").unwrap_or(expression).trim();
+
+    for part in split_top_level(strip_parens(expression), " AND ") {
+        if let Some(rule) = parse_condition(strip_parens(part), column) {
+            validation.merge(rule);
+        }
+    }
+
+    validation
+}
+
+fn parse_condition(condition: &str, column: &str) -> Option<ColumnValidation> {
+    if let Some((left, right)) = split_once_top_level(condition, " = ANY ") {
+        if !matches!(parse_operand(left, column)?, Operand::Column) {
+            return None;
+        }
+
+        let values = parse_string_array(strip_casts(right))?;
+
+        return Some(ColumnValidation {
+            one_of: Some(values),
+            ..Default::default()
+        });
+    }
+
+    let (left, operator, right) = [">=", "<=", "<>", "!=", ">", "<", "="]
+        .into_iter()
+        .find_map(|operator| {
+            let (left, right) = split_once_top_level(condition, &format!(" {operator} "))?;
+            Some((left, operator, right))
+        })?;
+
+    if let ("=", Some(value)) = (operator, parse_string_literal(strip_casts(right))) {
+        if !matches!(parse_operand(left, column)?, Operand::Column) {
+            return None;
+        }
+
+        return Some(ColumnValidation {
+            one_of: Some(vec![value]),
+            ..Default::default()
+        });
+    }
+
+    // Normalize to `column <operator> value`.
+    let (target, operator, value) = match (parse_operand(left, column)?, parse_operand(right, column)?) {
+        (target @ (Operand::Column | Operand::Length), Operand::Number(value)) => (target, operator, value),
+        (Operand::Number(value), target @ (Operand::Column | Operand::Length)) => {
+            let operator = match operator {
+                ">=" => "<=",
+                "<=" => ">=",
+                ">" => "<",
+                "<" => ">",
+                operator => operator,
+            };
+
+            (target, operator, value)
+        }
+        _ => return None,
+    };
+
+    let mut validation = ColumnValidation::default();
+
+    match target {
+        Operand::Column => match operator {
+            ">=" => validation.min = Some(value),
+            "<=" => validation.max = Some(value),
+            ">" => validation.exclusive_min = Some(value),
+            "<" => validation.exclusive_max = Some(value),
+            "=" => {
+                validation.min = Some(value);
+                validation.max = Some(value);
+            }
+            _ => return None,
+        },
+        Operand::Length => {
+            if value < 0.0 || value.fract() != 0.0 {
+                return None;
+            }
+
+            let value = value as u32;
+
+            match operator {
+                ">=" => validation.min_length = Some(value),
+                "<=" => validation.max_length = Some(value),
+                ">" => validation.min_length = Some(value + 1),
+                "<" => validation.max_length = Some(value.checked_sub(1)?),
+                "=" => {
+                    validation.min_length = Some(value);
+                    validation.max_length = Some(value);
+                }
+                _ => return None,
+            }
+        }
+        Operand::Number(_) => return None,
+    }
+
+    Some(validation)
+}
+
+fn parse_operand(operand: &str, column: &str) -> Option<Operand> {
+    let operand = strip_casts(operand);
+
+    for function in ["char_length(", "character_length(", "length("] {
+        if let Some(argument) = operand.strip_prefix(function).and_then(|rest| rest.strip_suffix(')')) {
+            return is_column(strip_casts(argument), column).then_some(Operand::Length);
+        }
+    }
+
+    if is_column(operand, column) {
+        return Some(Operand::Column);
+    }
+
+    let number = parse_string_literal(operand).unwrap_or_else(|| operand.to_string());
+
+    number
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|n| n.is_finite())
+        .map(Operand::Number)
+}
+
+fn is_column(operand: &str, column: &str) -> bool {
+    match operand.strip_prefix('"').and_then(|rest| rest.strip_suffix('"')) {
+        Some(quoted) => quoted.replace("\"\"", "\"") == column,
+        None => operand == column,
+    }
+}
+
+/// Parses `ARRAY['a'::text, 'b'::text]` into a list of strings. Returns `None` if any of the
+/// elements is not a string literal.
+fn parse_string_array(array: &str) -> Option<Vec<String>> {
+    let elements = array.strip_prefix("ARRAY[")?.strip_suffix(']')?;
+
+    split_top_level(elements, ", ")
+        .into_iter()
+        .map(|element| parse_string_literal(strip_casts(element)))
+        .collect()
+}
+
+/// Parses a single-quoted SQL string literal.
+fn parse_string_literal(literal: &str) -> Option<String> {
+    let inner = literal.strip_prefix('\'')?.strip_suffix('\'')?;
+
+    if inner.replace("''", "").contains('\'') {
+        return None;
+    }
+
+    Some(inner.replace("''", "'"))
+}
+
+/// Removes the type casts and enclosing parentheses, e.g. `((0)::numeric)` becomes `0`.
+fn strip_casts(mut expression: &str) -> &str {
+    loop {
+        expression = strip_parens(expression.trim());
+
+        match rsplit_once_top_level(expression, "::") {
+            Some((value, _)) => expression = value,
+            None => return expression,
+        }
+    }
+}
+
+/// Removes parentheses enclosing the whole expression.
+fn strip_parens(mut expression: &str) -> &str {
+    while let Some(inner) = expression.strip_prefix('(').and_then(|rest| rest.strip_suffix(')')) {
+        // `(a) AND (b)` starts and ends with a parenthesis, but they do not enclose the whole expression.
+        if depth_goes_negative(inner) {
+            break;
+        }
+
+        expression = inner.trim();
+    }
+
+    expression
+}
+
+fn depth_goes_negative(expression: &str) -> bool {
+    let mut depth = 0i32;
+
+    for (_, c) in top_level_chars(expression) {
+        match c {
+            '(' | '[' => depth += 1,
+            ')' | ']' => depth -= 1,
+            _ => (),
+        }
+
+        if depth < 0 {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Iterates over the characters outside of string literals, with their byte positions.
+fn top_level_chars(expression: &str) -> impl Iterator<Item = (usize, char)> + '_ {
+    let mut in_string = false;
+
+    expression.char_indices().filter(move |(_, c)| {
+        if *c == '\'' {
+            in_string = !in_string;
+            return false;
+        }
+
+        !in_string
+    })
+}
+
+/// Byte positions of the separator outside of parentheses and string literals.
+fn top_level_positions(expression: &str, separator: &str) -> Vec<usize> {
+    let mut depth = 0i32;
+    let mut positions = Vec::new();
+    let mut next_allowed = 0;
+
+    for (i, c) in top_level_chars(expression) {
+        match c {
+            '(' | '[' => depth += 1,
+            ')' | ']' => depth -= 1,
+            _ => (),
+        }
+
+        if depth == 0 && i >= next_allowed && expression[i..].starts_with(separator) {
+            positions.push(i);
+            next_allowed = i + separator.len();
+        }
+    }
+
+    positions
+}
+
+fn split_top_level<'a>(expression: &'a str, separator: &str) -> Vec<&'a str> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+
+    for position in top_level_positions(expression, separator) {
+        parts.push(expression[start..position].trim());
+        start = position + separator.len();
+    }
+
+    parts.push(expression[start..].trim());
+    parts
+}
+
+fn split_once_top_level<'a>(expression: &'a str, separator: &str) -> Option<(&'a str, &'a str)> {
+    let position = top_level_positions(expression, separator).into_iter().next()?;
+    Some((&expression[..position], &expression[position + separator.len()..]))
+}
+
+fn rsplit_once_top_level<'a>(expression: &'a str, separator: &str) -> Option<(&'a str, &'a str)> {
+    let position = top_level_positions(expression, separator).into_iter().last()?;
+    Some((&expression[..position], &expression[position + separator.len()..]))
+}
@@ -21,7 +21,9 @@ async fn introspect_sql(
     config: &Config,
     database_definition: &mut DatabaseDefinition,
 ) -> Result<(), anyhow::Error> {
-    use grafbase_database_definition::{ColumnType, EnumType, IdentityGeneration, ScalarType, TableColumn};
+    use grafbase_database_definition::{
+        ColumnType, ColumnValidation, EnumType, IdentityGeneration, ScalarType, TableColumn,
+    };
 
     let query = indoc::indoc! {r#"
         SELECT n.nspname                            AS schema,      -- Schema name from pg_namespace
@@ -38,12 +40,21 @@ async fn introspect_sql(
                -- NOTE: For standard views (relkind='v'), this might report FALSE
                -- even if the underlying column is NOT NULL. Configuration override might still be needed for views.
                NOT a.attnotnull                     AS is_nullable,
-               -- Get identity generation info from information_schema (requires LEFT JOIN)
-               isc.identity_generation,
+               -- Identity generation from pg_attribute: 'a' = ALWAYS, 'd' = BY DEFAULT, '' = not an identity column
+               CASE a.attidentity
+                   WHEN 'a' THEN 'ALWAYS'
+                   WHEN 'd' THEN 'BY DEFAULT'
+               END                                  AS identity_generation,
+               -- Generated columns (GENERATED ALWAYS AS (expr)) have a non-empty attgenerated
+               a.attgenerated <> ''                 AS is_generated,
                -- Get column description from pg_description
                d.description,
-               -- Include the relation kind (r=table, v=view, m=materialized view)
-               c.relkind
+               -- Include the relation kind (r=table, p=partitioned table, v=view, m=materialized view, f=foreign table)
+               c.relkind,
+               -- The maximum length of varchar(n) and char(n) columns, as in information_schema.columns
+               CASE WHEN t.typname IN ('varchar', 'bpchar') AND a.atttypmod > 0
+                   THEN a.atttypmod - 4
+               END                                  AS character_maximum_length
         FROM
             -- Start with relations (tables, views, mviews)
             pg_catalog.pg_class c
@@ -65,22 +76,17 @@ async fn introspect_sql(
                 LEFT JOIN
             -- Get column comments/descriptions
                 pg_catalog.pg_description d ON d.objoid = a.attrelid AND d.objsubid = a.attnum
-                LEFT JOIN
-            -- LEFT JOIN information_schema.columns primarily to get identity_generation
-            -- This join might fail for materialized views in your env, resulting in NULL for identity_generation
-                information_schema.columns isc ON isc.table_schema = n.nspname
-                AND isc.table_name = c.relname
-                AND isc.column_name = a.attname
         WHERE
-          -- Filter for Tables, Views, and Materialized Views
-            c.relkind IN ('r', 'v', 'm')
+          -- Filter for Tables, Partitioned Tables, Views, Materialized Views and Foreign Tables
+            c.relkind IN ('r', 'p', 'v', 'm', 'f')
           -- Filter out system schemas
           AND n.nspname <> ALL ( $1 )
           -- Filter out system columns (like ctid, oid, etc.)
           AND a.attnum > 0
           -- Filter out dropped columns
           AND NOT a.attisdropped
-        ORDER BY  CASE WHEN c.relkind = 'r' THEN 1 ELSE 2 END,
+        -- Must match the ordering of the tables query
+        ORDER BY  CASE WHEN c.relkind IN ('r', 'p') THEN 1 ELSE 2 END,
                  schema,
                  table_name,
                  -- Order by column number to maintain logical order
@@ -122,10 +128,15 @@ async fn introspect_sql(
 
         let column_name: &str = row.get(2);
 
+        let validation = ColumnValidation {
+            max_length: row.get::<Option<i32>, _>(13).map(|length| length as u32),
+            ..Default::default()
+        };
+
         let column_config = config
             .schemas
             .get(schema_name)
-            .and_then(|s| s.views.get(table_name))
+            .and_then(|s| s.view_config(table_name))
             .and_then(|v| v.columns.get(column_name));
 
         match column_config {
@@ -134,8 +145,9 @@ async fn introspect_sql(
                     TableColumn::new(table_id, database_type, column_name.to_string(), config.rename.clone());
 
                 column.set_nullable(config.nullable);
+                column.set_validation(validation);
 
-                if let Some(description) = row.get(10) {
+                if let Some(description) = row.get(11) {
                     column.set_description(description);
                 }
 
@@ -151,7 +163,10 @@ async fn introspect_sql(
                     column.set_identity_generation(IdentityGeneration::from_str(s)?);
                 }
 
-                if let Some(description) = row.get(10) {
+                column.set_generated(row.get(10));
+                column.set_validation(validation);
+
+                if let Some(description) = row.get(11) {
                     column.set_description(description);
                 }
 
@@ -169,7 +184,7 @@ fn check_overriden_columns(config: &Config, database_definition: &mut DatabaseDe
             bail!("Schema `{schema}` not found. Check your configuration.")
         };
 
-        for (view, view_config) in &schema_config.views {
+        for (view, view_config) in schema_config.view_configs() {
             let Some(table_id) = database_definition.get_table_id(schema_id, view) else {
                 bail!("View `{view}` not found in schema `{schema}`. Check your configuration.")
             };
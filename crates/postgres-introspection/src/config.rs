@@ -14,7 +14,7 @@ fn default_enable_mutations() -> bool {
 }
 
 /// Represents the overall configuration for the application.
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone)]
 #[serde(deny_unknown_fields)]
 pub struct Config {
     /// Determines whether mutations (write operations) are enabled for this configuration.
@@ -45,6 +45,18 @@ pub struct Config {
     /// This takes precedence over the allowlist.
     #[serde(default)]
     pub schema_denylist: Vec<String>,
+    /// If set, simple CHECK constraints and the maximum length of character columns are
+    /// rendered as validation directives on the create and update input fields.
+    #[serde(default)]
+    pub validation_directive: Option<ValidationDirectiveConfig>,
+    /// Splits the database into several subgraphs, keyed by subgraph name. If empty, the whole
+    /// database is rendered as one subgraph.
+    #[serde(default)]
+    pub subgraphs: BTreeMap<String, SubgraphConfig>,
+    /// Embeds the serialized database definition into the `@pgDatabase` directive, so the
+    /// extension does not need to build it from the SDL on start-up.
+    #[serde(default)]
+    pub precompute_definition: bool,
 }
 
 impl Config {
@@ -75,7 +87,7 @@ impl Config {
         };
 
         if table.relation_kind().is_view() {
-            let Some(view_config) = schema_config.views.get(table.database_name()) else {
+            let Some(view_config) = schema_config.view_config(table.database_name()) else {
                 return schema_config.enable_queries.unwrap_or(self.enable_queries);
             };
 
@@ -104,6 +116,14 @@ impl Config {
             && (!allowlist.is_empty() && allowlist.contains(&schema.to_string()))
     }
 
+    /// The name of the subgraph the given table is rendered to, if any.
+    pub fn subgraph_for_table(&self, schema: &str, table: &str) -> Option<&str> {
+        self.subgraphs
+            .iter()
+            .find(|(_, subgraph)| subgraph.contains_table(schema, table))
+            .map(|(name, _)| name.as_str())
+    }
+
     /// Determines whether a table is included in the GraphQL schema based on the configuration.
     /// A table is included if:
     /// 1. It's not in the schema's table_denylist
@@ -133,7 +153,7 @@ fn default_default_schema() -> String {
 }
 
 /// Represents the overrides for a database schema.
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone, Default)]
 #[serde(deny_unknown_fields)]
 pub struct SchemaConfig {
     /// Determines whether mutations (write operations) are enabled for this schema.
@@ -143,6 +163,10 @@ pub struct SchemaConfig {
     /// Configuration details for each view within the database, keyed by view name.
     #[serde(default)]
     pub views: BTreeMap<String, ViewConfig>,
+    /// Configuration details for each foreign table within the schema, keyed by table name.
+    /// Foreign tables are read-only, and configured the same way as views.
+    #[serde(default)]
+    pub foreign_tables: BTreeMap<String, ViewConfig>,
     /// Configuration overrides for each table within the schema, keyed by table name.
     #[serde(default)]
     pub tables: BTreeMap<String, TableConfig>,
@@ -158,7 +182,19 @@ pub struct SchemaConfig {
     pub table_denylist: Vec<String>,
 }
 
-#[derive(Deserialize, Debug)]
+impl SchemaConfig {
+    /// Iterates over the configuration of views and foreign tables in the schema.
+    pub(crate) fn view_configs(&self) -> impl Iterator<Item = (&String, &ViewConfig)> {
+        self.views.iter().chain(self.foreign_tables.iter())
+    }
+
+    /// The configuration for a view or a foreign table with the given name.
+    pub(crate) fn view_config(&self, name: &str) -> Option<&ViewConfig> {
+        self.views.get(name).or_else(|| self.foreign_tables.get(name))
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, Default)]
 #[serde(deny_unknown_fields)]
 pub struct TableConfig {
     /// Determines whether mutations (write operations) are enabled for this table.
@@ -171,10 +207,34 @@ pub struct TableConfig {
     /// Configuration for derived fields in this table, keyed by derive name.
     #[serde(default)]
     pub derives: BTreeMap<String, DeriveConfig>,
+    /// Fields resolved with an SQL expression over the row, keyed by field name.
+    #[serde(default)]
+    pub computed_fields: BTreeMap<String, ComputedFieldConfig>,
+}
+
+/// A field resolved with an SQL expression, such as `first_name || ' ' || last_name`.
+///
+/// The expression is added to the SELECT list of the queries. Unqualified column names refer to the
+/// row, which is also available with the name of the table for correlated subqueries:
+///
+/// ```toml
+/// [schemas.public.tables.posts.computed_fields.commentCount]
+/// type = "Int!"
+/// sql = "(SELECT count(*) FROM comments WHERE comments.post_id = posts.id)"
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct ComputedFieldConfig {
+    /// The GraphQL type of the field, e.g. `String!`.
+    pub r#type: String,
+    /// The SQL expression returning the value of the field.
+    pub sql: String,
+    /// An optional description for the field.
+    pub description: Option<String>,
 }
 
 /// Represents the configuration settings for a specific database relation (e.g., a view).
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone)]
 #[serde(deny_unknown_fields)]
 pub struct ViewConfig {
     /// Determines whether mutations (write operations) are enabled for this table.
@@ -254,7 +314,7 @@ pub struct ViewConfig {
 ///
 /// The composition will combine these User types into a single User type without extra
 /// configuration.
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone)]
 #[serde(deny_unknown_fields)]
 pub struct DeriveConfig {
     /// The type the derived field points to.
@@ -264,7 +324,7 @@ pub struct DeriveConfig {
 }
 
 /// Represents the configuration for a specific column within a view.
-#[derive(Deserialize, Debug, Default)]
+#[derive(Deserialize, Debug, Clone, Default)]
 #[serde(deny_unknown_fields)]
 pub struct ColumnConfig {
     /// Specifies whether the column can contain null values. Defaults to `true`.
@@ -279,8 +339,42 @@ pub struct ColumnConfig {
     pub description: Option<String>,
 }
 
+/// A group of schemas and tables rendered as a separate subgraph.
+///
+/// Foreign keys between the groups are rendered as `@derive` fields pointing to the entity of the
+/// other subgraph, instead of relations.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct SubgraphConfig {
+    /// Schemas with all of their tables and views in this subgraph.
+    #[serde(default)]
+    pub schemas: Vec<String>,
+    /// Single tables and views in this subgraph, in the form `schema.table`.
+    #[serde(default)]
+    pub tables: Vec<String>,
+}
+
+impl SubgraphConfig {
+    /// True, if the given table belongs to this subgraph.
+    pub fn contains_table(&self, schema: &str, table: &str) -> bool {
+        self.schemas.iter().any(|s| s == schema)
+            || self.tables.iter().any(|t| t.split_once('.') == Some((schema, table)))
+    }
+}
+
+/// Configures the directive used to render input validation rules.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct ValidationDirectiveConfig {
+    /// The name of the directive, without the `@`. Defaults to `constraint`.
+    #[serde(default = "default_validation_directive_name")]
+    pub name: String,
+    /// The URL the directive is imported from with a `@link` directive.
+    pub url: String,
+}
+
 /// Represents the configuration for a relationship defined within a view.
-#[derive(Deserialize, Debug, Default)]
+#[derive(Deserialize, Debug, Clone, Default)]
 #[serde(deny_unknown_fields)]
 pub struct RelationConfig {
     /// The name of the schema containing the referenced table. Defaults to the `public`.
@@ -294,6 +388,11 @@ pub struct RelationConfig {
     pub referenced_columns: Vec<String>,
 }
 
+// Helper function for default validation directive name
+fn default_validation_directive_name() -> String {
+    String::from("constraint")
+}
+
 // Helper function for default nullable value
 fn default_nullable() -> bool {
     true
@@ -46,6 +46,8 @@ async fn introspect_sql(
                        JOIN pg_constraint ON pg_constraint.conrelid = pg_class.oid
                        JOIN pg_namespace ON pg_class.relnamespace = pg_namespace.oid
               WHERE pg_constraint.contype = 'f' -- f = foreign key constraint
+                -- skip the copies of partitioned table constraints in each partition
+                AND pg_constraint.conparentid = 0
               ORDER BY conkey_idx) "constraint"
 
         JOIN pg_attribute parent_attribute
@@ -134,7 +136,7 @@ fn introspect_overrides(config: &Config, database_definition: &mut DatabaseDefin
             bail!("Could not find {schema} schema from the database. Check your configuration.")
         };
 
-        for (view_name, view_config) in &schema_config.views {
+        for (view_name, view_config) in schema_config.view_configs() {
             override_relations(
                 database_definition,
                 constrained_schema_id,
@@ -93,7 +93,7 @@ fn introspect_overrides(config: &Config, database_definition: &mut DatabaseDefin
             bail!("Schema `{schema}` not found. Check your configuration.")
         };
 
-        for (view, view_config) in &schema_config.views {
+        for (view, view_config) in schema_config.view_configs() {
             let Some(table_id) = database_definition.get_table_id(schema_id, view) else {
                 bail!("View `{view}` not found in schema `{schema}`. Check your configuration.")
             };
@@ -1,38 +1,94 @@
-use config::Config;
+use std::collections::BTreeMap;
+
+use anyhow::bail;
+use config::{Config, SubgraphConfig};
 use grafbase_database_definition::DatabaseDefinition;
 
+mod check_constraints;
 mod columns;
 pub mod config;
 mod enums;
 mod foreign_keys;
 mod keys;
+mod precompute;
 mod render;
 mod schemas;
+mod subgraphs;
 mod tables;
 
 /// Introspects a PostgreSQL database schema.
 ///
 /// This function connects to a PostgreSQL database and retrieves information about
-/// its schema including schemas, enums, tables, columns, foreign keys, and primary keys.
+/// its schema including schemas, enums, tables, columns, foreign keys, primary keys and check constraints.
 /// It then renders this information as an SDL (Schema Definition Language) string.
 ///
+/// If the configuration defines subgraphs, use [`introspect_subgraphs`] instead.
+///
 /// # Arguments
 ///
 /// * `conn` - A mutable reference to an active PostgreSQL connection.
 /// * `opts` - Options for customizing the introspection process.
 pub async fn introspect(conn: &mut sqlx::PgConnection, config: Config) -> anyhow::Result<String> {
+    if !config.subgraphs.is_empty() {
+        bail!("The configuration defines subgraphs, which must be introspected into a directory.");
+    }
+
+    let database_definition = introspect_definition(conn, &config, None).await?;
+
+    render::to_sdl(database_definition, &config)
+}
+
+/// Introspects a PostgreSQL database schema, rendering each of the subgraphs defined in the
+/// configuration as a separate SDL string. Returns the SDL strings keyed by subgraph name.
+///
+/// Foreign keys between tables of different subgraphs are rendered as `@derive` fields, pointing
+/// to the entity defined in the other subgraph.
+///
+/// # Arguments
+///
+/// * `conn` - A mutable reference to an active PostgreSQL connection.
+/// * `opts` - Options for customizing the introspection process.
+pub async fn introspect_subgraphs(
+    conn: &mut sqlx::PgConnection,
+    config: Config,
+) -> anyhow::Result<BTreeMap<String, String>> {
+    if config.subgraphs.is_empty() {
+        bail!("The configuration does not define any subgraphs.");
+    }
+
+    let database_definition = introspect_definition(conn, &config, None).await?;
+    subgraphs::validate(&database_definition, &config)?;
+
+    let mut result = BTreeMap::new();
+
+    for (name, subgraph) in &config.subgraphs {
+        let subgraph_config = subgraphs::config_for_subgraph(&database_definition, &config, name, subgraph);
+        let subgraph_definition = introspect_definition(conn, &subgraph_config, Some(subgraph)).await?;
+
+        result.insert(name.clone(), render::to_sdl(subgraph_definition, &subgraph_config)?);
+    }
+
+    Ok(result)
+}
+
+async fn introspect_definition(
+    conn: &mut sqlx::PgConnection,
+    config: &Config,
+    subgraph: Option<&SubgraphConfig>,
+) -> anyhow::Result<DatabaseDefinition> {
     let mut database_definition = DatabaseDefinition::new(config.database_name.clone());
 
-    schemas::introspect_database(conn, &config, &mut database_definition).await?;
+    schemas::introspect_database(conn, config, &mut database_definition).await?;
     enums::introspect_database(conn, &mut database_definition).await?;
-    tables::introspect_database(conn, &config, &mut database_definition).await?;
-    columns::introspect_database(conn, &config, &mut database_definition).await?;
-    foreign_keys::introspect_database(conn, &config, &mut database_definition).await?;
-    keys::introspect_database(conn, &config, &mut database_definition).await?;
+    tables::introspect_database(conn, config, subgraph, &mut database_definition).await?;
+    columns::introspect_database(conn, config, &mut database_definition).await?;
+    foreign_keys::introspect_database(conn, config, &mut database_definition).await?;
+    keys::introspect_database(conn, config, &mut database_definition).await?;
+    check_constraints::introspect_database(conn, &mut database_definition).await?;
 
     database_definition.finalize();
 
-    render::to_sdl(database_definition, &config)
+    Ok(database_definition)
 }
 
 /// A list of schemas to filter out automatically on every introspection.
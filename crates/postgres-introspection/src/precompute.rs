@@ -0,0 +1,63 @@
+use grafbase_database_definition::{ComputedField, DatabaseDefinition};
+use inflector::Inflector;
+
+use crate::config::Config;
+
+/// Adds the mappings from client names the extension builds when reading the SDL, so the
+/// serialized definition can be loaded without reading the directives of every field.
+///
+/// Must mirror the introspection of the SDL in the extension, with the names rendered into the SDL.
+pub(crate) fn prepare(database_definition: &mut DatabaseDefinition, config: &Config) {
+    let mut relations = Vec::new();
+    let mut keys = Vec::new();
+
+    for table in database_definition.tables() {
+        for relation in table.relations() {
+            relations.push((table.id(), relation.client_field_name(), relation.id()));
+        }
+
+        for key in table.keys() {
+            let field_names: Vec<_> = key
+                .columns()
+                .map(|column| column.table_column().client_name().to_string())
+                .collect();
+
+            // Compound keys are also found with the field names joined together.
+            let compound_name = (field_names.len() > 1).then(|| field_names.join("_").to_camel_case());
+
+            for field_name in field_names.into_iter().chain(compound_name) {
+                keys.push((table.id(), field_name, key.id()));
+            }
+        }
+    }
+
+    for (table_id, field_name, relation_id) in relations {
+        database_definition.push_client_name_relation_mapping(table_id, &field_name, relation_id);
+    }
+
+    for (table_id, field_name, key_id) in keys {
+        database_definition.push_client_field_key_mapping(&field_name, table_id, key_id);
+    }
+
+    let mut computed_fields = Vec::new();
+
+    for (schema_name, schema) in &config.schemas {
+        for (table_name, table) in &schema.tables {
+            let Some(table_id) = database_definition.get_table(schema_name, table_name).map(|t| t.id()) else {
+                continue;
+            };
+
+            for (field_name, computed_field) in &table.computed_fields {
+                computed_fields.push((table_id, field_name.clone(), computed_field.sql.clone()));
+            }
+        }
+    }
+
+    // The computed fields of a table must be next to each other, ordered by table id.
+    computed_fields.sort_by_key(|(table_id, _, _)| *table_id);
+
+    for (table_id, field_name, sql) in computed_fields {
+        let computed_field = ComputedField::new(table_id, field_name, sql);
+        database_definition.push_computed_field(computed_field, None);
+    }
+}
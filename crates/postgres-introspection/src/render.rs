@@ -1,4 +1,5 @@
 mod ast;
+mod computed_fields;
 mod derives;
 mod enums;
 mod input_types;
@@ -24,9 +25,12 @@ struct EnabledOperations {
     has_mutations: bool,
 }
 
-pub fn to_sdl(database_definition: DatabaseDefinition, config: &Config) -> anyhow::Result<String> {
+pub fn to_sdl(mut database_definition: DatabaseDefinition, config: &Config) -> anyhow::Result<String> {
+    if config.precompute_definition {
+        crate::precompute::prepare(&mut database_definition, config);
+    }
+
     let database_name = config.database_name.as_str();
-    let extension_url = config.extension_url.as_str();
     let default_schema = config.default_schema.as_str();
 
     let mut rendered = Schema::new();
@@ -43,13 +47,21 @@ pub fn to_sdl(database_definition: DatabaseDefinition, config: &Config) -> anyho
     };
 
     let Derives { types, fields } = derives::generate(&database_definition, config)?;
+    let computed_fields = computed_fields::generate(&database_definition, config)?;
 
     scalars::render(&mut rendered);
-    schema_directives::render(&database_definition, extension_url, &mut rendered);
+    schema_directives::render(&database_definition, config, &mut rendered);
     input_types::render(&database_definition, config, &mut operations, prefix, &mut rendered);
     enums::render(&database_definition, default_schema, &operations, &mut rendered);
     output_types::render(&database_definition, config, operations, types, &mut rendered);
-    tables::render(&database_definition, default_schema, operations, fields, &mut rendered);
+    tables::render(
+        &database_definition,
+        default_schema,
+        operations,
+        fields,
+        computed_fields,
+        &mut rendered,
+    );
 
     if operations.has_queries {
         query::render(&database_definition, config, prefix, &mut rendered);
@@ -3,7 +3,7 @@ use std::{
     fmt::{self, Write},
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ArgumentValue<'a> {
     String(Cow<'a, str>),
     Constant(Cow<'a, str>),
@@ -55,7 +55,25 @@ impl fmt::Display for ArgumentValue<'_> {
     }
 }
 
-#[derive(Debug)]
+/// Escapes a value to be rendered inside a GraphQL string.
+pub fn escape_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct Argument<'a> {
     pub(super) name: Cow<'a, str>,
     pub(super) value: ArgumentValue<'a>,
@@ -112,7 +130,7 @@ impl fmt::Display for Argument<'_> {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Directive<'a> {
     name: &'a str,
     arguments: Vec<Argument<'a>>,
@@ -26,6 +26,14 @@ impl<'a> Type<'a> {
         !self.fields.is_empty()
     }
 
+    pub fn has_field(&self, name: &str) -> bool {
+        self.fields.iter().any(|field| field.name == name)
+    }
+
+    pub fn has_directive(&self, directive: &Directive<'a>) -> bool {
+        self.directives.contains(directive)
+    }
+
     pub fn push_directive(&mut self, directive: Directive<'a>) {
         self.directives.push(directive);
     }
@@ -0,0 +1,76 @@
+use std::collections::BTreeMap;
+
+use anyhow::bail;
+use grafbase_database_definition::DatabaseDefinition;
+
+use crate::config::Config;
+
+use super::ast::{
+    directive::{Argument, ArgumentValue, Directive, escape_string},
+    field::Field,
+};
+
+/// Validates the computed fields in the configuration, ensuring they do not clash with the
+/// columns or relations of the table. Returns the fields keyed by the client name of the table.
+pub(super) fn generate<'a>(
+    database_definition: &'a DatabaseDefinition,
+    config: &'a Config,
+) -> anyhow::Result<BTreeMap<&'a str, Vec<Field<'a>>>> {
+    let mut computed_fields = BTreeMap::new();
+
+    for (schema_name, schema) in config.schemas.iter() {
+        for (table_name, table) in schema.tables.iter() {
+            if table.computed_fields.is_empty() {
+                continue;
+            }
+
+            let Some(database_table) = database_definition.get_table(schema_name, table_name) else {
+                bail!("Table {table_name} not found");
+            };
+
+            let mut fields = Vec::new();
+
+            for (field_name, computed_field) in table.computed_fields.iter() {
+                // The name is also used as a column alias in the generated SQL.
+                if database_table.find_database_column_for_field(field_name).is_some()
+                    || database_table.find_database_column(field_name).is_some()
+                {
+                    bail!(
+                        "Computed field {field_name} clashes with a column in type {}",
+                        database_table.client_name()
+                    );
+                }
+
+                if database_table
+                    .relations()
+                    .any(|relation| relation.client_field_name() == *field_name)
+                {
+                    bail!(
+                        "Computed field {field_name} clashes with a relation in type {}",
+                        database_table.client_name()
+                    );
+                }
+
+                let mut field = Field::new(field_name, &computed_field.r#type);
+
+                let mut directive = Directive::new("pgComputed");
+                directive.push_argument(Argument::new(
+                    "sql",
+                    ArgumentValue::String(escape_string(&computed_field.sql).into()),
+                ));
+
+                field.push_directive(directive);
+
+                if let Some(ref description) = computed_field.description {
+                    field.set_description(description);
+                }
+
+                fields.push(field);
+            }
+
+            computed_fields.insert(database_table.client_name(), fields);
+        }
+    }
+
+    Ok(computed_fields)
+}
@@ -109,8 +109,11 @@ fn add_field_to_type<'a>(
             );
         };
 
-        let field = Field::new(referenced, column.client_type(None).unwrap());
-        r#type.push_field(field);
+        // Several tables can derive the same entity
+        if !r#type.has_field(referenced) {
+            let field = Field::new(referenced, column.client_type(None).unwrap());
+            r#type.push_field(field);
+        }
 
         if !field_is_required && !column.is_nullable() {
             field_is_required = true;
@@ -123,7 +126,10 @@ fn add_field_to_type<'a>(
     let mut directive = Directive::new("key");
 
     directive.push_argument(Argument::string("fields", fields));
-    r#type.push_directive(directive);
+
+    if !r#type.has_directive(&directive) {
+        r#type.push_directive(directive);
+    }
 
     Ok(field_is_required)
 }
@@ -1,12 +1,16 @@
 use std::borrow::Cow;
 
-use grafbase_database_definition::{DatabaseDefinition, TableWalker};
+use grafbase_database_definition::{DatabaseDefinition, TableColumnWalker, TableWalker};
 use inflector::Inflector;
 use itertools::Itertools;
 
 use crate::{
     config::Config,
-    render::ast::{directive::Directive, field::Field, input::InputType},
+    render::ast::{
+        directive::{Argument, ArgumentValue, Directive, escape_string},
+        field::Field,
+        input::InputType,
+    },
 };
 
 use super::{EnabledOperations, ast::schema::Schema};
@@ -75,7 +79,7 @@ static NUMERIC_SCALARS: &[&str] = &["BigInt", "Float", "Decimal", "Int", "Money"
 
 pub fn render<'a>(
     database_definition: &'a DatabaseDefinition,
-    config: &Config,
+    config: &'a Config,
     operations: &mut EnabledOperations,
     prefix: Option<&str>,
     rendered: &mut Schema<'a>,
@@ -93,15 +97,22 @@ pub fn render<'a>(
         if config.mutations_allowed(table) {
             operations.has_mutations = true;
 
-            render_create_input(rendered, prefix, table);
-            render_update_input(rendered, prefix, table);
+            let validation_directive = config.validation_directive.as_ref().map(|config| config.name.as_str());
+
+            render_create_input(rendered, prefix, validation_directive, table);
+            render_update_input(rendered, prefix, validation_directive, table);
         }
     }
 
     render_scalar_inputs(database_definition, operations, rendered);
 }
 
-fn render_update_input<'a>(rendered: &mut Schema<'a>, prefix: Option<&str>, table: TableWalker<'a>) {
+fn render_update_input<'a>(
+    rendered: &mut Schema<'a>,
+    prefix: Option<&str>,
+    validation_directive: Option<&'a str>,
+    table: TableWalker<'a>,
+) {
     let type_name = match prefix {
         Some(prefix) => format!("{}_{}_UpdateInput", prefix, table.client_name()).to_pascal_case(),
         None => format!("{}UpdateInput", table.client_name()),
@@ -115,10 +126,45 @@ fn render_update_input<'a>(rendered: &mut Schema<'a>, prefix: Option<&str>, tabl
             continue;
         }
 
-        let type_name = if column.is_array() {
-            format!("{}ArrayUpdateInput", column.client_base_type().unwrap())
-        } else {
-            format!("{}UpdateInput", column.client_base_type().unwrap())
+        let scalar = column.client_base_type().unwrap();
+
+        // The shared update inputs can't carry the rules of one column, so a column with rules
+        // gets an update input of its own, validating the values it sets.
+        let type_name = match validation_directive.and_then(|name| render_validation(name, column)) {
+            Some(directive) => {
+                let type_name = match prefix {
+                    Some(prefix) => format!(
+                        "{}_{}_{}_FieldUpdateInput",
+                        prefix,
+                        table.client_name(),
+                        column.client_name()
+                    )
+                    .to_pascal_case(),
+                    None => format!(
+                        "{}{}FieldUpdateInput",
+                        table.client_name(),
+                        column.client_name().to_pascal_case()
+                    ),
+                };
+
+                let mut input = if column.is_array() {
+                    create_array_update_type(scalar, type_name.clone(), Some(directive))
+                } else {
+                    create_scalar_update_input(scalar, type_name.clone(), Some(directive))
+                };
+
+                input.set_description(format!(
+                    "Update input for the {} field of {}.",
+                    column.client_name(),
+                    table.client_name()
+                ));
+
+                rendered.push_input(input);
+
+                type_name
+            }
+            None if column.is_array() => format!("{scalar}ArrayUpdateInput"),
+            None => format!("{scalar}UpdateInput"),
         };
 
         mutation_input.push_field({
@@ -131,7 +177,12 @@ fn render_update_input<'a>(rendered: &mut Schema<'a>, prefix: Option<&str>, tabl
     rendered.push_input(mutation_input);
 }
 
-fn render_create_input<'a>(rendered: &mut Schema<'a>, prefix: Option<&str>, table: TableWalker<'a>) {
+fn render_create_input<'a>(
+    rendered: &mut Schema<'a>,
+    prefix: Option<&str>,
+    validation_directive: Option<&'a str>,
+    table: TableWalker<'a>,
+) {
     let type_name = match prefix {
         Some(prefix) => format!("{}_{}_CreateInput", prefix, table.client_name()).to_pascal_case(),
         None => format!("{}CreateInput", table.client_name()),
@@ -161,6 +212,11 @@ fn render_create_input<'a>(rendered: &mut Schema<'a>, prefix: Option<&str>, tabl
         mutation_input.push_field({
             let mut field = Field::new(column.client_name(), type_name);
             field.set_description(format!("Set field value for {}", column.client_name()));
+
+            if let Some(directive) = validation_directive.and_then(|name| render_validation(name, column)) {
+                field.push_directive(directive);
+            }
+
             field
         });
     }
@@ -227,6 +283,61 @@ fn render_many_lookup_input<'a>(rendered: &mut Schema<'a>, table: TableWalker<'a
     rendered.push_input(input);
 }
 
+/// Renders the validation rules of the column, if it has any.
+fn render_validation<'a>(name: &'a str, column: TableColumnWalker<'a>) -> Option<Directive<'a>> {
+    let validation = column.validation();
+
+    if validation.is_empty() {
+        return None;
+    }
+
+    let mut directive = Directive::new(name);
+
+    let numbers = [
+        ("min", validation.min),
+        ("max", validation.max),
+        ("exclusiveMin", validation.exclusive_min),
+        ("exclusiveMax", validation.exclusive_max),
+    ];
+
+    for (argument, value) in numbers {
+        if let Some(value) = value {
+            directive.push_argument(Argument::constant(argument, render_number(value)));
+        }
+    }
+
+    let lengths = [
+        ("minLength", validation.min_length),
+        ("maxLength", validation.max_length),
+    ];
+
+    for (argument, value) in lengths {
+        if let Some(value) = value {
+            directive.push_argument(Argument::constant(argument, value.to_string()));
+        }
+    }
+
+    if let Some(ref values) = validation.one_of {
+        let values = values
+            .iter()
+            .map(|value| ArgumentValue::String(escape_string(value).into()))
+            .collect();
+
+        directive.push_argument(Argument::new("oneOf", ArgumentValue::Array(values)));
+    }
+
+    Some(directive)
+}
+
+/// Renders whole numbers without a fraction, so they are valid for integer fields.
+fn render_number(value: f64) -> String {
+    if value.fract() == 0.0 && value.abs() < i64::MAX as f64 {
+        (value as i64).to_string()
+    } else {
+        value.to_string()
+    }
+}
+
 fn render_filter_input<'a>(rendered: &mut Schema<'a>, table: TableWalker<'a>) {
     let filter_name = format!("{}FilterInput", table.client_name());
     let mut filter_input = InputType::new(filter_name.clone());
@@ -425,8 +536,12 @@ fn render_scalar_inputs<'a>(
         rendered.push_input(create_scalar_filters(scalar));
 
         if operations.has_mutations {
-            rendered.push_input(create_scalar_update_input(scalar));
-            rendered.push_input(create_array_update_type(scalar));
+            rendered.push_input(create_scalar_update_input(scalar, format!("{scalar}UpdateInput"), None));
+            rendered.push_input(create_array_update_type(
+                scalar,
+                format!("{scalar}ArrayUpdateInput"),
+                None,
+            ));
         }
     }
 
@@ -441,43 +556,56 @@ fn render_scalar_inputs<'a>(
         rendered.push_input(create_scalar_array_filters(r#enum.client_name(), array_type));
 
         if operations.has_mutations {
-            rendered.push_input(create_scalar_update_input(r#enum.client_name()));
-            rendered.push_input(create_array_update_type(r#enum.client_name()));
+            let name = r#enum.client_name();
+
+            rendered.push_input(create_scalar_update_input(name, format!("{name}UpdateInput"), None));
+            rendered.push_input(create_array_update_type(name, format!("{name}ArrayUpdateInput"), None));
         }
     }
 }
 
-fn create_array_update_type(scalar: &str) -> InputType<'_> {
-    let input_type_name = format!("{scalar}ArrayUpdateInput");
-    let mut input = InputType::new(input_type_name.clone());
+/// The update input of an array column. The validation directive applies to the values set,
+/// appended and prepended.
+fn create_array_update_type<'a>(
+    scalar: &'a str,
+    input_type_name: String,
+    validation: Option<Directive<'a>>,
+) -> InputType<'a> {
+    let mut input = InputType::new(input_type_name);
 
     input.set_description(format!("Update input for {scalar} array type."));
     input.push_directive(Directive::new("oneOf"));
 
-    input.push_field({
-        let mut field = Field::new("set", format!("[{scalar}]"));
-        field.set_description("Replaces the value of a field with the specified value.");
-        field
-    });
+    let operations = [
+        ("set", "Replaces the value of a field with the specified value."),
+        ("append", "Append an array value to the column."),
+        ("prepend", "Prepend an array value to the column."),
+    ];
 
-    input.push_field({
-        let mut field = Field::new("append", format!("[{scalar}]"));
-        field.set_description("Append an array value to the column.");
-        field
-    });
+    for (operation, description) in operations {
+        input.push_field({
+            let mut field = Field::new(operation, format!("[{scalar}]"));
+            field.set_description(description);
 
-    input.push_field({
-        let mut field = Field::new("prepend", format!("[{scalar}]"));
-        field.set_description("Prepend an array value to the column.");
-        field
-    });
+            if let Some(ref directive) = validation {
+                field.push_directive(directive.clone());
+            }
+
+            field
+        });
+    }
 
     input
 }
 
-fn create_scalar_update_input(scalar: &str) -> InputType<'_> {
-    let input_type_name = format!("{scalar}UpdateInput");
-    let mut input = InputType::new(input_type_name.clone());
+/// The update input of a scalar column. The validation directive applies to the value set: the
+/// results of the other operations are left for the database to check.
+fn create_scalar_update_input<'a>(
+    scalar: &'a str,
+    input_type_name: String,
+    validation: Option<Directive<'a>>,
+) -> InputType<'a> {
+    let mut input = InputType::new(input_type_name);
 
     input.set_description(format!("Update input for {scalar} type."));
     input.push_directive(Directive::new("oneOf"));
@@ -485,6 +613,11 @@ fn create_scalar_update_input(scalar: &str) -> InputType<'_> {
     input.push_field({
         let mut field = Field::new("set", scalar);
         field.set_description("Replaces the value of a field with the specified value.");
+
+        if let Some(directive) = validation {
+            field.push_directive(directive);
+        }
+
         field
     });
 
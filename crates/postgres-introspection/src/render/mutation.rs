@@ -51,6 +51,8 @@ fn render_delete_mutations<'a>(mutation: &mut Type<'a>, prefix: Option<&str>, ta
         argument
     });
 
+    field.push_argument(dry_run_argument());
+
     mutation.push_field(field);
 
     // delete many
@@ -74,6 +76,8 @@ fn render_delete_mutations<'a>(mutation: &mut Type<'a>, prefix: Option<&str>, ta
         argument
     });
 
+    field.push_argument(dry_run_argument());
+
     mutation.push_field(field);
 }
 
@@ -106,6 +110,8 @@ fn render_update_mutations<'a>(mutation: &mut Type<'a>, prefix: Option<&str>, ta
         argument
     });
 
+    field.push_argument(dry_run_argument());
+
     mutation.push_field(field);
 
     // update many
@@ -145,6 +151,8 @@ fn render_update_mutations<'a>(mutation: &mut Type<'a>, prefix: Option<&str>, ta
         argument
     });
 
+    field.push_argument(dry_run_argument());
+
     mutation.push_field(field);
 }
 
@@ -202,3 +210,13 @@ fn render_create_mutations<'a>(mutation: &mut Type<'a>, prefix: Option<&str>, ta
 
     mutation.push_field(field);
 }
+
+fn dry_run_argument<'a>() -> Argument<'a> {
+    let mut argument = Argument::constant("dryRun", "Boolean");
+
+    argument.set_description(
+        "Runs the mutation in a transaction that is rolled back, returning the rows it would change per table",
+    );
+
+    argument
+}
@@ -26,6 +26,10 @@ pub fn render<'a>(
         render_page_info(rendered);
     }
 
+    if operations.has_mutations {
+        render_affected_table(rendered);
+    }
+
     for table in database_definition.tables().filter(|t| t.allowed_in_client()) {
         if config.mutations_allowed(table) {
             let returning_type = render_returning_type(rendered, table);
@@ -98,6 +102,7 @@ fn render_mutation_types<'a>(rendered: &mut Schema<'a>, table: TableWalker<'a>,
             format!("Return type when creating one {}", table.client_name()),
             returning_type.clone(),
             table.client_name(),
+            false,
         ),
         (
             format!("{}CreateManyPayload", table.client_name()),
@@ -107,12 +112,14 @@ fn render_mutation_types<'a>(rendered: &mut Schema<'a>, table: TableWalker<'a>,
             ),
             format!("[{returning_type}]!"),
             table.client_name(),
+            false,
         ),
         (
             format!("{}UpdatePayload", table.client_name()),
             format!("Return type when updating one {}", table.client_name()),
             returning_type.clone(),
             table.client_name(),
+            true,
         ),
         (
             format!("{}UpdateManyPayload", table.client_name()),
@@ -122,12 +129,14 @@ fn render_mutation_types<'a>(rendered: &mut Schema<'a>, table: TableWalker<'a>,
             ),
             format!("[{returning_type}]!"),
             table.client_name(),
+            true,
         ),
         (
             format!("{}DeletePayload", table.client_name()),
             format!("Return type when deleting one {}", table.client_name()),
             returning_type.clone(),
             table.client_name(),
+            true,
         ),
         (
             format!("{}DeleteManyPayload", table.client_name()),
@@ -137,10 +146,11 @@ fn render_mutation_types<'a>(rendered: &mut Schema<'a>, table: TableWalker<'a>,
             ),
             format!("[{returning_type}]!"),
             table.client_name(),
+            true,
         ),
     ];
 
-    for (type_name, type_description, returning_type, target) in mutations {
+    for (type_name, type_description, returning_type, target, supports_dry_run) in mutations {
         let mut r#type = Type::new(type_name);
 
         r#type.set_description(type_description);
@@ -165,6 +175,17 @@ fn render_mutation_types<'a>(rendered: &mut Schema<'a>, table: TableWalker<'a>,
             field
         });
 
+        if supports_dry_run {
+            r#type.push_field({
+                let mut field = Field::new("affectedTables", "[AffectedTable!]");
+                field.push_directive(Directive::new("shareable"));
+                field.set_description(
+                    "The rows a dry run would change per table, including the changes by foreign key actions",
+                );
+                field
+            });
+        }
+
         rendered.push_type(r#type);
     }
 }
@@ -235,3 +256,33 @@ fn render_page_info(rendered: &mut Schema<'_>) {
 
     rendered.push_type(r#type);
 }
+
+fn render_affected_table(rendered: &mut Schema<'_>) {
+    let mut r#type = Type::new("AffectedTable");
+
+    r#type.set_description("The rows a dry run of a mutation would change in one table");
+    r#type.push_directive(Directive::new("shareable"));
+
+    r#type.push_field({
+        let mut field = Field::new("table", "String!");
+        field.set_description("The name of the type of the table");
+        field.push_directive(Directive::new("shareable"));
+        field
+    });
+
+    r#type.push_field({
+        let mut field = Field::new("updated", "Int!");
+        field.set_description("The number of rows updated in the table");
+        field.push_directive(Directive::new("shareable"));
+        field
+    });
+
+    r#type.push_field({
+        let mut field = Field::new("deleted", "Int!");
+        field.set_description("The number of rows deleted from the table");
+        field.push_directive(Directive::new("shareable"));
+        field
+    });
+
+    rendered.push_type(r#type);
+}
@@ -1,18 +1,23 @@
+use base64::{Engine, prelude::BASE64_STANDARD};
 use grafbase_database_definition::DatabaseDefinition;
 
+use crate::config::Config;
+
 use super::ast::{
     directive::{Argument, ArgumentValue, Directive},
     schema::Schema,
 };
 
-pub fn render<'a>(database_definition: &'a DatabaseDefinition, extension_url: &'a str, rendered: &mut Schema<'a>) {
+pub fn render<'a>(database_definition: &'a DatabaseDefinition, config: &'a Config, rendered: &mut Schema<'a>) {
+    let extension_url = config.extension_url.as_str();
+
     rendered.push_directive({
         let mut directive = Directive::new("link");
 
         directive.render_multiline();
         directive.push_argument(Argument::string("url", extension_url));
 
-        let import = vec![
+        let mut import = vec![
             ArgumentValue::String("@pgDatabase".into()),
             ArgumentValue::String("@pgTable".into()),
             ArgumentValue::String("@pgColumn".into()),
@@ -37,6 +42,16 @@ pub fn render<'a>(database_definition: &'a DatabaseDefinition, extension_url: &'
             ArgumentValue::String("PgRelationType".into()),
         ];
 
+        let has_computed_fields = config
+            .schemas
+            .values()
+            .flat_map(|schema| schema.tables.values())
+            .any(|table| !table.computed_fields.is_empty());
+
+        if has_computed_fields {
+            import.insert(3, ArgumentValue::String("@pgComputed".into()));
+        }
+
         directive.push_argument(Argument::new(
             "import",
             ArgumentValue::MultiLineArray {
@@ -93,10 +108,33 @@ pub fn render<'a>(database_definition: &'a DatabaseDefinition, extension_url: &'
         directive
     });
 
+    if let Some(validation_directive) = &config.validation_directive {
+        rendered.push_directive({
+            let mut directive = Directive::new("link");
+            directive.render_multiline();
+
+            directive.push_argument(Argument::string("url", &validation_directive.url));
+
+            directive.push_argument(Argument::new(
+                "import",
+                ArgumentValue::Array(vec![ArgumentValue::String(
+                    format!("@{}", validation_directive.name).into(),
+                )]),
+            ));
+
+            directive
+        });
+    }
+
     rendered.push_directive({
         let mut directive = Directive::new("pgDatabase");
         directive.push_argument(Argument::string("name", database_definition.name()));
 
+        if config.precompute_definition {
+            let definition = BASE64_STANDARD.encode(database_definition.to_bytes());
+            directive.push_argument(Argument::string("definition", definition));
+        }
+
         directive
     });
 }
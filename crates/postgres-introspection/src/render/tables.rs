@@ -20,6 +20,7 @@ pub fn render<'a>(
     default_schema: &str,
     operations: EnabledOperations,
     mut derived_fields: BTreeMap<&'a str, Vec<Field<'a>>>,
+    mut computed_fields: BTreeMap<&'a str, Vec<Field<'a>>>,
     rendered: &mut Schema<'a>,
 ) {
     for table in database_definition.tables().filter(|t| t.allowed_in_client()) {
@@ -30,6 +31,12 @@ pub fn render<'a>(
             render_column(&mut render, table, column);
         }
 
+        if let Some(fields) = computed_fields.remove(table.client_name()) {
+            fields.into_iter().for_each(|field| {
+                render.push_field(field);
+            });
+        }
+
         // we do not do nested mutations, so we do not need to render relation
         // fields if no queries are allowed
         if operations.has_queries {
@@ -0,0 +1,113 @@
+//! Splits the database into several subgraphs. Every subgraph is introspected separately with its
+//! own configuration, where the foreign keys pointing to tables of other subgraphs are replaced
+//! with derives to the entities of those subgraphs.
+
+use anyhow::bail;
+use grafbase_database_definition::DatabaseDefinition;
+use indexmap::IndexMap;
+
+use crate::config::{Config, DeriveConfig, SubgraphConfig};
+
+/// Ensures every table is rendered to at most one subgraph.
+pub(crate) fn validate(database_definition: &DatabaseDefinition, config: &Config) -> anyhow::Result<()> {
+    for table in database_definition.tables() {
+        let mut subgraphs = config
+            .subgraphs
+            .iter()
+            .filter(|(_, subgraph)| subgraph.contains_table(table.schema(), table.database_name()))
+            .map(|(name, _)| name.as_str());
+
+        if let (Some(first), Some(second)) = (subgraphs.next(), subgraphs.next()) {
+            bail!(
+                "Table {}.{} is in subgraphs {first} and {second}. A table can be in only one subgraph.",
+                table.schema(),
+                table.database_name()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Creates the configuration for introspecting one subgraph. The `database_definition` must contain the
+/// tables of all subgraphs.
+pub(crate) fn config_for_subgraph(
+    database_definition: &DatabaseDefinition,
+    config: &Config,
+    subgraph_name: &str,
+    subgraph: &SubgraphConfig,
+) -> Config {
+    let mut subgraph_config = config.clone();
+
+    // Configuration for tables of other subgraphs would point to tables missing from this one.
+    for (schema_name, schema) in subgraph_config.schemas.iter_mut() {
+        schema
+            .tables
+            .retain(|table_name, _| subgraph.contains_table(schema_name, table_name));
+
+        schema
+            .views
+            .retain(|view_name, _| subgraph.contains_table(schema_name, view_name));
+
+        schema
+            .foreign_tables
+            .retain(|table_name, _| subgraph.contains_table(schema_name, table_name));
+
+        let relations = schema
+            .tables
+            .values_mut()
+            .map(|table| &mut table.relations)
+            .chain(schema.views.values_mut().map(|view| &mut view.relations))
+            .chain(schema.foreign_tables.values_mut().map(|table| &mut table.relations));
+
+        for relations in relations {
+            relations
+                .retain(|_, relation| subgraph.contains_table(&relation.referenced_schema, &relation.referenced_table));
+        }
+    }
+
+    for table in database_definition.tables() {
+        if table.relation_kind().is_view() || !subgraph.contains_table(table.schema(), table.database_name()) {
+            continue;
+        }
+
+        for relation in table.relations().filter(|relation| relation.is_referencing_side()) {
+            let referenced_table = relation.referenced_table();
+
+            // Tables in no subgraph are not rendered anywhere, so there is no entity to point to.
+            match config.subgraph_for_table(referenced_table.schema(), referenced_table.database_name()) {
+                Some(name) if name != subgraph_name => (),
+                _ => continue,
+            }
+
+            let fields: IndexMap<_, _> = relation
+                .referencing_columns()
+                .zip(relation.referenced_columns())
+                .map(|(referencing, referenced)| {
+                    (
+                        referencing.client_name().to_string(),
+                        referenced.client_name().to_string(),
+                    )
+                })
+                .collect();
+
+            let derive = DeriveConfig {
+                referenced_type: referenced_table.client_name().to_string(),
+                fields,
+            };
+
+            subgraph_config
+                .schemas
+                .entry(table.schema().to_string())
+                .or_default()
+                .tables
+                .entry(table.database_name().to_string())
+                .or_default()
+                .derives
+                .entry(relation.client_field_name())
+                .or_insert(derive);
+        }
+    }
+
+    subgraph_config
+}
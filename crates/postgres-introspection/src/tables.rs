@@ -1,10 +1,11 @@
-use crate::config::Config;
+use crate::config::{Config, SubgraphConfig};
 use grafbase_database_definition::{DatabaseDefinition, RelationKind, Table};
 use sqlx::{PgConnection, Row};
 
 pub(crate) async fn introspect_database(
     conn: &mut PgConnection,
     config: &Config,
+    subgraph: Option<&SubgraphConfig>,
     database_definition: &mut DatabaseDefinition,
 ) -> anyhow::Result<()> {
     let query = indoc::indoc! {r#"
@@ -16,10 +17,13 @@ pub(crate) async fn introspect_database(
         FROM pg_class
         INNER JOIN pg_namespace ON pg_namespace.oid = pg_class.relnamespace
         LEFT JOIN pg_description ON pg_description.objoid = pg_class.oid AND pg_description.objsubid = 0
-        WHERE pg_class.relkind IN ('r', 'v', 'm')
+        WHERE pg_class.relkind IN ('r', 'p', 'v', 'm', 'f')
         AND pg_namespace.nspname <> ALL ( $1 )
+        -- partitions are queried through their partitioned parent table
+        AND NOT pg_class.relispartition
         ORDER BY CASE pg_class.relkind
             WHEN 'r' THEN 1
+            WHEN 'p' THEN 1
             ELSE 2
           END,
           schema,
@@ -40,17 +44,24 @@ pub(crate) async fn introspect_database(
         };
 
         let kind = match row.get::<i8, _>(3) as u8 as char {
-            'r' => RelationKind::Relation,
+            // A partitioned table behaves like a normal table from the client perspective.
+            'r' | 'p' => RelationKind::Relation,
             'v' => RelationKind::View,
             'm' => RelationKind::MaterializedView,
+            'f' => RelationKind::ForeignTable,
             _ => unreachable!(),
         };
 
         // Skip tables that are not in the allowlist or are in the denylist
-        if !kind.is_view() && !config.is_table_included(&schema_name, &table_name) {
+        if (!kind.is_view() || kind.is_foreign_table()) && !config.is_table_included(&schema_name, &table_name) {
             continue;
         };
 
+        // Skip tables rendered to other subgraphs
+        if subgraph.is_some_and(|subgraph| !subgraph.contains_table(&schema_name, &table_name)) {
+            continue;
+        }
+
         let mut table = Table::<String>::new(schema_id, table_name, kind, None);
 
         if let Some(description) = row.get(2) {
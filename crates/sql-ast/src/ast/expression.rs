@@ -36,6 +36,19 @@ impl<'a> Expression<'a> {
         }
     }
 
+    /// A comma-separated list of parameters, each with an optional type cast for enum values.
+    pub fn many_typed_value(values: Vec<(DatabaseValue, Option<Cow<'a, str>>)>) -> Self {
+        Self {
+            kind: ExpressionKind::ManyParameterized(
+                values
+                    .into_iter()
+                    .map(|(value, enum_type)| ParameterizedValue { value, enum_type })
+                    .collect(),
+            ),
+            alias: None,
+        }
+    }
+
     pub fn enum_value(value: DatabaseValue, enum_type: impl Into<Cow<'a, str>>) -> Self {
         Self {
             kind: ExpressionKind::Parameterized(ParameterizedValue {
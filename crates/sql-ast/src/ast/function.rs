@@ -24,6 +24,7 @@ mod sum;
 mod to_jsonb;
 mod unnest;
 mod upper;
+mod window;
 
 pub use aggregate_to_string::*;
 pub use array_position::*;
@@ -51,6 +52,7 @@ pub use sum::*;
 pub use to_jsonb::*;
 pub use unnest::*;
 pub use upper::*;
+pub use window::*;
 
 use super::{Alias, Aliasable};
 
@@ -102,6 +104,9 @@ pub(crate) enum FunctionType<'a> {
     Decode(Decode<'a>),
     ConvertFrom(ConvertFrom<'a>),
     Replace(Replace<'a>),
+    WindowFunction(WindowFunction<'a>),
+    Window(Window<'a>),
+    Filter(Filter<'a>),
 }
 
 impl<'a> Aliasable<'a> for Function<'a> {
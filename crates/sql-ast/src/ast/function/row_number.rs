@@ -15,7 +15,7 @@ impl<'a> RowNumber<'a> {
     where
         T: IntoOrderDefinition<'a>,
     {
-        self.over.ordering.append(value.into_order_definition());
+        self.over = self.over.order_by(value);
         self
     }
 
@@ -24,7 +24,7 @@ impl<'a> RowNumber<'a> {
     where
         T: Into<Column<'a>>,
     {
-        self.over.partitioning.push(partition.into());
+        self.over = self.over.partition_by(partition);
         self
     }
 }
@@ -0,0 +1,170 @@
+use crate::ast::{ConditionTree, Expression, Over};
+
+use super::{Function, FunctionType};
+
+#[derive(Debug, Clone)]
+/// A function computed over a window of rows, `function OVER (...)`.
+pub struct Window<'a> {
+    pub(crate) function: Box<Function<'a>>,
+    pub(crate) over: Over<'a>,
+}
+
+#[derive(Debug, Clone)]
+/// An aggregate function over the rows matching the conditions,
+/// `function FILTER (WHERE ...)`.
+pub struct Filter<'a> {
+    pub(crate) function: Box<Function<'a>>,
+    pub(crate) conditions: ConditionTree<'a>,
+}
+
+#[derive(Debug, Clone)]
+/// A function computing a value from the window of the row. Needs a window defined with
+/// [`Function::over`].
+pub(crate) enum WindowFunction<'a> {
+    Rank,
+    DenseRank,
+    PercentRank,
+    CumeDist,
+    Ntile(u32),
+    Lag(OffsetValue<'a>),
+    Lead(OffsetValue<'a>),
+    FirstValue(Box<Expression<'a>>),
+    LastValue(Box<Expression<'a>>),
+    NthValue(Box<Expression<'a>>, u32),
+}
+
+#[derive(Debug, Clone)]
+/// The value of a row before or after the current row in the window.
+pub(crate) struct OffsetValue<'a> {
+    pub(crate) expression: Box<Expression<'a>>,
+    pub(crate) offset: u32,
+    pub(crate) default: Option<Box<Expression<'a>>>,
+}
+
+impl<'a> Function<'a> {
+    /// Compute the function over a window of rows. Works with the window functions and the
+    /// aggregate functions.
+    pub fn over(mut self, over: Over<'a>) -> Function<'a> {
+        let alias = self.alias.take();
+
+        Function {
+            r#type: FunctionType::Window(Window {
+                function: Box::new(self),
+                over,
+            }),
+            alias,
+        }
+    }
+
+    /// Aggregate only the rows matching the conditions. Works with the aggregate functions.
+    pub fn filter<T>(mut self, conditions: T) -> Function<'a>
+    where
+        T: Into<ConditionTree<'a>>,
+    {
+        // The filter goes before the window.
+        if let FunctionType::Window(window) = self.r#type {
+            self.r#type = FunctionType::Window(Window {
+                function: Box::new(window.function.filter(conditions)),
+                over: window.over,
+            });
+
+            return self;
+        }
+
+        let alias = self.alias.take();
+
+        Function {
+            r#type: FunctionType::Filter(Filter {
+                function: Box::new(self),
+                conditions: conditions.into(),
+            }),
+            alias,
+        }
+    }
+}
+
+impl<'a> From<WindowFunction<'a>> for Function<'a> {
+    fn from(value: WindowFunction<'a>) -> Self {
+        Self {
+            r#type: FunctionType::WindowFunction(value),
+            alias: None,
+        }
+    }
+}
+
+/// The rank of the row in the window ordering, with gaps after equal rows.
+pub fn rank<'a>() -> Function<'a> {
+    WindowFunction::Rank.into()
+}
+
+/// The rank of the row in the window ordering, without gaps after equal rows.
+pub fn dense_rank<'a>() -> Function<'a> {
+    WindowFunction::DenseRank.into()
+}
+
+/// The relative rank of the row, from 0 to 1.
+pub fn percent_rank<'a>() -> Function<'a> {
+    WindowFunction::PercentRank.into()
+}
+
+/// The fraction of the rows preceding or equal to the row, from 0 to 1.
+pub fn cume_dist<'a>() -> Function<'a> {
+    WindowFunction::CumeDist.into()
+}
+
+/// Divides the window into the given number of buckets, returning the bucket of the row.
+pub fn ntile<'a>(buckets: u32) -> Function<'a> {
+    WindowFunction::Ntile(buckets).into()
+}
+
+/// The value of the row the offset before the current row, or the default if there is no such
+/// row.
+pub fn lag<'a, E>(expression: E, offset: u32, default: Option<Expression<'a>>) -> Function<'a>
+where
+    E: Into<Expression<'a>>,
+{
+    WindowFunction::Lag(OffsetValue {
+        expression: Box::new(expression.into()),
+        offset,
+        default: default.map(Box::new),
+    })
+    .into()
+}
+
+/// The value of the row the offset after the current row, or the default if there is no such
+/// row.
+pub fn lead<'a, E>(expression: E, offset: u32, default: Option<Expression<'a>>) -> Function<'a>
+where
+    E: Into<Expression<'a>>,
+{
+    WindowFunction::Lead(OffsetValue {
+        expression: Box::new(expression.into()),
+        offset,
+        default: default.map(Box::new),
+    })
+    .into()
+}
+
+/// The value of the first row of the window frame.
+pub fn first_value<'a, E>(expression: E) -> Function<'a>
+where
+    E: Into<Expression<'a>>,
+{
+    WindowFunction::FirstValue(Box::new(expression.into())).into()
+}
+
+/// The value of the last row of the window frame.
+pub fn last_value<'a, E>(expression: E) -> Function<'a>
+where
+    E: Into<Expression<'a>>,
+{
+    WindowFunction::LastValue(Box::new(expression.into())).into()
+}
+
+/// The value of the nth row of the window frame, counting from 1.
+pub fn nth_value<'a, E>(expression: E, n: u32) -> Function<'a>
+where
+    E: Into<Expression<'a>>,
+{
+    WindowFunction::NthValue(Box::new(expression.into()), n).into()
+}
@@ -1,4 +1,4 @@
-use crate::ast::{Column, Ordering};
+use crate::ast::{Column, IntoOrderDefinition, Ordering};
 
 #[derive(Debug, Default, Clone)]
 /// Determines the partitioning and ordering of a rowset before the associated
@@ -6,10 +6,87 @@ use crate::ast::{Column, Ordering};
 pub struct Over<'a> {
     pub(crate) ordering: Ordering<'a>,
     pub(crate) partitioning: Vec<Column<'a>>,
+    pub(crate) frame: Option<Frame>,
 }
 
-impl Over<'_> {
+impl<'a> Over<'a> {
     pub fn is_empty(&self) -> bool {
-        self.ordering.is_empty() && self.partitioning.is_empty()
+        self.ordering.is_empty() && self.partitioning.is_empty() && self.frame.is_none()
     }
+
+    /// Define the order of the rows in the window.
+    pub fn order_by<T>(mut self, value: T) -> Self
+    where
+        T: IntoOrderDefinition<'a>,
+    {
+        self.ordering.append(value.into_order_definition());
+        self
+    }
+
+    /// Define the partitioning of the rows. The window of a row has only the rows of its
+    /// partition.
+    pub fn partition_by<T>(mut self, partition: T) -> Self
+    where
+        T: Into<Column<'a>>,
+    {
+        self.partitioning.push(partition.into());
+        self
+    }
+
+    /// Limit the window to the rows between the bounds, counted in rows.
+    pub fn rows(self, start: FrameBound, end: FrameBound) -> Self {
+        self.frame(FrameUnits::Rows, start, end)
+    }
+
+    /// Limit the window to the rows between the bounds, counted in the value of the ordering
+    /// column.
+    pub fn range(self, start: FrameBound, end: FrameBound) -> Self {
+        self.frame(FrameUnits::Range, start, end)
+    }
+
+    /// Limit the window to the rows between the bounds, counted in groups of rows with equal
+    /// ordering values.
+    pub fn groups(self, start: FrameBound, end: FrameBound) -> Self {
+        self.frame(FrameUnits::Groups, start, end)
+    }
+
+    fn frame(mut self, units: FrameUnits, start: FrameBound, end: FrameBound) -> Self {
+        self.frame = Some(Frame { units, start, end });
+        self
+    }
+}
+
+/// The frame clause of a window, limiting the rows of the window to the ones around the
+/// current row.
+#[derive(Debug, Clone, Copy)]
+pub struct Frame {
+    pub(crate) units: FrameUnits,
+    pub(crate) start: FrameBound,
+    pub(crate) end: FrameBound,
+}
+
+/// How the frame bounds are counted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameUnits {
+    /// `ROWS`
+    Rows,
+    /// `RANGE`
+    Range,
+    /// `GROUPS`
+    Groups,
+}
+
+/// The start or the end of a frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameBound {
+    /// `UNBOUNDED PRECEDING`, the first row of the partition.
+    UnboundedPreceding,
+    /// `n PRECEDING`
+    Preceding(u32),
+    /// `CURRENT ROW`
+    CurrentRow,
+    /// `n FOLLOWING`
+    Following(u32),
+    /// `UNBOUNDED FOLLOWING`, the last row of the partition.
+    UnboundedFollowing,
 }
@@ -1,4 +1,94 @@
 //! Visitor for reading an abstract SQL syntax tree, generating the query and
 //! gathering parameters in the right order.
 
+pub mod mysql;
 pub mod postgres;
+pub mod sqlite;
+
+mod visitor;
+
+use std::fmt;
+
+pub use mysql::Mysql;
+pub use postgres::Postgres;
+pub use sqlite::Sqlite;
+
+use grafbase_sdk::host_io::postgres::types::DatabaseValue;
+
+use crate::ast::Query;
+
+/// Renders the abstract syntax tree into a statement of one SQL dialect.
+pub trait Renderer {
+    /// The rendered statement, holding the SQL string and the bound parameters.
+    type Output;
+
+    /// Renders an AST node into a statement of the dialect. Fails if the tree uses a construct
+    /// the dialect has no equivalent for.
+    fn render<'a>(ast: impl Into<Query<'a>>) -> Result<Self::Output, Unsupported>;
+}
+
+/// A statement rendered into an SQL string with `?` placeholders, and the parameters bound to
+/// them in order.
+#[derive(Debug, Clone)]
+pub struct Statement {
+    sql: String,
+    parameters: Vec<DatabaseValue>,
+}
+
+impl Statement {
+    pub(crate) fn new(sql: String, parameters: Vec<DatabaseValue>) -> Self {
+        Self { sql, parameters }
+    }
+
+    /// The SQL string of the statement.
+    pub fn sql(&self) -> &str {
+        &self.sql
+    }
+
+    /// The parameters bound to the statement.
+    pub fn parameters(&self) -> &[DatabaseValue] {
+        &self.parameters
+    }
+
+    /// Splits the statement into the SQL string and the parameters.
+    pub fn into_parts(self) -> (String, Vec<DatabaseValue>) {
+        (self.sql, self.parameters)
+    }
+}
+
+impl fmt::Display for Statement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.sql)
+    }
+}
+
+/// A construct of the abstract syntax tree the dialect cannot render.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Unsupported {
+    dialect: &'static str,
+    feature: &'static str,
+}
+
+impl Unsupported {
+    pub(crate) fn new(dialect: &'static str, feature: &'static str) -> Self {
+        Self { dialect, feature }
+    }
+
+    /// The name of the dialect.
+    pub fn dialect(&self) -> &'static str {
+        self.dialect
+    }
+
+    /// The construct the dialect cannot render.
+    pub fn feature(&self) -> &'static str {
+        self.feature
+    }
+}
+
+impl fmt::Display for Unsupported {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} is not supported in {}", self.feature, self.dialect)
+    }
+}
+
+impl std::error::Error for Unsupported {}
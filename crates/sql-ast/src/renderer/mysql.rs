@@ -0,0 +1,502 @@
+//! Renders the abstract syntax tree into MySQL statements.
+//!
+//! The JSON functions map to the MySQL JSON functions, and lateral joins need MySQL 8.0.14 or
+//! newer. MySQL has no `RETURNING` clause, so modifying statements returning data fail the
+//! rendering with an [`Unsupported`] error, as do constructs like `row_to_json` and arrays.
+
+use core::fmt;
+use std::fmt::Write;
+
+use grafbase_sdk::host_io::postgres::types::DatabaseValue;
+
+use super::{
+    Renderer, Statement, Unsupported,
+    visitor::{self, Visitor},
+};
+use crate::ast::{
+    self, ArrayPosition, Cast, Concat, ConditionTree, ConvertFrom, Decode, Encode, EncodeFormat, Expression,
+    ExpressionKind, Frame, FrameUnits, Insert, JoinData, JsonAgg, JsonBuildArray, JsonBuildObject, JsonExtract,
+    JsonExtractArrayElem, JsonType, JsonUnquote, OnConflict, Order, Ordering, ParameterizedValue, RowToJson,
+    SqlStringPattern, ToJsonb, Unnest,
+};
+
+/// The MySQL dialect.
+#[derive(Debug, Default)]
+pub struct Mysql {
+    sql: String,
+    parameters: Vec<DatabaseValue>,
+    unsupported: Option<Unsupported>,
+}
+
+impl Renderer for Mysql {
+    type Output = Statement;
+
+    fn render<'a>(ast: impl Into<ast::Query<'a>>) -> Result<Self::Output, Unsupported> {
+        let mut renderer = Mysql::default();
+        renderer.visit_query(ast.into());
+
+        match renderer.unsupported {
+            Some(unsupported) => Err(unsupported),
+            None => Ok(Statement::new(renderer.sql, renderer.parameters)),
+        }
+    }
+}
+
+impl Visitor for Mysql {
+    const C_BACKTICK_OPEN: &'static str = "`";
+    const C_BACKTICK_CLOSE: &'static str = "`";
+
+    fn write<D: fmt::Display>(&mut self, s: D) {
+        self.sql
+            .write_fmt(format_args!("{s}"))
+            .expect("we ran out of memory or something else why write failed");
+    }
+
+    fn substitute_value(&mut self, value: ParameterizedValue<'_>) {
+        // Enums are strings in MySQL, and it has no arrays.
+        if value.value.to_list().is_some() {
+            self.unsupported("An array parameter");
+        }
+
+        self.parameters.push(value.value);
+        self.write("?");
+    }
+
+    fn unsupported(&mut self, feature: &'static str) {
+        if self.unsupported.is_none() {
+            self.unsupported = Some(Unsupported::new("MySQL", feature));
+        }
+    }
+
+    fn visit_limit_and_offset(&mut self, limit: Option<u32>, offset: Option<u32>) {
+        match (limit, offset) {
+            (Some(limit), Some(offset)) => {
+                self.write(" LIMIT ");
+                self.substitute_parameter(limit);
+
+                self.write(" OFFSET ");
+                self.substitute_parameter(offset);
+            }
+            (None, Some(offset)) => {
+                // MySQL needs a limit for the offset, the documented way is the largest value.
+                self.write(" LIMIT 18446744073709551615 OFFSET ");
+                self.substitute_parameter(offset);
+            }
+            (Some(limit), None) => {
+                self.write(" LIMIT ");
+                self.substitute_parameter(limit);
+            }
+            (None, None) => (),
+        }
+    }
+
+    fn visit_insert(&mut self, insert: Insert<'_>) {
+        // MySQL skips the conflicting rows with `IGNORE`, instead of a conflict clause.
+        if let Some(OnConflict::DoNothing) = insert.on_conflict {
+            self.write("INSERT IGNORE ");
+        } else {
+            self.write("INSERT ");
+        }
+
+        if let Some(table) = insert.table.clone() {
+            self.write("INTO ");
+            self.visit_table(table, true);
+        }
+
+        match insert.values {
+            Expression {
+                kind: ExpressionKind::Row(row),
+                ..
+            } if row.values.is_empty() => self.write(" () VALUES ()"),
+            values => self.visit_insert_values(insert.columns, values),
+        }
+
+        if let Some(on_conflict) = insert.on_conflict {
+            self.visit_on_conflict(on_conflict);
+        }
+
+        if let Some(returning) = insert.returning
+            && !returning.is_empty()
+        {
+            self.visit_returning(returning.into_iter().map(|r| r.into()).collect());
+        };
+    }
+
+    fn visit_on_conflict(&mut self, on_conflict: OnConflict<'_>) {
+        match on_conflict {
+            OnConflict::DoNothing => (),
+            // MySQL updates the row on a conflict with any unique key, the constraint columns
+            // are implied.
+            OnConflict::Update(update, _) => {
+                if update.conditions.is_some() {
+                    self.unsupported("A conditional update on a conflict");
+                }
+
+                self.write(" ON DUPLICATE KEY UPDATE ");
+                self.visit_update_set(update);
+            }
+        }
+    }
+
+    fn visit_returning(&mut self, _: Vec<Expression<'_>>) {
+        self.unsupported("RETURNING");
+    }
+
+    fn visit_filter(&mut self, _: ConditionTree<'_>) {
+        self.unsupported("FILTER");
+    }
+
+    fn visit_frame(&mut self, frame: Frame) {
+        match frame.units {
+            FrameUnits::Rows => self.write("ROWS"),
+            FrameUnits::Range => self.write("RANGE"),
+            FrameUnits::Groups => self.unsupported("A GROUPS frame"),
+        }
+
+        self.write(" BETWEEN ");
+        self.visit_frame_bound(frame.start);
+        self.write(" AND ");
+        self.visit_frame_bound(frame.end);
+    }
+
+    fn visit_ordering(&mut self, ordering: Ordering<'_>) {
+        let len = ordering.0.len();
+
+        for (i, (value, ordering)) in ordering.0.into_iter().enumerate() {
+            // MySQL sorts nulls first in ascending order and last in descending order. The other
+            // way around needs sorting by nullity first.
+            let direction = match ordering {
+                Some(Order::Asc) | Some(Order::AscNullsFirst) => " ASC",
+                Some(Order::Desc) | Some(Order::DescNullsLast) => " DESC",
+                Some(Order::AscNullsLast) => {
+                    self.visit_expression(value.clone());
+                    self.write(" IS NULL, ");
+
+                    " ASC"
+                }
+                Some(Order::DescNullsFirst) => {
+                    self.visit_expression(value.clone());
+                    self.write(" IS NOT NULL, ");
+
+                    " DESC"
+                }
+                None => "",
+            };
+
+            self.visit_expression(value);
+            self.write(direction);
+
+            if i < (len - 1) {
+                self.write(", ");
+            }
+        }
+    }
+
+    fn visit_concat(&mut self, concat: Concat<'_>) {
+        // The `||` operator is a logical or in MySQL.
+        let len = concat.exprs.len();
+        self.write("CONCAT");
+
+        self.surround_with("(", ")", |s| {
+            for (i, expr) in concat.exprs.into_iter().enumerate() {
+                s.visit_expression(expr);
+
+                if i < (len - 1) {
+                    s.write(", ");
+                }
+            }
+        });
+    }
+
+    fn visit_aggregate_to_string(&mut self, value: Expression<'_>) {
+        self.write("GROUP_CONCAT");
+        self.surround_with("(", ")", |s| s.visit_expression(value));
+    }
+
+    fn visit_json_extract(&mut self, json_extract: JsonExtract<'_>) {
+        let path = visitor::json_path(json_extract.path);
+
+        if json_extract.extract_as_string {
+            self.write("JSON_UNQUOTE(");
+        }
+
+        self.write("JSON_EXTRACT");
+        self.surround_with("(", ")", |s| {
+            s.visit_expression(*json_extract.column);
+            s.write(", ");
+            s.substitute_parameter(path);
+        });
+
+        if json_extract.extract_as_string {
+            self.write(")");
+        }
+    }
+
+    fn visit_json_unquote(&mut self, json_unquote: JsonUnquote<'_>) {
+        self.write("JSON_UNQUOTE");
+        self.surround_with("(", ")", |s| s.visit_expression(*json_unquote.expr));
+    }
+
+    fn visit_array_contains(&mut self, left: Expression<'_>, right: Expression<'_>, not: bool) {
+        if not {
+            self.write("( NOT ");
+        }
+
+        self.write("JSON_CONTAINS");
+        self.surround_with("(", ")", |s| {
+            s.visit_expression(left);
+            s.write(", ");
+            s.visit_expression(right);
+        });
+
+        if not {
+            self.write(" )");
+        }
+    }
+
+    fn visit_array_contained(&mut self, left: Expression<'_>, right: Expression<'_>, not: bool) {
+        self.visit_array_contains(right, left, not);
+    }
+
+    fn visit_array_overlaps(&mut self, left: Expression<'_>, right: Expression<'_>) {
+        self.write("JSON_OVERLAPS");
+        self.surround_with("(", ")", |s| {
+            s.visit_expression(left);
+            s.write(", ");
+            s.visit_expression(right);
+        });
+    }
+
+    fn visit_json_extract_array_item(&mut self, extract: JsonExtractArrayElem<'_>) {
+        self.write("JSON_UNQUOTE(JSON_EXTRACT");
+        self.surround_with("(", ")", |s| {
+            s.visit_expression(*extract.expr);
+            s.write(", ");
+            s.write("'$[");
+            s.write(extract.index);
+            s.write("]'");
+        });
+        self.write(")");
+    }
+
+    fn visit_json_type_equals(&mut self, left: Expression<'_>, json_type: JsonType<'_>, not: bool) {
+        self.write("JSON_TYPE");
+        self.surround_with("(", ")", |s| s.visit_expression(left));
+
+        // MySQL has several number types.
+        let types = match json_type {
+            JsonType::Array => "('ARRAY')",
+            JsonType::Boolean => "('BOOLEAN')",
+            JsonType::Number => "('INTEGER', 'UNSIGNED INTEGER', 'DOUBLE', 'DECIMAL')",
+            JsonType::Object => "('OBJECT')",
+            JsonType::String => "('STRING')",
+            JsonType::Null => "('NULL')",
+            JsonType::ColumnRef(column) => {
+                self.write(if not { " != " } else { " = " });
+                self.write("JSON_TYPE");
+                self.surround_with("(", ")", |s| s.visit_column(*column));
+
+                return;
+            }
+        };
+
+        self.write(if not { " NOT IN " } else { " IN " });
+        self.write(types);
+    }
+
+    fn visit_json_delete_at_path(&mut self, _: Expression<'_>, _: Expression<'_>) {
+        self.unsupported("Deleting a JSON path with an array of keys");
+    }
+
+    fn visit_like(&mut self, left: Expression<'_>, right: Expression<'_>) {
+        self.visit_expression(left);
+        self.write(" LIKE ");
+        self.visit_expression(right);
+    }
+
+    fn visit_not_like(&mut self, left: Expression<'_>, right: Expression<'_>) {
+        self.visit_expression(left);
+        self.write(" NOT LIKE ");
+        self.visit_expression(right);
+    }
+
+    fn visit_is_not_distinct_from(&mut self, left: Expression<'_>, right: Expression<'_>) {
+        self.visit_expression(left);
+        self.write(" <=> ");
+        self.visit_expression(right);
+    }
+
+    fn visit_any_selection(&mut self, left: Expression<'_>, right: Expression<'_>) {
+        // Without arrays, only a subquery can be on the right side.
+        if !matches!(right.kind, ExpressionKind::Selection(_)) {
+            self.unsupported("ANY with an array");
+        }
+
+        self.visit_expression(left);
+        self.write(" IN ");
+        self.visit_expression(right);
+    }
+
+    fn visit_not_all_selection(&mut self, left: Expression<'_>, right: Expression<'_>) {
+        if !matches!(right.kind, ExpressionKind::Selection(_)) {
+            self.unsupported("ALL with an array");
+        }
+
+        self.visit_expression(left);
+        self.write(" NOT IN ");
+        self.visit_expression(right);
+    }
+
+    fn visit_any(&mut self, _: Expression<'_>) {
+        self.unsupported("ANY");
+    }
+
+    fn visit_all(&mut self, _: Expression<'_>) {
+        self.unsupported("ALL");
+    }
+
+    fn visit_cast(&mut self, cast: Cast<'_>) {
+        // MySQL casts to a small set of types, with their own names.
+        let target_type = match cast.target_type.to_ascii_lowercase().as_str() {
+            "json" | "jsonb" => "JSON".into(),
+            "text" | "varchar" => "CHAR".into(),
+            "int" | "int4" | "int8" | "bigint" | "integer" => "SIGNED".into(),
+            _ => cast.target_type,
+        };
+
+        self.write("CAST");
+        self.surround_with("(", ")", |s| {
+            s.visit_expression(cast.expr);
+            s.write(" AS ");
+            s.write(target_type);
+        });
+    }
+
+    fn visit_row_to_json(&mut self, _: RowToJson<'_>) {
+        self.unsupported("row_to_json");
+    }
+
+    fn visit_to_jsonb(&mut self, _: ToJsonb<'_>) {
+        self.unsupported("to_jsonb");
+    }
+
+    fn visit_json_build_object(&mut self, json_build_object: JsonBuildObject<'_>) {
+        let values_length = json_build_object.values.len();
+        self.write("JSON_OBJECT(");
+
+        for (i, (name, expression)) in json_build_object.values.into_iter().enumerate() {
+            self.surround_with("'", "'", |renderer| {
+                renderer.write(&name);
+            });
+
+            self.write(", ");
+            self.visit_expression(expression);
+
+            if i < (values_length - 1) {
+                self.write(",");
+            }
+        }
+
+        self.write(")");
+    }
+
+    fn visit_json_build_array(&mut self, json_build_array: JsonBuildArray<'_>) {
+        self.write("JSON_ARRAY(");
+
+        let values_length = json_build_array.expressions.len();
+        for (i, expression) in json_build_array.expressions.into_iter().enumerate() {
+            self.visit_expression(expression);
+
+            if i < (values_length - 1) {
+                self.write(",");
+            }
+        }
+
+        self.write(")");
+    }
+
+    fn visit_json_agg(&mut self, json_agg: JsonAgg<'_>) {
+        // The order of the aggregated values must come from an ordered subquery in MySQL.
+        if json_agg.distinct {
+            self.unsupported("JSON_ARRAYAGG with DISTINCT");
+        }
+
+        if json_agg.order_by.is_some() {
+            self.unsupported("JSON_ARRAYAGG with ORDER BY");
+        }
+
+        self.write("JSON_ARRAYAGG");
+        self.surround_with("(", ")", |s| s.visit_expression(json_agg.expression));
+    }
+
+    fn visit_array_position(&mut self, _: ArrayPosition<'_>) {
+        self.unsupported("array_position");
+    }
+
+    fn visit_convert_from(&mut self, convert_from: ConvertFrom<'_>) {
+        if !matches!(convert_from.charset.to_ascii_lowercase().as_str(), "utf8" | "utf-8") {
+            self.unsupported("Converting from a character set other than UTF-8");
+        }
+
+        self.write("CONVERT");
+        self.surround_with("(", ")", |s| {
+            s.visit_expression(convert_from.expression);
+            s.write(" USING utf8mb4");
+        });
+    }
+
+    fn visit_encode(&mut self, encode: Encode<'_>) {
+        match encode.format {
+            EncodeFormat::Hex => {
+                self.write("LOWER");
+                self.surround_with("(HEX(", "))", |s| s.visit_expression(encode.expression));
+            }
+            EncodeFormat::Base64 => {
+                self.write("TO_BASE64");
+                self.surround_with("(", ")", |s| s.visit_expression(encode.expression));
+            }
+            EncodeFormat::Escape => self.unsupported("Escape encoding"),
+        }
+    }
+
+    fn visit_decode(&mut self, decode: Decode<'_>) {
+        match decode.format {
+            EncodeFormat::Hex => {
+                self.write("UNHEX");
+                self.surround_with("(", ")", |s| s.visit_expression(decode.expression));
+            }
+            EncodeFormat::Base64 => {
+                self.write("FROM_BASE64");
+                self.surround_with("(", ")", |s| s.visit_expression(decode.expression));
+            }
+            EncodeFormat::Escape => self.unsupported("Escape decoding"),
+        }
+    }
+
+    fn visit_sql_string_pattern(&mut self, pattern: SqlStringPattern<'_>) {
+        // MySQL reads backslash escapes in every string literal.
+        match pattern {
+            SqlStringPattern::Literal(content) | SqlStringPattern::EscapedContent(content) => {
+                self.surround_with("'", "'", |s| {
+                    s.write(content);
+                })
+            }
+        }
+    }
+
+    fn visit_unnest(&mut self, _: Unnest<'_>) {
+        self.unsupported("unnest");
+    }
+
+    fn visit_join_data(&mut self, data: JoinData<'_>, empty_on: bool) {
+        if data.lateral {
+            self.write(" LATERAL ");
+        }
+
+        self.visit_table(data.table, true);
+        if !data.conditions.is_no_condition() || empty_on {
+            self.write(" ON ");
+            self.visit_conditions(data.conditions)
+        }
+    }
+}
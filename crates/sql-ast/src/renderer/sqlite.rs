@@ -0,0 +1,363 @@
+//! Renders the abstract syntax tree into SQLite statements.
+//!
+//! The JSON functions map to the SQLite JSON1 functions, and JSON paths use the `->` and `->>`
+//! operators, which need SQLite 3.38 or newer. Constructs without an SQLite equivalent, such
+//! as lateral joins, array operators and `row_to_json`, fail the rendering with an
+//! [`Unsupported`] error.
+
+use core::fmt;
+use std::fmt::Write;
+
+use grafbase_sdk::host_io::postgres::types::DatabaseValue;
+
+use super::{
+    Renderer, Statement, Unsupported,
+    visitor::{self, Visitor},
+};
+use crate::ast::{
+    self, ArrayPosition, Cast, ConvertFrom, Decode, Encode, EncodeFormat, Expression, ExpressionKind, JoinData,
+    JsonAgg, JsonBuildArray, JsonBuildObject, JsonExtract, JsonExtractArrayElem, JsonType, JsonUnquote,
+    ParameterizedValue, RowToJson, SqlStringPattern, ToJsonb, Unnest,
+};
+
+/// The SQLite dialect.
+#[derive(Debug, Default)]
+pub struct Sqlite {
+    sql: String,
+    parameters: Vec<DatabaseValue>,
+    unsupported: Option<Unsupported>,
+}
+
+impl Renderer for Sqlite {
+    type Output = Statement;
+
+    fn render<'a>(ast: impl Into<ast::Query<'a>>) -> Result<Self::Output, Unsupported> {
+        let mut renderer = Sqlite::default();
+        renderer.visit_query(ast.into());
+
+        match renderer.unsupported {
+            Some(unsupported) => Err(unsupported),
+            None => Ok(Statement::new(renderer.sql, renderer.parameters)),
+        }
+    }
+}
+
+impl Visitor for Sqlite {
+    const C_BACKTICK_OPEN: &'static str = "\"";
+    const C_BACKTICK_CLOSE: &'static str = "\"";
+
+    fn write<D: fmt::Display>(&mut self, s: D) {
+        self.sql
+            .write_fmt(format_args!("{s}"))
+            .expect("we ran out of memory or something else why write failed");
+    }
+
+    fn substitute_value(&mut self, value: ParameterizedValue<'_>) {
+        // SQLite is dynamically typed, so enum and type casts are not needed. It has no arrays
+        // either.
+        if value.value.to_list().is_some() {
+            self.unsupported("An array parameter");
+        }
+
+        self.parameters.push(value.value);
+        self.write("?");
+        self.write(self.parameters.len());
+    }
+
+    fn unsupported(&mut self, feature: &'static str) {
+        if self.unsupported.is_none() {
+            self.unsupported = Some(Unsupported::new("SQLite", feature));
+        }
+    }
+
+    fn visit_limit_and_offset(&mut self, limit: Option<u32>, offset: Option<u32>) {
+        match (limit, offset) {
+            (Some(limit), Some(offset)) => {
+                self.write(" LIMIT ");
+                self.substitute_parameter(limit);
+
+                self.write(" OFFSET ");
+                self.substitute_parameter(offset);
+            }
+            (None, Some(offset)) => {
+                // SQLite needs a limit for the offset, and a negative limit means no limit.
+                self.write(" LIMIT -1 OFFSET ");
+                self.substitute_parameter(offset);
+            }
+            (Some(limit), None) => {
+                self.write(" LIMIT ");
+                self.substitute_parameter(limit);
+            }
+            (None, None) => (),
+        }
+    }
+
+    fn visit_aggregate_to_string(&mut self, value: Expression<'_>) {
+        self.write("group_concat");
+        self.surround_with("(", ")", |s| {
+            s.visit_expression(value);
+            s.write(", ','");
+        });
+    }
+
+    fn visit_json_extract(&mut self, json_extract: JsonExtract<'_>) {
+        let path = visitor::json_path(json_extract.path);
+
+        self.write("(");
+        self.visit_expression(*json_extract.column);
+
+        if json_extract.extract_as_string {
+            self.write(" ->> ");
+        } else {
+            self.write(" -> ");
+        }
+
+        self.substitute_parameter(path);
+        self.write(")");
+    }
+
+    fn visit_json_unquote(&mut self, json_unquote: JsonUnquote<'_>) {
+        self.write("(");
+        self.visit_expression(*json_unquote.expr);
+        self.write(" ->> '$'");
+        self.write(")");
+    }
+
+    fn visit_array_contains(&mut self, _: Expression<'_>, _: Expression<'_>, _: bool) {
+        self.unsupported("The array containment operator");
+    }
+
+    fn visit_array_contained(&mut self, _: Expression<'_>, _: Expression<'_>, _: bool) {
+        self.unsupported("The array containment operator");
+    }
+
+    fn visit_array_overlaps(&mut self, _: Expression<'_>, _: Expression<'_>) {
+        self.unsupported("The array overlap operator");
+    }
+
+    fn visit_json_extract_array_item(&mut self, extract: JsonExtractArrayElem<'_>) {
+        self.write("(");
+        self.visit_expression(*extract.expr);
+        self.write(" ->> ");
+        self.write(extract.index);
+        self.write(")");
+    }
+
+    fn visit_json_type_equals(&mut self, left: Expression<'_>, json_type: JsonType<'_>, not: bool) {
+        self.write("json_type");
+        self.surround_with("(", ")", |s| s.visit_expression(left));
+
+        // SQLite tells integers from reals, and true from false.
+        let types = match json_type {
+            JsonType::Array => "('array')",
+            JsonType::Boolean => "('true', 'false')",
+            JsonType::Number => "('integer', 'real')",
+            JsonType::Object => "('object')",
+            JsonType::String => "('text')",
+            JsonType::Null => "('null')",
+            JsonType::ColumnRef(column) => {
+                self.write(if not { " != " } else { " = " });
+                self.write("json_type");
+                self.surround_with("(", ")", |s| s.visit_column(*column));
+
+                return;
+            }
+        };
+
+        self.write(if not { " NOT IN " } else { " IN " });
+        self.write(types);
+    }
+
+    fn visit_json_delete_at_path(&mut self, _: Expression<'_>, _: Expression<'_>) {
+        self.unsupported("Deleting a JSON path with an array of keys");
+    }
+
+    fn visit_like(&mut self, left: Expression<'_>, right: Expression<'_>) {
+        self.visit_expression(left);
+        self.write(" LIKE ");
+        self.visit_expression(right);
+    }
+
+    fn visit_not_like(&mut self, left: Expression<'_>, right: Expression<'_>) {
+        self.visit_expression(left);
+        self.write(" NOT LIKE ");
+        self.visit_expression(right);
+    }
+
+    fn visit_is_not_distinct_from(&mut self, left: Expression<'_>, right: Expression<'_>) {
+        self.visit_expression(left);
+        self.write(" IS ");
+        self.visit_expression(right);
+    }
+
+    fn visit_any_selection(&mut self, left: Expression<'_>, right: Expression<'_>) {
+        // Without arrays, only a subquery can be on the right side.
+        if !matches!(right.kind, ExpressionKind::Selection(_)) {
+            self.unsupported("ANY with an array");
+        }
+
+        self.visit_expression(left);
+        self.write(" IN ");
+        self.visit_expression(right);
+    }
+
+    fn visit_not_all_selection(&mut self, left: Expression<'_>, right: Expression<'_>) {
+        if !matches!(right.kind, ExpressionKind::Selection(_)) {
+            self.unsupported("ALL with an array");
+        }
+
+        self.visit_expression(left);
+        self.write(" NOT IN ");
+        self.visit_expression(right);
+    }
+
+    fn visit_any(&mut self, _: Expression<'_>) {
+        self.unsupported("ANY");
+    }
+
+    fn visit_all(&mut self, _: Expression<'_>) {
+        self.unsupported("ALL");
+    }
+
+    fn visit_cast(&mut self, cast: Cast<'_>) {
+        // Casting to an unknown type gives a numeric value in SQLite, JSON is validated with
+        // a function instead.
+        if cast.target_type.eq_ignore_ascii_case("json") || cast.target_type.eq_ignore_ascii_case("jsonb") {
+            self.write("json");
+            self.surround_with("(", ")", |s| s.visit_expression(cast.expr));
+
+            return;
+        }
+
+        self.write("CAST");
+        self.surround_with("(", ")", |s| {
+            s.visit_expression(cast.expr);
+            s.write(" AS ");
+            s.write(cast.target_type);
+        });
+    }
+
+    fn visit_row_to_json(&mut self, _: RowToJson<'_>) {
+        self.unsupported("row_to_json");
+    }
+
+    fn visit_to_jsonb(&mut self, _: ToJsonb<'_>) {
+        self.unsupported("to_jsonb");
+    }
+
+    fn visit_json_build_object(&mut self, json_build_object: JsonBuildObject<'_>) {
+        let values_length = json_build_object.values.len();
+        self.write("json_object(");
+
+        for (i, (name, expression)) in json_build_object.values.into_iter().enumerate() {
+            self.surround_with("'", "'", |renderer| {
+                renderer.write(&name);
+            });
+
+            self.write(", ");
+            self.visit_expression(expression);
+
+            if i < (values_length - 1) {
+                self.write(",");
+            }
+        }
+
+        self.write(")");
+    }
+
+    fn visit_json_build_array(&mut self, json_build_array: JsonBuildArray<'_>) {
+        self.write("json_array(");
+
+        let values_length = json_build_array.expressions.len();
+        for (i, expression) in json_build_array.expressions.into_iter().enumerate() {
+            self.visit_expression(expression);
+
+            if i < (values_length - 1) {
+                self.write(",");
+            }
+        }
+
+        self.write(")");
+    }
+
+    fn visit_json_agg(&mut self, json_agg: JsonAgg<'_>) {
+        self.write("json_group_array(");
+
+        if json_agg.distinct {
+            self.write("DISTINCT ");
+        }
+
+        self.visit_expression(json_agg.expression);
+
+        if let Some(ordering) = json_agg.order_by {
+            self.write(" ORDER BY ");
+            self.visit_ordering(ordering);
+        }
+
+        self.write(")");
+    }
+
+    fn visit_array_position(&mut self, _: ArrayPosition<'_>) {
+        self.unsupported("array_position");
+    }
+
+    fn visit_convert_from(&mut self, convert_from: ConvertFrom<'_>) {
+        // SQLite reads blobs as text in the database encoding, which we expect to be UTF-8.
+        if !matches!(convert_from.charset.to_ascii_lowercase().as_str(), "utf8" | "utf-8") {
+            self.unsupported("Converting from a character set other than UTF-8");
+        }
+
+        self.write("CAST");
+        self.surround_with("(", ")", |s| {
+            s.visit_expression(convert_from.expression);
+            s.write(" AS TEXT");
+        });
+    }
+
+    fn visit_encode(&mut self, encode: Encode<'_>) {
+        match encode.format {
+            EncodeFormat::Hex => {
+                self.write("lower");
+                self.surround_with("(hex(", "))", |s| s.visit_expression(encode.expression));
+            }
+            EncodeFormat::Base64 => self.unsupported("Base64 encoding"),
+            EncodeFormat::Escape => self.unsupported("Escape encoding"),
+        }
+    }
+
+    fn visit_decode(&mut self, decode: Decode<'_>) {
+        match decode.format {
+            EncodeFormat::Hex => {
+                self.write("unhex");
+                self.surround_with("(", ")", |s| s.visit_expression(decode.expression));
+            }
+            EncodeFormat::Base64 => self.unsupported("Base64 decoding"),
+            EncodeFormat::Escape => self.unsupported("Escape decoding"),
+        }
+    }
+
+    fn visit_sql_string_pattern(&mut self, pattern: SqlStringPattern<'_>) {
+        match pattern {
+            SqlStringPattern::Literal(literal) => self.surround_with("'", "'", |s| {
+                s.write(literal);
+            }),
+            SqlStringPattern::EscapedContent(_) => self.unsupported("An escaped string"),
+        }
+    }
+
+    fn visit_unnest(&mut self, _: Unnest<'_>) {
+        self.unsupported("unnest");
+    }
+
+    fn visit_join_data(&mut self, data: JoinData<'_>, empty_on: bool) {
+        if data.lateral {
+            self.unsupported("A lateral join");
+        }
+
+        self.visit_table(data.table, true);
+        if !data.conditions.is_no_condition() || empty_on {
+            self.write(" ON ");
+            self.visit_conditions(data.conditions)
+        }
+    }
+}
@@ -0,0 +1,362 @@
+//! Renders the same abstract syntax trees with every dialect, to keep the renderers in line
+//! with each other.
+
+use grafbase_sdk::host_io::postgres::types::DatabaseType;
+use indoc::formatdoc;
+use sql_ast::{
+    ast::*,
+    renderer::{Mysql, Postgres, Renderer, Sqlite},
+};
+
+fn render<'a>(ast: impl Into<Query<'a>>) -> String {
+    let ast = ast.into();
+
+    let postgres = match Postgres::render(ast.clone()) {
+        Ok(statement) => statement.to_string(),
+        Err(error) => error.to_string(),
+    };
+
+    let sqlite = match Sqlite::render(ast.clone()) {
+        Ok(statement) => statement.to_string(),
+        Err(error) => error.to_string(),
+    };
+
+    let mysql = match Mysql::render(ast) {
+        Ok(statement) => statement.to_string(),
+        Err(error) => error.to_string(),
+    };
+
+    formatdoc! {r#"
+        postgres: {postgres}
+        sqlite:   {sqlite}
+        mysql:    {mysql}
+    "#}
+}
+
+fn value<'a>(value: impl DatabaseType) -> Expression<'a> {
+    Expression::value(value.into_bound_value(0))
+}
+
+#[test]
+fn select_with_filter_ordering_and_pagination() {
+    let mut select = Select::from_table(("public", "users"));
+    select.column(Column::new("id"));
+    select.column(Column::new("name"));
+    select.and_where(Column::new("age").greater_than(value(18)));
+    select.and_where(Column::new("name").like(value("%a%".to_string())));
+    select.order_by(Column::new("id").descend_nulls_last());
+    select.limit(10);
+    select.offset(20);
+
+    insta::assert_snapshot!(render(select), @r#"
+    postgres: SELECT "id", "name" FROM "public"."users" WHERE ("age" > $1 AND "name"::text LIKE $2) ORDER BY "id" DESC NULLS LAST LIMIT $3::bigint OFFSET $4::bigint
+    sqlite:   SELECT "id", "name" FROM "public"."users" WHERE ("age" > ?1 AND "name" LIKE ?2) ORDER BY "id" DESC NULLS LAST LIMIT ?3 OFFSET ?4
+    mysql:    SELECT `id`, `name` FROM `public`.`users` WHERE (`age` > ? AND `name` LIKE ?) ORDER BY `id` DESC LIMIT ? OFFSET ?
+    "#);
+}
+
+#[test]
+fn offset_without_limit() {
+    let mut select = Select::from_table("users");
+    select.offset(5);
+
+    insta::assert_snapshot!(render(select), @r#"
+    postgres: SELECT "users".* FROM "users" OFFSET $1::bigint
+    sqlite:   SELECT "users".* FROM "users" LIMIT -1 OFFSET ?1
+    mysql:    SELECT `users`.* FROM `users` LIMIT 18446744073709551615 OFFSET ?
+    "#);
+}
+
+#[test]
+fn json_aggregation() {
+    let mut inner = Select::from_table("users");
+    inner.value(json_build_object([("id", Column::new("id")), ("name", Column::new("name"))]).alias("user"));
+
+    let mut select = Select::from_table(Table::from(inner).alias("users"));
+    select.value(json_agg(Column::new("user"), None, false).alias("data"));
+
+    insta::assert_snapshot!(render(select), @r#"
+    postgres: SELECT json_agg("user") AS "data" FROM (SELECT json_build_object('id', "id",'name', "name") AS "user" FROM "users") AS "users"
+    sqlite:   SELECT json_group_array("user") AS "data" FROM (SELECT json_object('id', "id",'name', "name") AS "user" FROM "users") AS "users"
+    mysql:    SELECT JSON_ARRAYAGG(`user`) AS `data` FROM (SELECT JSON_OBJECT('id', `id`,'name', `name`) AS `user` FROM `users`) AS `users`
+    "#);
+}
+
+#[test]
+fn json_paths_and_types() {
+    let mut select = Select::from_table("documents");
+    select.value(json_extract(Column::new("data"), vec!["tags".into(), "0".into()], true).alias("tag"));
+    let author = Expression::from(json_extract(Column::new("data"), vec!["author".into()], false));
+    select.and_where(author.json_type_equals(JsonType::Object));
+
+    insta::assert_snapshot!(render(select), @r##"
+    postgres: SELECT ("data"#>>ARRAY[$1, $2]::text[]) AS "tag" FROM "documents" WHERE JSONB_TYPEOF(("data"#>ARRAY[$3]::text[])::jsonb) = $4
+    sqlite:   SELECT ("data" ->> ?1) AS "tag" FROM "documents" WHERE json_type(("data" -> ?2)) IN ('object')
+    mysql:    SELECT JSON_UNQUOTE(JSON_EXTRACT(`data`, ?)) AS `tag` FROM `documents` WHERE JSON_TYPE(JSON_EXTRACT(`data`, ?)) IN ('OBJECT')
+    "##);
+}
+
+#[test]
+fn insert_with_conflict_and_returning() {
+    let mut insert = Insert::single_into("users");
+    insert.value("id", value(1));
+    insert.value("name", value("Musti".to_string()));
+
+    let mut update = Update::table("users");
+    update.set("name", value("Naukio".to_string()));
+
+    let mut insert = Insert::from(insert);
+    insert.on_conflict(OnConflict::Update(update, vec![Column::new("id")]));
+    insert.returning(["id", "name"]);
+
+    insta::assert_snapshot!(render(insert), @r#"
+    postgres: INSERT INTO "users" ("id","name") VALUES ($1,$2) ON CONFLICT ("id") DO UPDATE SET "name" = $3 RETURNING "id", "name"
+    sqlite:   INSERT INTO "users" ("id","name") VALUES (?1,?2) ON CONFLICT ("id") DO UPDATE SET "name" = ?3 RETURNING "id", "name"
+    mysql:    RETURNING is not supported in MySQL
+    "#);
+}
+
+#[test]
+fn insert_with_conflict() {
+    let mut insert = Insert::single_into("users");
+    insert.value("id", value(1));
+    insert.value("name", value("Musti".to_string()));
+
+    let mut update = Update::table("users");
+    update.set("name", value("Naukio".to_string()));
+
+    let mut upsert = Insert::from(insert.clone());
+    upsert.on_conflict(OnConflict::Update(update, vec![Column::new("id")]));
+
+    let mut ignore = Insert::from(insert);
+    ignore.on_conflict(OnConflict::DoNothing);
+
+    insta::assert_snapshot!(render(upsert) + &render(ignore), @r#"
+    postgres: INSERT INTO "users" ("id","name") VALUES ($1,$2) ON CONFLICT ("id") DO UPDATE SET "name" = $3
+    sqlite:   INSERT INTO "users" ("id","name") VALUES (?1,?2) ON CONFLICT ("id") DO UPDATE SET "name" = ?3
+    mysql:    INSERT INTO `users` (`id`,`name`) VALUES (?,?) ON DUPLICATE KEY UPDATE `name` = ?
+    postgres: INSERT INTO "users" ("id","name") VALUES ($1,$2) ON CONFLICT DO NOTHING
+    sqlite:   INSERT INTO "users" ("id","name") VALUES (?1,?2) ON CONFLICT DO NOTHING
+    mysql:    INSERT IGNORE INTO `users` (`id`,`name`) VALUES (?,?)
+    "#);
+}
+
+#[test]
+fn insert_default_values() {
+    let insert = Insert::single_into("users");
+
+    insta::assert_snapshot!(render(insert.build()), @r#"
+    postgres: INSERT INTO "users" DEFAULT VALUES
+    sqlite:   INSERT INTO "users" DEFAULT VALUES
+    mysql:    INSERT INTO `users` () VALUES ()
+    "#);
+}
+
+#[test]
+fn ordering_with_nulls() {
+    let mut select = Select::from_table("users");
+    select.order_by(Column::new("name").ascend_nulls_last());
+    select.order_by(Column::new("age").descend_nulls_first());
+    select.order_by(Column::new("id").ascend_nulls_first());
+
+    insta::assert_snapshot!(render(select), @r#"
+    postgres: SELECT "users".* FROM "users" ORDER BY "name" ASC NULLS LAST, "age" DESC NULLS FIRST, "id" ASC NULLS FIRST
+    sqlite:   SELECT "users".* FROM "users" ORDER BY "name" ASC NULLS LAST, "age" DESC NULLS FIRST, "id" ASC NULLS FIRST
+    mysql:    SELECT `users`.* FROM `users` ORDER BY `name` IS NULL, `name` ASC, `age` IS NOT NULL, `age` DESC, `id` ASC
+    "#);
+}
+
+#[test]
+fn string_concatenation() {
+    let mut select = Select::from_table("users");
+    select.value(Expression::from(concat(vec![Column::new("first_name"), Column::new("last_name")])).alias("name"));
+
+    insta::assert_snapshot!(render(select), @r#"
+    postgres: SELECT ("first_name" || "last_name") AS "name" FROM "users"
+    sqlite:   SELECT ("first_name" || "last_name") AS "name" FROM "users"
+    mysql:    SELECT CONCAT(`first_name`, `last_name`) AS `name` FROM `users`
+    "#);
+}
+
+#[test]
+fn update_and_delete_with_returning() {
+    let mut update = Update::table("users");
+    update.set("name", value("Musti".to_string()));
+    update.so_that(Column::new("id").is_not_distinct_from(value(1)));
+    update.returning(["id"]);
+
+    let mut delete = Delete::from_table("users");
+    delete.so_that(Column::new("id").equals(value(1)));
+    delete.returning([Column::new("id")]);
+
+    insta::assert_snapshot!(render(update) + &render(delete), @r#"
+    postgres: UPDATE "users" SET "name" = $1 WHERE "id" IS NOT DISTINCT FROM $2 RETURNING "id"
+    sqlite:   UPDATE "users" SET "name" = ?1 WHERE "id" IS ?2 RETURNING "id"
+    mysql:    RETURNING is not supported in MySQL
+    postgres: DELETE FROM "users" WHERE "id" = $1 RETURNING "id"
+    sqlite:   DELETE FROM "users" WHERE "id" = ?1 RETURNING "id"
+    mysql:    RETURNING is not supported in MySQL
+    "#);
+}
+
+#[test]
+fn any_with_a_subquery() {
+    let mut inner = Select::from_table("admins");
+    inner.column(Column::new("user_id"));
+
+    let mut select = Select::from_table("users");
+    select.and_where(Column::new("id").any_selection(inner));
+
+    insta::assert_snapshot!(render(select), @r#"
+    postgres: SELECT "users".* FROM "users" WHERE "id" = ANY ((SELECT "user_id" FROM "admins"))
+    sqlite:   SELECT "users".* FROM "users" WHERE "id" IN (SELECT "user_id" FROM "admins")
+    mysql:    SELECT `users`.* FROM `users` WHERE `id` IN (SELECT `user_id` FROM `admins`)
+    "#);
+}
+
+#[test]
+fn any_with_an_array() {
+    let mut select = Select::from_table("users");
+    select.and_where(Column::new("id").any_selection(value(vec![1, 2, 3])));
+
+    insta::assert_snapshot!(render(select), @r#"
+    postgres: SELECT "users".* FROM "users" WHERE "id" = ANY ($1)
+    sqlite:   ANY with an array is not supported in SQLite
+    mysql:    ANY with an array is not supported in MySQL
+    "#);
+}
+
+#[test]
+fn array_parameter() {
+    let mut select = Select::from_table("posts");
+    select.and_where(Column::new("tags").equals(value(vec!["rust".to_string()])));
+
+    insta::assert_snapshot!(render(select), @r#"
+    postgres: SELECT "posts".* FROM "posts" WHERE "tags" = $1
+    sqlite:   An array parameter is not supported in SQLite
+    mysql:    An array parameter is not supported in MySQL
+    "#);
+}
+
+#[test]
+fn lateral_join() {
+    let mut posts = Select::from_table("posts");
+    posts.value(json_agg(Column::new("title"), None, false).alias("titles"));
+    posts.and_where(Column::new("user_id").equals(Column::new("id").table("users")));
+
+    let mut join = Table::from(posts).alias("posts").on(ConditionTree::single(raw("true")));
+    join.lateral();
+
+    let mut select = Select::from_table("users");
+    select.left_join(join);
+
+    insta::assert_snapshot!(render(select), @r#"
+    postgres: SELECT "users".* FROM "users" LEFT JOIN  LATERAL (SELECT json_agg("title") AS "titles" FROM "posts" WHERE "user_id" = "users"."id") AS "posts" ON true
+    sqlite:   A lateral join is not supported in SQLite
+    mysql:    SELECT `users`.* FROM `users` LEFT JOIN  LATERAL (SELECT JSON_ARRAYAGG(`title`) AS `titles` FROM `posts` WHERE `user_id` = `users`.`id`) AS `posts` ON true
+    "#);
+}
+
+#[test]
+fn row_to_json() {
+    let mut select = Select::from_table("users");
+    select.value(sql_ast::ast::row_to_json("users", false));
+
+    insta::assert_snapshot!(render(select), @r#"
+    postgres: SELECT ROW_TO_JSON("users") FROM "users"
+    sqlite:   row_to_json is not supported in SQLite
+    mysql:    row_to_json is not supported in MySQL
+    "#);
+}
+
+#[test]
+fn aggregate_over_a_window_frame() {
+    let over = Over::default()
+        .partition_by("account_id")
+        .order_by(Column::new("created_at").ascend())
+        .rows(FrameBound::Preceding(6), FrameBound::CurrentRow);
+
+    let mut select = Select::from_table("payments");
+    select.value(sum(Column::new("amount")).over(over).alias("weekly_total"));
+
+    insta::assert_snapshot!(render(select), @r#"
+    postgres: SELECT SUM("amount") OVER (PARTITION BY "account_id" ORDER BY "created_at" ASC ROWS BETWEEN 6 PRECEDING AND CURRENT ROW) AS "weekly_total" FROM "payments"
+    sqlite:   SELECT SUM("amount") OVER (PARTITION BY "account_id" ORDER BY "created_at" ASC ROWS BETWEEN 6 PRECEDING AND CURRENT ROW) AS "weekly_total" FROM "payments"
+    mysql:    SELECT SUM(`amount`) OVER (PARTITION BY `account_id` ORDER BY `created_at` ASC ROWS BETWEEN 6 PRECEDING AND CURRENT ROW) AS `weekly_total` FROM `payments`
+    "#);
+}
+
+#[test]
+fn ranking_and_offset_functions() {
+    let over = || {
+        Over::default()
+            .partition_by("team_id")
+            .order_by(Column::new("score").descend())
+    };
+
+    let mut select = Select::from_table("players");
+    select.value(rank().over(over()).alias("rank"));
+    select.value(dense_rank().over(over()).alias("dense_rank"));
+    select.value(ntile(4).over(over()).alias("quartile"));
+    select.value(
+        lag(Column::new("score"), 1, Some(value(0)))
+            .over(over())
+            .alias("previous_score"),
+    );
+    select.value(lead(Column::new("score"), 2, None).over(over()).alias("next_score"));
+    select.value(
+        first_value(Column::new("name"))
+            .over(over().range(FrameBound::UnboundedPreceding, FrameBound::UnboundedFollowing))
+            .alias("best"),
+    );
+
+    insta::assert_snapshot!(render(select), @r#"
+    postgres: SELECT RANK() OVER (PARTITION BY "team_id" ORDER BY "score" DESC) AS "rank", DENSE_RANK() OVER (PARTITION BY "team_id" ORDER BY "score" DESC) AS "dense_rank", NTILE(4) OVER (PARTITION BY "team_id" ORDER BY "score" DESC) AS "quartile", LAG("score", 1, $1) OVER (PARTITION BY "team_id" ORDER BY "score" DESC) AS "previous_score", LEAD("score", 2) OVER (PARTITION BY "team_id" ORDER BY "score" DESC) AS "next_score", FIRST_VALUE("name") OVER (PARTITION BY "team_id" ORDER BY "score" DESC RANGE BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING) AS "best" FROM "players"
+    sqlite:   SELECT RANK() OVER (PARTITION BY "team_id" ORDER BY "score" DESC) AS "rank", DENSE_RANK() OVER (PARTITION BY "team_id" ORDER BY "score" DESC) AS "dense_rank", NTILE(4) OVER (PARTITION BY "team_id" ORDER BY "score" DESC) AS "quartile", LAG("score", 1, ?1) OVER (PARTITION BY "team_id" ORDER BY "score" DESC) AS "previous_score", LEAD("score", 2) OVER (PARTITION BY "team_id" ORDER BY "score" DESC) AS "next_score", FIRST_VALUE("name") OVER (PARTITION BY "team_id" ORDER BY "score" DESC RANGE BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING) AS "best" FROM "players"
+    mysql:    SELECT RANK() OVER (PARTITION BY `team_id` ORDER BY `score` DESC) AS `rank`, DENSE_RANK() OVER (PARTITION BY `team_id` ORDER BY `score` DESC) AS `dense_rank`, NTILE(4) OVER (PARTITION BY `team_id` ORDER BY `score` DESC) AS `quartile`, LAG(`score`, 1, ?) OVER (PARTITION BY `team_id` ORDER BY `score` DESC) AS `previous_score`, LEAD(`score`, 2) OVER (PARTITION BY `team_id` ORDER BY `score` DESC) AS `next_score`, FIRST_VALUE(`name`) OVER (PARTITION BY `team_id` ORDER BY `score` DESC RANGE BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING) AS `best` FROM `players`
+    "#);
+}
+
+#[test]
+fn aggregate_with_a_filter() {
+    let mut select = Select::from_table("orders");
+    select.value(count(asterisk()).alias("total"));
+    select.value(
+        count(asterisk())
+            .filter(Column::new("status").equals(value("shipped".to_string())))
+            .alias("shipped"),
+    );
+    select.value(
+        sum(Column::new("amount"))
+            .over(
+                Over::default()
+                    .order_by("created_at")
+                    .groups(FrameBound::Preceding(1), FrameBound::Following(1)),
+            )
+            .filter(Column::new("status").not_equals(value("cancelled".to_string())))
+            .alias("nearby_amount"),
+    );
+
+    insta::assert_snapshot!(render(select), @r#"
+    postgres: SELECT COUNT(*) AS "total", COUNT(*) FILTER (WHERE "status" = $1) AS "shipped", SUM("amount") FILTER (WHERE "status" <> $2) OVER (ORDER BY "created_at" GROUPS BETWEEN 1 PRECEDING AND 1 FOLLOWING) AS "nearby_amount" FROM "orders"
+    sqlite:   SELECT COUNT(*) AS "total", COUNT(*) FILTER (WHERE "status" = ?1) AS "shipped", SUM("amount") FILTER (WHERE "status" <> ?2) OVER (ORDER BY "created_at" GROUPS BETWEEN 1 PRECEDING AND 1 FOLLOWING) AS "nearby_amount" FROM "orders"
+    mysql:    FILTER is not supported in MySQL
+    "#);
+}
+
+#[test]
+fn select_all_from_an_expression() {
+    let aliased = Select::from_table(Table::from(raw("generate_series(1, 3)")).alias("numbers"));
+    let unaliased = Select::from_table(Table::from(raw("generate_series(1, 3)")));
+
+    insta::assert_snapshot!(render(aliased), @r#"
+    postgres: SELECT "numbers".* FROM generate_series(1, 3) AS "numbers"
+    sqlite:   SELECT "numbers".* FROM generate_series(1, 3) AS "numbers"
+    mysql:    SELECT `numbers`.* FROM generate_series(1, 3) AS `numbers`
+    "#);
+    insta::assert_snapshot!(render(unaliased), @r#"
+    postgres: Selecting all columns of an expression without an alias is not supported in Postgres
+    sqlite:   Selecting all columns of an expression without an alias is not supported in SQLite
+    mysql:    Selecting all columns of an expression without an alias is not supported in MySQL
+    "#);
+}
@@ -1,6 +1,9 @@
 // This is for tests only.
 fn main() {
+    let out_dir = std::path::PathBuf::from(std::env::var("OUT_DIR").unwrap());
+
     tonic_build::configure()
+        .file_descriptor_set_path(out_dir.join("route_guide_descriptor.bin"))
         .type_attribute("routeguide.Point", "#[derive(Hash)]")
         .compile_protos(&["tests/proto/routeguide/route_guide.proto"], &["proto"])
         .unwrap();
@@ -0,0 +1,283 @@
+//! Client-side load balancing over the endpoints of a service, with health checks.
+//!
+//! Extensions have no background tasks, so endpoints are health checked when they are picked for a
+//! call and their last check is older than the interval of the service. Each instance of the
+//! extension in the gateway keeps its own state.
+
+use crate::config::{HealthCheck, LoadBalancing, Service};
+use grafbase_sdk::{
+    host_io::{
+        grpc::{GrpcClient, GrpcStatus, GrpcStatusCode},
+        logger::log,
+    },
+    types::Error,
+};
+use prost::Message as _;
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+const HEALTH_SERVICE: &str = "grpc.health.v1.Health";
+const HEALTH_METHOD: &str = "Check";
+
+/// The `SERVING` value of `grpc.health.v1.HealthCheckResponse.ServingStatus`.
+const SERVING: i32 = 1;
+
+/// The statuses telling that an endpoint could not process a call, rather than an answer of the
+/// service to the call.
+const ENDPOINT_ERRORS: [GrpcStatusCode; 5] = [
+    GrpcStatusCode::Unavailable,
+    GrpcStatusCode::DeadlineExceeded,
+    GrpcStatusCode::Unknown,
+    GrpcStatusCode::Internal,
+    GrpcStatusCode::ResourceExhausted,
+];
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct HealthCheckRequest {
+    #[prost(string, tag = "1")]
+    service: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct HealthCheckResponse {
+    #[prost(int32, tag = "1")]
+    status: i32,
+}
+
+/// The balancers of the configured services, by service name.
+pub(crate) struct Balancers(HashMap<String, Balancer>);
+
+impl Balancers {
+    pub(crate) fn new(services: &[Service]) -> Result<Self, Error> {
+        services
+            .iter()
+            .map(|service| Ok((service.name.clone(), Balancer::new(service)?)))
+            .collect::<Result<_, Error>>()
+            .map(Balancers)
+    }
+
+    pub(crate) fn get_mut(&mut self, service_name: &str) -> Result<&mut Balancer, Error> {
+        self.0
+            .get_mut(service_name)
+            .ok_or_else(|| Error::new(format!("Service not found: {service_name}")))
+    }
+}
+
+pub(crate) struct Balancer {
+    service_name: String,
+    policy: LoadBalancing,
+    health_check: Option<HealthCheckSettings>,
+    endpoints: Vec<Endpoint>,
+    /// The endpoint the next round robin pick starts from.
+    next: usize,
+}
+
+struct HealthCheckSettings {
+    interval: Duration,
+    timeout: Duration,
+    request: Vec<u8>,
+    /// The static metadata of the service, to authenticate the health checks.
+    metadata: Vec<(String, Vec<u8>)>,
+}
+
+struct Endpoint {
+    address: String,
+    client: GrpcClient,
+    healthy: bool,
+    /// When the endpoint is due for a health check. `None` means on the next pick.
+    next_check: Option<Instant>,
+    /// The calls that failed with an endpoint error since start-up.
+    errors: u64,
+}
+
+impl Balancer {
+    fn new(service: &Service) -> Result<Self, Error> {
+        let addresses = match (&service.address, service.addresses.as_slice()) {
+            (Some(address), []) => std::slice::from_ref(address),
+            (None, [_, ..]) => service.addresses.as_slice(),
+            (Some(_), [_, ..]) => {
+                return Err(Error::new(format!(
+                    "Service {} can be configured with either an `address` or `addresses`, not both",
+                    service.name
+                )));
+            }
+            (None, []) => {
+                return Err(Error::new(format!(
+                    "Service {} must be configured with an `address` or `addresses`",
+                    service.name
+                )));
+            }
+        };
+
+        let endpoints = addresses
+            .iter()
+            .map(|address| {
+                if address.starts_with("dns:") {
+                    return Err(Error::new(format!(
+                        "Invalid address {address} for service {}: `dns:` targets are not supported, as the gateway does not expose name resolution to extensions. List the addresses of the replicas in `addresses` instead",
+                        service.name
+                    )));
+                }
+
+                Ok(Endpoint {
+                    address: address.clone(),
+                    client: GrpcClient::new(address)?,
+                    healthy: true,
+                    next_check: None,
+                    errors: 0,
+                })
+            })
+            .collect::<Result<_, Error>>()?;
+
+        let health_check = service.health_check.as_ref().map(|health_check| {
+            let HealthCheck {
+                interval_ms,
+                timeout_ms,
+                service_name,
+            } = health_check;
+
+            HealthCheckSettings {
+                interval: Duration::from_millis(*interval_ms),
+                timeout: Duration::from_millis(*timeout_ms),
+                request: HealthCheckRequest {
+                    service: service_name.clone(),
+                }
+                .encode_to_vec(),
+                metadata: crate::metadata::build_static(service),
+            }
+        });
+
+        Ok(Balancer {
+            service_name: service.name.clone(),
+            policy: service.load_balancing,
+            health_check,
+            endpoints,
+            next: 0,
+        })
+    }
+
+    /// Makes one attempt of a call on the endpoint picked by the policy of the service.
+    pub(crate) fn call<T>(
+        &mut self,
+        attempt: impl FnOnce(&GrpcClient) -> Result<T, GrpcStatus>,
+    ) -> Result<T, GrpcStatus> {
+        let index = self.pick();
+        let endpoint = &mut self.endpoints[index];
+
+        let result = attempt(&endpoint.client);
+
+        if let Err(status) = &result
+            && ENDPOINT_ERRORS.contains(&status.code())
+        {
+            endpoint.errors += 1;
+
+            // An endpoint that stopped answering is checked again before its next call.
+            if status.code() == GrpcStatusCode::Unavailable {
+                endpoint.next_check = None;
+            }
+
+            log::warn!(
+                "gRPC call to endpoint {} of service {} failed with status {:?}, {} failed calls to this endpoint so far: {}",
+                endpoint.address,
+                self.service_name,
+                status.code(),
+                endpoint.errors,
+                status.message()
+            );
+        }
+
+        result
+    }
+
+    /// The first healthy endpoint in the order of the policy. When all the endpoints are unhealthy,
+    /// the calls go on with the order of the policy rather than fail without trying.
+    fn pick(&mut self) -> usize {
+        let count = self.endpoints.len();
+
+        let start = match self.policy {
+            LoadBalancing::PickFirst => 0,
+            LoadBalancing::RoundRobin => {
+                let start = self.next;
+                self.next = (start + 1) % count;
+                start
+            }
+        };
+
+        (0..count)
+            .map(|offset| (start + offset) % count)
+            .find(|index| self.is_healthy(*index))
+            .unwrap_or(start)
+    }
+
+    fn is_healthy(&mut self, index: usize) -> bool {
+        let Some(health_check) = &self.health_check else {
+            return true;
+        };
+
+        let endpoint = &mut self.endpoints[index];
+        let now = Instant::now();
+
+        if endpoint.next_check.is_some_and(|next_check| now < next_check) {
+            return endpoint.healthy;
+        }
+
+        let healthy = check(endpoint, health_check, &self.service_name);
+
+        match (endpoint.healthy, healthy) {
+            (true, false) => log::warn!(
+                "Ejecting endpoint {} of service {}, which failed its health check",
+                endpoint.address,
+                self.service_name
+            ),
+            (false, true) => log::info!(
+                "Endpoint {} of service {} passed its health check again",
+                endpoint.address,
+                self.service_name
+            ),
+            _ => (),
+        }
+
+        endpoint.healthy = healthy;
+        endpoint.next_check = Some(now + health_check.interval);
+
+        healthy
+    }
+}
+
+/// Calls `grpc.health.v1.Health/Check` on an endpoint. Servers without the health service are
+/// considered healthy, as in other gRPC clients.
+fn check(endpoint: &Endpoint, health_check: &HealthCheckSettings, service_name: &str) -> bool {
+    let response = endpoint.client.unary(
+        &health_check.request,
+        HEALTH_SERVICE,
+        HEALTH_METHOD,
+        &health_check.metadata,
+        Some(health_check.timeout),
+    );
+
+    match response {
+        Ok(response) => {
+            HealthCheckResponse::decode(response.message()).is_ok_and(|response| response.status == SERVING)
+        }
+        Err(status) if status.code() == GrpcStatusCode::Unimplemented => {
+            log::debug!(
+                "Endpoint {} of service {service_name} does not implement {HEALTH_SERVICE}, considering it healthy",
+                endpoint.address
+            );
+
+            true
+        }
+        Err(status) => {
+            log::debug!(
+                "Health check of endpoint {} of service {service_name} failed with status {:?}: {}",
+                endpoint.address,
+                status.code(),
+                status.message()
+            );
+
+            false
+        }
+    }
+}
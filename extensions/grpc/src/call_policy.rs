@@ -0,0 +1,157 @@
+use crate::config::{RetryPolicy, Service};
+use grafbase_sdk::host_io::{grpc::GrpcStatus, logger::log};
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher as _, Hasher as _},
+    time::{Duration, Instant},
+};
+
+/// The metadata a server can return to ask for a specific delay before the next attempt, or
+/// with a negative value, for no retry at all.
+const RETRY_PUSHBACK_KEY: &str = "grpc-retry-pushback-ms";
+
+/// The deadline and retry policy of a call to a gRPC method.
+pub(crate) struct CallPolicy<'a> {
+    timeout: Option<Duration>,
+    retry: Option<&'a RetryPolicy>,
+}
+
+impl<'a> CallPolicy<'a> {
+    /// The policy of a method, with the method settings taking precedence over the service
+    /// settings. Only methods configured as idempotent are retried.
+    ///
+    /// The deadline of the service does not apply to server streaming methods, as it would end
+    /// long-lived subscriptions. They only get a deadline configured on the method itself.
+    pub(crate) fn new(service: &'a Service, method: &str, server_streaming: bool) -> Self {
+        let method = service.get_method(method);
+        let service_timeout = service.timeout_ms.filter(|_| !server_streaming);
+
+        let timeout = method
+            .and_then(|method| method.timeout_ms)
+            .or(service_timeout)
+            .map(Duration::from_millis);
+
+        let retry = method
+            .filter(|method| method.idempotent)
+            .and_then(|method| method.retry.as_ref().or(service.retry.as_ref()));
+
+        CallPolicy { timeout, retry }
+    }
+
+    /// Runs the attempts of a call until one succeeds, fails with a status that cannot be
+    /// retried, or the attempts or the deadline are exhausted. Each attempt receives the time
+    /// left before the deadline.
+    pub(crate) fn call<T>(
+        &self,
+        mut attempt: impl FnMut(Option<Duration>) -> Result<T, GrpcStatus>,
+    ) -> Result<T, GrpcStatus> {
+        let deadline = self.timeout.map(|timeout| Instant::now() + timeout);
+        let mut retries = 0;
+
+        loop {
+            let remaining = deadline.map(|deadline| deadline.saturating_duration_since(Instant::now()));
+
+            let status = match attempt(remaining) {
+                Ok(response) => return Ok(response),
+                Err(status) => status,
+            };
+
+            let Some(retry) = self.retry else {
+                return Err(status);
+            };
+
+            retries += 1;
+
+            if retries >= retry.max_attempts.get() || !retry.retryable_status_codes.contains(&status.code()) {
+                return Err(status);
+            }
+
+            let backoff = match retry_pushback(&status) {
+                Some(Some(pushback)) => pushback,
+                Some(None) => return Err(status),
+                None => with_jitter(retry.backoff(retries)),
+            };
+
+            if deadline.is_some_and(|deadline| Instant::now() + backoff >= deadline) {
+                return Err(status);
+            }
+
+            log::debug!(
+                "Retrying gRPC call after {:?} status in {backoff:?}: {}",
+                status.code(),
+                status.message()
+            );
+
+            std::thread::sleep(backoff);
+        }
+    }
+}
+
+/// The delay requested by the server, if any. `Some(None)` means the server asked not to retry.
+fn retry_pushback(status: &GrpcStatus) -> Option<Option<Duration>> {
+    let (_, value) = status.metadata().iter().find(|(key, _)| key == RETRY_PUSHBACK_KEY)?;
+
+    let pushback = std::str::from_utf8(value)
+        .ok()
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .map(Duration::from_millis);
+
+    Some(pushback)
+}
+
+/// Spreads the retries of concurrent calls, between half and all of the backoff.
+fn with_jitter(backoff: Duration) -> Duration {
+    let random = RandomState::new().build_hasher().finish();
+    let half = backoff / 2;
+    let jitter_ms = random % (half.as_millis() as u64 + 1);
+
+    half + Duration::from_millis(jitter_ms)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::CallPolicy;
+    use crate::config::Service;
+
+    fn service() -> Service {
+        serde_json::from_value(serde_json::json!({
+            "name": "routeguide.RouteGuide",
+            "address": "http://localhost:10000",
+            "timeout_ms": 2000,
+            "methods": [
+                { "name": "GetFeature", "timeout_ms": 500 },
+                { "name": "ListFeatures", "timeout_ms": 60000 },
+            ],
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn unary_deadlines() {
+        let service = service();
+
+        assert_eq!(
+            CallPolicy::new(&service, "GetFeature", false).timeout,
+            Some(Duration::from_millis(500))
+        );
+
+        assert_eq!(
+            CallPolicy::new(&service, "RecordRoute", false).timeout,
+            Some(Duration::from_secs(2))
+        );
+    }
+
+    #[test]
+    fn server_streaming_deadlines() {
+        let service = service();
+
+        assert_eq!(
+            CallPolicy::new(&service, "ListFeatures", true).timeout,
+            Some(Duration::from_secs(60))
+        );
+
+        assert_eq!(CallPolicy::new(&service, "RouteChat", true).timeout, None);
+    }
+}
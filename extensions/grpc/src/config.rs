@@ -1,10 +1,311 @@
+use grafbase_sdk::host_io::grpc::GrpcStatusCode;
+use regex::Regex;
+use std::{num::NonZeroU32, time::Duration};
+
 #[derive(serde::Deserialize)]
 pub(crate) struct GrpcConfiguration {
     pub(crate) services: Vec<Service>,
+    /// The `code` extension of the errors, by gRPC status code.
+    #[serde(default, deserialize_with = "deserialize_error_codes")]
+    pub(crate) error_codes: Vec<(GrpcStatusCode, String)>,
+    /// Includes the `google.rpc.DebugInfo` details of the services in the errors.
+    #[serde(default)]
+    pub(crate) expose_debug_info: bool,
 }
 
+/// The gRPC client of the gateway only takes the address of a service, so options like TLS
+/// certificates or keepalive settings cannot be applied. Unknown keys are rejected rather than
+/// silently ignored.
 #[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
 pub(crate) struct Service {
     pub(crate) name: String,
-    pub(crate) address: String,
+    /// The address of a single endpoint of the service.
+    pub(crate) address: Option<String>,
+    /// The addresses of the replicas of the service, balanced with the `load_balancing` policy.
+    #[serde(default)]
+    pub(crate) addresses: Vec<String>,
+    #[serde(default)]
+    pub(crate) load_balancing: LoadBalancing,
+    /// Ejects the endpoints that are not serving, as reported by `grpc.health.v1.Health/Check`.
+    pub(crate) health_check: Option<HealthCheck>,
+    /// The metadata sent with every call to the service, in order.
+    #[serde(default)]
+    pub(crate) metadata: Vec<MetadataRule>,
+    /// The deadline of the calls to the service, including retries.
+    pub(crate) timeout_ms: Option<u64>,
+    /// The retry policy of the idempotent methods of the service.
+    pub(crate) retry: Option<RetryPolicy>,
+    /// Overrides of the policy for individual methods.
+    #[serde(default)]
+    pub(crate) methods: Vec<MethodConfig>,
+    /// Reads the definitions of the service from its server reflection endpoint at start-up.
+    #[serde(default)]
+    pub(crate) reflection: bool,
+    /// The definitions of the service, as a base64 encoded `FileDescriptorSet`.
+    #[serde(default, deserialize_with = "deserialize_file_descriptor_set")]
+    pub(crate) file_descriptor_set: Option<Vec<u8>>,
+}
+
+impl Service {
+    pub(crate) fn get_method(&self, name: &str) -> Option<&MethodConfig> {
+        self.methods.iter().find(|method| method.name == name)
+    }
+}
+
+#[derive(Clone, Copy, Default, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum LoadBalancing {
+    /// Calls the first healthy endpoint, in the order of the configuration.
+    #[default]
+    PickFirst,
+    /// Spreads the calls over the healthy endpoints in turn.
+    RoundRobin,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct HealthCheck {
+    /// The delay before an endpoint is checked again.
+    #[serde(default = "default_health_check_interval_ms")]
+    pub(crate) interval_ms: u64,
+    #[serde(default = "default_health_check_timeout_ms")]
+    pub(crate) timeout_ms: u64,
+    /// The name sent in the health check requests. The empty default asks for the health of the
+    /// whole server.
+    #[serde(default)]
+    pub(crate) service_name: String,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct MethodConfig {
+    pub(crate) name: String,
+    /// Only idempotent methods are retried.
+    #[serde(default)]
+    pub(crate) idempotent: bool,
+    pub(crate) timeout_ms: Option<u64>,
+    pub(crate) retry: Option<RetryPolicy>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct RetryPolicy {
+    /// The maximum number of attempts, including the first one.
+    #[serde(default = "default_max_attempts")]
+    pub(crate) max_attempts: NonZeroU32,
+    #[serde(default = "default_initial_backoff_ms")]
+    pub(crate) initial_backoff_ms: u64,
+    #[serde(default = "default_max_backoff_ms")]
+    pub(crate) max_backoff_ms: u64,
+    #[serde(default = "default_backoff_multiplier")]
+    pub(crate) backoff_multiplier: f64,
+    #[serde(
+        default = "default_retryable_status_codes",
+        deserialize_with = "deserialize_status_codes"
+    )]
+    pub(crate) retryable_status_codes: Vec<GrpcStatusCode>,
+}
+
+impl RetryPolicy {
+    /// The backoff before the given retry, starting at 1, without jitter.
+    pub(crate) fn backoff(&self, retry: u32) -> Duration {
+        let multiplier = self.backoff_multiplier.max(1.0).powi(retry.saturating_sub(1) as i32);
+        let backoff_ms = (self.initial_backoff_ms as f64 * multiplier).min(self.max_backoff_ms as f64);
+
+        Duration::from_millis(backoff_ms as u64)
+    }
+}
+
+fn default_max_attempts() -> NonZeroU32 {
+    NonZeroU32::new(3).unwrap()
+}
+
+fn default_initial_backoff_ms() -> u64 {
+    100
+}
+
+fn default_max_backoff_ms() -> u64 {
+    2_000
+}
+
+fn default_backoff_multiplier() -> f64 {
+    2.0
+}
+
+fn default_health_check_interval_ms() -> u64 {
+    5_000
+}
+
+fn default_health_check_timeout_ms() -> u64 {
+    1_000
+}
+
+fn default_retryable_status_codes() -> Vec<GrpcStatusCode> {
+    vec![GrpcStatusCode::Unavailable]
+}
+
+fn deserialize_error_codes<'de, D>(deserializer: D) -> Result<Vec<(GrpcStatusCode, String)>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let codes: std::collections::BTreeMap<String, String> = serde::Deserialize::deserialize(deserializer)?;
+
+    codes
+        .into_iter()
+        .map(|(name, extension_code)| {
+            crate::status::parse_code(&name)
+                .map(|code| (code, extension_code))
+                .ok_or_else(|| serde::de::Error::custom(format!("Unknown gRPC status code `{name}`")))
+        })
+        .collect()
+}
+
+fn deserialize_status_codes<'de, D>(deserializer: D) -> Result<Vec<GrpcStatusCode>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let names: Vec<String> = serde::Deserialize::deserialize(deserializer)?;
+
+    names
+        .iter()
+        .map(|name| {
+            crate::status::parse_code(name)
+                .ok_or_else(|| serde::de::Error::custom(format!("Unknown gRPC status code `{name}`")))
+        })
+        .collect()
+}
+
+fn deserialize_file_descriptor_set<'de, D>(deserializer: D) -> Result<Option<Vec<u8>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let value: String = serde::Deserialize::deserialize(deserializer)?;
+
+    crate::metadata::decode_binary_value(&value)
+        .map(Some)
+        .map_err(|err| serde::de::Error::custom(format!("Invalid base64 file descriptor set: {err}")))
+}
+
+/// A rule adding metadata to the calls to a service. See the README for the configuration format.
+#[derive(serde::Deserialize)]
+#[serde(try_from = "MetadataRuleConfig")]
+pub(crate) enum MetadataRule {
+    /// A static entry. The value of binary (`-bin`) entries is decoded from base64 in the configuration.
+    Insert { name: String, value: Vec<u8> },
+    /// Forwards the values of a header, under the same name or a new one.
+    Forward {
+        name: String,
+        rename: Option<String>,
+        default: Option<String>,
+    },
+    /// Forwards all headers with a name matching the pattern.
+    ForwardPattern { pattern: Regex },
+}
+
+#[derive(serde::Deserialize)]
+#[serde(tag = "rule", rename_all = "snake_case", deny_unknown_fields)]
+enum MetadataRuleConfig {
+    Insert {
+        name: String,
+        value: String,
+    },
+    Forward {
+        name: Option<String>,
+        pattern: Option<String>,
+        rename: Option<String>,
+        default: Option<String>,
+    },
+}
+
+impl TryFrom<MetadataRuleConfig> for MetadataRule {
+    type Error = String;
+
+    fn try_from(config: MetadataRuleConfig) -> Result<Self, Self::Error> {
+        match config {
+            MetadataRuleConfig::Insert { name, value } => {
+                validate_metadata_key(&name)?;
+
+                let value = if crate::metadata::is_binary_key(&name) {
+                    crate::metadata::decode_binary_value(&value)
+                        .map_err(|err| format!("Invalid base64 value for binary metadata `{name}`: {err}"))?
+                } else {
+                    validate_ascii_value(&name, &value)?;
+                    value.into_bytes()
+                };
+
+                Ok(MetadataRule::Insert { name, value })
+            }
+            MetadataRuleConfig::Forward {
+                name: Some(name),
+                pattern: None,
+                rename,
+                default,
+            } => {
+                let name = name.to_ascii_lowercase();
+                validate_metadata_key(rename.as_deref().unwrap_or(&name))?;
+
+                if let Some(default) = &default {
+                    let key = rename.as_deref().unwrap_or(&name);
+
+                    if crate::metadata::is_binary_key(key) {
+                        crate::metadata::decode_binary_value(default)
+                            .map_err(|err| format!("Invalid base64 default for binary metadata `{key}`: {err}"))?;
+                    } else {
+                        validate_ascii_value(key, default)?;
+                    }
+                }
+
+                Ok(MetadataRule::Forward { name, rename, default })
+            }
+            MetadataRuleConfig::Forward {
+                name: None,
+                pattern: Some(pattern),
+                rename: None,
+                default: None,
+            } => {
+                let pattern =
+                    Regex::new(&pattern).map_err(|err| format!("Invalid metadata pattern `{pattern}`: {err}"))?;
+
+                Ok(MetadataRule::ForwardPattern { pattern })
+            }
+            MetadataRuleConfig::Forward {
+                name: None,
+                pattern: Some(_),
+                ..
+            } => Err("`rename` and `default` can only be used when forwarding a header by name".to_owned()),
+            MetadataRuleConfig::Forward { .. } => {
+                Err("A forward metadata rule must have either a `name` or a `pattern`".to_owned())
+            }
+        }
+    }
+}
+
+fn validate_metadata_key(name: &str) -> Result<(), String> {
+    let is_valid = !name.is_empty()
+        && name
+            .bytes()
+            .all(|byte| matches!(byte, b'0'..=b'9' | b'a'..=b'z' | b'-' | b'_' | b'.'));
+
+    if !is_valid {
+        return Err(format!(
+            "Invalid metadata key `{name}`: keys are made of lowercase letters, digits, `-`, `_` and `.`"
+        ));
+    }
+
+    if crate::metadata::is_reserved_key(name) {
+        return Err(format!("The metadata key `{name}` is reserved by gRPC"));
+    }
+
+    Ok(())
+}
+
+fn validate_ascii_value(name: &str, value: &str) -> Result<(), String> {
+    if value.bytes().all(|byte| (0x20..=0x7e).contains(&byte)) {
+        Ok(())
+    } else {
+        Err(format!(
+            "Invalid value for metadata `{name}`: only printable ASCII is allowed, use a `-bin` key for binary values"
+        ))
+    }
 }
@@ -1,5 +1,6 @@
 mod arguments;
 mod field;
+mod json;
 mod map;
 mod message;
 mod repeated;
@@ -1,10 +1,13 @@
 use crate::schema;
 use serde::de;
 
+/// Encodes the `input` argument of a field into the messages of a call. The input of a client
+/// streaming method is either a list, with one message per element, or a single message.
 pub(crate) struct ArgumentsDeserialize<'a> {
     pub(crate) schema: &'a schema::Schema,
     pub(crate) message: &'a schema::Message,
-    pub(crate) out: &'a mut Vec<u8>,
+    pub(crate) client_streaming: bool,
+    pub(crate) out: &'a mut Vec<Vec<u8>>,
 }
 
 impl<'de> de::DeserializeSeed<'de> for ArgumentsDeserialize<'_> {
@@ -30,12 +33,22 @@ impl<'de> de::Visitor<'de> for ArgumentsDeserialize<'_> {
         let key: Option<&str> = map.next_key()?;
 
         match key {
+            Some("input") if self.client_streaming => map.next_value_seed(MessagesDeserialize {
+                schema: self.schema,
+                message: self.message,
+                out: self.out,
+            }),
             Some("input") => {
+                let mut message = Vec::new();
+
                 map.next_value_seed(super::message::MessageDeserialize {
                     schema: self.schema,
                     message_definition: self.message,
-                    out: self.out,
+                    out: &mut message,
                 })?;
+
+                self.out.push(message);
+
                 Ok(())
             }
             _ => Err(de::Error::custom("expected a single argument called `input`")),
@@ -46,3 +59,69 @@ impl<'de> de::Visitor<'de> for ArgumentsDeserialize<'_> {
         formatter.write_str("an `input` field")
     }
 }
+
+struct MessagesDeserialize<'a> {
+    schema: &'a schema::Schema,
+    message: &'a schema::Message,
+    out: &'a mut Vec<Vec<u8>>,
+}
+
+impl<'de> de::DeserializeSeed<'de> for MessagesDeserialize<'_> {
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> de::Visitor<'de> for MessagesDeserialize<'_> {
+    type Value = ();
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: de::SeqAccess<'de>,
+    {
+        loop {
+            let mut message = Vec::new();
+
+            let seed = super::message::MessageDeserialize {
+                schema: self.schema,
+                message_definition: self.message,
+                out: &mut message,
+            };
+
+            if seq.next_element_seed(seed)?.is_none() {
+                return Ok(());
+            }
+
+            self.out.push(message);
+        }
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let mut message = Vec::new();
+
+        de::Visitor::visit_map(
+            super::message::MessageDeserialize {
+                schema: self.schema,
+                message_definition: self.message,
+                out: &mut message,
+            },
+            map,
+        )?;
+
+        self.out.push(message);
+
+        Ok(())
+    }
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str(&format!("a message of type {} or a list of them", self.message.name))
+    }
+}
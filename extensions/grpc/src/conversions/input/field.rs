@@ -63,6 +63,18 @@ impl<'de> de::DeserializeSeed<'de> for FieldVisitor<'_> {
 
                 Ok(())
             }
+            FieldType::Struct | FieldType::Value | FieldType::ListValue | FieldType::Any => {
+                let value = serde_json::Value::deserialize(deserializer)?;
+
+                // null is a value of its own for `Value`, and leaves the other types unset.
+                if value.is_null() && *self.field_type != FieldType::Value {
+                    return Ok(());
+                }
+
+                encode_message(self.tag, self.out, |out| {
+                    super::json::encode(self.field_type, &value, self.schema, out)
+                })
+            }
             FieldType::Map(map_definition) => {
                 let (key_type, value_type) = map_definition.as_ref();
 
@@ -325,12 +337,8 @@ impl<'de> de::Visitor<'de> for FieldVisitor<'_> {
 
             FieldType::Scalar(ScalarType::Bytes) => {
                 encoding::encode_key(self.tag, encoding::WireType::LengthDelimited, self.out);
-                let decoded = base64::engine::general_purpose::URL_SAFE
-                    .decode(v)
-                    .or_else(|_| base64::engine::general_purpose::URL_SAFE_NO_PAD.decode(v))
-                    .or_else(|_| base64::engine::general_purpose::STANDARD.decode(v))
-                    .or_else(|_| base64::engine::general_purpose::STANDARD_NO_PAD.decode(v))
-                    .map_err(|err| de::Error::custom(format!("Invalid base64 string: {err}")))?;
+                let decoded =
+                    decode_base64(v).map_err(|err| de::Error::custom(format!("Invalid base64 string: {err}")))?;
 
                 encoding::encode_varint(decoded.len() as u64, self.out);
 
@@ -341,12 +349,8 @@ impl<'de> de::Visitor<'de> for FieldVisitor<'_> {
             FieldType::Wrapper(ScalarType::Bytes) => encode_message(self.tag, self.out, |out| {
                 encoding::encode_key(1, encoding::WireType::LengthDelimited, out);
 
-                let decoded = base64::engine::general_purpose::URL_SAFE
-                    .decode(v)
-                    .or_else(|_| base64::engine::general_purpose::URL_SAFE_NO_PAD.decode(v))
-                    .or_else(|_| base64::engine::general_purpose::STANDARD.decode(v))
-                    .or_else(|_| base64::engine::general_purpose::STANDARD_NO_PAD.decode(v))
-                    .map_err(|err| de::Error::custom(format!("Invalid base64 string: {err}")))?;
+                let decoded =
+                    decode_base64(v).map_err(|err| de::Error::custom(format!("Invalid base64 string: {err}")))?;
 
                 encoding::encode_varint(decoded.len() as u64, out);
 
@@ -412,6 +416,15 @@ impl<'de> de::Visitor<'de> for FieldVisitor<'_> {
     }
 }
 
+/// Bytes are accepted in both the standard and URL-safe base64 alphabets, with or without padding.
+pub(super) fn decode_base64(v: &str) -> Result<Vec<u8>, base64::DecodeError> {
+    base64::engine::general_purpose::URL_SAFE
+        .decode(v)
+        .or_else(|_| base64::engine::general_purpose::URL_SAFE_NO_PAD.decode(v))
+        .or_else(|_| base64::engine::general_purpose::STANDARD.decode(v))
+        .or_else(|_| base64::engine::general_purpose::STANDARD_NO_PAD.decode(v))
+}
+
 fn encode_message<E>(tag: u32, out: &mut Vec<u8>, f: impl FnOnce(&mut Vec<u8>) -> Result<(), E>) -> Result<(), E> {
     let mut submessage = Vec::new();
     f(&mut submessage)?;
@@ -0,0 +1,135 @@
+//! Encoding of the `Struct`, `Value`, `ListValue` and `Any` well-known types from JSON, following
+//! the [ProtoJSON format](https://protobuf.dev/programming-guides/json/).
+
+use crate::schema::{self, FieldType};
+use prost::encoding;
+use serde::de::{self, DeserializeSeed as _};
+use serde_json::{Map, Value};
+
+/// Encodes the body of a message of one of the JSON well-known types.
+pub(super) fn encode<E: de::Error>(
+    field_type: &FieldType,
+    value: &Value,
+    schema: &schema::Schema,
+    out: &mut Vec<u8>,
+) -> Result<(), E> {
+    match (field_type, value) {
+        (FieldType::Value, value) => encode_value(value, out),
+        (FieldType::Struct, Value::Object(fields)) => encode_struct(fields, out),
+        (FieldType::ListValue, Value::Array(values)) => encode_list(values, out),
+        (FieldType::Any, Value::Object(fields)) => return encode_any(fields, schema, out),
+        (FieldType::ListValue, value) => return Err(de::Error::invalid_type(unexpected(value), &"a JSON array")),
+        (_, value) => return Err(de::Error::invalid_type(unexpected(value), &"a JSON object")),
+    }
+
+    Ok(())
+}
+
+/// See https://github.com/protocolbuffers/protobuf/blob/main/src/google/protobuf/struct.proto
+fn encode_value(value: &Value, out: &mut Vec<u8>) {
+    match value {
+        Value::Null => encoding::int32::encode(1, &0, out),
+        // Numbers are doubles in `Value`, like in JavaScript.
+        Value::Number(number) => encoding::double::encode(2, &number.as_f64().unwrap_or_default(), out),
+        Value::String(string) => encoding::string::encode(3, string, out),
+        Value::Bool(bool) => encoding::bool::encode(4, bool, out),
+        Value::Object(fields) => encode_submessage(5, out, |out| encode_struct(fields, out)),
+        Value::Array(values) => encode_submessage(6, out, |out| encode_list(values, out)),
+    }
+}
+
+fn encode_struct(fields: &Map<String, Value>, out: &mut Vec<u8>) {
+    // `fields` is a map<string, Value>, so each field is a map entry.
+    for (key, value) in fields {
+        encode_submessage(1, out, |entry| {
+            encoding::string::encode(1, key, entry);
+            encode_submessage(2, entry, |out| encode_value(value, out));
+        });
+    }
+}
+
+fn encode_list(values: &[Value], out: &mut Vec<u8>) {
+    for value in values {
+        encode_submessage(1, out, |out| encode_value(value, out));
+    }
+}
+
+/// An `Any` is either a message defined in the schema, with its type URL in an `@type` field, or
+/// the raw `typeUrl` and base64 encoded `value` of a message the schema does not know about.
+fn encode_any<E: de::Error>(fields: &Map<String, Value>, schema: &schema::Schema, out: &mut Vec<u8>) -> Result<(), E> {
+    let (type_url, value) = if let Some(type_url) = fields.get("@type") {
+        let type_url = type_url
+            .as_str()
+            .ok_or_else(|| de::Error::invalid_type(unexpected(type_url), &"a type URL string"))?;
+
+        let message_definition = schema.get_message_by_type_url(type_url).ok_or_else(|| {
+            de::Error::custom(format!(
+                "unknown message type {type_url} in Any, the message must be defined in @protoMessages"
+            ))
+        })?;
+
+        let message_fields = fields
+            .iter()
+            .filter(|(key, _)| *key != "@type")
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect();
+
+        let mut value = Vec::new();
+
+        super::MessageDeserialize {
+            schema,
+            message_definition,
+            out: &mut value,
+        }
+        .deserialize(&Value::Object(message_fields))
+        .map_err(de::Error::custom)?;
+
+        (type_url, value)
+    } else {
+        if let Some(key) = fields.keys().find(|key| *key != "typeUrl" && *key != "value") {
+            return Err(de::Error::unknown_field(key, &["@type", "typeUrl", "value"]));
+        }
+
+        let type_url = match fields.get("typeUrl") {
+            Some(Value::String(type_url)) => type_url,
+            Some(type_url) => return Err(de::Error::invalid_type(unexpected(type_url), &"a type URL string")),
+            None => return Err(de::Error::missing_field("typeUrl")),
+        };
+
+        let value = match fields.get("value") {
+            Some(Value::String(value)) => super::field::decode_base64(value)
+                .map_err(|err| de::Error::custom(format!("Invalid base64 string: {err}")))?,
+            Some(Value::Null) | None => Vec::new(),
+            Some(value) => return Err(de::Error::invalid_type(unexpected(value), &"a base64 string")),
+        };
+
+        (type_url.as_str(), value)
+    };
+
+    encoding::string::encode(1, &type_url.to_owned(), out);
+    encoding::bytes::encode(2, &value, out);
+
+    Ok(())
+}
+
+fn encode_submessage(tag: u32, out: &mut Vec<u8>, f: impl FnOnce(&mut Vec<u8>)) {
+    let mut submessage = Vec::new();
+    f(&mut submessage);
+
+    encoding::encode_key(tag, encoding::WireType::LengthDelimited, out);
+    encoding::encode_varint(submessage.len() as u64, out);
+    out.extend_from_slice(&submessage);
+}
+
+fn unexpected(value: &Value) -> de::Unexpected<'_> {
+    match value {
+        Value::Null => de::Unexpected::Unit,
+        Value::Bool(bool) => de::Unexpected::Bool(*bool),
+        Value::Number(number) => number
+            .as_f64()
+            .map_or(de::Unexpected::Other("number"), de::Unexpected::Float),
+        Value::String(string) => de::Unexpected::Str(string),
+        Value::Array(_) => de::Unexpected::Seq,
+        Value::Object(_) => de::Unexpected::Map,
+    }
+}
@@ -31,33 +31,190 @@ impl<'de> de::Visitor<'de> for MessageDeserialize<'_> {
     where
         A: de::MapAccess<'de>,
     {
-        while let Some(key) = map.next_key::<&str>()? {
-            let field = self.message_definition.fields.get(key).ok_or_else(|| {
-                de::Error::custom(format!(
-                    "unknown field {} on message {}",
-                    key, self.message_definition.name
-                ))
-            })?;
+        // The members set for each oneof group, at most one per group.
+        let mut oneof_members: Vec<(&str, &str)> = Vec::new();
 
-            let tag = field.number;
+        while let Some(key) = map.next_key::<&str>()? {
+            if let Some(field) = self.message_definition.fields.get(key) {
+                if let Some(group) = &field.oneof {
+                    set_oneof_member(&mut oneof_members, self.message_definition, group, &field.name)?;
+                }
 
-            if field.repeated {
-                map.next_value_seed(super::repeated::RepeatedFieldVisitor {
-                    tag,
+                map.next_value_seed(FieldSeed {
                     schema: self.schema,
                     field,
                     out: self.out,
                 })?;
-            } else {
-                map.next_value_seed(super::field::FieldVisitor {
+
+                continue;
+            }
+
+            // A oneof group as a nested input object, with one member.
+            if self.message_definition.has_oneof(key) {
+                let member = map.next_value_seed(OneofDeserialize {
                     schema: self.schema,
-                    field_type: &field.ty,
-                    tag,
+                    message_definition: self.message_definition,
+                    group: key,
                     out: self.out,
                 })?;
+
+                if let Some(member) = member {
+                    set_oneof_member(&mut oneof_members, self.message_definition, key, member)?;
+                }
+
+                continue;
             }
+
+            return Err(de::Error::custom(format!(
+                "unknown field {} on message {}",
+                key, self.message_definition.name
+            )));
         }
 
         Ok(())
     }
 }
+
+fn set_oneof_member<'a, E: de::Error>(
+    oneof_members: &mut Vec<(&'a str, &'a str)>,
+    message_definition: &schema::Message,
+    group: &'a str,
+    member: &'a str,
+) -> Result<(), E> {
+    if let Some((_, previous)) = oneof_members.iter().find(|(set_group, _)| *set_group == group) {
+        return Err(de::Error::custom(format!(
+            "fields {previous} and {member} of message {} are members of the same oneof {group}, only one of them can be set",
+            message_definition.name
+        )));
+    }
+
+    oneof_members.push((group, member));
+
+    Ok(())
+}
+
+struct FieldSeed<'a> {
+    schema: &'a schema::Schema,
+    field: &'a schema::Field,
+    out: &'a mut Vec<u8>,
+}
+
+impl<'de> de::DeserializeSeed<'de> for FieldSeed<'_> {
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        let tag = self.field.number;
+
+        if self.field.repeated {
+            super::repeated::RepeatedFieldVisitor {
+                tag,
+                schema: self.schema,
+                field: self.field,
+                out: self.out,
+            }
+            .deserialize(deserializer)
+        } else {
+            super::field::FieldVisitor {
+                schema: self.schema,
+                field_type: &self.field.ty,
+                tag,
+                out: self.out,
+            }
+            .deserialize(deserializer)
+        }
+    }
+}
+
+/// A oneof group given as an object with a single member, like an `@oneOf` input object.
+/// Returns the name of the member that was set, if any.
+struct OneofDeserialize<'a> {
+    schema: &'a schema::Schema,
+    message_definition: &'a schema::Message,
+    group: &'a str,
+    out: &'a mut Vec<u8>,
+}
+
+impl<'de> de::DeserializeSeed<'de> for OneofDeserialize<'_> {
+    type Value = Option<&'de str>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        deserializer.deserialize_option(self)
+    }
+}
+
+impl<'de> de::Visitor<'de> for OneofDeserialize<'_> {
+    type Value = Option<&'de str>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            formatter,
+            "an object with one member of oneof {} on message {}",
+            self.group, self.message_definition.name
+        )
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(None)
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(None)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let mut set_member = None;
+
+        while let Some(key) = map.next_key::<&'de str>()? {
+            let field = self
+                .message_definition
+                .fields
+                .get(key)
+                .filter(|field| field.oneof.as_deref() == Some(self.group))
+                .ok_or_else(|| {
+                    de::Error::custom(format!(
+                        "{key} is not a member of oneof {} on message {}",
+                        self.group, self.message_definition.name
+                    ))
+                })?;
+
+            if let Some(previous) = set_member {
+                return Err(de::Error::custom(format!(
+                    "fields {previous} and {key} of message {} are members of the same oneof {}, only one of them can be set",
+                    self.message_definition.name, self.group
+                )));
+            }
+
+            map.next_value_seed(FieldSeed {
+                schema: self.schema,
+                field,
+                out: &mut *self.out,
+            })?;
+
+            set_member = Some(key);
+        }
+
+        Ok(set_member)
+    }
+}
@@ -1,8 +1,9 @@
+mod decode_json;
 mod decode_message;
 mod decode_value;
 mod message_serialize;
 mod value_serialize;
 
-use self::{decode_message::*, decode_value::*, value_serialize::*};
+use self::{decode_json::*, decode_message::*, decode_value::*, value_serialize::*};
 
 pub(crate) use self::message_serialize::MessageSerialize;
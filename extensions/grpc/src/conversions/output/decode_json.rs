@@ -0,0 +1,167 @@
+//! Decoding of the `Struct`, `Value`, `ListValue` and `Any` well-known types to JSON, following
+//! the [ProtoJSON format](https://protobuf.dev/programming-guides/json/).
+
+use super::*;
+use crate::schema;
+use bytes::Buf as _;
+use prost::encoding;
+
+/// Decodes the body of a message of one of the JSON well-known types.
+pub(super) fn decode_json(
+    proto: &mut bytes::Bytes,
+    field_type: &schema::FieldType,
+    schema: &schema::Schema,
+    ctx: encoding::DecodeContext,
+) -> Result<Value, prost::DecodeError> {
+    match field_type {
+        schema::FieldType::Struct => decode_struct(proto, ctx),
+        schema::FieldType::Value => decode_json_value(proto, ctx),
+        schema::FieldType::ListValue => decode_list(proto, ctx),
+        schema::FieldType::Any => decode_any(proto, schema, ctx),
+        _ => unreachable!("{field_type:?} is not a JSON well-known type"),
+    }
+}
+
+/// See https://github.com/protocolbuffers/protobuf/blob/main/src/google/protobuf/struct.proto
+fn decode_json_value(proto: &mut bytes::Bytes, ctx: encoding::DecodeContext) -> Result<Value, prost::DecodeError> {
+    // `kind` is a oneof, so the last field wins. A value without kind is null.
+    let mut value = Value::Null;
+
+    while proto.has_remaining() {
+        let (tag, wire_type) = encoding::decode_key(proto)?;
+
+        value = match tag {
+            1 => {
+                encoding::skip_field(wire_type, tag, proto, ctx.clone())?;
+                Value::Null
+            }
+            2 => {
+                let mut number = 0f64;
+                encoding::double::merge(wire_type, &mut number, proto, ctx.clone())?;
+                Value::Double(number)
+            }
+            3 => {
+                let mut string = String::new();
+                encoding::string::merge(wire_type, &mut string, proto, ctx.clone())?;
+                Value::String(string.into())
+            }
+            4 => {
+                let mut bool = false;
+                encoding::bool::merge(wire_type, &mut bool, proto, ctx.clone())?;
+                Value::Bool(bool)
+            }
+            5 => decode_struct(&mut submessage(proto, wire_type)?, ctx.clone())?,
+            6 => decode_list(&mut submessage(proto, wire_type)?, ctx.clone())?,
+            _ => {
+                encoding::skip_field(wire_type, tag, proto, ctx.clone())?;
+                continue;
+            }
+        };
+    }
+
+    Ok(value)
+}
+
+fn decode_struct(proto: &mut bytes::Bytes, ctx: encoding::DecodeContext) -> Result<Value, prost::DecodeError> {
+    let mut fields: Vec<(Value, Value)> = Vec::new();
+
+    while proto.has_remaining() {
+        let (tag, wire_type) = encoding::decode_key(proto)?;
+
+        if tag != 1 {
+            encoding::skip_field(wire_type, tag, proto, ctx.clone())?;
+            continue;
+        }
+
+        // `fields` is a map<string, Value>. https://protobuf.dev/programming-guides/encoding/#maps
+        let mut entry = submessage(proto, wire_type)?;
+        let mut key = String::new();
+        let mut value = Value::Null;
+
+        while entry.has_remaining() {
+            let (tag, wire_type) = encoding::decode_key(&mut entry)?;
+
+            match tag {
+                1 => encoding::string::merge(wire_type, &mut key, &mut entry, ctx.clone())?,
+                2 => value = decode_json_value(&mut submessage(&mut entry, wire_type)?, ctx.clone())?,
+                _ => encoding::skip_field(wire_type, tag, &mut entry, ctx.clone())?,
+            }
+        }
+
+        match fields.iter_mut().find(|(existing, _)| existing.is_str(&key)) {
+            Some((_, previous)) => *previous = value,
+            None => fields.push((Value::String(key.into()), value)),
+        }
+    }
+
+    Ok(Value::Map(fields))
+}
+
+fn decode_list(proto: &mut bytes::Bytes, ctx: encoding::DecodeContext) -> Result<Value, prost::DecodeError> {
+    let mut values = Vec::new();
+
+    while proto.has_remaining() {
+        let (tag, wire_type) = encoding::decode_key(proto)?;
+
+        if tag == 1 {
+            values.push(decode_json_value(&mut submessage(proto, wire_type)?, ctx.clone())?);
+        } else {
+            encoding::skip_field(wire_type, tag, proto, ctx.clone())?;
+        }
+    }
+
+    Ok(Value::List(values))
+}
+
+/// An `Any` holding a message defined in the schema is decoded to that message, with its type URL
+/// in an `@type` field. Other messages are returned as their `typeUrl` and base64 encoded `value`.
+fn decode_any(
+    proto: &mut bytes::Bytes,
+    schema: &schema::Schema,
+    ctx: encoding::DecodeContext,
+) -> Result<Value, prost::DecodeError> {
+    let mut type_url = String::new();
+    let mut value = Vec::new();
+
+    while proto.has_remaining() {
+        let (tag, wire_type) = encoding::decode_key(proto)?;
+
+        match tag {
+            1 => encoding::string::merge(wire_type, &mut type_url, proto, ctx.clone())?,
+            2 => encoding::bytes::merge(wire_type, &mut value, proto, ctx.clone())?,
+            _ => encoding::skip_field(wire_type, tag, proto, ctx.clone())?,
+        }
+    }
+
+    let Some(message_definition) = schema.get_message_by_type_url(&type_url) else {
+        return Ok(Value::Map(vec![
+            (Value::String("typeUrl".into()), Value::String(type_url.into())),
+            (Value::String("value".into()), Value::Bytes(value.into())),
+        ]));
+    };
+
+    let Value::Map(fields) = decode_message(&mut value.into(), message_definition, schema, ctx)? else {
+        unreachable!("messages are decoded to maps")
+    };
+
+    let mut any = Vec::with_capacity(fields.len() + 1);
+    any.push((Value::String("@type".into()), Value::String(type_url.into())));
+    any.extend(fields);
+
+    Ok(Value::Map(any))
+}
+
+pub(super) fn submessage(
+    proto: &mut bytes::Bytes,
+    wire_type: encoding::WireType,
+) -> Result<bytes::Bytes, prost::DecodeError> {
+    encoding::check_wire_type(encoding::WireType::LengthDelimited, wire_type)?;
+
+    let len = encoding::decode_length_delimiter(&mut *proto)?;
+
+    if len > proto.remaining() {
+        return Err(prost::DecodeError::new("buffer underflow"));
+    }
+
+    Ok(proto.copy_to_bytes(len))
+}
@@ -28,6 +28,20 @@ pub(super) fn decode_message(
             continue;
         };
 
+        if let Some(group) = &field.oneof {
+            // Setting a member of a oneof clears the other members. The group holds the name of
+            // the member that is set.
+            let siblings = message_definition.oneof_siblings(field).collect::<Vec<_>>();
+            map.retain(|(key, _)| !siblings.iter().any(|sibling| key.is_str(&sibling.name)));
+
+            let case = Value::String(field.name.clone().into());
+
+            match map.iter_mut().find(|(key, _)| key.is_str(group)) {
+                Some((_, previous)) => *previous = case,
+                None => map.push((Value::String(group.clone().into()), case)),
+            }
+        }
+
         let previous_entry = map.iter_mut().find(|(key, _)| key.is_str(&field.name));
 
         if field.repeated {
@@ -177,8 +177,7 @@ pub(super) fn decode_value(
         }
 
         (encoding::WireType::LengthDelimited, Some(schema::FieldType::Message(message_id))) => {
-            let len = encoding::decode_length_delimiter(&mut *proto)?;
-            let mut message_bytes = proto.copy_to_bytes(len);
+            let mut message_bytes = submessage(proto, wire_type)?;
 
             decode_message(&mut message_bytes, &schema[*message_id], schema, ctx).map(Some)
         }
@@ -196,8 +195,7 @@ pub(super) fn decode_value(
         }
 
         (encoding::WireType::LengthDelimited, Some(schema::FieldType::Map(types))) => {
-            let len = encoding::decode_length_delimiter(&mut *proto)?;
-            let mut map_entry_bytes = proto.copy_to_bytes(len);
+            let mut map_entry_bytes = submessage(proto, wire_type)?;
 
             let (key_type, value_type) = types.as_ref();
 
@@ -228,8 +226,7 @@ pub(super) fn decode_value(
         }
 
         (encoding::WireType::LengthDelimited, Some(schema::FieldType::Duration | schema::FieldType::Timestamp)) => {
-            let len = encoding::decode_length_delimiter(&mut *proto)?;
-            let mut message_bytes = proto.copy_to_bytes(len);
+            let mut message_bytes = submessage(proto, wire_type)?;
 
             let mut seconds = None;
             let mut nanos = None;
@@ -291,22 +288,19 @@ pub(super) fn decode_value(
         }
 
         (encoding::WireType::LengthDelimited, Some(schema::FieldType::Empty)) => {
-            let len = prost::encoding::decode_length_delimiter(&mut *proto)?;
-            proto.advance(len);
+            submessage(proto, wire_type)?;
 
             Ok(Some(Value::Map(vec![])))
         }
 
         (encoding::WireType::LengthDelimited, Some(schema::FieldType::NullValue)) => {
-            let len = prost::encoding::decode_length_delimiter(&mut *proto)?;
-            proto.advance(len);
+            submessage(proto, wire_type)?;
 
             Ok(Some(Value::Null))
         }
 
         (encoding::WireType::LengthDelimited, Some(schema::FieldType::Wrapper(inner))) => {
-            let len = prost::encoding::decode_length_delimiter(&mut *proto)?;
-            let mut message = proto.copy_to_bytes(len);
+            let mut message = submessage(proto, wire_type)?;
 
             if message.is_empty() {
                 return Ok(Some(Value::Null));
@@ -323,14 +317,27 @@ pub(super) fn decode_value(
             )
         }
 
+        (
+            encoding::WireType::LengthDelimited,
+            Some(
+                field_type @ (schema::FieldType::Struct
+                | schema::FieldType::Value
+                | schema::FieldType::ListValue
+                | schema::FieldType::Any),
+            ),
+        ) => {
+            let mut message = submessage(proto, wire_type)?;
+
+            decode_json(&mut message, field_type, schema, ctx).map(Some)
+        }
+
         (encoding::WireType::LengthDelimited, Some(schema::FieldType::FieldMask)) => {
-            let len = prost::encoding::decode_length_delimiter(&mut *proto)?;
+            let mut message = submessage(proto, wire_type)?;
 
-            if len == 0 {
+            if message.is_empty() {
                 return Ok(Some(Value::Null));
             }
 
-            let mut message = proto.copy_to_bytes(len);
             if let (1, encoding::WireType::LengthDelimited) = encoding::decode_key(&mut message)? {
                 let mut value = String::new();
                 prost::encoding::string::merge(wire_type, &mut value, &mut message, ctx)?;
@@ -342,8 +349,7 @@ pub(super) fn decode_value(
         }
 
         (encoding::WireType::LengthDelimited, _) => {
-            let len = prost::encoding::decode_length_delimiter(&mut *proto)?;
-            proto.advance(len);
+            submessage(proto, wire_type)?;
 
             Ok(None)
         }
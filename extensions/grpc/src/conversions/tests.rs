@@ -14,162 +14,189 @@ fn roundtrip() {
                     r#type: "string".to_owned(),
                     repeated: false,
                     number: 3,
+                    oneof: None,
                 },
                 directives::ProtoField {
                     name: "gpgPublicKey".to_owned(),
                     r#type: "bytes".to_owned(),
                     repeated: false,
                     number: 4,
+                    oneof: None,
                 },
                 directives::ProtoField {
                     name: "numberInt32".to_owned(),
                     r#type: "int32".to_owned(),
                     repeated: false,
                     number: 7,
+                    oneof: None,
                 },
                 directives::ProtoField {
                     name: "numberInt64".to_owned(),
                     r#type: "int64".to_owned(),
                     repeated: false,
                     number: 8,
+                    oneof: None,
                 },
                 directives::ProtoField {
                     name: "friends".to_owned(),
                     r#type: "TestMessage".to_owned(),
                     repeated: true,
                     number: 9,
+                    oneof: None,
                 },
                 directives::ProtoField {
                     name: "contacts".to_owned(),
                     r#type: "map<string, TestMessage>".to_owned(),
                     repeated: false,
                     number: 29,
+                    oneof: None,
                 },
                 directives::ProtoField {
                     name: "numberSInt32".to_owned(),
                     r#type: "sint32".to_owned(),
                     repeated: false,
                     number: 10,
+                    oneof: None,
                 },
                 directives::ProtoField {
                     name: "numberSInt64".to_owned(),
                     r#type: "sint64".to_owned(),
                     repeated: false,
                     number: 12,
+                    oneof: None,
                 },
                 directives::ProtoField {
                     name: "numberSFixed32".to_owned(),
                     r#type: "sfixed32".to_owned(),
                     repeated: true,
                     number: 13,
+                    oneof: None,
                 },
                 directives::ProtoField {
                     name: "numberSFixed64".to_owned(),
                     r#type: "sfixed64".to_owned(),
                     repeated: true,
                     number: 14,
+                    oneof: None,
                 },
                 directives::ProtoField {
                     name: "numberFixed32".to_owned(),
                     r#type: "fixed32".to_owned(),
                     repeated: true,
                     number: 15,
+                    oneof: None,
                 },
                 directives::ProtoField {
                     name: "numberFixed64".to_owned(),
                     r#type: "fixed64".to_owned(),
                     repeated: true,
                     number: 16,
+                    oneof: None,
                 },
                 directives::ProtoField {
                     name: "favoriteColor".to_owned(),
                     r#type: "Color".to_owned(),
                     repeated: false,
                     number: 17,
+                    oneof: None,
                 },
                 directives::ProtoField {
                     name: "nullValue".to_owned(),
                     r#type: ".google.protobuf.NullValue".to_owned(),
                     repeated: false,
                     number: 18,
+                    oneof: None,
                 },
                 directives::ProtoField {
                     name: "boolValue".to_owned(),
                     r#type: ".google.protobuf.BoolValue".to_owned(),
                     repeated: false,
                     number: 19,
+                    oneof: None,
                 },
                 directives::ProtoField {
                     name: "stringValue".to_owned(),
                     r#type: ".google.protobuf.StringValue".to_owned(),
                     repeated: false,
                     number: 20,
+                    oneof: None,
                 },
                 directives::ProtoField {
                     name: "bytesValue".to_owned(),
                     r#type: ".google.protobuf.BytesValue".to_owned(),
                     repeated: false,
                     number: 21,
+                    oneof: None,
                 },
                 directives::ProtoField {
                     name: "int32Value".to_owned(),
                     r#type: ".google.protobuf.Int32Value".to_owned(),
                     repeated: false,
                     number: 22,
+                    oneof: None,
                 },
                 directives::ProtoField {
                     name: "int64Value".to_owned(),
                     r#type: ".google.protobuf.Int64Value".to_owned(),
                     repeated: false,
                     number: 23,
+                    oneof: None,
                 },
                 directives::ProtoField {
                     name: "uint32Value".to_owned(),
                     r#type: ".google.protobuf.UInt32Value".to_owned(),
                     repeated: false,
                     number: 24,
+                    oneof: None,
                 },
                 directives::ProtoField {
                     name: "uint64Value".to_owned(),
                     r#type: ".google.protobuf.UInt64Value".to_owned(),
                     repeated: false,
                     number: 25,
+                    oneof: None,
                 },
                 directives::ProtoField {
                     name: "floatValue".to_owned(),
                     r#type: ".google.protobuf.FloatValue".to_owned(),
                     repeated: false,
                     number: 26,
+                    oneof: None,
                 },
                 directives::ProtoField {
                     name: "doubleValue".to_owned(),
                     r#type: ".google.protobuf.DoubleValue".to_owned(),
                     repeated: false,
                     number: 27,
+                    oneof: None,
                 },
                 directives::ProtoField {
                     name: "duration".to_owned(),
                     r#type: ".google.protobuf.Duration".to_owned(),
                     repeated: false,
                     number: 30,
+                    oneof: None,
                 },
                 directives::ProtoField {
                     name: "timestamp".to_owned(),
                     r#type: ".google.protobuf.Timestamp".to_owned(),
                     repeated: false,
                     number: 31,
+                    oneof: None,
                 },
                 directives::ProtoField {
                     name: "fieldMask".to_owned(),
                     r#type: ".google.protobuf.FieldMask".to_owned(),
                     repeated: false,
                     number: 32,
+                    oneof: None,
                 },
                 directives::ProtoField {
                     name: "empty".to_owned(),
                     r#type: ".google.protobuf.Empty".to_owned(),
                     repeated: false,
                     number: 33,
+                    oneof: None,
                 },
             ],
         }],
@@ -318,6 +345,7 @@ fn packed_repeated_fields() {
                 number: 6,
                 repeated: true,
                 r#type: "int32".to_owned(),
+                oneof: None,
             }],
         }],
         vec![],
@@ -332,3 +360,223 @@ fn packed_repeated_fields() {
 
     insta::assert_snapshot!(serialized);
 }
+
+fn oneof_schema() -> schema::Schema {
+    let field = |name: &str, r#type: &str, number, oneof: Option<&str>| directives::ProtoField {
+        name: name.to_owned(),
+        number,
+        repeated: false,
+        r#type: r#type.to_owned(),
+        oneof: oneof.map(str::to_owned),
+    };
+
+    schema::Schema::new(
+        vec![],
+        vec![
+            directives::ProtoMessageDefinition {
+                name: "Shape".to_owned(),
+                fields: vec![
+                    field("name", "string", 1, None),
+                    field("circle", "Circle", 2, Some("kind")),
+                    field("square", "Square", 3, Some("kind")),
+                    field("label", "string", 4, Some("kind")),
+                ],
+            },
+            directives::ProtoMessageDefinition {
+                name: "Circle".to_owned(),
+                fields: vec![field("radius", "int32", 1, None)],
+            },
+            directives::ProtoMessageDefinition {
+                name: "Square".to_owned(),
+                fields: vec![field("side", "int32", 1, None)],
+            },
+        ],
+        vec![],
+    )
+    .unwrap()
+}
+
+fn encode(schema: &schema::Schema, message: &schema::Message, input: &str) -> Result<Vec<u8>, serde_json::Error> {
+    let mut out = Vec::new();
+
+    MessageDeserialize {
+        schema,
+        message_definition: message,
+        out: &mut out,
+    }
+    .deserialize(&mut serde_json::Deserializer::from_str(input))?;
+
+    Ok(out)
+}
+
+fn decode(schema: &schema::Schema, message: &schema::Message, proto: Vec<u8>) -> String {
+    let bytes = bytes::Bytes::from(proto);
+
+    serde_json::to_string(&super::output::MessageSerialize::new(&bytes, message, schema)).unwrap()
+}
+
+#[test]
+fn oneof() {
+    let schema = oneof_schema();
+    let message = schema.get_message("Shape").unwrap();
+
+    // Members set directly on the message.
+    let proto = encode(&schema, message, r#"{ "name": "wheel", "circle": { "radius": 2 } }"#).unwrap();
+    insta::assert_snapshot!(decode(&schema, message, proto), @r#"{"name":"wheel","kind":"circle","circle":{"radius":2}}"#);
+
+    // The group as a nested input object.
+    let proto = encode(&schema, message, r#"{ "kind": { "square": { "side": 3 } } }"#).unwrap();
+    insta::assert_snapshot!(decode(&schema, message, proto), @r#"{"kind":"square","square":{"side":3}}"#);
+
+    // An unset group.
+    let proto = encode(&schema, message, r#"{ "name": "nothing", "kind": null }"#).unwrap();
+    insta::assert_snapshot!(decode(&schema, message, proto), @r#"{"name":"nothing"}"#);
+
+    // The last member on the wire wins.
+    let mut proto = encode(&schema, message, r#"{ "circle": { "radius": 2 } }"#).unwrap();
+    proto.extend(encode(&schema, message, r#"{ "label": "tile" }"#).unwrap());
+    insta::assert_snapshot!(decode(&schema, message, proto), @r#"{"kind":"label","label":"tile"}"#);
+}
+
+#[test]
+fn oneof_with_several_members() {
+    let schema = oneof_schema();
+    let message = schema.get_message("Shape").unwrap();
+
+    let error = encode(&schema, message, r#"{ "circle": { "radius": 2 }, "label": "tile" }"#).unwrap_err();
+    insta::assert_snapshot!(error, @"fields circle and label of message Shape are members of the same oneof kind, only one of them can be set at line 1 column 36");
+
+    let error = encode(
+        &schema,
+        message,
+        r#"{ "kind": { "circle": { "radius": 2 }, "label": "tile" } }"#,
+    )
+    .unwrap_err();
+    insta::assert_snapshot!(error, @"fields circle and label of message Shape are members of the same oneof kind, only one of them can be set at line 1 column 46");
+
+    let error = encode(
+        &schema,
+        message,
+        r#"{ "label": "tile", "kind": { "circle": { "radius": 2 } } }"#,
+    )
+    .unwrap_err();
+    insta::assert_snapshot!(error, @"fields label and circle of message Shape are members of the same oneof kind, only one of them can be set at line 1 column 58");
+
+    let error = encode(&schema, message, r#"{ "kind": { "name": "wheel" } }"#).unwrap_err();
+    insta::assert_snapshot!(error, @"name is not a member of oneof kind on message Shape at line 1 column 18");
+}
+
+fn well_known_types_schema() -> schema::Schema {
+    let field = |name: &str, r#type: &str, number, repeated| directives::ProtoField {
+        name: name.to_owned(),
+        number,
+        repeated,
+        r#type: r#type.to_owned(),
+        oneof: None,
+    };
+
+    schema::Schema::new(
+        vec![],
+        vec![
+            directives::ProtoMessageDefinition {
+                name: "Event".to_owned(),
+                fields: vec![
+                    field("attributes", ".google.protobuf.Struct", 1, false),
+                    field("value", ".google.protobuf.Value", 2, false),
+                    field("tags", ".google.protobuf.ListValue", 3, false),
+                    field("values", ".google.protobuf.Value", 4, true),
+                    field("payload", ".google.protobuf.Any", 5, false),
+                ],
+            },
+            directives::ProtoMessageDefinition {
+                name: ".routeguide.Point".to_owned(),
+                fields: vec![
+                    field("latitude", "int32", 1, false),
+                    field("longitude", "int32", 2, false),
+                ],
+            },
+        ],
+        vec![],
+    )
+    .unwrap()
+}
+
+#[test]
+fn struct_value_and_list_value() {
+    let schema = well_known_types_schema();
+    let message = schema.get_message("Event").unwrap();
+
+    let proto = encode(
+        &schema,
+        message,
+        r#"{
+            "attributes": { "name": "launch", "count": 3, "ok": true, "nothing": null, "nested": { "list": [1.5, "two", [], {}] } },
+            "value": null,
+            "tags": ["a", 1, false],
+            "values": [{ "a": 1 }, "b", null]
+        }"#,
+    )
+    .unwrap();
+    insta::assert_snapshot!(decode(&schema, message, proto), @r#"{"attributes":{"name":"launch","count":3.0,"ok":true,"nothing":null,"nested":{"list":[1.5,"two",[],{}]}},"value":null,"tags":["a",1.0,false],"values":[{"a":1.0},"b",null]}"#);
+
+    // Null leaves the other types unset.
+    let proto = encode(&schema, message, r#"{ "attributes": null, "tags": null, "value": 2 }"#).unwrap();
+    insta::assert_snapshot!(decode(&schema, message, proto), @r#"{"value":2.0}"#);
+
+    let error = encode(&schema, message, r#"{ "attributes": [1] }"#).unwrap_err();
+    insta::assert_snapshot!(error, @"invalid type: sequence, expected a JSON object at line 1 column 21");
+
+    let error = encode(&schema, message, r#"{ "tags": { "a": 1 } }"#).unwrap_err();
+    insta::assert_snapshot!(error, @"invalid type: map, expected a JSON array at line 1 column 22");
+}
+
+#[test]
+fn truncated_struct() {
+    let schema = well_known_types_schema();
+    let message = schema.get_message("Event").unwrap();
+
+    // The attributes field announces ten bytes, but only two follow.
+    let bytes = bytes::Bytes::from(vec![0x0a, 10, 0x0a, 0x00]);
+    let error = serde_json::to_string(&super::output::MessageSerialize::new(&bytes, message, &schema)).unwrap_err();
+    insta::assert_snapshot!(error, @"Failed to decode message: failed to decode Protobuf message: buffer underflow");
+}
+
+#[test]
+fn any() {
+    let schema = well_known_types_schema();
+    let message = schema.get_message("Event").unwrap();
+
+    // A message defined in the schema.
+    let proto = encode(
+        &schema,
+        message,
+        r#"{ "payload": { "@type": "type.googleapis.com/routeguide.Point", "latitude": 1, "longitude": 2 } }"#,
+    )
+    .unwrap();
+    insta::assert_snapshot!(decode(&schema, message, proto), @r#"{"payload":{"@type":"type.googleapis.com/routeguide.Point","latitude":1,"longitude":2}}"#);
+
+    // Any other message.
+    let proto = encode(
+        &schema,
+        message,
+        r#"{ "payload": { "typeUrl": "type.googleapis.com/other.Message", "value": "CgNhYmM=" } }"#,
+    )
+    .unwrap();
+    insta::assert_snapshot!(decode(&schema, message, proto), @r#"{"payload":{"typeUrl":"type.googleapis.com/other.Message","value":"CgNhYmM="}}"#);
+
+    let error = encode(
+        &schema,
+        message,
+        r#"{ "payload": { "@type": "type.googleapis.com/other.Message", "name": "abc" } }"#,
+    )
+    .unwrap_err();
+    insta::assert_snapshot!(error, @"unknown message type type.googleapis.com/other.Message in Any, the message must be defined in @protoMessages at line 1 column 78");
+
+    let error = encode(
+        &schema,
+        message,
+        r#"{ "payload": { "@type": "type.googleapis.com/routeguide.Point", "altitude": 1 } }"#,
+    )
+    .unwrap_err();
+    insta::assert_snapshot!(error, @"unknown field altitude on message .routeguide.Point at line 1 column 81");
+}
@@ -0,0 +1,296 @@
+//! Definitions of messages, enums and services read from protobuf descriptors at start-up, as an
+//! alternative to the `@protoMessages`, `@protoEnums` and `@protoServices` directives.
+
+mod compatibility;
+mod reflection;
+
+#[cfg(test)]
+mod tests;
+
+pub(crate) use self::compatibility::check_fields;
+
+use crate::{balancer::Balancer, config::Service, directives, schema};
+use grafbase_sdk::types::Error;
+use prost::Message as _;
+use prost_types::{
+    DescriptorProto, EnumDescriptorProto, FieldDescriptorProto, FileDescriptorProto, FileDescriptorSet,
+    field_descriptor_proto::{Label, Type},
+};
+
+#[derive(Debug, Default)]
+pub(crate) struct Definitions {
+    pub(crate) services: Vec<directives::ProtoServiceDefinition>,
+    pub(crate) messages: Vec<directives::ProtoMessageDefinition>,
+    pub(crate) enums: Vec<directives::ProtoEnumDefinition>,
+}
+
+impl Definitions {
+    /// Loads the definitions of a service configured with `reflection` or a `file_descriptor_set`.
+    /// Returns whether the service has descriptors.
+    pub(crate) fn load(&mut self, service: &Service, balancer: &mut Balancer) -> Result<bool, Error> {
+        let files = match (&service.file_descriptor_set, service.reflection) {
+            (Some(_), true) => {
+                return Err(Error::new(format!(
+                    "Service {} can be configured with either `reflection` or a `file_descriptor_set`, not both",
+                    service.name
+                )));
+            }
+            (Some(file_descriptor_set), false) => {
+                FileDescriptorSet::decode(file_descriptor_set.as_slice())
+                    .map_err(|err| {
+                        Error::new(format!(
+                            "Invalid file descriptor set for service {}: {err}",
+                            service.name
+                        ))
+                    })?
+                    .file
+            }
+            (None, true) => reflection::fetch_files(service, balancer)?,
+            (None, false) => return Ok(false),
+        };
+
+        self.ingest(&service.name, &files)?;
+
+        Ok(true)
+    }
+
+    /// Adds the definitions of the directives of the schema. Definitions found in both must match.
+    pub(crate) fn merge(
+        mut self,
+        services: Vec<directives::ProtoServiceDefinition>,
+        messages: Vec<directives::ProtoMessageDefinition>,
+        enums: Vec<directives::ProtoEnumDefinition>,
+    ) -> Result<Self, Error> {
+        for service in services {
+            if self.services.iter().any(|existing| existing.name == service.name) {
+                return Err(Error::new(format!(
+                    "Service {} is defined both in @protoServices and by descriptors",
+                    service.name
+                )));
+            }
+
+            self.services.push(service);
+        }
+
+        for message in messages {
+            match self.messages.iter().find(|existing| existing.name == message.name) {
+                Some(existing) if *existing != message => {
+                    return Err(Error::new(format!(
+                        "Message {} in @protoMessages does not match its descriptor",
+                        message.name
+                    )));
+                }
+                Some(_) => (),
+                None => self.messages.push(message),
+            }
+        }
+
+        for r#enum in enums {
+            match self.enums.iter().find(|existing| existing.name == r#enum.name) {
+                Some(existing) if *existing != r#enum => {
+                    return Err(Error::new(format!(
+                        "Enum {} in @protoEnums does not match its descriptor",
+                        r#enum.name
+                    )));
+                }
+                Some(_) => (),
+                None => self.enums.push(r#enum),
+            }
+        }
+
+        Ok(self)
+    }
+
+    fn ingest(&mut self, service_name: &str, files: &[FileDescriptorProto]) -> Result<(), Error> {
+        let service = files
+            .iter()
+            .flat_map(|file| file.service.iter().map(move |service| (file.package(), service)))
+            .find(|(package, service)| qualified_name(package, service.name()) == service_name)
+            .map(|(_, service)| service)
+            .ok_or_else(|| Error::new(format!("Service {service_name} is not defined in its descriptors")))?;
+
+        if self.services.iter().any(|existing| existing.name == service_name) {
+            return Err(Error::new(format!("Service {service_name} is configured twice")));
+        }
+
+        self.services.push(directives::ProtoServiceDefinition {
+            name: service_name.to_owned(),
+            methods: service
+                .method
+                .iter()
+                .map(|method| directives::ProtoMethodDefinition {
+                    name: method.name().to_owned(),
+                    input_type: method.input_type().to_owned(),
+                    output_type: method.output_type().to_owned(),
+                    server_streaming: method.server_streaming(),
+                    client_streaming: method.client_streaming(),
+                })
+                .collect(),
+        });
+
+        // Types are named like in the `type_name` of fields: fully qualified, with a leading dot.
+        for file in files {
+            let scope = match file.package() {
+                "" => String::new(),
+                package => format!(".{package}"),
+            };
+
+            for message in &file.message_type {
+                self.ingest_message(&scope, message)?;
+            }
+
+            for r#enum in &file.enum_type {
+                self.ingest_enum(&scope, r#enum)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn ingest_message(&mut self, scope: &str, message: &DescriptorProto) -> Result<(), Error> {
+        let name = format!("{scope}.{}", message.name());
+
+        // The files of a service can be shared with other services.
+        if schema::well_known_type(&name).is_some() || self.messages.iter().any(|existing| existing.name == name) {
+            return Ok(());
+        }
+
+        let fields = message
+            .field
+            .iter()
+            .map(|field| ingest_field(&name, message, field))
+            .collect::<Result<_, _>>()?;
+
+        self.messages.push(directives::ProtoMessageDefinition {
+            name: name.clone(),
+            fields,
+        });
+
+        for nested in message.nested_type.iter().filter(|nested| !is_map_entry(nested)) {
+            self.ingest_message(&name, nested)?;
+        }
+
+        for r#enum in &message.enum_type {
+            self.ingest_enum(&name, r#enum)?;
+        }
+
+        Ok(())
+    }
+
+    fn ingest_enum(&mut self, scope: &str, r#enum: &EnumDescriptorProto) -> Result<(), Error> {
+        let name = format!("{scope}.{}", r#enum.name());
+
+        if schema::well_known_type(&name).is_some() || self.enums.iter().any(|existing| existing.name == name) {
+            return Ok(());
+        }
+
+        let values = r#enum
+            .value
+            .iter()
+            .map(|value| {
+                let number = u32::try_from(value.number()).map_err(|_| {
+                    Error::new(format!(
+                        "Value {} of enum {name} is negative, which is not supported",
+                        value.name()
+                    ))
+                })?;
+
+                Ok(directives::ProtoEnumValueDefinition {
+                    name: value.name().to_owned(),
+                    number,
+                })
+            })
+            .collect::<Result<_, Error>>()?;
+
+        self.enums.push(directives::ProtoEnumDefinition { name, values });
+
+        Ok(())
+    }
+}
+
+fn ingest_field(
+    message_name: &str,
+    message: &DescriptorProto,
+    field: &FieldDescriptorProto,
+) -> Result<directives::ProtoField, Error> {
+    // Map fields are repeated entries of a nested message with a key and a value field.
+    let map_entry = message.nested_type.iter().find(|nested| {
+        field.r#type() == Type::Message
+            && is_map_entry(nested)
+            && field.type_name() == format!("{message_name}.{}", nested.name())
+    });
+
+    let (r#type, repeated) = match map_entry {
+        Some(entry) => {
+            let entry_field = |number| {
+                entry
+                    .field
+                    .iter()
+                    .find(|field| field.number() == number)
+                    .ok_or_else(|| Error::new(format!("Invalid map entry {message_name}.{}", entry.name())))
+            };
+
+            let key_type = field_type(message_name, entry_field(1)?)?;
+            let value_type = field_type(message_name, entry_field(2)?)?;
+
+            (format!("map<{key_type}, {value_type}>"), false)
+        }
+        None => (field_type(message_name, field)?, field.label() == Label::Repeated),
+    };
+
+    // Optional fields of proto3 are in a oneof of their own, which is not part of the API.
+    let oneof = field
+        .oneof_index
+        .filter(|_| !field.proto3_optional())
+        .and_then(|index| message.oneof_decl.get(index as usize))
+        .map(|oneof| oneof.name().to_owned());
+
+    Ok(directives::ProtoField {
+        name: field.name().to_owned(),
+        number: field.number() as u32,
+        repeated,
+        r#type,
+        oneof,
+    })
+}
+
+fn field_type(message_name: &str, field: &FieldDescriptorProto) -> Result<String, Error> {
+    let scalar_type = match field.r#type() {
+        Type::Message | Type::Enum => return Ok(field.type_name().to_owned()),
+        Type::Group => {
+            return Err(Error::new(format!(
+                "Field {message_name}.{} is a group, which is not supported",
+                field.name()
+            )));
+        }
+        Type::Double => schema::ScalarType::Double,
+        Type::Float => schema::ScalarType::Float,
+        Type::Int64 => schema::ScalarType::Int64,
+        Type::Uint64 => schema::ScalarType::UInt64,
+        Type::Int32 => schema::ScalarType::Int32,
+        Type::Fixed64 => schema::ScalarType::Fixed64,
+        Type::Fixed32 => schema::ScalarType::Fixed32,
+        Type::Bool => schema::ScalarType::Bool,
+        Type::String => schema::ScalarType::String,
+        Type::Bytes => schema::ScalarType::Bytes,
+        Type::Uint32 => schema::ScalarType::UInt32,
+        Type::Sfixed32 => schema::ScalarType::Sfixed32,
+        Type::Sfixed64 => schema::ScalarType::Sfixed64,
+        Type::Sint32 => schema::ScalarType::Sint32,
+        Type::Sint64 => schema::ScalarType::Sint64,
+    };
+
+    Ok(scalar_type.proto_name().to_owned())
+}
+
+fn is_map_entry(message: &DescriptorProto) -> bool {
+    message.options.as_ref().is_some_and(|options| options.map_entry())
+}
+
+fn qualified_name(package: &str, name: &str) -> String {
+    if package.is_empty() {
+        name.to_owned()
+    } else {
+        format!("{package}.{name}")
+    }
+}
@@ -0,0 +1,307 @@
+use crate::{
+    directives,
+    schema::{self, FieldType, ScalarType},
+};
+use grafbase_sdk::types::{
+    DefinitionId, Error, FieldDefinition, InputObjectDefinition, ObjectDefinition, SubgraphSchema, Type, TypeDefinition,
+};
+
+/// Checks the fields mapped to the methods of services with descriptors against the messages of the
+/// methods, so that a change of the protobuf definitions fails at start-up rather than on requests.
+pub(crate) fn check_fields(
+    subgraph_schemas: &[SubgraphSchema],
+    schema: &schema::Schema,
+    services: &[&str],
+) -> Result<(), Error> {
+    for subgraph_schema in subgraph_schemas {
+        for field in subgraph_schema.iter_fields() {
+            for directive in field.directives().filter(|directive| directive.name() == "grpcMethod") {
+                let grpc_method: directives::GrpcMethod = directive.arguments()?;
+
+                if !services.contains(&grpc_method.service.as_str()) {
+                    continue;
+                }
+
+                check_field(field, &grpc_method, schema).map_err(|err| {
+                    Error::new(format!(
+                        "Field {}.{} does not match the descriptors of service {}: {err}",
+                        field.parent_entity(),
+                        field.name(),
+                        grpc_method.service
+                    ))
+                })?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// The pairs of GraphQL types and messages already checked, as types can be recursive.
+type Visited<'a> = Vec<(DefinitionId, &'a str)>;
+
+fn check_field<'a>(
+    field: FieldDefinition<'_>,
+    grpc_method: &directives::GrpcMethod,
+    schema: &'a schema::Schema,
+) -> Result<(), String> {
+    let method = schema
+        .get_service(&grpc_method.service)
+        .and_then(|service| service.get_method(&grpc_method.method))
+        .ok_or_else(|| format!("method {} is not defined", grpc_method.method))?;
+
+    let message = |name: &str| {
+        schema
+            .get_message(name)
+            .ok_or_else(|| format!("message {name} is not defined"))
+    };
+
+    let input_message = message(&method.input_type)?;
+    let output_message = message(&method.output_type)?;
+
+    let mut visited: Visited<'a> = Vec::new();
+
+    if let Some(argument) = field.arguments().find(|argument| argument.name() == "input")
+        && let TypeDefinition::InputObject(input_object) = argument.ty().definition()
+    {
+        check_input_object(input_object, input_message, schema, &mut visited)?;
+    }
+
+    if let TypeDefinition::Object(object) = field.ty().definition() {
+        check_object(object, output_message, schema, &mut visited)?;
+    }
+
+    Ok(())
+}
+
+fn check_input_object<'a>(
+    input_object: InputObjectDefinition<'_>,
+    message: &'a schema::Message,
+    schema: &'a schema::Schema,
+    visited: &mut Visited<'a>,
+) -> Result<(), String> {
+    if visited.contains(&(input_object.id(), message.name.as_str())) {
+        return Ok(());
+    }
+
+    visited.push((input_object.id(), message.name.as_str()));
+
+    for input_field in input_object.input_fields() {
+        let name = input_field.name();
+
+        if let Some(field) = message.fields.get(name) {
+            check_type(input_field.ty(), field, schema, false).map_err(|err| {
+                format!(
+                    "input field {}.{name} does not match field {name} of message {}: {err}",
+                    input_object.name(),
+                    message.name
+                )
+            })?;
+
+            if let (FieldType::Message(id), TypeDefinition::InputObject(nested)) =
+                (&field.ty, input_field.ty().definition())
+            {
+                check_input_object(nested, &schema[*id], schema, visited)?;
+            }
+
+            continue;
+        }
+
+        // A oneof group as a nested input object.
+        if message.has_oneof(name) {
+            if let TypeDefinition::InputObject(group) = input_field.ty().definition() {
+                for member in group.input_fields() {
+                    let Some(field) = message
+                        .fields
+                        .get(member.name())
+                        .filter(|field| field.oneof.as_deref() == Some(name))
+                    else {
+                        return Err(format!(
+                            "input field {}.{} is not a member of oneof {name} in message {}",
+                            group.name(),
+                            member.name(),
+                            message.name
+                        ));
+                    };
+
+                    check_type(member.ty(), field, schema, false).map_err(|err| {
+                        format!(
+                            "input field {}.{} does not match field {} of message {}: {err}",
+                            group.name(),
+                            member.name(),
+                            member.name(),
+                            message.name
+                        )
+                    })?;
+                }
+            }
+
+            continue;
+        }
+
+        return Err(format!(
+            "input field {}.{name} has no matching field in message {}",
+            input_object.name(),
+            message.name
+        ));
+    }
+
+    Ok(())
+}
+
+fn check_object<'a>(
+    object: ObjectDefinition<'_>,
+    message: &'a schema::Message,
+    schema: &'a schema::Schema,
+    visited: &mut Visited<'a>,
+) -> Result<(), String> {
+    if visited.contains(&(object.id(), message.name.as_str())) {
+        return Ok(());
+    }
+
+    visited.push((object.id(), message.name.as_str()));
+
+    for object_field in object.fields() {
+        let name = object_field.name();
+
+        // Fields resolved by methods of their own.
+        if object_field
+            .directives()
+            .any(|directive| directive.name() == "grpcMethod")
+        {
+            continue;
+        }
+
+        if let Some(field) = message.fields.get(name) {
+            check_type(object_field.ty(), field, schema, true).map_err(|err| {
+                format!(
+                    "field {}.{name} does not match field {name} of message {}: {err}",
+                    object.name(),
+                    message.name
+                )
+            })?;
+
+            if let (FieldType::Message(id), TypeDefinition::Object(nested)) =
+                (&field.ty, object_field.ty().definition())
+            {
+                check_object(nested, &schema[*id], schema, visited)?;
+            }
+
+            continue;
+        }
+
+        if !message.has_oneof(name) {
+            return Err(format!(
+                "field {}.{name} has no matching field in message {}",
+                object.name(),
+                message.name
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks the list shape and the type of a GraphQL field or input field against a message field.
+/// Nested messages are checked by the callers.
+fn check_type(ty: Type<'_>, field: &schema::Field, schema: &schema::Schema, output: bool) -> Result<(), String> {
+    // Maps are rendered as lists of entries or as custom scalars, and are not checked further.
+    if let FieldType::Map(_) = field.ty {
+        return Ok(());
+    }
+
+    if ty.is_list() != field.repeated {
+        return Err(if field.repeated {
+            "the field is repeated, but the GraphQL type is not a list".to_owned()
+        } else {
+            "the GraphQL type is a list, but the field is not repeated".to_owned()
+        });
+    }
+
+    let compatible = match (ty.definition(), &field.ty) {
+        (TypeDefinition::Object(_) | TypeDefinition::InputObject(_), ty) => {
+            matches!(ty, FieldType::Message(_) | FieldType::Empty)
+        }
+        (TypeDefinition::Enum(enum_definition), FieldType::Enum(id)) => {
+            let proto_enum = &schema[*id];
+
+            if let Some(value) = enum_definition
+                .values()
+                .find(|value| !proto_enum.values.iter().any(|proto| proto.name == value.name()))
+            {
+                return Err(format!(
+                    "the value {} of enum {} is not defined in {}",
+                    value.name(),
+                    enum_definition.name(),
+                    proto_enum.name
+                ));
+            }
+
+            // Responses can contain any value of the protobuf enum.
+            if output
+                && let Some(value) = proto_enum
+                    .values
+                    .iter()
+                    .find(|proto| !enum_definition.values().any(|value| value.name() == proto.name))
+            {
+                return Err(format!(
+                    "the value {} of {} is not defined in enum {}",
+                    value.name,
+                    proto_enum.name,
+                    enum_definition.name()
+                ));
+            }
+
+            true
+        }
+        (TypeDefinition::Scalar(scalar), ty) => scalar_matches(scalar.name(), ty),
+        _ => false,
+    };
+
+    if compatible {
+        Ok(())
+    } else {
+        Err(format!(
+            "{} is not compatible with {}",
+            ty.definition().name(),
+            field.ty.proto_name(schema)
+        ))
+    }
+}
+
+/// Whether the values of a protobuf type map to a GraphQL scalar. Custom scalars other than the
+/// ones rendered by protoc-gen-grafbase-subgraph accept any value that is not a message or an enum.
+fn scalar_matches(scalar: &str, ty: &FieldType) -> bool {
+    let scalar_type = match ty {
+        FieldType::Scalar(scalar_type) | FieldType::Wrapper(scalar_type) => Some(*scalar_type),
+        _ => None,
+    };
+
+    match scalar {
+        "Int" => matches!(
+            scalar_type,
+            Some(
+                ScalarType::Int32
+                    | ScalarType::UInt32
+                    | ScalarType::Sint32
+                    | ScalarType::Fixed32
+                    | ScalarType::Sfixed32
+            )
+        ),
+        "I64" => matches!(
+            scalar_type,
+            Some(ScalarType::Int64 | ScalarType::Sint64 | ScalarType::Sfixed64)
+        ),
+        "U64" => matches!(scalar_type, Some(ScalarType::UInt64 | ScalarType::Fixed64)),
+        "Float" => {
+            scalar_type.is_some_and(|ty| !matches!(ty, ScalarType::Bool | ScalarType::String | ScalarType::Bytes))
+        }
+        "Boolean" => scalar_type == Some(ScalarType::Bool),
+        "Bytes" => scalar_type == Some(ScalarType::Bytes),
+        "String" => {
+            matches!(scalar_type, Some(ScalarType::String))
+                || matches!(ty, FieldType::Duration | FieldType::Timestamp | FieldType::FieldMask)
+        }
+        _ => !matches!(ty, FieldType::Message(_) | FieldType::Enum(_)),
+    }
+}
@@ -0,0 +1,190 @@
+//! A client of the gRPC server reflection service, see
+//! <https://github.com/grpc/grpc/blob/master/doc/server-reflection.md>.
+
+use crate::{balancer::Balancer, config::Service};
+use grafbase_sdk::{
+    host_io::grpc::{GrpcStatus, GrpcStatusCode},
+    types::Error,
+};
+use prost::Message as _;
+use prost_types::FileDescriptorProto;
+use std::time::Duration;
+
+/// The reflection service, and its alpha version still served by many servers.
+const REFLECTION_SERVICES: [&str; 2] = [
+    "grpc.reflection.v1.ServerReflection",
+    "grpc.reflection.v1alpha.ServerReflection",
+];
+
+const REFLECTION_METHOD: &str = "ServerReflectionInfo";
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct ServerReflectionRequest {
+    #[prost(string, tag = "1")]
+    host: String,
+    #[prost(oneof = "MessageRequest", tags = "3, 4")]
+    message_request: Option<MessageRequest>,
+}
+
+#[derive(Clone, PartialEq, prost::Oneof)]
+enum MessageRequest {
+    #[prost(string, tag = "3")]
+    FileByFilename(String),
+    #[prost(string, tag = "4")]
+    FileContainingSymbol(String),
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct ServerReflectionResponse {
+    #[prost(oneof = "MessageResponse", tags = "4, 7")]
+    message_response: Option<MessageResponse>,
+}
+
+#[derive(Clone, PartialEq, prost::Oneof)]
+enum MessageResponse {
+    #[prost(message, tag = "4")]
+    FileDescriptorResponse(FileDescriptorResponse),
+    #[prost(message, tag = "7")]
+    ErrorResponse(ErrorResponse),
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct FileDescriptorResponse {
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    file_descriptor_proto: Vec<Vec<u8>>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct ErrorResponse {
+    #[prost(int32, tag = "1")]
+    error_code: i32,
+    #[prost(string, tag = "2")]
+    error_message: String,
+}
+
+/// Fetches the file defining the service, and the files it depends on.
+pub(super) fn fetch_files(service: &Service, balancer: &mut Balancer) -> Result<Vec<FileDescriptorProto>, Error> {
+    let mut reflection = Reflection {
+        balancer,
+        service,
+        metadata: crate::metadata::build_static(service),
+        reflection_service: REFLECTION_SERVICES[0],
+    };
+
+    let mut files: Vec<FileDescriptorProto> = Vec::new();
+    let mut received = reflection.request(MessageRequest::FileContainingSymbol(service.name.clone()))?;
+
+    // Servers usually send the dependencies of a file along with it, but don't have to. Missing
+    // dependencies are requested by name.
+    loop {
+        for file in received {
+            if !files.iter().any(|existing| existing.name == file.name) {
+                files.push(file);
+            }
+        }
+
+        let missing = files
+            .iter()
+            .flat_map(|file| &file.dependency)
+            .find(|dependency| !files.iter().any(|file| file.name() == dependency.as_str()))
+            .cloned();
+
+        let Some(missing) = missing else {
+            return Ok(files);
+        };
+
+        received = reflection.request(MessageRequest::FileByFilename(missing.clone()))?;
+
+        if !received.iter().any(|file| file.name() == missing) {
+            return Err(Error::new(format!(
+                "Server reflection of service {} did not return the file {missing}",
+                service.name
+            )));
+        }
+    }
+}
+
+struct Reflection<'a> {
+    balancer: &'a mut Balancer,
+    service: &'a Service,
+    metadata: Vec<(String, Vec<u8>)>,
+    reflection_service: &'static str,
+}
+
+impl Reflection<'_> {
+    fn request(&mut self, request: MessageRequest) -> Result<Vec<FileDescriptorProto>, Error> {
+        let request = ServerReflectionRequest {
+            host: String::new(),
+            message_request: Some(request),
+        }
+        .encode_to_vec();
+
+        let response = match self.exchange(&request) {
+            Err(status)
+                if status.code() == GrpcStatusCode::Unimplemented
+                    && self.reflection_service == REFLECTION_SERVICES[0] =>
+            {
+                self.reflection_service = REFLECTION_SERVICES[1];
+                self.exchange(&request)
+            }
+            result => result,
+        }
+        .map_err(|status| {
+            Error::new(format!(
+                "Server reflection of service {} failed. Status code: {:?}. Message: {}",
+                self.service.name,
+                status.code(),
+                status.message()
+            ))
+        })?
+        .unwrap_or_default();
+
+        let response = ServerReflectionResponse::decode(response.as_slice()).map_err(|err| {
+            Error::new(format!(
+                "Invalid server reflection response for service {}: {err}",
+                self.service.name
+            ))
+        })?;
+
+        match response.message_response {
+            Some(MessageResponse::FileDescriptorResponse(response)) => response
+                .file_descriptor_proto
+                .iter()
+                .map(|file| {
+                    FileDescriptorProto::decode(file.as_slice()).map_err(|err| {
+                        Error::new(format!(
+                            "Invalid file descriptor from the server reflection of service {}: {err}",
+                            self.service.name
+                        ))
+                    })
+                })
+                .collect(),
+            Some(MessageResponse::ErrorResponse(error)) => Err(Error::new(format!(
+                "Server reflection of service {} failed: {}",
+                self.service.name, error.error_message
+            ))),
+            None => Err(Error::new(format!(
+                "Server reflection of service {} returned no file descriptors",
+                self.service.name
+            ))),
+        }
+    }
+
+    /// Sends one request on the bidirectional stream. The server answers each request with exactly
+    /// one response, so the stream is not read further.
+    fn exchange(&mut self, request: &[u8]) -> Result<Option<Vec<u8>>, GrpcStatus> {
+        let timeout = self.service.timeout_ms.map(Duration::from_millis);
+
+        self.balancer.call(|client| {
+            client
+                .streaming(
+                    request,
+                    self.reflection_service,
+                    REFLECTION_METHOD,
+                    &self.metadata,
+                    timeout,
+                )?
+                .next_message()
+        })
+    }
+}
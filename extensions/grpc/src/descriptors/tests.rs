@@ -0,0 +1,189 @@
+use super::Definitions;
+use crate::{directives, schema};
+use prost::Message as _;
+use prost_types::{
+    DescriptorProto, EnumDescriptorProto, EnumValueDescriptorProto, FieldDescriptorProto, FileDescriptorProto,
+    FileDescriptorSet, MessageOptions, MethodDescriptorProto, OneofDescriptorProto, ServiceDescriptorProto,
+    field_descriptor_proto::{Label, Type},
+};
+
+fn field(name: &str, number: i32, r#type: Type, type_name: Option<&str>) -> FieldDescriptorProto {
+    FieldDescriptorProto {
+        name: Some(name.to_owned()),
+        number: Some(number),
+        label: Some(Label::Optional.into()),
+        r#type: Some(r#type.into()),
+        type_name: type_name.map(str::to_owned),
+        ..Default::default()
+    }
+}
+
+fn orders_file() -> FileDescriptorProto {
+    FileDescriptorProto {
+        name: Some("shop/orders.proto".to_owned()),
+        package: Some("shop".to_owned()),
+        dependency: vec!["google/protobuf/timestamp.proto".to_owned()],
+        message_type: vec![DescriptorProto {
+            name: Some("Order".to_owned()),
+            field: vec![
+                FieldDescriptorProto {
+                    label: Some(Label::Repeated.into()),
+                    ..field("quantities", 1, Type::Message, Some(".shop.Order.QuantitiesEntry"))
+                },
+                FieldDescriptorProto {
+                    oneof_index: Some(0),
+                    ..field("card", 2, Type::String, None)
+                },
+                FieldDescriptorProto {
+                    oneof_index: Some(0),
+                    ..field("voucher", 3, Type::String, None)
+                },
+                FieldDescriptorProto {
+                    oneof_index: Some(1),
+                    proto3_optional: Some(true),
+                    ..field("note", 4, Type::String, None)
+                },
+                FieldDescriptorProto {
+                    label: Some(Label::Repeated.into()),
+                    ..field("items", 5, Type::Message, Some(".shop.Order.Item"))
+                },
+                field("created_at", 6, Type::Message, Some(".google.protobuf.Timestamp")),
+                field("status", 7, Type::Enum, Some(".shop.Order.Status")),
+            ],
+            nested_type: vec![
+                DescriptorProto {
+                    name: Some("QuantitiesEntry".to_owned()),
+                    field: vec![
+                        field("key", 1, Type::String, None),
+                        field("value", 2, Type::Int32, None),
+                    ],
+                    options: Some(MessageOptions {
+                        map_entry: Some(true),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+                DescriptorProto {
+                    name: Some("Item".to_owned()),
+                    field: vec![field("sku", 1, Type::String, None)],
+                    ..Default::default()
+                },
+            ],
+            enum_type: vec![EnumDescriptorProto {
+                name: Some("Status".to_owned()),
+                value: vec![
+                    EnumValueDescriptorProto {
+                        name: Some("PENDING".to_owned()),
+                        number: Some(0),
+                        ..Default::default()
+                    },
+                    EnumValueDescriptorProto {
+                        name: Some("SHIPPED".to_owned()),
+                        number: Some(1),
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            }],
+            oneof_decl: vec![
+                OneofDescriptorProto {
+                    name: Some("payment".to_owned()),
+                    ..Default::default()
+                },
+                OneofDescriptorProto {
+                    name: Some("_note".to_owned()),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        }],
+        service: vec![ServiceDescriptorProto {
+            name: Some("Orders".to_owned()),
+            method: vec![MethodDescriptorProto {
+                name: Some("GetOrder".to_owned()),
+                input_type: Some(".shop.Order".to_owned()),
+                output_type: Some(".shop.Order".to_owned()),
+                server_streaming: Some(true),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }],
+        ..Default::default()
+    }
+}
+
+fn timestamp_file() -> FileDescriptorProto {
+    FileDescriptorProto {
+        name: Some("google/protobuf/timestamp.proto".to_owned()),
+        package: Some("google.protobuf".to_owned()),
+        message_type: vec![DescriptorProto {
+            name: Some("Timestamp".to_owned()),
+            field: vec![
+                field("seconds", 1, Type::Int64, None),
+                field("nanos", 2, Type::Int32, None),
+            ],
+            ..Default::default()
+        }],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn route_guide() {
+    let file_descriptor_set =
+        FileDescriptorSet::decode(&include_bytes!(concat!(env!("OUT_DIR"), "/route_guide_descriptor.bin"))[..])
+            .unwrap();
+
+    let mut definitions = Definitions::default();
+    definitions
+        .ingest("routeguide.RouteGuide", &file_descriptor_set.file)
+        .unwrap();
+
+    insta::assert_debug_snapshot!(definitions);
+}
+
+#[test]
+fn maps_oneofs_and_nested_types() {
+    let mut definitions = Definitions::default();
+    definitions
+        .ingest("shop.Orders", &[orders_file(), timestamp_file()])
+        .unwrap();
+
+    insta::assert_debug_snapshot!(definitions);
+
+    schema::Schema::new(definitions.services, definitions.messages, definitions.enums).unwrap();
+}
+
+#[test]
+fn unknown_service() {
+    let mut definitions = Definitions::default();
+    let error = definitions.ingest("shop.Carts", &[orders_file()]).unwrap_err();
+
+    insta::assert_snapshot!(error, @"Service shop.Carts is not defined in its descriptors");
+}
+
+#[test]
+fn merge_with_directives() {
+    let mut definitions = Definitions::default();
+    definitions.ingest("shop.Orders", &[orders_file()]).unwrap();
+
+    let item = |r#type: &str| directives::ProtoMessageDefinition {
+        name: ".shop.Order.Item".to_owned(),
+        fields: vec![directives::ProtoField {
+            name: "sku".to_owned(),
+            number: 1,
+            repeated: false,
+            r#type: r#type.to_owned(),
+            oneof: None,
+        }],
+    };
+
+    let error = Definitions::default()
+        .merge(vec![], vec![item("string")], vec![])
+        .and_then(|definitions| definitions.merge(vec![], vec![item("bytes")], vec![]))
+        .unwrap_err();
+    insta::assert_snapshot!(error, @"Message .shop.Order.Item in @protoMessages does not match its descriptor");
+
+    let definitions = definitions.merge(vec![], vec![item("string")], vec![]).unwrap();
+    assert_eq!(definitions.messages.len(), 2);
+}
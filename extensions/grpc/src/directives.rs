@@ -1,7 +1,11 @@
 #[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub(crate) struct GrpcMethod {
     pub(crate) service: String,
     pub(crate) method: String,
+    /// The `google.protobuf.FieldMask` field of the input filled with the selected fields.
+    #[serde(default)]
+    pub(crate) field_mask: Option<String>,
     // Note: there is an `input` field. It is left out to be serialized separately, directly into a protocol buffer. The service and method fields are required for that last step.
 }
 
@@ -10,19 +14,22 @@ pub(crate) struct ProtoMessages {
     pub(crate) definitions: Vec<ProtoMessageDefinition>,
 }
 
-#[derive(Debug, serde::Deserialize)]
+#[derive(Debug, PartialEq, serde::Deserialize)]
 pub(crate) struct ProtoMessageDefinition {
     pub(crate) name: String,
     pub(crate) fields: Vec<ProtoField>,
 }
 
-#[derive(Debug, serde::Deserialize)]
+#[derive(Debug, PartialEq, serde::Deserialize)]
 pub(crate) struct ProtoField {
     pub(crate) name: String,
     pub(crate) number: u32,
     #[serde(default)]
     pub(crate) repeated: bool,
     pub(crate) r#type: String,
+    /// The oneof group the field is a member of.
+    #[serde(default)]
+    pub(crate) oneof: Option<String>,
 }
 
 #[derive(Debug, serde::Deserialize)]
@@ -30,7 +37,7 @@ pub(crate) struct ProtoServices {
     pub(crate) definitions: Vec<ProtoServiceDefinition>,
 }
 
-#[derive(Debug, serde::Deserialize)]
+#[derive(Debug, PartialEq, serde::Deserialize)]
 pub(crate) struct ProtoServiceDefinition {
     pub(crate) name: String,
     pub(crate) methods: Vec<ProtoMethodDefinition>,
@@ -42,12 +49,17 @@ impl ProtoServiceDefinition {
     }
 }
 
-#[derive(Debug, serde::Deserialize)]
+#[derive(Debug, PartialEq, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct ProtoMethodDefinition {
     pub(crate) name: String,
     pub(crate) input_type: String,
     pub(crate) output_type: String,
+    #[serde(default)]
+    pub(crate) server_streaming: bool,
+    /// The input of client streaming methods is a list of messages.
+    #[serde(default)]
+    pub(crate) client_streaming: bool,
 }
 
 #[derive(Debug, serde::Deserialize)]
@@ -55,13 +67,13 @@ pub(crate) struct ProtoEnums {
     pub(crate) definitions: Vec<ProtoEnumDefinition>,
 }
 
-#[derive(Debug, serde::Deserialize)]
+#[derive(Debug, PartialEq, serde::Deserialize)]
 pub(crate) struct ProtoEnumDefinition {
     pub(crate) name: String,
     pub(crate) values: Vec<ProtoEnumValueDefinition>,
 }
 
-#[derive(Debug, serde::Deserialize)]
+#[derive(Debug, PartialEq, serde::Deserialize)]
 pub(crate) struct ProtoEnumValueDefinition {
     pub(crate) name: String,
     pub(crate) number: u32,
@@ -0,0 +1,129 @@
+//! Fills a `google.protobuf.FieldMask` field of the input of a call with the paths of the fields
+//! selected in the query, for `@grpcMethod(fieldMask: "...")`.
+
+use crate::schema::{self, FieldType};
+use grafbase_sdk::types::{DefinitionId, Error, SelectionSet, SubgraphSchema};
+use prost::encoding;
+use std::collections::HashMap;
+
+/// The names of the fields of the subgraph schemas, to read the selection sets of resolved fields.
+pub(crate) struct FieldNames(HashMap<DefinitionId, String>);
+
+impl FieldNames {
+    pub(crate) fn new(subgraph_schemas: &[SubgraphSchema]) -> Self {
+        FieldNames(
+            subgraph_schemas
+                .iter()
+                .flat_map(|subgraph_schema| subgraph_schema.iter_fields())
+                .map(|field| (field.id(), field.name().to_owned()))
+                .collect(),
+        )
+    }
+}
+
+/// Appends the field mask to an encoded input message. A mask also given in the input is merged with
+/// this one, as for any message field present twice on the wire.
+pub(crate) fn append(
+    input: &mut Vec<u8>,
+    mask_field_name: &str,
+    input_message: &schema::Message,
+    output_message: &schema::Message,
+    selection_set: SelectionSet<'_>,
+    schema: &schema::Schema,
+    field_names: &FieldNames,
+) -> Result<(), Error> {
+    let mask_field = input_message
+        .fields
+        .get(mask_field_name)
+        .filter(|field| field.ty == FieldType::FieldMask && !field.repeated)
+        .ok_or_else(|| {
+            Error::new(format!(
+                "The fieldMask of the method must be a google.protobuf.FieldMask field of message {}, found {mask_field_name}",
+                input_message.name
+            ))
+        })?;
+
+    let mut paths = Vec::new();
+    collect_paths(selection_set, output_message, "", schema, field_names, &mut paths);
+
+    let mut mask = Vec::new();
+
+    for path in &paths {
+        encoding::string::encode(1, path, &mut mask);
+    }
+
+    encoding::encode_key(mask_field.number, encoding::WireType::LengthDelimited, input);
+    encoding::encode_varint(mask.len() as u64, input);
+    input.extend_from_slice(&mask);
+
+    Ok(())
+}
+
+/// Collects the paths of the selected fields, in the protobuf names of the fields. Paths stop at
+/// repeated and map fields, which can only be the last element of a path.
+fn collect_paths(
+    selection_set: SelectionSet<'_>,
+    message: &schema::Message,
+    prefix: &str,
+    schema: &schema::Schema,
+    field_names: &FieldNames,
+    paths: &mut Vec<String>,
+) {
+    let push = |name: &str, paths: &mut Vec<String>| {
+        let path = if prefix.is_empty() {
+            name.to_owned()
+        } else {
+            format!("{prefix}.{name}")
+        };
+
+        if !paths.contains(&path) {
+            paths.push(path);
+        }
+    };
+
+    for selected in selection_set.fields() {
+        // Fields like `__typename` have no protobuf counterpart.
+        let Some(name) = field_names.0.get(&selected.definition_id()) else {
+            continue;
+        };
+
+        if let Some(field) = message.fields.get(name) {
+            match &field.ty {
+                FieldType::Message(message_id) if !field.repeated && !selected.selection_set().is_empty() => {
+                    let prefix = if prefix.is_empty() {
+                        field.name.clone()
+                    } else {
+                        format!("{prefix}.{}", field.name)
+                    };
+
+                    collect_paths(
+                        selected.selection_set(),
+                        &schema[*message_id],
+                        &prefix,
+                        schema,
+                        field_names,
+                        paths,
+                    );
+                }
+                _ => push(&field.name, paths),
+            }
+
+            continue;
+        }
+
+        // The name of the member of a oneof that is set: all the members are needed.
+        if message.has_oneof(name) {
+            let mut members = message
+                .fields
+                .values()
+                .filter(|field| field.oneof.as_deref() == Some(name.as_str()))
+                .collect::<Vec<_>>();
+
+            members.sort_by_key(|field| field.number);
+
+            for member in members {
+                push(&member.name, paths);
+            }
+        }
+    }
+}
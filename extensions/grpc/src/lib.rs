@@ -1,8 +1,14 @@
+mod balancer;
+mod call_policy;
 mod config;
 mod conversions;
+mod descriptors;
 mod directives;
+mod field_mask;
+mod metadata;
 mod resolvers;
 mod schema;
+mod status;
 
 use grafbase_sdk::{
     IntoSubscription, ResolverExtension,
@@ -15,6 +21,8 @@ use grafbase_sdk::{
 #[derive(ResolverExtension)]
 struct Grpc {
     schema: schema::Schema,
+    field_names: field_mask::FieldNames,
+    balancers: balancer::Balancers,
     configuration: config::GrpcConfiguration,
 }
 
@@ -24,9 +32,10 @@ impl ResolverExtension for Grpc {
         let mut messages = Vec::new();
         let mut enums = Vec::new();
 
-        let configuration = config.deserialize()?;
+        let configuration: config::GrpcConfiguration = config.deserialize()?;
+        let mut balancers = balancer::Balancers::new(&configuration.services)?;
 
-        for schema in schemas {
+        for schema in &schemas {
             for directive in schema.directives() {
                 match directive.name() {
                     "protoMessages" => {
@@ -55,8 +64,25 @@ impl ResolverExtension for Grpc {
             }
         }
 
+        // Services configured with descriptors don't need the definition directives.
+        let mut definitions = descriptors::Definitions::default();
+        let mut services_with_descriptors = Vec::new();
+
+        for service in &configuration.services {
+            if definitions.load(service, balancers.get_mut(&service.name)?)? {
+                services_with_descriptors.push(service.name.as_str());
+            }
+        }
+
+        let definitions = definitions.merge(services, messages, enums)?;
+        let schema = schema::Schema::new(definitions.services, definitions.messages, definitions.enums)?;
+
+        descriptors::check_fields(&schemas, &schema, &services_with_descriptors)?;
+
         Ok(Grpc {
-            schema: schema::Schema::new(services, messages, enums)?,
+            schema,
+            field_names: field_mask::FieldNames::new(&schemas),
+            balancers,
             configuration,
         })
     }
@@ -65,21 +91,37 @@ impl ResolverExtension for Grpc {
         &mut self,
         _ctx: &AuthorizedOperationContext,
         prepared: &[u8],
-        _headers: SubgraphHeaders,
+        headers: SubgraphHeaders,
         variables: Variables,
     ) -> Result<Response, Error> {
         let field = ResolvedField::try_from(prepared)?;
-        resolvers::grpc_method(field, variables, &self.schema, &self.configuration)
+        resolvers::grpc_method(
+            field,
+            &headers,
+            variables,
+            &self.schema,
+            &self.field_names,
+            &mut self.balancers,
+            &self.configuration,
+        )
     }
 
     fn resolve_subscription<'s>(
         &'s mut self,
         _ctx: &'s AuthorizedOperationContext,
         prepared: &'s [u8],
-        _headers: SubgraphHeaders,
+        headers: SubgraphHeaders,
         variables: Variables,
     ) -> Result<impl IntoSubscription<'s>, Error> {
         let field = ResolvedField::try_from(prepared)?;
-        resolvers::grpc_method_subscription(field, variables, &self.schema, &self.configuration)
+        resolvers::grpc_method_subscription(
+            field,
+            &headers,
+            variables,
+            &self.schema,
+            &self.field_names,
+            &mut self.balancers,
+            &self.configuration,
+        )
     }
 }
@@ -0,0 +1,115 @@
+use crate::config::{MetadataRule, Service};
+use base64::{
+    Engine as _, alphabet,
+    engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig},
+};
+use grafbase_sdk::{host_io::logger::log, types::SubgraphHeaders};
+
+/// Binary metadata is base64 encoded on the wire, with optional padding.
+const BINARY_VALUE: GeneralPurpose = GeneralPurpose::new(
+    &alphabet::STANDARD,
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+/// Keys set by the transport or by gRPC itself, which are never sent as custom metadata.
+const RESERVED_KEYS: &[&str] = &[
+    "connection",
+    "content-length",
+    "content-type",
+    "host",
+    "keep-alive",
+    "proxy-connection",
+    "te",
+    "transfer-encoding",
+    "upgrade",
+    "user-agent",
+];
+
+pub(crate) fn is_binary_key(name: &str) -> bool {
+    name.ends_with("-bin")
+}
+
+pub(crate) fn is_reserved_key(name: &str) -> bool {
+    name.starts_with("grpc-") || name.starts_with(':') || RESERVED_KEYS.contains(&name)
+}
+
+pub(crate) fn decode_binary_value(value: &str) -> Result<Vec<u8>, base64::DecodeError> {
+    BINARY_VALUE.decode(value.trim())
+}
+
+/// Builds the metadata of a call to the service from its configured rules and the headers of the
+/// subgraph request.
+pub(crate) fn build(service: &Service, headers: &SubgraphHeaders) -> Vec<(String, Vec<u8>)> {
+    let mut metadata = Vec::new();
+
+    for rule in &service.metadata {
+        match rule {
+            MetadataRule::Insert { name, value } => metadata.push((name.clone(), value.clone())),
+            MetadataRule::Forward { name, rename, default } => {
+                let key = rename.as_deref().unwrap_or(name);
+                let mut values = headers.get_all(name.as_str()).peekable();
+
+                if values.peek().is_none() {
+                    if let Some(default) = default {
+                        push_value(&mut metadata, key, default.as_bytes());
+                    }
+
+                    continue;
+                }
+
+                for value in values {
+                    push_value(&mut metadata, key, value.as_bytes());
+                }
+            }
+            MetadataRule::ForwardPattern { pattern } => {
+                for (name, value) in headers.iter() {
+                    let name = name.as_str();
+
+                    if is_reserved_key(name) || !pattern.is_match(name) {
+                        continue;
+                    }
+
+                    push_value(&mut metadata, name, value.as_bytes());
+                }
+            }
+        }
+    }
+
+    metadata
+}
+
+/// Builds the metadata of calls made outside of a request, like server reflection, from the static
+/// entries and the defaults of forwarded headers.
+pub(crate) fn build_static(service: &Service) -> Vec<(String, Vec<u8>)> {
+    let mut metadata = Vec::new();
+
+    for rule in &service.metadata {
+        match rule {
+            MetadataRule::Insert { name, value } => metadata.push((name.clone(), value.clone())),
+            MetadataRule::Forward {
+                name,
+                rename,
+                default: Some(default),
+            } => push_value(&mut metadata, rename.as_deref().unwrap_or(name), default.as_bytes()),
+            MetadataRule::Forward { default: None, .. } | MetadataRule::ForwardPattern { .. } => (),
+        }
+    }
+
+    metadata
+}
+
+fn push_value(metadata: &mut Vec<(String, Vec<u8>)>, key: &str, value: &[u8]) {
+    if !is_binary_key(key) {
+        metadata.push((key.to_owned(), value.to_vec()));
+        return;
+    }
+
+    let decoded = std::str::from_utf8(value)
+        .map_err(|err| err.to_string())
+        .and_then(|value| decode_binary_value(value).map_err(|err| err.to_string()));
+
+    match decoded {
+        Ok(decoded) => metadata.push((key.to_owned(), decoded)),
+        Err(err) => log::warn!("Skipping binary metadata `{key}` with a value that is not valid base64: {err}"),
+    }
+}
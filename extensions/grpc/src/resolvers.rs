@@ -1,91 +1,159 @@
 mod streaming_response;
 
 use crate::{
+    balancer::Balancers,
+    call_policy::CallPolicy,
     config::{self, Service},
     conversions::{self, ArgumentsDeserialize},
     directives::{self, ProtoMethodDefinition},
-    schema,
+    field_mask::{self, FieldNames},
+    metadata, schema, status,
 };
-use grafbase_sdk::types::{Error, ResolvedField, Response, Variables};
+use grafbase_sdk::types::{Error, ResolvedField, Response, SubgraphHeaders, Variables};
 use streaming_response::StreamingResponse;
 
 pub(crate) fn grpc_method(
     field: ResolvedField<'_>,
+    headers: &SubgraphHeaders,
     variables: Variables,
     schema: &schema::Schema,
+    field_names: &FieldNames,
+    balancers: &mut Balancers,
     configuration: &config::GrpcConfiguration,
 ) -> Result<Response, Error> {
+    let method_info = extract_method_info(&field, schema, configuration)?;
+
     let MethodInfo {
-        input_message,
         output_message,
         service,
         method,
-    } = extract_method_info(&field, schema, configuration)?;
+        ..
+    } = method_info;
 
-    let mut input_proto = Vec::new();
+    if method.server_streaming {
+        return Err(Error::new(format!(
+            "Method {} is server streaming, it can only be used on subscription fields",
+            method.name
+        )));
+    }
 
-    field.arguments_seed(
-        &variables,
-        ArgumentsDeserialize {
-            schema,
-            message: input_message,
-            out: &mut input_proto,
-        },
-    )?;
+    let input_proto = encode_input(&field, variables, schema, field_names, &method_info)?;
+
+    let balancer = balancers.get_mut(&service.name)?;
+
+    let metadata = metadata::build(service, headers);
 
-    let client = grafbase_sdk::host_io::grpc::GrpcClient::new(&service.address)?;
+    let policy = CallPolicy::new(service, &method.name, method.server_streaming);
 
-    let metadata = &[];
+    let result = policy.call(|timeout| {
+        balancer.call(|client| client.unary(&input_proto, &service.name, &method.name, &metadata, timeout))
+    });
 
-    match client.unary(&input_proto, &service.name, &method.name, metadata, None) {
+    match result {
         Ok(response) => Ok(Response::data(conversions::MessageSerialize::new(
             &response.into_message().into(),
             output_message,
             schema,
         ))),
-        Err(err) => Err(Error::new(format!(
-            "gRPC error. Status code: {:?}. Message: {}",
-            err.code(),
-            err.message()
-        ))),
+        Err(status) => Err(status::to_error(&status, configuration)),
     }
 }
 
 pub(crate) fn grpc_method_subscription<'a>(
     field: ResolvedField<'a>,
+    headers: &SubgraphHeaders,
     variables: Variables,
     schema: &'a schema::Schema,
+    field_names: &FieldNames,
+    balancers: &mut Balancers,
     configuration: &'a config::GrpcConfiguration,
 ) -> Result<StreamingResponse<'a>, Error> {
+    let method_info = extract_method_info(&field, schema, configuration)?;
+
     let MethodInfo {
-        input_message,
         output_message,
         service,
         method,
-    } = extract_method_info(&field, schema, configuration)?;
+        ..
+    } = method_info;
+
+    let input_proto = encode_input(&field, variables, schema, field_names, &method_info)?;
+
+    let balancer = balancers.get_mut(&service.name)?;
+
+    let metadata = metadata::build(service, headers);
+
+    let policy = CallPolicy::new(service, &method.name, method.server_streaming);
+
+    let result = policy.call(|timeout| {
+        balancer.call(|client| client.streaming(&input_proto, &service.name, &method.name, &metadata, timeout))
+    });
+
+    match result {
+        Ok(response) => Ok(StreamingResponse {
+            response,
+            output_message,
+            schema,
+            configuration,
+        }),
+        Err(status) => Err(status::to_error(&status, configuration)),
+    }
+}
+
+/// Encodes the input of the call. The gRPC client of the gateway sends a single message per call,
+/// so client streaming methods can only be called with one message.
+fn encode_input(
+    field: &ResolvedField<'_>,
+    variables: Variables,
+    schema: &schema::Schema,
+    field_names: &FieldNames,
+    method_info: &MethodInfo<'_>,
+) -> Result<Vec<u8>, Error> {
+    let MethodInfo {
+        input_message,
+        output_message,
+        method,
+        field_mask,
+        ..
+    } = method_info;
+
+    let mut messages = Vec::new();
 
-    let mut input_proto = Vec::new();
     field.arguments_seed(
         &variables,
         ArgumentsDeserialize {
             schema,
             message: input_message,
-            out: &mut input_proto,
+            client_streaming: method.client_streaming,
+            out: &mut messages,
         },
     )?;
 
-    let client = grafbase_sdk::host_io::grpc::GrpcClient::new(&service.address)?;
+    let count = messages.len();
 
-    let metadata = &[];
+    let mut message = match messages.pop() {
+        Some(message) if count == 1 => message,
+        _ => {
+            return Err(Error::new(format!(
+                "Method {} is client streaming and was called with {count} messages, but only one message per call is supported",
+                method.name
+            )));
+        }
+    };
 
-    match client.streaming(&input_proto, &service.name, &method.name, metadata, None) {
-        Ok(response) => Ok(StreamingResponse {
-            response,
+    if let Some(field_mask) = field_mask {
+        field_mask::append(
+            &mut message,
+            field_mask,
+            input_message,
             output_message,
+            field.selection_set(),
             schema,
-        }),
-        Err(_) => todo!(),
+            field_names,
+        )?;
     }
+
+    Ok(message)
 }
 
 struct MethodInfo<'a> {
@@ -93,6 +161,8 @@ struct MethodInfo<'a> {
     output_message: &'a schema::Message,
     service: &'a Service,
     method: &'a ProtoMethodDefinition,
+    /// The input field filled with the selected fields, if any.
+    field_mask: Option<String>,
 }
 
 fn extract_method_info<'a>(
@@ -133,5 +203,6 @@ fn extract_method_info<'a>(
         method,
         input_message,
         output_message,
+        field_mask: grpc_method_directive.field_mask,
     })
 }
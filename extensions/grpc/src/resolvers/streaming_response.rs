@@ -1,4 +1,4 @@
-use crate::{conversions, schema};
+use crate::{config::GrpcConfiguration, conversions, schema, status};
 use grafbase_sdk::{
     Subscription,
     host_io::grpc::GrpcStreamingResponse,
@@ -9,6 +9,7 @@ pub(crate) struct StreamingResponse<'a> {
     pub(super) response: GrpcStreamingResponse,
     pub(super) output_message: &'a schema::Message,
     pub(super) schema: &'a schema::Schema,
+    pub(super) configuration: &'a GrpcConfiguration,
 }
 
 impl Subscription for StreamingResponse<'_> {
@@ -16,13 +17,7 @@ impl Subscription for StreamingResponse<'_> {
         let response_proto = match self.response.next_message() {
             Ok(Some(message)) => message,
             Ok(None) => return Ok(None),
-            Err(err) => {
-                return Err(Error::new(format!(
-                    "gRPC error. Status code: {:?}. Message: {}",
-                    err.code(),
-                    err.message()
-                )));
-            }
+            Err(status) => return Err(status::to_error(&status, self.configuration)),
         };
 
         Ok(Some(
@@ -67,6 +67,7 @@ impl Schema {
                             })?,
                             number: field.number,
                             repeated: field.repeated,
+                            oneof: field.oneof.clone(),
                         },
                     ))
                 })
@@ -92,4 +93,14 @@ impl Schema {
     pub(crate) fn get_message(&self, name: &str) -> Option<&Message> {
         self.messages.iter().find(|m| m.name == name)
     }
+
+    /// Finds the message embedded in an `Any`. The last segment of the type URL is the fully
+    /// qualified name of the message, which is defined with or without a leading dot.
+    pub(crate) fn get_message_by_type_url(&self, type_url: &str) -> Option<&Message> {
+        let name = type_url.rsplit_once('/').map_or(type_url, |(_, name)| name);
+
+        self.messages
+            .iter()
+            .find(|m| m.name.strip_prefix('.').unwrap_or(&m.name) == name)
+    }
 }
@@ -4,7 +4,6 @@ use std::{
     str::FromStr,
 };
 
-#[expect(clippy::unnecessary_lazy_evaluations)] // clippy is drunk
 pub(in crate::schema) fn ingest_type(
     r#type: &str,
     messages_by_name: &HashMap<&str, MessageDefinitionId>,
@@ -28,25 +27,35 @@ pub(in crate::schema) fn ingest_type(
 
             Some(FieldType::Map(Box::new((key_type, value_type))))
         })
-        .or_else(|| match r#type {
-            ".google.protobuf.Duration" => Some(FieldType::Duration),
-            ".google.protobuf.Timestamp" => Some(FieldType::Timestamp),
-            ".google.protobuf.Empty" => Some(FieldType::Empty),
-            ".google.protobuf.NullValue" => Some(FieldType::NullValue),
-            ".google.protobuf.FieldMask" => Some(FieldType::FieldMask),
-
-            ".google.protobuf.BoolValue" => Some(FieldType::Wrapper(ScalarType::Bool)),
-            ".google.protobuf.BytesValue" => Some(FieldType::Wrapper(ScalarType::Bytes)),
-            ".google.protobuf.DoubleValue" => Some(FieldType::Wrapper(ScalarType::Double)),
-            ".google.protobuf.FloatValue" => Some(FieldType::Wrapper(ScalarType::Float)),
-            ".google.protobuf.Int32Value" => Some(FieldType::Wrapper(ScalarType::Int32)),
-            ".google.protobuf.Int64Value" => Some(FieldType::Wrapper(ScalarType::Int64)),
-            ".google.protobuf.StringValue" => Some(FieldType::Wrapper(ScalarType::String)),
-            ".google.protobuf.UInt32Value" => Some(FieldType::Wrapper(ScalarType::UInt32)),
-            ".google.protobuf.UInt64Value" => Some(FieldType::Wrapper(ScalarType::UInt64)),
-
-            _ => None,
-        })
+        .or_else(|| well_known_type(r#type))
+}
+
+/// The well-known types with their own JSON mapping, by fully qualified name. They are not ingested
+/// as messages.
+pub(crate) fn well_known_type(name: &str) -> Option<FieldType> {
+    match name {
+        ".google.protobuf.Duration" => Some(FieldType::Duration),
+        ".google.protobuf.Timestamp" => Some(FieldType::Timestamp),
+        ".google.protobuf.Empty" => Some(FieldType::Empty),
+        ".google.protobuf.NullValue" => Some(FieldType::NullValue),
+        ".google.protobuf.FieldMask" => Some(FieldType::FieldMask),
+        ".google.protobuf.Struct" => Some(FieldType::Struct),
+        ".google.protobuf.Value" => Some(FieldType::Value),
+        ".google.protobuf.ListValue" => Some(FieldType::ListValue),
+        ".google.protobuf.Any" => Some(FieldType::Any),
+
+        ".google.protobuf.BoolValue" => Some(FieldType::Wrapper(ScalarType::Bool)),
+        ".google.protobuf.BytesValue" => Some(FieldType::Wrapper(ScalarType::Bytes)),
+        ".google.protobuf.DoubleValue" => Some(FieldType::Wrapper(ScalarType::Double)),
+        ".google.protobuf.FloatValue" => Some(FieldType::Wrapper(ScalarType::Float)),
+        ".google.protobuf.Int32Value" => Some(FieldType::Wrapper(ScalarType::Int32)),
+        ".google.protobuf.Int64Value" => Some(FieldType::Wrapper(ScalarType::Int64)),
+        ".google.protobuf.StringValue" => Some(FieldType::Wrapper(ScalarType::String)),
+        ".google.protobuf.UInt32Value" => Some(FieldType::Wrapper(ScalarType::UInt32)),
+        ".google.protobuf.UInt64Value" => Some(FieldType::Wrapper(ScalarType::UInt64)),
+
+        _ => None,
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -69,6 +78,14 @@ pub(crate) enum FieldType {
     Empty,
     /// https://protobuf.dev/reference/protobuf/google.protobuf/#field-mask
     FieldMask,
+    /// https://protobuf.dev/reference/protobuf/google.protobuf/#struct
+    Struct,
+    /// https://protobuf.dev/reference/protobuf/google.protobuf/#value
+    Value,
+    /// https://protobuf.dev/reference/protobuf/google.protobuf/#list-value
+    ListValue,
+    /// https://protobuf.dev/reference/protobuf/google.protobuf/#any
+    Any,
 }
 
 impl FieldType {
@@ -107,6 +124,10 @@ impl FieldType {
             FieldType::Empty => f.write_str(".google.protobuf.Empty"),
             FieldType::FieldMask => f.write_str(".google.protobuf.FieldMask"),
             FieldType::Timestamp => f.write_str(".google.protobuf.Timestamp"),
+            FieldType::Struct => f.write_str(".google.protobuf.Struct"),
+            FieldType::Value => f.write_str(".google.protobuf.Value"),
+            FieldType::ListValue => f.write_str(".google.protobuf.ListValue"),
+            FieldType::Any => f.write_str(".google.protobuf.Any"),
         })
     }
 }
@@ -6,6 +6,8 @@ pub(crate) struct Field {
     pub(crate) ty: FieldType,
     pub(crate) number: u32,
     pub(crate) repeated: bool,
+    /// The oneof group the field is a member of.
+    pub(crate) oneof: Option<String>,
 }
 
 pub(crate) struct Message {
@@ -13,6 +15,20 @@ pub(crate) struct Message {
     pub(crate) fields: HashMap<String, Field>,
 }
 
+impl Message {
+    /// Whether the message has a oneof group with this name.
+    pub(crate) fn has_oneof(&self, group: &str) -> bool {
+        self.fields.values().any(|field| field.oneof.as_deref() == Some(group))
+    }
+
+    /// The other members of the oneof group of a field.
+    pub(crate) fn oneof_siblings<'a>(&'a self, field: &'a Field) -> impl Iterator<Item = &'a Field> {
+        self.fields.values().filter(move |sibling| {
+            field.oneof.is_some() && sibling.oneof == field.oneof && sibling.number != field.number
+        })
+    }
+}
+
 pub(crate) struct EnumDefinition {
     pub(crate) name: String,
     pub(crate) values: Vec<EnumValueDefinition>,
@@ -0,0 +1,91 @@
+mod details;
+
+use crate::config::GrpcConfiguration;
+use grafbase_sdk::{
+    host_io::grpc::{GrpcStatus, GrpcStatusCode},
+    types::Error,
+};
+use serde_json::json;
+
+/// The metadata holding the encoded `google.rpc.Status` of a failed call, with its details.
+const STATUS_DETAILS_KEY: &str = "grpc-status-details-bin";
+
+/// The status codes with their canonical names.
+///
+/// Reference: <https://github.com/grpc/grpc/blob/master/doc/statuscodes.md#status-codes-and-their-use-in-grpc>
+const CODES: [(GrpcStatusCode, &str); 17] = [
+    (GrpcStatusCode::Ok, "OK"),
+    (GrpcStatusCode::Cancelled, "CANCELLED"),
+    (GrpcStatusCode::Unknown, "UNKNOWN"),
+    (GrpcStatusCode::InvalidArgument, "INVALID_ARGUMENT"),
+    (GrpcStatusCode::DeadlineExceeded, "DEADLINE_EXCEEDED"),
+    (GrpcStatusCode::NotFound, "NOT_FOUND"),
+    (GrpcStatusCode::AlreadyExists, "ALREADY_EXISTS"),
+    (GrpcStatusCode::PermissionDenied, "PERMISSION_DENIED"),
+    (GrpcStatusCode::ResourceExhausted, "RESOURCE_EXHAUSTED"),
+    (GrpcStatusCode::FailedPrecondition, "FAILED_PRECONDITION"),
+    (GrpcStatusCode::Aborted, "ABORTED"),
+    (GrpcStatusCode::OutOfRange, "OUT_OF_RANGE"),
+    (GrpcStatusCode::Unimplemented, "UNIMPLEMENTED"),
+    (GrpcStatusCode::Internal, "INTERNAL"),
+    (GrpcStatusCode::Unavailable, "UNAVAILABLE"),
+    (GrpcStatusCode::DataLoss, "DATA_LOSS"),
+    (GrpcStatusCode::Unauthenticated, "UNAUTHENTICATED"),
+];
+
+/// Parses a status code from its canonical name, like `UNAVAILABLE`, case insensitively.
+pub(crate) fn parse_code(name: &str) -> Option<GrpcStatusCode> {
+    CODES
+        .iter()
+        .find(|(_, code_name)| code_name.eq_ignore_ascii_case(name))
+        .map(|(code, _)| *code)
+}
+
+/// The canonical name of a status code, like `UNAVAILABLE`.
+pub(crate) fn code_name(code: GrpcStatusCode) -> &'static str {
+    CODES
+        .iter()
+        .find(|(candidate, _)| *candidate == code)
+        .map(|(_, name)| *name)
+        .unwrap_or("UNKNOWN")
+}
+
+/// Converts the status of a failed call into a GraphQL error. The name of the status code and
+/// the details of the status are in the `grpc` extension, and the `code` extension is set from
+/// the configured mapping.
+pub(crate) fn to_error(status: &GrpcStatus, configuration: &GrpcConfiguration) -> Error {
+    let message = format!(
+        "gRPC error. Status code: {:?}. Message: {}",
+        status.code(),
+        status.message()
+    );
+
+    let mut grpc = json!({ "status": code_name(status.code()) });
+
+    let details = status
+        .metadata()
+        .iter()
+        .find(|(key, _)| key == STATUS_DETAILS_KEY)
+        .and_then(|(_, value)| details::decode_status(value))
+        .map(|status| details::details_to_json(&status.details, configuration.expose_debug_info))
+        .filter(|details| !details.is_empty());
+
+    if let Some(details) = details {
+        grpc["details"] = details.into();
+    }
+
+    let code = configuration
+        .error_codes
+        .iter()
+        .find(|(code, _)| *code == status.code())
+        .map(|(_, extension_code)| extension_code);
+
+    let error = match code {
+        Some(code) => Error::new(message.clone()).extension("code", code),
+        None => Ok(Error::new(message.clone())),
+    };
+
+    error
+        .and_then(|error| error.extension("grpc", &grpc))
+        .unwrap_or_else(|_| Error::new(message))
+}
@@ -0,0 +1,348 @@
+//! The `google.rpc.Status` message and the standard error details, from
+//! <https://github.com/googleapis/googleapis/blob/master/google/rpc/error_details.proto>.
+
+use base64::Engine as _;
+use prost::Message as _;
+use serde_json::{Map, Value, json};
+use std::collections::HashMap;
+
+#[cfg(test)]
+mod tests;
+
+const TYPE_URL_PREFIX: &str = "type.googleapis.com/";
+
+const DEBUG_INFO_TYPE: &str = "google.rpc.DebugInfo";
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub(super) struct Status {
+    #[prost(int32, tag = "1")]
+    pub(super) code: i32,
+    #[prost(string, tag = "2")]
+    pub(super) message: String,
+    #[prost(message, repeated, tag = "3")]
+    pub(super) details: Vec<Any>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub(super) struct Any {
+    #[prost(string, tag = "1")]
+    pub(super) type_url: String,
+    #[prost(bytes = "vec", tag = "2")]
+    pub(super) value: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct Duration {
+    #[prost(int64, tag = "1")]
+    seconds: i64,
+    #[prost(int32, tag = "2")]
+    nanos: i32,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct ErrorInfo {
+    #[prost(string, tag = "1")]
+    reason: String,
+    #[prost(string, tag = "2")]
+    domain: String,
+    #[prost(map = "string, string", tag = "3")]
+    metadata: HashMap<String, String>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct RetryInfo {
+    #[prost(message, optional, tag = "1")]
+    retry_delay: Option<Duration>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct DebugInfo {
+    #[prost(string, repeated, tag = "1")]
+    stack_entries: Vec<String>,
+    #[prost(string, tag = "2")]
+    detail: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct QuotaFailure {
+    #[prost(message, repeated, tag = "1")]
+    violations: Vec<QuotaViolation>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct QuotaViolation {
+    #[prost(string, tag = "1")]
+    subject: String,
+    #[prost(string, tag = "2")]
+    description: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct PreconditionFailure {
+    #[prost(message, repeated, tag = "1")]
+    violations: Vec<PreconditionViolation>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct PreconditionViolation {
+    #[prost(string, tag = "1")]
+    r#type: String,
+    #[prost(string, tag = "2")]
+    subject: String,
+    #[prost(string, tag = "3")]
+    description: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct BadRequest {
+    #[prost(message, repeated, tag = "1")]
+    field_violations: Vec<FieldViolation>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct FieldViolation {
+    #[prost(string, tag = "1")]
+    field: String,
+    #[prost(string, tag = "2")]
+    description: String,
+    #[prost(string, tag = "3")]
+    reason: String,
+    #[prost(message, optional, tag = "4")]
+    localized_message: Option<LocalizedMessage>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct RequestInfo {
+    #[prost(string, tag = "1")]
+    request_id: String,
+    #[prost(string, tag = "2")]
+    serving_data: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct ResourceInfo {
+    #[prost(string, tag = "1")]
+    resource_type: String,
+    #[prost(string, tag = "2")]
+    resource_name: String,
+    #[prost(string, tag = "3")]
+    owner: String,
+    #[prost(string, tag = "4")]
+    description: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct Help {
+    #[prost(message, repeated, tag = "1")]
+    links: Vec<Link>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct Link {
+    #[prost(string, tag = "1")]
+    description: String,
+    #[prost(string, tag = "2")]
+    url: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct LocalizedMessage {
+    #[prost(string, tag = "1")]
+    locale: String,
+    #[prost(string, tag = "2")]
+    message: String,
+}
+
+/// Decodes the `grpc-status-details-bin` metadata. The value can be the raw message, or its
+/// base64 encoding as sent on the wire.
+pub(super) fn decode_status(value: &[u8]) -> Option<Status> {
+    Status::decode(value).ok().or_else(|| {
+        let decoded = crate::metadata::decode_binary_value(std::str::from_utf8(value).ok()?).ok()?;
+        Status::decode(decoded.as_slice()).ok()
+    })
+}
+
+/// Converts the details to JSON. The `google.rpc.DebugInfo` details hold stack traces and other
+/// internals of the server, and are only included if configured.
+pub(super) fn details_to_json(details: &[Any], expose_debug_info: bool) -> Vec<Value> {
+    details
+        .iter()
+        .filter(|detail| expose_debug_info || type_name(detail) != DEBUG_INFO_TYPE)
+        .map(detail_to_json)
+        .collect()
+}
+
+/// Converts a detail to JSON, following the proto3 JSON mapping of `Any`. Unknown details, or
+/// details that cannot be decoded, are returned with their base64 encoded value.
+fn detail_to_json(detail: &Any) -> Value {
+    let fields = decode_detail(type_name(detail), &detail.value)
+        .unwrap_or_else(|| json!({ "value": base64::engine::general_purpose::STANDARD.encode(&detail.value) }));
+
+    let mut object = Map::new();
+    object.insert("@type".to_owned(), Value::String(detail.type_url.clone()));
+
+    if let Value::Object(fields) = fields {
+        object.extend(fields);
+    }
+
+    Value::Object(object)
+}
+
+fn type_name(detail: &Any) -> &str {
+    detail
+        .type_url
+        .strip_prefix(TYPE_URL_PREFIX)
+        .unwrap_or(&detail.type_url)
+}
+
+fn decode_detail(type_name: &str, value: &[u8]) -> Option<Value> {
+    let value = match type_name {
+        "google.rpc.ErrorInfo" => {
+            let info = ErrorInfo::decode(value).ok()?;
+
+            json!({
+                "reason": info.reason,
+                "domain": info.domain,
+                "metadata": info.metadata,
+            })
+        }
+        "google.rpc.RetryInfo" => {
+            let info = RetryInfo::decode(value).ok()?;
+
+            json!({ "retryDelay": info.retry_delay.map(|delay| format_duration(&delay)) })
+        }
+        DEBUG_INFO_TYPE => {
+            let info = DebugInfo::decode(value).ok()?;
+
+            json!({
+                "stackEntries": info.stack_entries,
+                "detail": info.detail,
+            })
+        }
+        "google.rpc.QuotaFailure" => {
+            let failure = QuotaFailure::decode(value).ok()?;
+
+            let violations = failure
+                .violations
+                .into_iter()
+                .map(|violation| {
+                    json!({
+                        "subject": violation.subject,
+                        "description": violation.description,
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            json!({ "violations": violations })
+        }
+        "google.rpc.PreconditionFailure" => {
+            let failure = PreconditionFailure::decode(value).ok()?;
+
+            let violations = failure
+                .violations
+                .into_iter()
+                .map(|violation| {
+                    json!({
+                        "type": violation.r#type,
+                        "subject": violation.subject,
+                        "description": violation.description,
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            json!({ "violations": violations })
+        }
+        "google.rpc.BadRequest" => {
+            let bad_request = BadRequest::decode(value).ok()?;
+
+            let field_violations = bad_request
+                .field_violations
+                .into_iter()
+                .map(|violation| {
+                    let mut json = json!({
+                        "field": violation.field,
+                        "description": violation.description,
+                    });
+
+                    if !violation.reason.is_empty() {
+                        json["reason"] = Value::String(violation.reason);
+                    }
+
+                    if let Some(message) = violation.localized_message {
+                        json["localizedMessage"] = localized_message_to_json(message);
+                    }
+
+                    json
+                })
+                .collect::<Vec<_>>();
+
+            json!({ "fieldViolations": field_violations })
+        }
+        "google.rpc.RequestInfo" => {
+            let info = RequestInfo::decode(value).ok()?;
+
+            json!({
+                "requestId": info.request_id,
+                "servingData": info.serving_data,
+            })
+        }
+        "google.rpc.ResourceInfo" => {
+            let info = ResourceInfo::decode(value).ok()?;
+
+            json!({
+                "resourceType": info.resource_type,
+                "resourceName": info.resource_name,
+                "owner": info.owner,
+                "description": info.description,
+            })
+        }
+        "google.rpc.Help" => {
+            let help = Help::decode(value).ok()?;
+
+            let links = help
+                .links
+                .into_iter()
+                .map(|link| {
+                    json!({
+                        "description": link.description,
+                        "url": link.url,
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            json!({ "links": links })
+        }
+        "google.rpc.LocalizedMessage" => localized_message_to_json(LocalizedMessage::decode(value).ok()?),
+        _ => return None,
+    };
+
+    Some(value)
+}
+
+fn localized_message_to_json(message: LocalizedMessage) -> Value {
+    json!({
+        "locale": message.locale,
+        "message": message.message,
+    })
+}
+
+/// Formats a duration like the proto3 JSON mapping, with 0, 3, 6 or 9 fractional digits.
+fn format_duration(duration: &Duration) -> String {
+    let sign = if duration.seconds < 0 || duration.nanos < 0 {
+        "-"
+    } else {
+        ""
+    };
+    let seconds = duration.seconds.unsigned_abs();
+    let nanos = duration.nanos.unsigned_abs();
+
+    if nanos == 0 {
+        format!("{sign}{seconds}s")
+    } else if nanos % 1_000_000 == 0 {
+        format!("{sign}{seconds}.{:03}s", nanos / 1_000_000)
+    } else if nanos % 1_000 == 0 {
+        format!("{sign}{seconds}.{:06}s", nanos / 1_000)
+    } else {
+        format!("{sign}{seconds}.{nanos:09}s")
+    }
+}
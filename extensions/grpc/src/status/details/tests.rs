@@ -0,0 +1,140 @@
+use super::*;
+
+fn any(type_name: &str, message: &impl prost::Message) -> Any {
+    Any {
+        type_url: format!("{TYPE_URL_PREFIX}{type_name}"),
+        value: message.encode_to_vec(),
+    }
+}
+
+fn status() -> Status {
+    Status {
+        code: 3,
+        message: "invalid point".to_owned(),
+        details: vec![
+            any(
+                "google.rpc.BadRequest",
+                &BadRequest {
+                    field_violations: vec![FieldViolation {
+                        field: "point.latitude".to_owned(),
+                        description: "must be between -90 and 90 degrees".to_owned(),
+                        reason: "OUT_OF_RANGE".to_owned(),
+                        localized_message: Some(LocalizedMessage {
+                            locale: "fr-FR".to_owned(),
+                            message: "doit être entre -90 et 90 degrés".to_owned(),
+                        }),
+                    }],
+                },
+            ),
+            any(
+                "google.rpc.ErrorInfo",
+                &ErrorInfo {
+                    reason: "INVALID_POINT".to_owned(),
+                    domain: "routeguide.example.com".to_owned(),
+                    metadata: HashMap::from([("latitude".to_owned(), "91".to_owned())]),
+                },
+            ),
+            any(
+                "google.rpc.RetryInfo",
+                &RetryInfo {
+                    retry_delay: Some(Duration {
+                        seconds: 1,
+                        nanos: 500_000_000,
+                    }),
+                },
+            ),
+            Any {
+                type_url: "type.googleapis.com/routeguide.Custom".to_owned(),
+                value: vec![1, 2, 3],
+            },
+        ],
+    }
+}
+
+#[test]
+fn decode_raw_and_base64_status() {
+    let encoded = status().encode_to_vec();
+
+    assert_eq!(decode_status(&encoded), Some(status()));
+
+    let base64 = base64::engine::general_purpose::STANDARD_NO_PAD.encode(&encoded);
+    assert_eq!(decode_status(base64.as_bytes()), Some(status()));
+
+    assert_eq!(decode_status(b"not a status"), None);
+}
+
+#[test]
+fn details_to_json() {
+    let details = super::details_to_json(&status().details, false);
+
+    insta::assert_json_snapshot!(details, @r#"
+    [
+      {
+        "@type": "type.googleapis.com/google.rpc.BadRequest",
+        "fieldViolations": [
+          {
+            "field": "point.latitude",
+            "description": "must be between -90 and 90 degrees",
+            "reason": "OUT_OF_RANGE",
+            "localizedMessage": {
+              "locale": "fr-FR",
+              "message": "doit être entre -90 et 90 degrés"
+            }
+          }
+        ]
+      },
+      {
+        "@type": "type.googleapis.com/google.rpc.ErrorInfo",
+        "reason": "INVALID_POINT",
+        "domain": "routeguide.example.com",
+        "metadata": {
+          "latitude": "91"
+        }
+      },
+      {
+        "@type": "type.googleapis.com/google.rpc.RetryInfo",
+        "retryDelay": "1.500s"
+      },
+      {
+        "@type": "type.googleapis.com/routeguide.Custom",
+        "value": "AQID"
+      }
+    ]
+    "#);
+}
+
+#[test]
+fn debug_info_only_if_exposed() {
+    let details = [any(
+        "google.rpc.DebugInfo",
+        &DebugInfo {
+            stack_entries: vec!["at routeguide.Server.getFeature(Server.java:42)".to_owned()],
+            detail: "point lookup failed".to_owned(),
+        },
+    )];
+
+    assert!(super::details_to_json(&details, false).is_empty());
+
+    insta::assert_json_snapshot!(super::details_to_json(&details, true), @r#"
+    [
+      {
+        "@type": "type.googleapis.com/google.rpc.DebugInfo",
+        "stackEntries": [
+          "at routeguide.Server.getFeature(Server.java:42)"
+        ],
+        "detail": "point lookup failed"
+      }
+    ]
+    "#);
+}
+
+#[test]
+fn duration_format() {
+    let format = |seconds, nanos| format_duration(&Duration { seconds, nanos });
+
+    assert_eq!(format(3, 0), "3s");
+    assert_eq!(format(1, 10_000_000), "1.010s");
+    assert_eq!(format(0, 1_000), "0.000001s");
+    assert_eq!(format(0, 1), "0.000000001s");
+    assert_eq!(format(-1, -500_000_000), "-1.500s");
+}
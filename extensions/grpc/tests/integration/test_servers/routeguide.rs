@@ -5,11 +5,13 @@
 use std::collections::HashMap;
 use std::net::SocketAddr;
 use std::pin::Pin;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
 use std::time::Instant;
 
 use tokio::sync::mpsc;
 use tokio_stream::{Stream, StreamExt, wrappers::ReceiverStream};
+use tonic::metadata::MetadataMap;
 use tonic::transport::Server;
 use tonic::{Request, Response, Status};
 
@@ -55,9 +57,23 @@ pub fn load_data() -> Vec<routeguide::Feature> {
         .collect()
 }
 
+/// Lets the tests observe and alter the behaviour of GetFeature.
+#[derive(Debug, Default)]
+pub(crate) struct ServerState {
+    /// The metadata of the GetFeature requests.
+    pub(crate) received_metadata: Mutex<Vec<MetadataMap>>,
+    /// The number of GetFeature calls left to fail with UNAVAILABLE.
+    pub(crate) unavailable_calls: AtomicU32,
+    /// The number of GetFeature calls.
+    pub(crate) calls: AtomicU32,
+    /// The status the next GetFeature call fails with.
+    pub(crate) next_error: Mutex<Option<Status>>,
+}
+
 #[derive(Debug)]
 pub struct RouteGuideService {
     features: Arc<Vec<Feature>>,
+    state: Arc<ServerState>,
 }
 
 #[tonic::async_trait]
@@ -65,6 +81,26 @@ impl RouteGuide for RouteGuideService {
     async fn get_feature(&self, request: Request<Point>) -> Result<Response<Feature>, Status> {
         println!("GetFeature = {:?}", request);
 
+        self.state.calls.fetch_add(1, Ordering::SeqCst);
+        self.state
+            .received_metadata
+            .lock()
+            .unwrap()
+            .push(request.metadata().clone());
+
+        let unavailable = self
+            .state
+            .unavailable_calls
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |calls| calls.checked_sub(1));
+
+        if unavailable.is_ok() {
+            return Err(Status::unavailable("try again"));
+        }
+
+        if let Some(status) = self.state.next_error.lock().unwrap().take() {
+            return Err(status);
+        }
+
         for feature in &self.features[..] {
             if feature.location.as_ref() == Some(request.get_ref()) {
                 return Ok(Response::new(feature.clone()));
@@ -164,10 +200,18 @@ impl RouteGuide for RouteGuideService {
 }
 
 pub(crate) async fn run_server(addr: SocketAddr) -> Result<(), Box<dyn std::error::Error>> {
+    run_server_with_state(addr, Default::default()).await
+}
+
+pub(crate) async fn run_server_with_state(
+    addr: SocketAddr,
+    state: Arc<ServerState>,
+) -> Result<(), Box<dyn std::error::Error>> {
     println!("RouteGuideServer listening on: {addr}");
 
     let route_guide = RouteGuideService {
         features: Arc::new(load_data()),
+        state,
     };
 
     let svc = RouteGuideServer::new(route_guide);
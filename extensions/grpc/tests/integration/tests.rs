@@ -1,7 +1,11 @@
 use grafbase_sdk::test::TestGateway;
-use std::{net::SocketAddr, str::FromStr as _};
+use std::{
+    net::SocketAddr,
+    str::FromStr as _,
+    sync::{Arc, atomic::Ordering},
+};
 
-use crate::integration::test_servers::routeguide::routeguide::Point;
+use crate::integration::test_servers::routeguide::{ServerState, routeguide::Point};
 
 #[tokio::test]
 async fn basic() {
@@ -315,3 +319,1004 @@ async fn basic() {
     ]
     "#);
 }
+
+/// A subgraph with the GetFeature method of the route guide.
+const GET_FEATURE_SUBGRAPH: &str = r#"
+    extend schema
+        @link(url: "<self>", import: ["@grpcMethod", "@protoMessages", "@protoServices"])
+        @protoMessages(definitions: [
+          {
+            name: "Point"
+            fields: [
+              { name: "latitude", type: "int32", number: 1 }
+              { name: "longitude", type: "int32", number: 2 }
+            ]
+          },
+          {
+            name: "Feature"
+            fields: [
+              { name: "name", type: "string", number: 1 }
+              { name: "location", type: "Point", number: 2 }
+            ]
+          }
+        ])
+        @protoServices(definitions: [
+          {
+            name: "routeguide.RouteGuide"
+            methods: [
+            { name: "GetFeature", inputType: "Point", outputType: "Feature" }
+            ]
+          }
+        ])
+
+    type Query {
+        getFeature(input: PointInput!): Feature @grpcMethod(service: "routeguide.RouteGuide", method: "GetFeature")
+    }
+
+    input PointInput {
+        latitude: Int!
+        longitude: Int!
+    }
+
+    type Feature {
+        name: String
+        location: Point
+    }
+
+    type Point {
+        latitude: Int!
+        longitude: Int!
+    }
+"#;
+
+#[tokio::test]
+async fn metadata() {
+    let grpc_server_addr =
+        SocketAddr::from_str(&format!("[::1]:{}", (rand::random::<u16>() % 16_384) + 49_152)).unwrap();
+
+    let gateway = TestGateway::builder()
+        .subgraph(GET_FEATURE_SUBGRAPH)
+        .toml_config(format!(
+            r#"
+            [[headers]]
+            rule = "forward"
+            pattern = "^x-"
+
+            [[headers]]
+            rule = "forward"
+            name = "authorization"
+
+            [[extensions.grpc.config.services]]
+            name = "routeguide.RouteGuide"
+            address = "http://{grpc_server_addr}"
+
+            [[extensions.grpc.config.services.metadata]]
+            rule = "insert"
+            name = "x-client"
+            value = "grafbase"
+
+            [[extensions.grpc.config.services.metadata]]
+            rule = "insert"
+            name = "x-static-bin"
+            value = "AAEC"
+
+            [[extensions.grpc.config.services.metadata]]
+            rule = "forward"
+            name = "authorization"
+
+            [[extensions.grpc.config.services.metadata]]
+            rule = "forward"
+            name = "x-tenant"
+            rename = "tenant-id"
+
+            [[extensions.grpc.config.services.metadata]]
+            rule = "forward"
+            name = "x-region"
+            default = "eu-west-1"
+
+            [[extensions.grpc.config.services.metadata]]
+            rule = "forward"
+            pattern = "^x-trace-"
+
+            [[extensions.grpc.config.services.metadata]]
+            rule = "forward"
+            name = "x-context-bin"
+            "#
+        ))
+        .build()
+        .await
+        .unwrap();
+
+    let state = Arc::new(ServerState::default());
+
+    tokio::spawn({
+        let state = state.clone();
+
+        async move {
+            super::test_servers::routeguide::run_server_with_state(grpc_server_addr, state)
+                .await
+                .unwrap()
+        }
+    });
+
+    // Leave the server some time to start.
+    tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+
+    let response = gateway
+        .query(
+            r#"
+            query {
+                getFeature(input: { latitude: 409146138, longitude: -746188906 }) {
+                    name
+                }
+            }"#,
+        )
+        .header("authorization", "Bearer secret-token")
+        .header("x-tenant", "acme")
+        .header("x-trace-id", "trace-1")
+        .header("x-trace-span", "span-1")
+        .header("x-context-bin", "AwQF")
+        .header("x-ignored", "ignored")
+        .send()
+        .await;
+
+    insta::assert_json_snapshot!(response, @r#"
+    {
+      "data": {
+        "getFeature": {
+          "name": "Berkshire Valley Management Area Trail, Jefferson, NJ, USA"
+        }
+      }
+    }
+    "#);
+
+    let metadata = state.received_metadata.lock().unwrap().pop().unwrap();
+
+    let ascii = |key: &str| {
+        metadata
+            .get_all(key)
+            .iter()
+            .map(|value| value.to_str().unwrap().to_owned())
+            .collect::<Vec<_>>()
+    };
+
+    let binary = |key: &str| {
+        metadata
+            .get_all_bin(key)
+            .iter()
+            .map(|value| value.to_bytes().unwrap().to_vec())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(ascii("x-client"), ["grafbase"]);
+    assert_eq!(binary("x-static-bin"), [vec![0, 1, 2]]);
+    assert_eq!(ascii("authorization"), ["Bearer secret-token"]);
+    assert_eq!(ascii("tenant-id"), ["acme"]);
+    assert!(ascii("x-tenant").is_empty());
+    assert_eq!(ascii("x-region"), ["eu-west-1"]);
+    assert_eq!(ascii("x-trace-id"), ["trace-1"]);
+    assert_eq!(ascii("x-trace-span"), ["span-1"]);
+    assert_eq!(binary("x-context-bin"), [vec![3, 4, 5]]);
+    assert!(ascii("x-ignored").is_empty());
+}
+
+#[tokio::test]
+async fn retries() {
+    let grpc_server_addr =
+        SocketAddr::from_str(&format!("[::1]:{}", (rand::random::<u16>() % 16_384) + 49_152)).unwrap();
+
+    let gateway = TestGateway::builder()
+        .subgraph(GET_FEATURE_SUBGRAPH)
+        .toml_config(format!(
+            r#"
+            [[extensions.grpc.config.services]]
+            name = "routeguide.RouteGuide"
+            address = "http://{grpc_server_addr}"
+            timeout_ms = 5000
+
+            [extensions.grpc.config.services.retry]
+            max_attempts = 3
+            initial_backoff_ms = 10
+            retryable_status_codes = ["UNAVAILABLE"]
+
+            [[extensions.grpc.config.services.methods]]
+            name = "GetFeature"
+            idempotent = true
+            "#
+        ))
+        .build()
+        .await
+        .unwrap();
+
+    let state = Arc::new(ServerState::default());
+
+    tokio::spawn({
+        let state = state.clone();
+
+        async move {
+            super::test_servers::routeguide::run_server_with_state(grpc_server_addr, state)
+                .await
+                .unwrap()
+        }
+    });
+
+    // Leave the server some time to start.
+    tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+
+    let query = r#"
+            query {
+                getFeature(input: { latitude: 409146138, longitude: -746188906 }) {
+                    name
+                }
+            }"#;
+
+    // Two failures, the third attempt succeeds.
+    state.unavailable_calls.store(2, Ordering::SeqCst);
+
+    let response = gateway.query(query).send().await;
+
+    insta::assert_json_snapshot!(response, @r#"
+    {
+      "data": {
+        "getFeature": {
+          "name": "Berkshire Valley Management Area Trail, Jefferson, NJ, USA"
+        }
+      }
+    }
+    "#);
+
+    assert_eq!(state.calls.swap(0, Ordering::SeqCst), 3);
+
+    // The attempts are exhausted.
+    state.unavailable_calls.store(5, Ordering::SeqCst);
+
+    let response = gateway.query(query).send().await;
+
+    insta::assert_json_snapshot!(response, @r#"
+    {
+      "data": {
+        "getFeature": null
+      },
+      "errors": [
+        {
+          "message": "gRPC error. Status code: Unavailable. Message: try again",
+          "locations": [
+            {
+              "line": 3,
+              "column": 17
+            }
+          ],
+          "path": [
+            "getFeature"
+          ],
+          "extensions": {
+            "code": "EXTENSION_ERROR",
+            "grpc": {
+              "status": "UNAVAILABLE"
+            }
+          }
+        }
+      ]
+    }
+    "#);
+
+    assert_eq!(state.calls.swap(0, Ordering::SeqCst), 3);
+}
+
+/// The `google.rpc.Status` message, with a `google.rpc.BadRequest` detail.
+mod rpc {
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub(super) struct Status {
+        #[prost(int32, tag = "1")]
+        pub(super) code: i32,
+        #[prost(string, tag = "2")]
+        pub(super) message: String,
+        #[prost(message, repeated, tag = "3")]
+        pub(super) details: Vec<Any>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub(super) struct Any {
+        #[prost(string, tag = "1")]
+        pub(super) type_url: String,
+        #[prost(bytes = "vec", tag = "2")]
+        pub(super) value: Vec<u8>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub(super) struct BadRequest {
+        #[prost(message, repeated, tag = "1")]
+        pub(super) field_violations: Vec<FieldViolation>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub(super) struct FieldViolation {
+        #[prost(string, tag = "1")]
+        pub(super) field: String,
+        #[prost(string, tag = "2")]
+        pub(super) description: String,
+    }
+}
+
+#[tokio::test]
+async fn error_details() {
+    let grpc_server_addr =
+        SocketAddr::from_str(&format!("[::1]:{}", (rand::random::<u16>() % 16_384) + 49_152)).unwrap();
+
+    let gateway = TestGateway::builder()
+        .subgraph(GET_FEATURE_SUBGRAPH)
+        .toml_config(format!(
+            r#"
+            [extensions.grpc.config.error_codes]
+            INVALID_ARGUMENT = "BAD_USER_INPUT"
+
+            [[extensions.grpc.config.services]]
+            name = "routeguide.RouteGuide"
+            address = "http://{grpc_server_addr}"
+            "#
+        ))
+        .build()
+        .await
+        .unwrap();
+
+    let state = Arc::new(ServerState::default());
+
+    tokio::spawn({
+        let state = state.clone();
+
+        async move {
+            super::test_servers::routeguide::run_server_with_state(grpc_server_addr, state)
+                .await
+                .unwrap()
+        }
+    });
+
+    // Leave the server some time to start.
+    tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+
+    let bad_request = rpc::BadRequest {
+        field_violations: vec![rpc::FieldViolation {
+            field: "latitude".to_owned(),
+            description: "must be between -90 and 90 degrees".to_owned(),
+        }],
+    };
+
+    let details = rpc::Status {
+        code: tonic::Code::InvalidArgument as i32,
+        message: "invalid point".to_owned(),
+        details: vec![rpc::Any {
+            type_url: "type.googleapis.com/google.rpc.BadRequest".to_owned(),
+            value: prost::Message::encode_to_vec(&bad_request),
+        }],
+    };
+
+    *state.next_error.lock().unwrap() = Some(tonic::Status::with_details(
+        tonic::Code::InvalidArgument,
+        "invalid point",
+        prost::Message::encode_to_vec(&details).into(),
+    ));
+
+    let response = gateway
+        .query(
+            r#"
+            query {
+                getFeature(input: { latitude: 409146138, longitude: -746188906 }) {
+                    name
+                }
+            }"#,
+        )
+        .send()
+        .await;
+
+    insta::assert_json_snapshot!(response, @r#"
+    {
+      "data": {
+        "getFeature": null
+      },
+      "errors": [
+        {
+          "message": "gRPC error. Status code: InvalidArgument. Message: invalid point",
+          "locations": [
+            {
+              "line": 3,
+              "column": 17
+            }
+          ],
+          "path": [
+            "getFeature"
+          ],
+          "extensions": {
+            "code": "BAD_USER_INPUT",
+            "grpc": {
+              "status": "INVALID_ARGUMENT",
+              "details": [
+                {
+                  "@type": "type.googleapis.com/google.rpc.BadRequest",
+                  "fieldViolations": [
+                    {
+                      "field": "latitude",
+                      "description": "must be between -90 and 90 degrees"
+                    }
+                  ]
+                }
+              ]
+            }
+          }
+        }
+      ]
+    }
+    "#);
+}
+
+#[tokio::test]
+async fn client_streaming() {
+    let grpc_server_addr =
+        SocketAddr::from_str(&format!("[::1]:{}", (rand::random::<u16>() % 16_384) + 49_152)).unwrap();
+
+    let gateway = TestGateway::builder()
+        .subgraph(
+            r#"
+            extend schema
+                @link(url: "<self>", import: ["@grpcMethod", "@protoMessages", "@protoServices"])
+                @protoMessages(definitions: [
+                  {
+                    name: "Point"
+                    fields: [
+                      { name: "latitude", type: "int32", number: 1 }
+                      { name: "longitude", type: "int32", number: 2 }
+                    ]
+                  },
+                  {
+                    name: "RouteSummary"
+                    fields: [
+                      { name: "pointCount", type: "int32", number: 1 }
+                      { name: "featureCount", type: "int32", number: 2 }
+                    ]
+                  }
+                ])
+                @protoServices(definitions: [
+                  {
+                    name: "routeguide.RouteGuide"
+                    methods: [
+                    { name: "RecordRoute", inputType: "Point", outputType: "RouteSummary", clientStreaming: true }
+                    ]
+                  }
+                ])
+
+            type Query {
+                hello: String
+            }
+
+            type Mutation {
+                recordRoute(input: [PointInput!]!): RouteSummary @grpcMethod(service: "routeguide.RouteGuide", method: "RecordRoute")
+                recordPoint(input: PointInput!): RouteSummary @grpcMethod(service: "routeguide.RouteGuide", method: "RecordRoute")
+            }
+
+            input PointInput {
+                latitude: Int!
+                longitude: Int!
+            }
+
+            type RouteSummary {
+                pointCount: Int
+                featureCount: Int
+            }
+        "#,
+        )
+        .toml_config(format!(
+            r#"
+            [[extensions.grpc.config.services]]
+            name = "routeguide.RouteGuide"
+            address = "http://{grpc_server_addr}"
+            "#
+        ))
+        .build()
+        .await
+        .unwrap();
+
+    tokio::spawn(async move {
+        super::test_servers::routeguide::run_server(grpc_server_addr)
+            .await
+            .unwrap()
+    });
+
+    // Leave the server some time to start.
+    tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+
+    let response = gateway
+        .query(
+            r#"
+            mutation {
+                recordRoute(input: [{ latitude: 409146138, longitude: -746188906 }]) {
+                    pointCount
+                    featureCount
+                }
+            }"#,
+        )
+        .send()
+        .await;
+
+    insta::assert_json_snapshot!(response, @r#"
+    {
+      "data": {
+        "recordRoute": {
+          "pointCount": 1,
+          "featureCount": 1
+        }
+      }
+    }
+    "#);
+
+    // A single message, as rendered by protoc-gen-grafbase-subgraph.
+    let response = gateway
+        .query(
+            r#"
+            mutation {
+                recordPoint(input: { latitude: 409146138, longitude: -746188906 }) {
+                    pointCount
+                    featureCount
+                }
+            }"#,
+        )
+        .send()
+        .await;
+
+    insta::assert_json_snapshot!(response, @r#"
+    {
+      "data": {
+        "recordPoint": {
+          "pointCount": 1,
+          "featureCount": 1
+        }
+      }
+    }
+    "#);
+
+    let response = gateway
+        .query(
+            r#"
+            mutation {
+                recordRoute(input: [
+                    { latitude: 409146138, longitude: -746188906 }
+                    { latitude: 407838351, longitude: -746143763 }
+                ]) {
+                    pointCount
+                }
+            }"#,
+        )
+        .send()
+        .await;
+
+    insta::assert_json_snapshot!(response, @r#"
+    {
+      "data": {
+        "recordRoute": null
+      },
+      "errors": [
+        {
+          "message": "Method RecordRoute is client streaming and was called with 2 messages, but only one message per call is supported",
+          "locations": [
+            {
+              "line": 3,
+              "column": 17
+            }
+          ],
+          "path": [
+            "recordRoute"
+          ],
+          "extensions": {
+            "code": "EXTENSION_ERROR"
+          }
+        }
+      ]
+    }
+    "#);
+}
+
+#[tokio::test]
+async fn file_descriptor_set() {
+    use base64::Engine as _;
+
+    let grpc_server_addr =
+        SocketAddr::from_str(&format!("[::1]:{}", (rand::random::<u16>() % 16_384) + 49_152)).unwrap();
+
+    let file_descriptor_set = base64::engine::general_purpose::STANDARD
+        .encode(include_bytes!(concat!(env!("OUT_DIR"), "/route_guide_descriptor.bin")));
+
+    let gateway = TestGateway::builder()
+        .subgraph(
+            r#"
+            extend schema
+                @link(url: "<self>", import: ["@grpcMethod"])
+
+            type Query {
+                getFeature(input: PointInput!): Feature @grpcMethod(service: "routeguide.RouteGuide", method: "GetFeature")
+            }
+
+            input PointInput {
+                latitude: Int!
+                longitude: Int!
+            }
+
+            type Feature {
+                name: String
+                location: Point
+            }
+
+            type Point {
+                latitude: Int!
+                longitude: Int!
+            }
+            "#,
+        )
+        .toml_config(format!(
+            r#"
+            [[extensions.grpc.config.services]]
+            name = "routeguide.RouteGuide"
+            address = "http://{grpc_server_addr}"
+            file_descriptor_set = "{file_descriptor_set}"
+            "#
+        ))
+        .build()
+        .await
+        .unwrap();
+
+    tokio::spawn(async move {
+        super::test_servers::routeguide::run_server(grpc_server_addr)
+            .await
+            .unwrap()
+    });
+
+    // Leave the server some time to start.
+    tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+
+    let response = gateway
+        .query(
+            r#"
+            query {
+                getFeature(input: { latitude: 409146138, longitude: -746188906 }) {
+                    name
+                    location {
+                        latitude
+                        longitude
+                    }
+                }
+            }"#,
+        )
+        .send()
+        .await;
+
+    insta::assert_json_snapshot!(response, @r#"
+    {
+      "data": {
+        "getFeature": {
+          "name": "Berkshire Valley Management Area Trail, Jefferson, NJ, USA",
+          "location": {
+            "latitude": 409146138,
+            "longitude": -746188906
+          }
+        }
+      }
+    }
+    "#);
+}
+
+#[tokio::test]
+async fn file_descriptor_set_mismatch() {
+    use base64::Engine as _;
+
+    let file_descriptor_set = base64::engine::general_purpose::STANDARD
+        .encode(include_bytes!(concat!(env!("OUT_DIR"), "/route_guide_descriptor.bin")));
+
+    let cases = [
+        (
+            "latitude: String!\n longitude: Int!",
+            "name: String\n location: Point",
+            "input field PointInput.latitude does not match field latitude of message .routeguide.Point: \
+             String is not compatible with int32",
+        ),
+        (
+            "latitude: Int!\n longitude: Int!",
+            "name: [String!]\n location: Point",
+            "field Feature.name does not match field name of message .routeguide.Feature: \
+             the GraphQL type is a list, but the field is not repeated",
+        ),
+    ];
+
+    for (point_input, feature, expected) in cases {
+        let error = TestGateway::builder()
+            .subgraph(format!(
+                r#"
+                extend schema
+                    @link(url: "<self>", import: ["@grpcMethod"])
+
+                type Query {{
+                    getFeature(input: PointInput!): Feature @grpcMethod(service: "routeguide.RouteGuide", method: "GetFeature")
+                }}
+
+                input PointInput {{
+                    {point_input}
+                }}
+
+                type Feature {{
+                    {feature}
+                }}
+
+                type Point {{
+                    latitude: Int!
+                    longitude: Int!
+                }}
+                "#
+            ))
+            .toml_config(format!(
+                r#"
+                [[extensions.grpc.config.services]]
+                name = "routeguide.RouteGuide"
+                address = "http://[::1]:50051"
+                file_descriptor_set = "{file_descriptor_set}"
+                "#
+            ))
+            .build()
+            .await
+            .err()
+            .unwrap()
+            .to_string();
+
+        assert!(
+            error.contains(&format!(
+                "Field Query.getFeature does not match the descriptors of service routeguide.RouteGuide: {expected}"
+            )),
+            "{error}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn field_mask() {
+    let grpc_server_addr =
+        SocketAddr::from_str(&format!("[::1]:{}", (rand::random::<u16>() % 16_384) + 49_152)).unwrap();
+
+    // The server ignores the mask, an unknown field of Point for it.
+    let gateway = TestGateway::builder()
+        .subgraph(
+            r#"
+            extend schema
+                @link(url: "<self>", import: ["@grpcMethod", "@protoMessages", "@protoServices"])
+                @protoMessages(definitions: [
+                  {
+                    name: "Point"
+                    fields: [
+                      { name: "latitude", type: "int32", number: 1 }
+                      { name: "longitude", type: "int32", number: 2 }
+                      { name: "read_mask", type: ".google.protobuf.FieldMask", number: 3 }
+                    ]
+                  },
+                  {
+                    name: "Feature"
+                    fields: [
+                      { name: "name", type: "string", number: 1 }
+                      { name: "location", type: "Point", number: 2 }
+                    ]
+                  }
+                ])
+                @protoServices(definitions: [
+                  {
+                    name: "routeguide.RouteGuide"
+                    methods: [
+                    { name: "GetFeature", inputType: "Point", outputType: "Feature" }
+                    ]
+                  }
+                ])
+
+            type Query {
+                getFeature(input: PointInput!): Feature
+                    @grpcMethod(service: "routeguide.RouteGuide", method: "GetFeature", fieldMask: "read_mask")
+                getFeatureWithInvalidMask(input: PointInput!): Feature
+                    @grpcMethod(service: "routeguide.RouteGuide", method: "GetFeature", fieldMask: "latitude")
+            }
+
+            input PointInput {
+                latitude: Int!
+                longitude: Int!
+            }
+
+            type Feature {
+                name: String
+                location: Point
+            }
+
+            type Point {
+                latitude: Int!
+                longitude: Int!
+            }
+            "#,
+        )
+        .toml_config(format!(
+            r#"
+            [[extensions.grpc.config.services]]
+            name = "routeguide.RouteGuide"
+            address = "http://{grpc_server_addr}"
+            "#
+        ))
+        .build()
+        .await
+        .unwrap();
+
+    tokio::spawn(async move {
+        super::test_servers::routeguide::run_server(grpc_server_addr)
+            .await
+            .unwrap()
+    });
+
+    // Leave the server some time to start.
+    tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+
+    let response = gateway
+        .query(
+            r#"
+            query {
+                getFeature(input: { latitude: 409146138, longitude: -746188906 }) {
+                    __typename
+                    title: name
+                    location {
+                        latitude
+                    }
+                }
+            }"#,
+        )
+        .send()
+        .await;
+
+    insta::assert_json_snapshot!(response, @r#"
+    {
+      "data": {
+        "getFeature": {
+          "__typename": "Feature",
+          "title": "Berkshire Valley Management Area Trail, Jefferson, NJ, USA",
+          "location": {
+            "latitude": 409146138
+          }
+        }
+      }
+    }
+    "#);
+
+    let response = gateway
+        .query(
+            r#"
+            query {
+                getFeatureWithInvalidMask(input: { latitude: 409146138, longitude: -746188906 }) {
+                    name
+                }
+            }"#,
+        )
+        .send()
+        .await;
+
+    insta::assert_json_snapshot!(response, @r#"
+    {
+      "data": {
+        "getFeatureWithInvalidMask": null
+      },
+      "errors": [
+        {
+          "message": "The fieldMask of the method must be a google.protobuf.FieldMask field of message Point, found latitude",
+          "locations": [
+            {
+              "line": 3,
+              "column": 17
+            }
+          ],
+          "path": [
+            "getFeatureWithInvalidMask"
+          ],
+          "extensions": {
+            "code": "EXTENSION_ERROR"
+          }
+        }
+      ]
+    }
+    "#);
+}
+
+#[tokio::test]
+async fn load_balancing() {
+    let random_addr = || SocketAddr::from_str(&format!("[::1]:{}", (rand::random::<u16>() % 16_384) + 49_152)).unwrap();
+
+    let first_server_addr = random_addr();
+    let second_server_addr = random_addr();
+    // Nothing listens on this one.
+    let unreachable_addr = random_addr();
+
+    let first_state = Arc::new(ServerState::default());
+    let second_state = Arc::new(ServerState::default());
+
+    for (addr, state) in [
+        (first_server_addr, first_state.clone()),
+        (second_server_addr, second_state.clone()),
+    ] {
+        tokio::spawn(async move {
+            super::test_servers::routeguide::run_server_with_state(addr, state)
+                .await
+                .unwrap()
+        });
+    }
+
+    // Leave the servers some time to start.
+    tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+
+    let query = r#"
+        query {
+            getFeature(input: { latitude: 409146138, longitude: -746188906 }) {
+                name
+            }
+        }"#;
+
+    let round_robin = TestGateway::builder()
+        .subgraph(GET_FEATURE_SUBGRAPH)
+        .toml_config(format!(
+            r#"
+            [[extensions.grpc.config.services]]
+            name = "routeguide.RouteGuide"
+            addresses = ["http://{first_server_addr}", "http://{second_server_addr}"]
+            load_balancing = "round_robin"
+            "#
+        ))
+        .build()
+        .await
+        .unwrap();
+
+    for _ in 0..4 {
+        let response = round_robin.query(query).send().await;
+
+        insta::assert_json_snapshot!(response, @r#"
+        {
+          "data": {
+            "getFeature": {
+              "name": "Berkshire Valley Management Area Trail, Jefferson, NJ, USA"
+            }
+          }
+        }
+        "#);
+    }
+
+    let first_calls = first_state.calls.swap(0, Ordering::SeqCst);
+    let second_calls = second_state.calls.swap(0, Ordering::SeqCst);
+
+    assert_eq!(first_calls + second_calls, 4);
+    assert!(first_calls > 0 && second_calls > 0);
+
+    // The unreachable endpoint fails its health check and is ejected. The test server doesn't
+    // implement the health service, so it is considered healthy.
+    let pick_first = TestGateway::builder()
+        .subgraph(GET_FEATURE_SUBGRAPH)
+        .toml_config(format!(
+            r#"
+            [[extensions.grpc.config.services]]
+            name = "routeguide.RouteGuide"
+            addresses = ["http://{unreachable_addr}", "http://{first_server_addr}"]
+
+            [extensions.grpc.config.services.health_check]
+            interval_ms = 60000
+            "#
+        ))
+        .build()
+        .await
+        .unwrap();
+
+    for _ in 0..2 {
+        let response = pick_first.query(query).send().await;
+
+        insta::assert_json_snapshot!(response, @r#"
+        {
+          "data": {
+            "getFeature": {
+              "name": "Berkshire Valley Management Area Trail, Jefferson, NJ, USA"
+            }
+          }
+        }
+        "#);
+    }
+
+    assert_eq!(first_state.calls.load(Ordering::SeqCst), 2);
+}
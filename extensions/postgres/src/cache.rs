@@ -0,0 +1,126 @@
+use std::{cell::RefCell, collections::HashMap, time::Duration};
+
+use grafbase_database_definition::TableWalker;
+use grafbase_sdk::host_io::{
+    cache::Cache,
+    postgres::{Query, types::DatabaseValue},
+};
+use sha2::{Digest, Sha256};
+
+use crate::config::{CacheConfig, TableCacheConfig};
+
+/// Caches the results of the read operations of a database in the host cache, per table.
+///
+/// The host cache can neither overwrite nor remove entries, so every table has a generation which
+/// is part of the cache key. A mutation on the table bumps the generation, making the older
+/// entries unreachable for this instance. Other instances of the extension, in this gateway or
+/// others, keep serving their entries until the configured maximum age.
+pub struct QueryCache {
+    tables: HashMap<String, TableCache>,
+    generations: RefCell<HashMap<String, u64>>,
+}
+
+struct TableCache {
+    cache: Cache,
+    scope: Vec<String>,
+}
+
+impl QueryCache {
+    /// Creates the caches of the configured tables, or returns `None` if no table is cached.
+    pub fn new(database_name: &str, config: &CacheConfig) -> Option<Self> {
+        if config.tables.is_empty() {
+            return None;
+        }
+
+        let tables = config
+            .tables
+            .iter()
+            .map(|(table, TableCacheConfig { max_age_ms, scope })| {
+                let cache = Cache::builder(format!("postgres.{database_name}.{table}"), config.max_entries)
+                    .time_to_live(Some(Duration::from_millis(*max_age_ms)))
+                    .build();
+
+                let table_cache = TableCache {
+                    cache,
+                    scope: scope.clone(),
+                };
+
+                (table.clone(), table_cache)
+            })
+            .collect();
+
+        Some(Self {
+            tables,
+            generations: RefCell::new(HashMap::new()),
+        })
+    }
+
+    /// Whether any cached table scopes its results by claims of the access token.
+    pub fn is_scoped(&self) -> bool {
+        self.tables.values().any(|table| !table.scope.is_empty())
+    }
+
+    /// Returns the cached result of the query on the table, running `fetch` to fill the cache
+    /// if the result is missing. The query is run directly if the table is not cached.
+    ///
+    /// A query with no result is cached as empty bytes.
+    pub fn get_or_fetch<E>(
+        &self,
+        table: TableWalker<'_>,
+        query: &Query,
+        parameters: &[DatabaseValue],
+        claims: Option<&serde_json::Value>,
+        fetch: impl FnOnce() -> Result<Option<Vec<u8>>, E>,
+    ) -> Result<Option<Vec<u8>>, E> {
+        let table = table_key(table);
+
+        let Some(table_cache) = self.tables.get(&table) else {
+            return fetch();
+        };
+
+        let generation = self.generations.borrow().get(&table).copied().unwrap_or_default();
+
+        let mut hasher = Sha256::new();
+        hasher.update(generation.to_le_bytes());
+
+        for claim in &table_cache.scope {
+            let value = claims.and_then(|claims| claims.get(claim));
+            update_with_length(&mut hasher, &serde_json::to_vec(&value).unwrap_or_default());
+        }
+
+        update_with_length(&mut hasher, query.to_string().as_bytes());
+
+        // The SDK exposes no encoding of the bound values besides their debug representation.
+        for parameter in parameters {
+            update_with_length(&mut hasher, format!("{parameter:?}").as_bytes());
+        }
+
+        let key = format!("{:x}", hasher.finalize());
+
+        let bytes = table_cache
+            .cache
+            .try_get_or_insert_bytes(&key, || fetch().map(Option::unwrap_or_default))?;
+
+        Ok(Some(bytes).filter(|bytes| !bytes.is_empty()))
+    }
+
+    /// Makes the cached results of the table unreachable, after a mutation on it.
+    pub fn invalidate(&self, table: TableWalker<'_>) {
+        let table = table_key(table);
+
+        if self.tables.contains_key(&table) {
+            *self.generations.borrow_mut().entry(table).or_default() += 1;
+        }
+    }
+}
+
+fn table_key(table: TableWalker<'_>) -> String {
+    format!("{}.{}", table.schema(), table.database_name())
+}
+
+/// Hashes the bytes prefixed by their length, so consecutive parts of the key cannot run into
+/// each other.
+fn update_with_length(hasher: &mut Sha256, bytes: &[u8]) {
+    hasher.update((bytes.len() as u64).to_le_bytes());
+    hasher.update(bytes);
+}
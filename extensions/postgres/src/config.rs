@@ -1,6 +1,13 @@
+use std::{collections::HashMap, time::Duration};
+
+use grafbase_database_definition::TableWalker;
+use grafbase_sdk::types::{AuthorizedOperationContext, SubgraphHeaders};
+
 #[derive(Debug, Clone, serde::Deserialize)]
 pub struct PostgresConfig {
     pub databases: Vec<DatabaseConfig>,
+    #[serde(default)]
+    pub debug: DebugConfig,
 }
 
 #[derive(Debug, Clone, serde::Deserialize)]
@@ -9,6 +16,9 @@ pub struct DatabaseConfig {
     pub name: String,
     pub url: String,
     pub pool: Option<PoolConfig>,
+    pub limits: LimitsConfig,
+    pub cache: CacheConfig,
+    pub cursors: CursorConfig,
 }
 
 impl Default for DatabaseConfig {
@@ -17,6 +27,9 @@ impl Default for DatabaseConfig {
             name: "default".to_string(),
             url: "postgres://localhost:5432/postgres".to_string(),
             pool: None,
+            limits: LimitsConfig::default(),
+            cache: CacheConfig::default(),
+            cursors: CursorConfig::default(),
         }
     }
 }
@@ -30,3 +43,244 @@ pub struct PoolConfig {
     pub acquire_timeout_ms: Option<u64>,
     pub max_lifetime_ms: Option<u64>,
 }
+
+/// Limits protecting the database from expensive queries.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct LimitsConfig {
+    /// The maximum value of the `first` and `last` arguments.
+    pub max_page_size: Option<u64>,
+    /// The page size if the query defines neither `first` nor `last`. Defaults to `max_page_size`.
+    pub default_page_size: Option<u64>,
+    /// The maximum number of nested relations in one query.
+    pub max_depth: Option<usize>,
+    /// Aborts statements running longer than this.
+    pub statement_timeout_ms: Option<u64>,
+    /// Overrides for specific tables, keyed by `schema.table`.
+    pub tables: HashMap<String, TableLimitsConfig>,
+}
+
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct TableLimitsConfig {
+    pub max_page_size: Option<u64>,
+    pub default_page_size: Option<u64>,
+}
+
+impl LimitsConfig {
+    /// The maximum value of the `first` and `last` arguments when selecting from the given table.
+    pub fn max_page_size(&self, table: TableWalker<'_>) -> Option<u64> {
+        self.table(table)
+            .and_then(|table| table.max_page_size)
+            .or(self.max_page_size)
+    }
+
+    /// The page size for the given table, if the query defines neither `first` nor `last`.
+    pub fn default_page_size(&self, table: TableWalker<'_>) -> Option<u64> {
+        self.table(table)
+            .and_then(|table| table.default_page_size)
+            .or(self.default_page_size)
+            .or_else(|| self.max_page_size(table))
+    }
+
+    /// Checks the default page sizes are within the maximum page sizes.
+    pub fn validate(&self) -> Result<(), String> {
+        if let (Some(default), Some(max)) = (self.default_page_size, self.max_page_size)
+            && default > max
+        {
+            return Err(format!(
+                "default_page_size {default} is larger than max_page_size {max}"
+            ));
+        }
+
+        for (name, table) in &self.tables {
+            let max = table.max_page_size.or(self.max_page_size);
+            let default = table.default_page_size.or(self.default_page_size);
+
+            if let (Some(default), Some(max)) = (default, max)
+                && default > max
+            {
+                return Err(format!(
+                    "default_page_size {default} of table {name} is larger than max_page_size {max}"
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn table(&self, table: TableWalker<'_>) -> Option<&TableLimitsConfig> {
+        self.tables
+            .get(&format!("{}.{}", table.schema(), table.database_name()))
+    }
+}
+
+/// Caching of query results in the host cache, shared by all instances of the extension.
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(default)]
+pub struct CacheConfig {
+    /// The maximum number of cached results per table.
+    pub max_entries: usize,
+    /// Tables to cache the query results of, keyed by `schema.table`.
+    pub tables: HashMap<String, TableCacheConfig>,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            max_entries: 1000,
+            tables: HashMap::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct TableCacheConfig {
+    /// How long a result is served from the cache.
+    pub max_age_ms: u64,
+    /// Claims of the access token to cache the results separately for, such as `sub` for
+    /// results depending on the user.
+    #[serde(default)]
+    pub scope: Vec<String>,
+}
+
+/// The format of the pagination cursors.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct CursorConfig {
+    /// Seals the cursors with this secret, so they are opaque to clients and cannot be forged.
+    /// Without a secret, the cursors hold the values of the ordering columns in base64-encoded
+    /// JSON.
+    pub secret: Option<String>,
+}
+
+/// Debugging of the generated statements.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct DebugConfig {
+    /// Collects debug information of the statements, if the request opts in with the header
+    /// or the claim.
+    pub enabled: bool,
+    /// Requests with this header set to `true` get debug information.
+    pub header: Option<String>,
+    /// Requests with a token having this claim set to `true` get debug information.
+    pub claim: Option<String>,
+    /// Adds the query plan to the debug information, from `EXPLAIN` without executing the
+    /// statement again.
+    pub explain: bool,
+    /// Plans queries with `EXPLAIN ANALYZE` instead, which executes them a second time to add the
+    /// actual row counts and timings. Mutations are only planned.
+    pub explain_analyze: bool,
+    /// Logs statements running longer than this. Applies to all requests.
+    pub slow_statement_threshold_ms: Option<u64>,
+}
+
+impl DebugConfig {
+    /// Whether to collect debug information for the request.
+    pub fn is_enabled_for(&self, ctx: &AuthorizedOperationContext, headers: &SubgraphHeaders) -> bool {
+        let header_value = self.header.as_ref().and_then(|header| headers.get(header.as_str()));
+
+        let claims = self.claim.as_ref().and_then(|_| {
+            ctx.token()
+                .as_bytes()
+                .and_then(|bytes| serde_json::from_slice::<serde_json::Value>(bytes).ok())
+        });
+
+        self.is_enabled(header_value.as_ref().map(|value| value.as_bytes()), claims.as_ref())
+    }
+
+    /// Whether to collect debug information, given the value of the configured header and the
+    /// claims of the token.
+    fn is_enabled(&self, header_value: Option<&[u8]>, claims: Option<&serde_json::Value>) -> bool {
+        if !self.enabled {
+            return false;
+        }
+
+        let header_enabled =
+            self.header.is_some() && header_value.is_some_and(|value| value.eq_ignore_ascii_case(b"true"));
+
+        let claim_enabled = self.claim.as_ref().is_some_and(|claim| {
+            claims.is_some_and(|claims| claims.get(claim) == Some(&serde_json::Value::Bool(true)))
+        });
+
+        header_enabled || claim_enabled
+    }
+
+    /// Whether a statement that took the given time is logged as slow.
+    pub fn is_slow(&self, duration: Duration) -> bool {
+        self.slow_statement_threshold_ms
+            .is_some_and(|threshold| duration >= Duration::from_millis(threshold))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use serde_json::json;
+
+    use super::DebugConfig;
+
+    fn debug_config() -> DebugConfig {
+        DebugConfig {
+            enabled: true,
+            header: Some("x-postgres-debug".to_owned()),
+            claim: Some("postgres_debug".to_owned()),
+            slow_statement_threshold_ms: Some(500),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn debug_enabled_by_header_or_claim() {
+        let config = debug_config();
+
+        assert!(config.is_enabled(Some(b"true"), None));
+        assert!(config.is_enabled(Some(b"TRUE"), None));
+        assert!(!config.is_enabled(Some(b"1"), None));
+        assert!(!config.is_enabled(None, None));
+
+        assert!(config.is_enabled(None, Some(&json!({ "postgres_debug": true }))));
+        assert!(!config.is_enabled(None, Some(&json!({ "postgres_debug": "true" }))));
+        assert!(!config.is_enabled(None, Some(&json!({ "sub": "musti" }))));
+    }
+
+    #[test]
+    fn debug_requires_enabled_and_configured_opt_in() {
+        let disabled = DebugConfig {
+            enabled: false,
+            ..debug_config()
+        };
+
+        assert!(!disabled.is_enabled(Some(b"true"), Some(&json!({ "postgres_debug": true }))));
+
+        let without_header = DebugConfig {
+            header: None,
+            ..debug_config()
+        };
+
+        assert!(!without_header.is_enabled(Some(b"true"), None));
+
+        let without_claim = DebugConfig {
+            claim: None,
+            ..debug_config()
+        };
+
+        assert!(!without_claim.is_enabled(None, Some(&json!({ "postgres_debug": true }))));
+    }
+
+    #[test]
+    fn slow_statement_threshold() {
+        let config = debug_config();
+
+        assert!(!config.is_slow(Duration::from_millis(499)));
+        assert!(config.is_slow(Duration::from_millis(500)));
+
+        let without_threshold = DebugConfig {
+            slow_statement_threshold_ms: None,
+            ..debug_config()
+        };
+
+        assert!(!without_threshold.is_slow(Duration::from_secs(60)));
+    }
+}
@@ -6,6 +6,12 @@ pub mod update_input;
 
 use std::collections::HashMap;
 
+use crate::{
+    cache::QueryCache,
+    config::{DebugConfig, LimitsConfig},
+    cursor::CursorCodec,
+    debug::StatementLog,
+};
 use create_input::{CreateInputIterator, CreateInputParameters, CreateManyInputParameters};
 use filter::{FilterIterator, LookupFilterIterator, MultipleFilterIterator, UniqueFilterIterator};
 use grafbase_database_definition::{
@@ -29,6 +35,13 @@ pub struct Context<'a> {
     pub(super) variables: &'a Variables,
     pub(super) database_definition: &'a DatabaseDefinition,
     pub(super) pool: &'a postgres::Pool,
+    pub(super) limits: &'a LimitsConfig,
+    pub(super) debug: &'a DebugConfig,
+    pub(super) statement_log: Option<&'a StatementLog>,
+    pub(super) cache: Option<&'a QueryCache>,
+    /// The claims of the access token, parsed only if a cached table is scoped by them.
+    pub(super) claims: Option<&'a Value>,
+    pub(super) cursors: Option<&'a CursorCodec>,
     pub(super) operation: Operation,
     pub(super) field: Field<'a>,
 }
@@ -48,6 +61,12 @@ struct FilterUnique {
     lookup: serde_json::Map<String, Value>,
 }
 
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DryRunParameters {
+    dry_run: Option<bool>,
+}
+
 #[derive(Clone, Copy)]
 pub struct PageInfo {
     has_next_page: bool,
@@ -108,6 +127,12 @@ impl<'a> Context<'a> {
         self.operation
     }
 
+    /// Whether the mutation is a dry run, which is rolled back after counting the affected rows.
+    pub(crate) fn is_dry_run(self) -> Result<bool, SdkError> {
+        let args = self.field.arguments::<DryRunParameters>(self.variables)?;
+        Ok(args.dry_run.unwrap_or_default())
+    }
+
     /// Creates a `SelectionIterator` for a flat selection based on the current field and context.
     /// For pagination, use `collection_selection`.
     pub(crate) fn selection(self, table: TableWalker<'a>) -> Result<SelectionIterator<'a>, SdkError> {
@@ -203,7 +228,10 @@ impl<'a> Context<'a> {
     }
 }
 
-fn rename_enum_variants(column: TableColumnWalker<'_>, value: DatabaseValue) -> Result<DatabaseValue, SdkError> {
+pub(crate) fn rename_enum_variants(
+    column: TableColumnWalker<'_>,
+    value: DatabaseValue,
+) -> Result<DatabaseValue, SdkError> {
     let value = match column.database_type() {
         DatabaseType::Scalar(_) => value,
         DatabaseType::Enum(r#enum) => {
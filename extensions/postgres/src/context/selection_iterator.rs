@@ -1,12 +1,14 @@
 pub mod collection_args;
 
 use collection_args::{CollectionArgs, CollectionParameters};
-use grafbase_database_definition::{DatabaseType, EnumWalker, RelationWalker, TableColumnWalker, TableWalker};
+use grafbase_database_definition::{
+    ComputedFieldWalker, DatabaseType, EnumWalker, RelationWalker, TableColumnWalker, TableWalker,
+};
 use grafbase_sdk::{
     SdkError,
     types::{Field, SelectionSet},
 };
-use sql_ast::ast::{self, Aliasable, Case, Column, Comparable, Expression, Select, json_agg, raw_str};
+use sql_ast::ast::{self, Aliasable, Case, Column, Comparable, Expression, Select, Table, json_agg, raw, raw_str};
 use std::{borrow::Cow, collections::HashMap};
 
 use super::{Context, PageInfo};
@@ -56,6 +58,32 @@ impl<'a> SelectColumn<'a> {
     }
 }
 
+#[derive(Clone)]
+pub struct SelectComputed<'a> {
+    field: ComputedFieldWalker<'a>,
+    alias: Option<&'a str>,
+}
+
+impl<'a> SelectComputed<'a> {
+    /// Splices the SQL expression of the field into a subquery, which has the row of the given
+    /// table alias in scope under the database name of the table:
+    ///
+    /// `(SELECT first_name || ' ' || last_name FROM (SELECT "User".*) AS "users")`
+    ///
+    /// The table alias must have all the columns of the table available.
+    pub fn into_expression(self, row: Cow<'a, str>) -> (ComputedFieldWalker<'a>, Expression<'a>, Option<&'a str>) {
+        let mut row_select = Select::default();
+        row_select.value(Table::from(row).asterisk());
+
+        let row_table = Table::from(row_select).alias(self.field.table().database_name());
+
+        let mut select = Select::from_table(row_table);
+        select.value(raw(self.field.sql()));
+
+        (self.field, Expression::from(select), self.alias)
+    }
+}
+
 #[derive(Clone)]
 pub struct Unnest<'a>(TableColumnWalker<'a>, EnumWalker<'a>, Option<&'a str>);
 
@@ -96,6 +124,8 @@ pub enum TableSelection<'a> {
     /// Returns a selection which transforms an array of enum values into an array of
     /// GraphQL enum values, renamed.
     ColumnUnnest(Unnest<'a>),
+    /// Selects the value of an SQL expression over the row.
+    Computed(SelectComputed<'a>),
     /// Joins a unique row with a nested selection.
     JoinUnique(RelationWalker<'a>, SelectionIterator<'a>, Option<&'a str>),
     /// Joins a collection of rows with a nested selection.
@@ -121,6 +151,8 @@ pub struct SelectionIterator<'a> {
     selects_cursor: bool,
     selects_edges: bool,
     selects_nodes: bool,
+    // the number of relations joined before this selection
+    depth: usize,
 }
 
 impl<'a> SelectionIterator<'a> {
@@ -228,6 +260,7 @@ impl<'a> SelectionIterator<'a> {
             selects_cursor: false,
             selects_edges: false,
             selects_nodes: false,
+            depth: 0,
         })
     }
 
@@ -295,6 +328,18 @@ impl<'a> Iterator for SelectionIterator<'a> {
             }
         }
 
+        // Selecting an SQL expression.
+        if let Some(field) = self
+            .ctx
+            .database_definition
+            .computed_field_for_field_definition(selection_field.definition_id())
+        {
+            return Some(Ok(TableSelection::Computed(SelectComputed {
+                field,
+                alias: selection_field.alias(),
+            })));
+        }
+
         // Joining a table with the current one, selecting from the joined table.
         let relation = match self
             .ctx
@@ -308,11 +353,21 @@ impl<'a> Iterator for SelectionIterator<'a> {
             }
         };
 
+        let depth = self.depth + 1;
+
+        if let Some(max_depth) = self.ctx.limits.max_depth
+            && depth > max_depth
+        {
+            return Some(Err(SdkError::from(format!(
+                "The query exceeds the maximum relation nesting depth of {max_depth}"
+            ))));
+        }
+
         if relation.is_other_side_one() {
             // The other side has a unique constraint, so our join must return at most one row.
             let selection_set = selection_field.selection_set();
 
-            let iterator = match Self::unique(
+            let mut iterator = match Self::unique(
                 self.ctx,
                 relation.referenced_table(),
                 selection_field,
@@ -322,6 +377,8 @@ impl<'a> Iterator for SelectionIterator<'a> {
                 Err(err) => return Some(Err(err)),
             };
 
+            iterator.depth = depth;
+
             Some(Ok(TableSelection::JoinUnique(
                 relation,
                 iterator,
@@ -340,7 +397,7 @@ impl<'a> Iterator for SelectionIterator<'a> {
             //
             let selection_set = selection_field.selection_set();
 
-            let iterator = match Self::edges(
+            let mut iterator = match Self::edges(
                 self.ctx,
                 relation.referenced_table(),
                 selection_field,
@@ -350,8 +407,16 @@ impl<'a> Iterator for SelectionIterator<'a> {
                 Err(error) => return Some(Err(error)),
             };
 
+            iterator.depth = depth;
+
             // By defining this, we mark the next select to return a collecton.
-            let args = CollectionArgs::new(self.ctx.database_definition, relation.referenced_table(), params);
+            let args = CollectionArgs::new(
+                self.ctx.database_definition,
+                relation.referenced_table(),
+                self.ctx.limits,
+                self.ctx.cursors,
+                params,
+            );
 
             match args {
                 Ok(args) => Some(Ok(TableSelection::JoinMany(
@@ -5,6 +5,10 @@ use grafbase_sdk::SdkError;
 
 use sql_ast::ast::{Aliasable, Column, Order};
 
+use itertools::Itertools;
+
+use crate::{config::LimitsConfig, cursor::CursorCodec};
+
 #[derive(Clone, Default)]
 pub struct CollectionOrdering<'a> {
     inner: Vec<(TableColumnWalker<'a>, Option<Order>)>,
@@ -64,12 +68,39 @@ impl<'a> CollectionArgs<'a> {
     pub(crate) fn new(
         database_definition: &'a DatabaseDefinition,
         table: TableWalker<'a>,
+        limits: &LimitsConfig,
+        cursors: Option<&CursorCodec>,
         mut params: CollectionParameters,
     ) -> Result<Self, SdkError> {
         if let (Some(_), Some(_)) = (params.first, params.last) {
             return Err(SdkError::from("first and last parameters can't be both defined"));
         }
 
+        if let Some(max) = limits.max_page_size(table) {
+            for (name, value) in [("first", params.first), ("last", params.last)] {
+                if let Some(value) = value
+                    && value > max
+                {
+                    return Err(SdkError::from(format!(
+                        "The {name} argument of {value} exceeds the maximum page size of {max} for type {}",
+                        table.client_name()
+                    )));
+                }
+            }
+        }
+
+        // Without a limit from the client, we select a default page. Paginating backwards from a
+        // cursor must take the page from the end.
+        if params.first.is_none() && params.last.is_none() {
+            let page_size = limits.default_page_size(table);
+
+            if params.before.is_some() {
+                params.last = page_size;
+            } else {
+                params.first = page_size;
+            }
+        }
+
         let constraint = table
             .implicit_ordering_key()
             .expect("tables at this point must have at least one unique constraint");
@@ -138,14 +169,26 @@ impl<'a> CollectionArgs<'a> {
             order_by.outer.push((column, Some(outer_direction)));
         }
 
-        Ok(Self {
+        let mut args = Self {
             first: params.first,
             last: params.last,
             before: params.before,
             after: params.after,
             order_by,
             extra_columns,
-        })
+        };
+
+        if let Some(cursors) = cursors {
+            let order = args.order_spec();
+
+            for cursor in [&mut args.before, &mut args.after].into_iter().flatten() {
+                *cursor = cursors
+                    .open(&order, cursor)
+                    .map_err(|error| SdkError::from(format!("Invalid cursor: {error}")))?;
+            }
+        }
+
+        Ok(args)
     }
 
     /// Select the first N items. An example GraphQL definition: `userCollection(first: N)`.
@@ -189,6 +232,22 @@ impl<'a> CollectionArgs<'a> {
         &self.order_by
     }
 
+    /// The ordering of the collection as given by the client, such as `name:desc,id:asc`. Sealed
+    /// cursors are only valid for the ordering they were created for.
+    pub(crate) fn order_spec(&self) -> String {
+        self.order_by
+            .outer()
+            .map(|(column, order)| {
+                let direction = match order {
+                    Some(Order::Desc | Order::DescNullsFirst | Order::DescNullsLast) => "desc",
+                    _ => "asc",
+                };
+
+                format!("{}:{direction}", column.database_name())
+            })
+            .join(",")
+    }
+
     /// A set of extra columns needing to select in the collecting query. Needed to handle the ordering of the outer
     /// layers.
     pub(crate) fn extra_columns(&self) -> impl ExactSizeIterator<Item = (TableColumnWalker<'a>, Column<'static>)> + '_ {
@@ -0,0 +1,370 @@
+use std::{borrow::Cow, fmt};
+
+use base64::{Engine, prelude::BASE64_URL_SAFE_NO_PAD};
+use chacha20poly1305::{
+    XChaCha20Poly1305, XNonce,
+    aead::{Aead, KeyInit, Payload as AeadPayload},
+};
+use grafbase_sdk::{
+    SdkError,
+    types::{Field, SelectionSet},
+};
+use hmac::{Hmac, Mac};
+use serde_json::Value;
+use sha2::Sha256;
+
+use crate::context::{
+    Context,
+    selection_iterator::collection_args::{CollectionArgs, CollectionParameters},
+};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// The version of the sealed cursor format, the first byte of a cursor.
+const VERSION: u8 = 1;
+
+/// The length of the random nonce of the encryption.
+const NONCE_LENGTH: usize = 24;
+
+/// The length of the authentication tag, appended to the encrypted payload.
+const TAG_LENGTH: usize = 16;
+
+/// The minimum length of the configured secret.
+pub const MIN_SECRET_LENGTH: usize = 32;
+
+/// Seals the cursors generated by the database, so clients can neither read the values of the
+/// ordering columns nor forge a cursor.
+///
+/// A sealed cursor is the version byte, followed by a random nonce and the payload encrypted
+/// with XChaCha20-Poly1305, encoded as URL-safe base64. The version byte is authenticated as
+/// associated data. The payload holds the cursor of the database and the ordering the cursor was
+/// generated for.
+pub struct CursorCodec {
+    cipher: XChaCha20Poly1305,
+}
+
+/// The reason a cursor could not be opened.
+#[derive(Debug)]
+pub enum CursorError {
+    /// The cursor is not a sealed cursor.
+    Malformed,
+    /// The cursor was sealed with another version of the format.
+    UnsupportedVersion(u8),
+    /// The cursor was modified, or sealed with another secret.
+    InvalidSignature,
+    /// The cursor was generated for another ordering of the collection.
+    OrderMismatch { expected: String, found: String },
+}
+
+impl fmt::Display for CursorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CursorError::Malformed => f.write_str("the cursor is malformed"),
+            CursorError::UnsupportedVersion(version) => write!(f, "cursor version {version} is not supported"),
+            CursorError::InvalidSignature => f.write_str("the cursor signature is invalid"),
+            CursorError::OrderMismatch { expected, found } => {
+                write!(
+                    f,
+                    "the cursor was created for ordering {found}, the query orders by {expected}"
+                )
+            }
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Payload<'a> {
+    #[serde(rename = "o", borrow)]
+    order: Cow<'a, str>,
+    #[serde(rename = "c", borrow)]
+    cursor: Cow<'a, str>,
+}
+
+impl CursorCodec {
+    pub fn new(secret: &str) -> Self {
+        let key = derive_key(secret, b"postgres cursor encryption");
+
+        Self {
+            cipher: XChaCha20Poly1305::new(&key.into()),
+        }
+    }
+
+    /// Seals a cursor generated by the database for a collection with the given ordering.
+    pub fn seal(&self, order: &str, cursor: &str) -> Result<String, SdkError> {
+        let payload = Payload {
+            order: Cow::Borrowed(order),
+            cursor: Cow::Borrowed(cursor),
+        };
+
+        let data = serde_json::to_vec(&payload).map_err(|error| format!("could not serialize the cursor: {error}"))?;
+
+        let mut nonce = [0; NONCE_LENGTH];
+        getrandom::fill(&mut nonce).map_err(|error| format!("could not generate a cursor nonce: {error}"))?;
+
+        let encrypted = self
+            .cipher
+            .encrypt(
+                XNonce::from_slice(&nonce),
+                AeadPayload {
+                    msg: &data,
+                    aad: &[VERSION],
+                },
+            )
+            .map_err(|_| SdkError::from("could not encrypt the cursor"))?;
+
+        let mut sealed = Vec::with_capacity(1 + NONCE_LENGTH + encrypted.len());
+        sealed.push(VERSION);
+        sealed.extend_from_slice(&nonce);
+        sealed.extend_from_slice(&encrypted);
+
+        Ok(BASE64_URL_SAFE_NO_PAD.encode(sealed))
+    }
+
+    /// Opens a sealed cursor, returning the cursor of the database. Fails if the cursor was
+    /// tampered with, or was sealed for another ordering.
+    pub fn open(&self, order: &str, sealed: &str) -> Result<String, CursorError> {
+        let sealed = BASE64_URL_SAFE_NO_PAD
+            .decode(sealed)
+            .map_err(|_| CursorError::Malformed)?;
+
+        let Some((&version, rest)) = sealed.split_first() else {
+            return Err(CursorError::Malformed);
+        };
+
+        if version != VERSION {
+            return Err(CursorError::UnsupportedVersion(version));
+        }
+
+        if rest.len() < NONCE_LENGTH + TAG_LENGTH {
+            return Err(CursorError::Malformed);
+        }
+
+        let (nonce, encrypted) = rest.split_at(NONCE_LENGTH);
+
+        let data = self
+            .cipher
+            .decrypt(
+                XNonce::from_slice(nonce),
+                AeadPayload {
+                    msg: encrypted,
+                    aad: &[version],
+                },
+            )
+            .map_err(|_| CursorError::InvalidSignature)?;
+
+        let payload: Payload<'_> = serde_json::from_slice(&data).map_err(|_| CursorError::Malformed)?;
+
+        if payload.order != order {
+            return Err(CursorError::OrderMismatch {
+                expected: order.to_string(),
+                found: payload.order.into_owned(),
+            });
+        }
+
+        Ok(payload.cursor.into_owned())
+    }
+}
+
+fn derive_key(secret: &str, purpose: &[u8]) -> [u8; 32] {
+    let mut mac = <HmacSha256 as Mac>::new_from_slice(secret.as_bytes()).expect("HMAC accepts any key length");
+    mac.update(purpose);
+    mac.finalize().into_bytes().into()
+}
+
+/// Where the cursors are in the result of an operation.
+pub(crate) enum ResultShape<'a> {
+    /// A connection with edges and page info, with the order spec of its arguments.
+    Connection(&'a str),
+    /// A single row, or null.
+    Row,
+    /// A list of rows.
+    Rows,
+}
+
+/// Seals the cursors in the result of the current field, if the database seals its cursors.
+/// Cursors of nested collections are sealed too.
+pub(crate) fn seal_result(
+    ctx: Context<'_>,
+    shape: ResultShape<'_>,
+    data: Option<Vec<u8>>,
+) -> Result<Option<Vec<u8>>, SdkError> {
+    let (Some(codec), Some(data)) = (ctx.cursors, data.as_deref()) else {
+        return Ok(data);
+    };
+
+    let mut value: Value =
+        serde_json::from_slice(data).map_err(|error| SdkError::from(format!("invalid query result: {error}")))?;
+
+    let selection = ctx.field.selection_set();
+
+    match shape {
+        ResultShape::Connection(order) => seal_connection(ctx, codec, ctx.field, order, &mut value)?,
+        ResultShape::Row => seal_row(ctx, codec, selection, &mut value)?,
+        ResultShape::Rows => {
+            for row in value.as_array_mut().into_iter().flatten() {
+                seal_row(ctx, codec, selection, row)?;
+            }
+        }
+    }
+
+    let data = serde_json::to_vec(&value).map_err(|error| SdkError::from(format!("invalid query result: {error}")))?;
+
+    Ok(Some(data))
+}
+
+fn seal_connection(
+    ctx: Context<'_>,
+    codec: &CursorCodec,
+    field: Field<'_>,
+    order: &str,
+    value: &mut Value,
+) -> Result<(), SdkError> {
+    let Some(connection) = value.as_object_mut() else {
+        return Ok(());
+    };
+
+    let node_selection = find_field(ctx, field.selection_set(), "edges")
+        .and_then(|edges| find_field(ctx, edges.selection_set(), "node"))
+        .map(|node| node.selection_set());
+
+    if let Some(Value::Array(edges)) = connection.get_mut("edges") {
+        for edge in edges {
+            if let Some(Value::String(cursor)) = edge.get_mut("cursor") {
+                *cursor = codec.seal(order, cursor)?;
+            }
+
+            if let (Some(node), Some(selection)) = (edge.get_mut("node"), node_selection) {
+                seal_row(ctx, codec, selection, node)?;
+            }
+        }
+    }
+
+    if let Some(Value::Object(page_info)) = connection.get_mut("pageInfo") {
+        for key in ["startCursor", "endCursor"] {
+            if let Some(Value::String(cursor)) = page_info.get_mut(key) {
+                *cursor = codec.seal(order, cursor)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Seals the cursors of the collections joined to a row.
+fn seal_row(
+    ctx: Context<'_>,
+    codec: &CursorCodec,
+    selection: SelectionSet<'_>,
+    value: &mut Value,
+) -> Result<(), SdkError> {
+    let Some(row) = value.as_object_mut() else {
+        return Ok(());
+    };
+
+    for field in selection.fields() {
+        let Some(relation) = ctx
+            .database_definition
+            .get_relation_id_for_client_field_id(field.definition_id())
+            .map(|id| ctx.database_definition.walk(id))
+        else {
+            continue;
+        };
+
+        let key = field
+            .alias()
+            .map(Cow::Borrowed)
+            .unwrap_or_else(|| relation.client_field_name().into());
+
+        let Some(nested) = row.get_mut(key.as_ref()) else {
+            continue;
+        };
+
+        if relation.is_other_side_one() {
+            seal_row(ctx, codec, field.selection_set(), nested)?;
+        } else {
+            let table = relation.referenced_table();
+
+            let params = field
+                .arguments::<CollectionParameters>(ctx.variables)
+                .ok()
+                .unwrap_or_default();
+
+            let args = CollectionArgs::new(ctx.database_definition, table, ctx.limits, None, params)?;
+
+            seal_connection(ctx, codec, field, &args.order_spec(), nested)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn find_field<'a>(ctx: Context<'_>, selection: SelectionSet<'a>, name: &str) -> Option<Field<'a>> {
+    selection
+        .fields()
+        .find(|f| ctx.database_definition.get_name_for_field_definition(f.definition_id()) == Some(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::{Engine, prelude::BASE64_URL_SAFE_NO_PAD};
+
+    use super::{CursorCodec, CursorError};
+
+    const SECRET: &str = "super-secret-cursor-key-for-the-tests";
+
+    #[test]
+    fn seal_and_open() {
+        let codec = CursorCodec::new(SECRET);
+
+        let first = codec.seal("id:asc", "WzFd").unwrap();
+        let second = codec.seal("id:asc", "WzFd").unwrap();
+
+        assert_ne!(first, second);
+        assert_eq!(codec.open("id:asc", &first).unwrap(), "WzFd");
+        assert_eq!(codec.open("id:asc", &second).unwrap(), "WzFd");
+
+        assert!(matches!(
+            codec.open("name:desc,id:asc", &first),
+            Err(CursorError::OrderMismatch { .. })
+        ));
+
+        assert!(matches!(
+            CursorCodec::new("another-secret-of-at-least-32-bytes").open("id:asc", &first),
+            Err(CursorError::InvalidSignature)
+        ));
+    }
+
+    #[test]
+    fn reject_modified_cursor() {
+        let codec = CursorCodec::new(SECRET);
+        let sealed = BASE64_URL_SAFE_NO_PAD
+            .decode(codec.seal("id:asc", "WzFd").unwrap())
+            .unwrap();
+
+        for index in 1..sealed.len() {
+            let mut modified = sealed.clone();
+            modified[index] ^= 1;
+
+            assert!(matches!(
+                codec.open("id:asc", &BASE64_URL_SAFE_NO_PAD.encode(modified)),
+                Err(CursorError::InvalidSignature)
+            ));
+        }
+
+        let mut truncated = sealed.clone();
+        truncated.truncate(20);
+
+        assert!(matches!(
+            codec.open("id:asc", &BASE64_URL_SAFE_NO_PAD.encode(truncated)),
+            Err(CursorError::Malformed)
+        ));
+
+        let mut other_version = sealed;
+        other_version[0] = 2;
+
+        assert!(matches!(
+            codec.open("id:asc", &BASE64_URL_SAFE_NO_PAD.encode(other_version)),
+            Err(CursorError::UnsupportedVersion(2))
+        ));
+    }
+}
@@ -0,0 +1,54 @@
+use std::cell::RefCell;
+
+use grafbase_sdk::{
+    SdkError,
+    host_io::logger::log,
+    types::{Data, Error, Response},
+};
+use serde_json::json;
+
+/// The message of the error carrying the debug information of a successful response.
+const DEBUG_MESSAGE: &str = "Postgres debug information";
+
+/// Debug information of one executed statement.
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatementInfo {
+    pub sql: String,
+    pub parameter_count: usize,
+    pub duration_ms: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plan: Option<serde_json::Value>,
+}
+
+/// Collects the statements executed when resolving a field, if the request asked for debug
+/// information.
+#[derive(Debug, Default)]
+pub struct StatementLog {
+    statements: RefCell<Vec<StatementInfo>>,
+}
+
+impl StatementLog {
+    pub fn push(&self, statement: StatementInfo) {
+        self.statements.borrow_mut().push(statement);
+    }
+
+    /// Logs the collected statements and adds them to the `postgres` extension of an error. A
+    /// successful response keeps its data, with the statements in an extra error, as the resolver
+    /// API has no response extensions.
+    pub fn into_response(self, result: Result<Data, SdkError>) -> Result<Response, SdkError> {
+        let statements = json!({ "statements": self.statements.into_inner() });
+
+        log::info!(postgres = statements.to_string(); "statement debug information");
+
+        let response = match result {
+            Ok(data) => Response {
+                data: Some(data),
+                errors: vec![Error::new(DEBUG_MESSAGE).extension("postgres", statements)?],
+            },
+            Err(error) => Error::from(error).extension("postgres", statements)?.into(),
+        };
+
+        Ok(response)
+    }
+}
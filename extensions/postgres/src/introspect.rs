@@ -2,6 +2,7 @@ mod enums;
 mod field_mapping;
 mod foreign_keys;
 mod keys;
+mod precomputed;
 mod schemas;
 mod tables;
 
@@ -14,6 +15,7 @@ use grafbase_sdk::types::SubgraphSchema;
 #[serde(rename_all = "camelCase")]
 pub struct PgDatabase {
     pub name: String,
+    pub definition: Option<String>,
 }
 
 #[derive(Debug, Clone, serde::Deserialize)]
@@ -33,6 +35,12 @@ pub struct PgColumn {
     pub enum_schema: Option<String>,
 }
 
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PgComputed {
+    pub sql: String,
+}
+
 #[derive(Debug, Clone, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PgEnum {
@@ -75,14 +83,26 @@ pub fn from_sdl(subgraph_schemas: Vec<SubgraphSchema>) -> HashMap<String, Databa
             continue;
         };
 
-        let mut database_definition = DatabaseDefinition::new(pg_database.name);
+        let precomputed = pg_database
+            .definition
+            .as_deref()
+            .and_then(|definition| precomputed::load(&schema, &pg_database.name, definition));
 
-        schemas::introspect_sdl(&schema, &mut database_definition);
-        enums::introspect_sdl(&schema, &mut database_definition);
-        tables::introspect_sdl(&schema, &mut database_definition);
-        foreign_keys::introspect_sdl(&schema, &mut database_definition);
-        keys::introspect_sdl(&schema, &mut database_definition);
-        field_mapping::introspect(&schema, &mut database_definition);
+        let database_definition = match precomputed {
+            Some(database_definition) => database_definition,
+            None => {
+                let mut database_definition = DatabaseDefinition::new(pg_database.name);
+
+                schemas::introspect_sdl(&schema, &mut database_definition);
+                enums::introspect_sdl(&schema, &mut database_definition);
+                tables::introspect_sdl(&schema, &mut database_definition);
+                foreign_keys::introspect_sdl(&schema, &mut database_definition);
+                keys::introspect_sdl(&schema, &mut database_definition);
+                field_mapping::introspect(&schema, &mut database_definition);
+
+                database_definition
+            }
+        };
 
         result.insert(schema.subgraph_name().to_string(), database_definition);
     }
@@ -0,0 +1,99 @@
+use base64::{Engine, prelude::BASE64_STANDARD};
+use grafbase_database_definition::DatabaseDefinition;
+use grafbase_sdk::{
+    host_io::logger::log,
+    types::{SubgraphSchema, TypeDefinition},
+};
+
+use super::field_mapping;
+
+/// Loads the definition serialized by the introspection into the `@pgDatabase` directive. Returns
+/// `None` if the definition cannot be loaded or a table field has no counterpart in it, in which
+/// case the definition is built from the SDL. Other edits of the SDL are not detected.
+pub(super) fn load(schema: &SubgraphSchema, database_name: &str, encoded: &str) -> Option<DatabaseDefinition> {
+    let subgraph_name = schema.subgraph_name();
+
+    let bytes = match BASE64_STANDARD.decode(encoded) {
+        Ok(bytes) => bytes,
+        Err(error) => {
+            log::warn!("Invalid precomputed definition in subgraph {subgraph_name}: {error}");
+            return None;
+        }
+    };
+
+    let mut database_definition = match DatabaseDefinition::from_bytes(&bytes) {
+        Ok(database_definition) => database_definition,
+        Err(error) => {
+            log::warn!("Cannot load the precomputed definition of subgraph {subgraph_name}, reading the SDL: {error}");
+            return None;
+        }
+    };
+
+    if database_definition.name() != database_name || !bind_fields(schema, &mut database_definition) {
+        log::warn!("The precomputed definition of subgraph {subgraph_name} does not match the schema, reading the SDL");
+        return None;
+    }
+
+    field_mapping::introspect(schema, &mut database_definition);
+
+    Some(database_definition)
+}
+
+/// The ids of the field definitions are assigned by the gateway, so they are bound to the
+/// columns, computed fields and relations by name. Only the directive names are read. Returns
+/// false if a field of a table has no counterpart in the definition.
+fn bind_fields(schema: &SubgraphSchema, database_definition: &mut DatabaseDefinition) -> bool {
+    for r#type in schema.type_definitions() {
+        let TypeDefinition::Object(object) = r#type else {
+            continue;
+        };
+
+        if !object.directives().any(|directive| directive.name() == "pgTable") {
+            continue;
+        }
+
+        let Some(table_id) = database_definition
+            .find_table_for_client_type(object.name())
+            .map(|t| t.id())
+        else {
+            return false;
+        };
+
+        for field in object.fields() {
+            for directive in field.directives() {
+                match directive.name() {
+                    "pgColumn" => {
+                        let Some(column_id) = database_definition.get_table_column_id_for_field(table_id, field.name())
+                        else {
+                            return false;
+                        };
+
+                        database_definition.push_column_to_definition(field.id(), column_id);
+                    }
+                    "pgComputed" => {
+                        let Some(computed_field_id) =
+                            database_definition.get_computed_field_id_for_field(table_id, field.name())
+                        else {
+                            return false;
+                        };
+
+                        database_definition.push_computed_field_to_definition(field.id(), computed_field_id);
+                    }
+                    "pgRelation" => {
+                        let Some(relation_id) = database_definition
+                            .get_relation_for_client_name(table_id, field.name())
+                            .map(|relation| relation.id())
+                        else {
+                            return false;
+                        };
+
+                        database_definition.push_client_id_relation_mapping(field.id(), relation_id);
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    true
+}
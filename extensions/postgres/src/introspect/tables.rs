@@ -1,9 +1,9 @@
 use grafbase_database_definition::{
-    ColumnType, DatabaseDefinition, EnumType, ScalarKind, ScalarType, SchemaId, Table, TableColumn,
+    ColumnType, ComputedField, DatabaseDefinition, EnumType, ScalarKind, ScalarType, SchemaId, Table, TableColumn,
 };
 use grafbase_sdk::types::{FieldDefinition, SubgraphSchema, TypeDefinition};
 
-use super::{PgColumn, PgTable};
+use super::{PgColumn, PgComputed, PgTable};
 
 pub(crate) fn introspect_sdl(schema: &SubgraphSchema, database_definition: &mut DatabaseDefinition) {
     for r#type in schema.type_definitions() {
@@ -33,6 +33,17 @@ pub(crate) fn introspect_sdl(schema: &SubgraphSchema, database_definition: &mut
         let table_id = database_definition.push_table(table);
 
         for field in definition.fields() {
+            if let Some(pg_computed) = field
+                .directives()
+                .find(|directive| directive.name() == "pgComputed")
+                .and_then(|d| d.arguments::<PgComputed>().ok())
+            {
+                let computed_field = ComputedField::new(table_id, field.name().to_string(), pg_computed.sql);
+                database_definition.push_computed_field(computed_field, Some(field.id()));
+
+                continue;
+            }
+
             let Some(pg_column) = field
                 .directives()
                 .find(|directive| directive.name() == "pgColumn")
@@ -3,10 +3,12 @@ mod create_many;
 mod create_one;
 mod delete_many;
 mod delete_one;
+mod dry_run;
 mod find_many;
 mod find_one;
 mod lookup;
 mod query;
+mod statement;
 mod update_many;
 mod update_one;
 
@@ -14,7 +16,7 @@ use grafbase_database_definition::Operation;
 use grafbase_sdk::{SdkError, types::Data};
 
 pub(super) fn execute(ctx: super::Context<'_>) -> Result<Data, SdkError> {
-    match ctx.operation() {
+    let result = match ctx.operation() {
         Operation::FindOne(table_id) => find_one::execute(ctx, table_id),
         Operation::FindMany(table_id) => find_many::execute(ctx, table_id),
         Operation::Lookup(table_id) => lookup::execute(ctx, table_id),
@@ -24,5 +26,16 @@ pub(super) fn execute(ctx: super::Context<'_>) -> Result<Data, SdkError> {
         Operation::CreateMany(table_id) => create_many::execute(ctx, table_id),
         Operation::UpdateOne(table_id) => update_one::execute(ctx, table_id),
         Operation::UpdateMany(table_id) => update_many::execute(ctx, table_id),
+    };
+
+    let operation = ctx.operation();
+
+    if let Some(cache) = ctx.cache
+        && operation.is_mutation()
+        && result.is_ok()
+    {
+        cache.invalidate(ctx.database_definition.walk(operation.table_id()));
     }
+
+    result
 }
@@ -1,22 +1,19 @@
 use grafbase_database_definition::TableId;
-use grafbase_sdk::{SdkError, host_io::logger::log, types::Data};
-use sql_ast::renderer;
+use grafbase_sdk::{SdkError, types::Data};
 
 use crate::context::Context;
 
-use super::query;
+use super::{query, statement::Statement};
 
 pub(crate) fn execute(ctx: Context<'_>, table_id: TableId) -> Result<Data, SdkError> {
     let table = ctx.database_definition.walk(table_id);
     let ast = query::insert::build(&ctx, table, ctx.create_many_input(table)?)?;
-    let query = renderer::postgres::render(ast);
-
-    log::debug!(query = query.to_string(); "executing query");
+    let statement = Statement::new(ctx, ast);
 
     let connection = ctx.pool.acquire()?;
 
     if ctx.mutation_is_returning() {
-        let rows = query.fetch(&connection)?;
+        let rows = statement.fetch(&connection)?;
         let mut result = Vec::with_capacity(rows.size_hint().0);
 
         for mut row in rows {
@@ -35,7 +32,7 @@ pub(crate) fn execute(ctx: Context<'_>, table_id: TableId) -> Result<Data, SdkEr
 
         Ok(Data::Json(data))
     } else {
-        let row_count = query.execute(&connection)?;
+        let row_count = statement.execute(&connection)?;
 
         let data = serde_json::to_vec(&serde_json::json!({
             "rowCount": row_count,
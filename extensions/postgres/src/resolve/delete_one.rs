@@ -1,22 +1,17 @@
-use super::query;
+use super::{dry_run::MutationConnection, query, statement::Statement};
 use crate::context::Context;
 use grafbase_database_definition::TableId;
-use grafbase_sdk::{SdkError, host_io::logger::log, types::Data};
-use sql_ast::renderer;
+use grafbase_sdk::{SdkError, types::Data};
 
 pub(crate) fn execute(ctx: Context<'_>, table_id: TableId) -> Result<Data, SdkError> {
     let table = ctx.database_definition.walk(table_id);
     let ast = query::delete::build(&ctx, ctx.unique_filter(table)?, table)?;
-    let query = renderer::postgres::render(ast);
+    let statement = Statement::new(ctx, ast);
 
-    log::debug!(query = query.to_string(); "executing query");
-
-    let connection = ctx.pool.acquire()?;
+    let connection = MutationConnection::acquire(ctx, table)?;
 
     if ctx.mutation_is_returning() {
-        let mut rows = query.fetch(&connection)?;
-
-        let result = match rows.next() {
+        let result = match statement.fetch(connection.as_connection())?.next() {
             Some(mut row) => match row.next() {
                 Some(Ok(col)) => col.as_json()?.unwrap_or(serde_json::Value::Null),
                 Some(Err(err)) => return Err(SdkError::from(format!("query error: {err}"))),
@@ -32,14 +27,14 @@ pub(crate) fn execute(ctx: Context<'_>, table_id: TableId) -> Result<Data, SdkEr
             "rowCount": row_count,
         });
 
-        Ok(Data::Json(serde_json::to_vec(&data).unwrap()))
+        connection.finish(data)
     } else {
-        let row_count = query.execute(&connection)?;
+        let row_count = statement.execute(connection.as_connection())?;
 
         let data = serde_json::json!({
             "rowCount": row_count,
         });
 
-        Ok(Data::Json(serde_json::to_vec(&data).unwrap()))
+        connection.finish(data)
     }
 }
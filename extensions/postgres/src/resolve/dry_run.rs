@@ -0,0 +1,207 @@
+use std::fmt::Write;
+
+use grafbase_database_definition::{Operation, TableWalker};
+use grafbase_sdk::{
+    SdkError,
+    host_io::postgres::{Connection, ConnectionLike, Query, Transaction},
+    types::Data,
+};
+use serde_json::{Value, json};
+
+use crate::context::Context;
+
+/// The connection an update or delete runs on. A dry run executes the statement in a
+/// transaction, which is rolled back after counting the rows changed in the mutated table and
+/// in the tables referencing it through foreign keys, which can be changed by the referential
+/// actions of the keys.
+///
+/// The changes are counted from the statistics of the transaction, summed over the partitions
+/// of partitioned tables. Foreign tables have no statistics, but they cannot have foreign keys
+/// either, so only the mutated table can be one. Its count is at least the row count of the
+/// statement.
+pub(super) enum MutationConnection<'a> {
+    Connection(Connection),
+    DryRun {
+        transaction: Transaction,
+        tables: Vec<TableWalker<'a>>,
+        deletes: bool,
+        before: Vec<TableChanges>,
+    },
+}
+
+/// The rows changed in one table and its partitions by the current transaction.
+#[derive(Debug, Default, Clone, Copy, serde::Deserialize)]
+pub(super) struct TableChanges {
+    updated: i64,
+    deleted: i64,
+}
+
+impl<'a> MutationConnection<'a> {
+    /// Acquires a connection, or begins a transaction if the mutation is a dry run.
+    pub fn acquire(ctx: Context<'a>, table: TableWalker<'a>) -> Result<Self, SdkError> {
+        if !ctx.is_dry_run()? {
+            return ctx.pool.acquire().map(MutationConnection::Connection);
+        }
+
+        let transaction = ctx.pool.begin_transaction()?;
+        let tables = referencing_tables(table);
+
+        // Without the statistics, the changes made by referential actions cannot be counted.
+        if table.relations().any(|relation| relation.is_referenced_side()) && !track_counts(&transaction)? {
+            return Err(SdkError::from(
+                "Dry runs of mutations on tables referenced by foreign keys need the track_counts setting of the database to be on",
+            ));
+        }
+
+        let deletes = matches!(ctx.operation(), Operation::DeleteOne(_) | Operation::DeleteMany(_));
+        let before = fetch_changes(&transaction, &tables)?;
+
+        Ok(MutationConnection::DryRun {
+            transaction,
+            tables,
+            deletes,
+            before,
+        })
+    }
+
+    pub fn as_connection(&self) -> ConnectionLike<'_> {
+        match self {
+            MutationConnection::Connection(connection) => connection.into(),
+            MutationConnection::DryRun { transaction, .. } => transaction.into(),
+        }
+    }
+
+    /// Finishes the mutation with the given payload. A dry run is rolled back, adding the
+    /// changed rows per table to the payload.
+    pub fn finish(self, mut payload: Value) -> Result<Data, SdkError> {
+        if let MutationConnection::DryRun {
+            transaction,
+            tables,
+            deletes,
+            before,
+        } = self
+        {
+            let after = fetch_changes(&transaction, &tables)?;
+            transaction.rollback()?;
+
+            let row_count = payload["rowCount"].as_i64().unwrap_or_default();
+            let mut affected_tables = Vec::new();
+
+            for (index, table) in tables.into_iter().enumerate() {
+                let before = before.get(index).copied().unwrap_or_default();
+                let after = after.get(index).copied().unwrap_or_default();
+
+                let mut updated = after.updated - before.updated;
+                let mut deleted = after.deleted - before.deleted;
+
+                // Updates delete no rows. An updated row moving to another partition is counted as
+                // deleted from its old partition.
+                if !deletes {
+                    updated += deleted;
+                    deleted = 0;
+                }
+
+                if index == 0 && deletes {
+                    deleted = deleted.max(row_count);
+                } else if index == 0 {
+                    updated = updated.max(row_count);
+                }
+
+                if updated > 0 || deleted > 0 {
+                    affected_tables.push(json!({
+                        "table": table.client_name(),
+                        "updated": updated,
+                        "deleted": deleted,
+                    }));
+                }
+            }
+
+            payload["affectedTables"] = Value::Array(affected_tables);
+        }
+
+        let data = serde_json::to_vec(&payload)
+            .map_err(|error| SdkError::from(format!("invalid mutation result: {error}")))?;
+
+        Ok(Data::Json(data))
+    }
+}
+
+/// The table and all tables referencing it through foreign keys, directly or through other
+/// referencing tables.
+fn referencing_tables(table: TableWalker<'_>) -> Vec<TableWalker<'_>> {
+    let mut tables = vec![table];
+    let mut index = 0;
+
+    while let Some(current) = tables.get(index).copied() {
+        index += 1;
+
+        for relation in current.relations().filter(|relation| relation.is_referenced_side()) {
+            let referencing = relation.referenced_table();
+
+            if !tables.iter().any(|table| table.id() == referencing.id()) {
+                tables.push(referencing);
+            }
+        }
+    }
+
+    tables
+}
+
+/// Reads the number of rows the transaction updated and deleted so far in the given tables, in
+/// the same order. The counts of a table include all of its partitions. The statistics can
+/// include changes from earlier statements of the transaction, so the changes of a statement are
+/// the difference of the values before and after it.
+fn fetch_changes(transaction: &Transaction, tables: &[TableWalker<'_>]) -> Result<Vec<TableChanges>, SdkError> {
+    let tables: Vec<Value> = tables
+        .iter()
+        .enumerate()
+        .map(|(position, table)| {
+            json!({
+                "position": position,
+                "schema": table.schema(),
+                "name": table.database_name(),
+            })
+        })
+        .collect();
+
+    let mut query = Query::builder();
+
+    write!(
+        query,
+        "SELECT coalesce(json_agg(json_build_object('updated', counts.updated, 'deleted', counts.deleted) \
+         ORDER BY t.position), '[]') \
+         FROM json_to_recordset($1::json) AS t(position int, schema text, name text) \
+         CROSS JOIN LATERAL ( \
+         SELECT coalesce(sum(s.n_tup_upd), 0)::int8 AS updated, coalesce(sum(s.n_tup_del), 0)::int8 AS deleted \
+         FROM pg_partition_tree(to_regclass(format('%I.%I', t.schema, t.name))) AS tree \
+         JOIN pg_stat_xact_user_tables AS s ON s.relid = tree.relid \
+         ) AS counts"
+    )
+    .unwrap();
+
+    query.bind(Value::Array(tables));
+
+    let Some(mut row) = query.finalize().fetch(transaction)?.next() else {
+        return Ok(Vec::new());
+    };
+
+    match row.next() {
+        Some(column) => Ok(column?.as_json()?.unwrap_or_default()),
+        None => Ok(Vec::new()),
+    }
+}
+
+/// Whether the database collects the statistics of the table changes.
+fn track_counts(transaction: &Transaction) -> Result<bool, SdkError> {
+    let mut query = Query::builder();
+    write!(query, "SELECT to_json(current_setting('track_counts')::bool)").unwrap();
+
+    let Some(mut row) = query.finalize().fetch(transaction)?.next() else {
+        return Ok(false);
+    };
+
+    match row.next() {
+        Some(column) => Ok(column?.as_json()?.unwrap_or_default()),
+        None => Ok(false),
+    }
+}
@@ -1,13 +1,15 @@
 use grafbase_database_definition::TableId;
-use grafbase_sdk::{SdkError, host_io::logger::log, types::Data};
-use sql_ast::renderer;
-
-use crate::context::{
-    Context,
-    selection_iterator::collection_args::{CollectionArgs, CollectionParameters},
+use grafbase_sdk::{SdkError, types::Data};
+
+use crate::{
+    context::{
+        Context,
+        selection_iterator::collection_args::{CollectionArgs, CollectionParameters},
+    },
+    cursor::{self, ResultShape},
 };
 
-use super::{builder::SelectBuilder, query};
+use super::{builder::SelectBuilder, query, statement::Statement};
 
 fn empty() -> Data {
     Data::Json(serde_json::to_vec(&serde_json::Value::Array(Vec::new())).unwrap())
@@ -18,32 +20,27 @@ pub(crate) fn execute(ctx: Context<'_>, table_id: TableId) -> Result<Data, SdkEr
     let mut builder = SelectBuilder::new(table, ctx.collection_selection(table)?, "node");
     let collection_params = ctx.field.arguments::<CollectionParameters>(ctx.variables)?;
 
-    let args = CollectionArgs::new(ctx.database_definition, table, collection_params)?;
+    let args = CollectionArgs::new(
+        ctx.database_definition,
+        table,
+        ctx.limits,
+        ctx.cursors,
+        collection_params,
+    )?;
 
     if let Ok(filter) = ctx.filter(table) {
         builder.set_filter(filter);
     }
 
-    let ast = query::select::pagination::build(builder, args)?;
-    let query = renderer::postgres::render(ast);
-
-    log::debug!(query = query.to_string(); "executing query");
+    let order = args.order_spec();
 
-    let connection = ctx.pool.acquire()?;
-    let mut rows = query.fetch(&connection)?;
-
-    let mut row = match rows.next() {
-        Some(row) => row,
-        None => return Ok(empty()),
-    };
+    let ast = query::select::pagination::build(builder, args)?;
+    let statement = Statement::new(ctx, ast);
 
-    let col = match row.next() {
-        Some(Ok(col)) => col,
-        Some(Err(e)) => return Err(SdkError::from(format!("query error: {e}"))),
-        None => return Ok(empty()),
-    };
+    let data = statement.fetch_json(table)?;
+    let data = cursor::seal_result(ctx, ResultShape::Connection(&order), data)?;
 
-    let data = col.into_bytes().map(Data::Json).unwrap_or_else(empty);
+    let data = data.map(Data::Json).unwrap_or_else(empty);
 
     Ok(data)
 }
@@ -1,10 +1,10 @@
 use grafbase_database_definition::TableId;
-use grafbase_sdk::{SdkError, host_io::logger::log, types::Data};
-use sql_ast::renderer;
+use grafbase_sdk::{SdkError, types::Data};
 
 use crate::{
     context::Context,
-    resolve::{builder::SelectBuilder, query},
+    cursor::{self, ResultShape},
+    resolve::{builder::SelectBuilder, query, statement::Statement},
 };
 
 fn null() -> Data {
@@ -21,25 +21,12 @@ pub(crate) fn execute(ctx: Context<'_>, table_id: TableId) -> Result<Data, SdkEr
     }
 
     let ast = query::select::unique::build(builder)?;
-    let query = renderer::postgres::render(ast);
+    let statement = Statement::new(ctx, ast);
 
-    log::debug!(query = query.to_string(); "executing query");
+    let data = statement.fetch_json(table)?;
+    let data = cursor::seal_result(ctx, ResultShape::Row, data)?;
 
-    let connection = ctx.pool.acquire()?;
-    let mut rows = query.fetch(&connection)?;
-
-    let mut row = match rows.next() {
-        Some(row) => row,
-        None => return Ok(null()),
-    };
-
-    let col = match row.next() {
-        Some(Ok(col)) => col,
-        Some(Err(e)) => return Err(SdkError::from(format!("query error: {e}"))),
-        None => return Ok(null()),
-    };
-
-    let data = col.into_bytes().map(Data::Json).unwrap_or_else(null);
+    let data = data.map(Data::Json).unwrap_or_else(null);
 
     Ok(data)
 }
@@ -1,7 +1,9 @@
-use grafbase_sdk::{SdkError, host_io::logger::log, types::Data};
-use sql_ast::renderer;
+use grafbase_sdk::{SdkError, types::Data};
 
-use crate::resolve::{builder::SelectBuilder, query};
+use crate::{
+    cursor::{self, ResultShape},
+    resolve::{builder::SelectBuilder, query, statement::Statement},
+};
 
 fn empty() -> Data {
     Data::Json(serde_json::to_vec(&serde_json::Value::Array(Vec::new())).unwrap())
@@ -19,25 +21,12 @@ pub(crate) fn execute(
     }
 
     let ast = query::lookup::build(builder)?;
-    let query = renderer::postgres::render(ast);
+    let statement = Statement::new(ctx, ast);
 
-    log::debug!(query = query.to_string(); "executing query");
+    let data = statement.fetch_json(table)?;
+    let data = cursor::seal_result(ctx, ResultShape::Rows, data)?;
 
-    let connection = ctx.pool.acquire()?;
-    let mut rows = query.fetch(&connection)?;
-
-    let mut row = match rows.next() {
-        Some(row) => row,
-        None => return Ok(empty()),
-    };
-
-    let col = match row.next() {
-        Some(Ok(col)) => col,
-        Some(Err(e)) => return Err(SdkError::from(format!("query error: {e}"))),
-        None => return Ok(empty()),
-    };
-
-    let data = col.into_bytes().map(Data::Json).unwrap_or_else(empty);
+    let data = data.map(Data::Json).unwrap_or_else(empty);
 
     Ok(data)
 }
@@ -43,7 +43,10 @@ pub fn build<'a>(
 
                     returning.push((alias, Expression::from(nested)));
                 }
-                // our output type doesn't have relations, so this is never reachable
+                // our output type doesn't have computed fields or relations, so these are never reachable
+                TableSelection::Computed(..) => {
+                    unreachable!("computed fields are not part of the returning type")
+                }
                 TableSelection::JoinMany(..) | TableSelection::JoinUnique(..) => {
                     unreachable!("we cannot join in a delete statement")
                 }
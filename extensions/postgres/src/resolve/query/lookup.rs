@@ -1,7 +1,10 @@
 use std::borrow::Cow;
 
 use grafbase_database_definition::{RelationWalker, TableColumnWalker};
-use grafbase_sdk::{SdkError, host_io::postgres::types::DatabaseValue};
+use grafbase_sdk::{
+    SdkError,
+    host_io::{logger::log, postgres::types::DatabaseValue},
+};
 use indexmap::{IndexMap, map::Entry};
 use serde_json::Value;
 use sql_ast::ast::{
@@ -12,6 +15,7 @@ use sql_ast::ast::{
 use crate::{
     context::{
         order::OrderKind,
+        rename_enum_variants,
         selection_iterator::{SelectionIterator, TableSelection, collection_args::CollectionArgs},
     },
     resolve::builder::SelectBuilder,
@@ -31,45 +35,60 @@ pub fn build(builder: SelectBuilder<'_>) -> Result<Select<'_>, SdkError> {
     let mut input_order_alias = Alias::new("input_order");
     let mut column_values: IndexMap<TableColumnWalker<'_>, Vec<Value>> = IndexMap::new();
     let mut lookup_columns = Vec::new();
+    let mut batch_size = 0;
 
     for order in lookup_order {
-        match order {
-            OrderKind::Single(column, value) => match column_values.entry(column) {
+        let items = match order {
+            OrderKind::Single(column, value) => vec![(column, value)],
+            OrderKind::Composite(items) => items,
+        };
+
+        // Every key is a row in the unnested arrays, so all keys must define the same columns.
+        if batch_size > 0
+            && (items.len() != lookup_columns.len()
+                || items.iter().any(|(column, _)| !column_values.contains_key(column)))
+        {
+            return Err(SdkError::from("All lookup keys must have the same fields"));
+        }
+
+        for (column, value) in items {
+            match column_values.entry(column) {
                 Entry::Occupied(mut occupied_entry) => occupied_entry.get_mut().push(value),
                 Entry::Vacant(vacant_entry) => {
                     lookup_columns.push(column);
                     input_order_alias.push_column(column.database_name());
                     vacant_entry.insert(vec![value]);
                 }
-            },
-            OrderKind::Composite(items) => {
-                for (column, value) in items {
-                    match column_values.entry(column) {
-                        Entry::Occupied(mut occupied_entry) => occupied_entry.get_mut().push(value),
-                        Entry::Vacant(vacant_entry) => {
-                            lookup_columns.push(column);
-                            input_order_alias.push_column(column.database_name());
-                            vacant_entry.insert(vec![value]);
-                        }
-                    }
-                }
             }
         }
+
+        batch_size += 1;
     }
 
+    log::debug!(
+        table = builder.table().client_name(),
+        batch_size = batch_size,
+        key_columns = lookup_columns.len();
+        "lookup batch"
+    );
+
     input_order_alias.push_column("ord");
 
+    // One typed array per key column, unnested in parallel with the ordinality of the input.
     let mut unnest_values = Vec::new();
 
     for (column, values) in column_values {
-        unnest_values.push(DatabaseValue::from_json_input(
-            Value::Array(values),
-            column.database_type(),
-            true,
-        )?);
+        let value = DatabaseValue::from_json_input(Value::Array(values), column.database_type(), true)?;
+        let value = rename_enum_variants(column, value)?;
+        let enum_type = column
+            .database_type()
+            .enum_database_name()
+            .map(|name| Cow::Owned(format!("{name}[]")));
+
+        unnest_values.push((value, enum_type));
     }
 
-    let unnest = unnest(Expression::many_value(unnest_values), true).alias(input_order_alias);
+    let unnest = unnest(Expression::many_typed_value(unnest_values), true).alias(input_order_alias);
 
     let main_table_ref =
         Table::from((builder.table().schema(), builder.table().database_name())).alias(builder.table().client_name());
@@ -100,6 +119,12 @@ pub fn build(builder: SelectBuilder<'_>) -> Result<Select<'_>, SdkError> {
                 let col_expr = Column::from((builder.table().client_name(), column.database_name()));
                 columns_for_json.push((column.client_name().to_string(), col_expr.into()));
             }
+            // SQL expressions over the main table row (e.g., User.fullName)
+            TableSelection::Computed(select) => {
+                let (field, expr, alias) = select.into_expression(builder.table().client_name().into());
+                let alias = alias.unwrap_or_else(|| field.client_name());
+                columns_for_json.push((alias.to_string(), expr));
+            }
             // Nested object/array fetched via LATERAL JOIN (e.g., blogs, author)
             TableSelection::JoinUnique(relation, nested_selection, alias) => {
                 inject_relation(
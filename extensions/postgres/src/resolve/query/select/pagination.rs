@@ -132,7 +132,7 @@ fn build_filtered_cte<'a>(builder: &SelectBuilder<'a>, args: &CollectionArgs<'a>
 
     let mut cursor_payload_expressions: Vec<Expression<'_>> = Vec::new();
 
-    attach_selection(builder, args, &mut select)?;
+    attach_selection(builder, args, true, &mut select)?;
     attach_cursor_filters(args, &mut select);
 
     if let Some(relation) = builder.relation() {
@@ -194,7 +194,7 @@ fn build_nodes_cte<'a>(builder: &SelectBuilder<'a>, args: &CollectionArgs<'a>) -
     let table = Table::from(FILTERED).alias(builder.table().client_name());
     let mut select = Select::from_table(table);
 
-    attach_selection(builder, args, &mut select)?;
+    attach_selection(builder, args, false, &mut select)?;
 
     if builder.selects_cursor() {
         select.column(Column::from((builder.table().client_name(), "cursor")).alias("cursor"));
@@ -456,6 +456,14 @@ fn build_edges_select<'a>(builder: &SelectBuilder<'a>, args: &CollectionArgs<'a>
                 selected_columns.insert(column.id());
                 collecting_select.value(Expression::from(nested).alias(alias));
             }
+            TableSelection::Computed(select) => {
+                let (field, _, alias) = select.into_expression(builder.table().client_name().into());
+                let alias = alias.unwrap_or_else(|| field.client_name());
+
+                // The expression is evaluated in the filtered CTE, see `attach_selection`.
+                let column = Column::from((builder.table().client_name(), field.client_name()));
+                collecting_select.value(Expression::from(column).alias(alias));
+            }
             // m:1, 1:1
             TableSelection::JoinUnique(relation, selection, alias) => {
                 let client_field_name = alias
@@ -531,12 +539,17 @@ fn build_edges_select<'a>(builder: &SelectBuilder<'a>, args: &CollectionArgs<'a>
 ///
 /// This function processes the selection items from the builder and adds them to the select statement.
 /// It tracks already selected columns in a HashSet to avoid duplicates.
+///
+/// Computed fields are evaluated if `from_table` is set, meaning the select has the table with all of its
+/// columns in scope. Otherwise they are selected as columns of the previously evaluated select.
 fn attach_selection<'a>(
     builder: &SelectBuilder<'a>,
     args: &CollectionArgs<'a>,
+    from_table: bool,
     select: &mut Select<'a>,
 ) -> Result<(), SdkError> {
     let mut selected_columns = HashSet::new();
+    let mut selected_computed_fields = HashSet::new();
 
     for selection_item in builder.selection() {
         match selection_item? {
@@ -547,6 +560,19 @@ fn attach_selection<'a>(
                     select.value(expr.alias(column_meta.database_name()));
                 }
             }
+            TableSelection::Computed(computed_select) => {
+                let (field, expr, _) = computed_select.into_expression(builder.table().client_name().into());
+
+                if !selected_computed_fields.insert(field.id()) {
+                    continue;
+                }
+
+                if from_table {
+                    select.value(expr.alias(field.client_name()));
+                } else {
+                    select.column(Column::from((builder.table().client_name(), field.client_name())));
+                }
+            }
             TableSelection::JoinUnique(relation, _, _) => {
                 for column in relation.referencing_columns() {
                     if selected_columns.insert(column.id()) {
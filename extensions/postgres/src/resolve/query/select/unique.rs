@@ -51,6 +51,12 @@ pub fn build(builder: SelectBuilder<'_>) -> Result<Select<'_>, SdkError> {
 
                 collecting_select.value(Expression::from(nested).alias(alias));
             }
+            TableSelection::Computed(select) => {
+                let (field, expr, alias) = select.into_expression(builder.table().client_name().into());
+                let alias = alias.unwrap_or_else(|| field.client_name());
+
+                collecting_select.value(expr.alias(alias));
+            }
             // m:1, 1:1
             TableSelection::JoinUnique(relation, selection, alias) => {
                 let client_field_name = alias
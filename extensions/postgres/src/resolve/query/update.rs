@@ -61,6 +61,10 @@ pub fn build<'a>(
                         join_data
                     });
                 }
+                // the returning type has no computed fields
+                TableSelection::Computed(..) => {
+                    unreachable!("computed fields are not part of the returning type")
+                }
                 // we will not have relations in the first phase
                 TableSelection::JoinUnique(..) | TableSelection::JoinMany(..) => {
                     todo!("we'll get back to this with nested updates")
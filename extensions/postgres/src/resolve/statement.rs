@@ -0,0 +1,146 @@
+use std::time::{Duration, Instant};
+
+use grafbase_database_definition::TableWalker;
+use grafbase_sdk::{
+    SdkError,
+    host_io::{
+        logger::log,
+        postgres::{ColumnIterator, ConnectionLike, Query, types::DatabaseValue},
+    },
+};
+use sql_ast::{ast, renderer};
+
+use crate::{context::Context, debug::StatementInfo};
+
+/// A rendered statement, ready to be executed. Measures the execution, logging slow statements
+/// and collecting debug information if the request asked for it.
+pub(super) struct Statement<'a> {
+    ctx: Context<'a>,
+    query: Query,
+    parameters: Vec<DatabaseValue>,
+    explain: Option<Query>,
+}
+
+impl<'a> Statement<'a> {
+    pub fn new(ctx: Context<'a>, ast: impl Into<ast::Query<'a>>) -> Self {
+        let ast = ast.into();
+
+        let explain = match ctx.statement_log {
+            Some(_) if ctx.debug.explain || ctx.debug.explain_analyze => {
+                let analyze = ctx.debug.explain_analyze && !ctx.operation().is_mutation();
+                Some(renderer::postgres::render_explain(ast.clone(), analyze))
+            }
+            _ => None,
+        };
+
+        let (query, parameters) = renderer::postgres::render_with_parameters(ast);
+
+        log::debug!(query = query.to_string(); "executing query");
+
+        Self {
+            ctx,
+            query,
+            parameters,
+            explain,
+        }
+    }
+
+    /// Executes the statement, returning the rows.
+    pub fn fetch<'c>(
+        self,
+        connection: impl Into<ConnectionLike<'c>>,
+    ) -> Result<impl Iterator<Item = ColumnIterator>, SdkError> {
+        let connection = connection.into();
+        let start = Instant::now();
+        let result = self.query.clone().fetch(connection);
+
+        self.finish(connection, start.elapsed());
+
+        result
+    }
+
+    /// Executes a statement returning its result as JSON in the first column of the first row.
+    /// The result is served from the query cache if the table is cached, unless the request
+    /// collects debug information about the executed statements.
+    pub fn fetch_json(self, table: TableWalker<'_>) -> Result<Option<Vec<u8>>, SdkError> {
+        let cache = match self.ctx.cache {
+            Some(cache) if self.ctx.statement_log.is_none() => cache,
+            _ => return self.fetch_json_uncached(),
+        };
+
+        let query = self.query.clone();
+        let parameters = self.parameters.clone();
+
+        cache.get_or_fetch(table, &query, &parameters, self.ctx.claims, || {
+            self.fetch_json_uncached()
+        })
+    }
+
+    fn fetch_json_uncached(self) -> Result<Option<Vec<u8>>, SdkError> {
+        let connection = self.ctx.pool.acquire()?;
+        let mut rows = self.fetch(&connection)?;
+
+        let Some(mut row) = rows.next() else {
+            return Ok(None);
+        };
+
+        match row.next() {
+            Some(Ok(column)) => Ok(column.into_bytes()),
+            Some(Err(e)) => Err(SdkError::from(format!("query error: {e}"))),
+            None => Ok(None),
+        }
+    }
+
+    /// Executes the statement, returning the number of affected rows.
+    pub fn execute<'c>(self, connection: impl Into<ConnectionLike<'c>>) -> Result<u64, SdkError> {
+        let connection = connection.into();
+        let start = Instant::now();
+        let result = self.query.clone().execute(connection);
+
+        self.finish(connection, start.elapsed());
+
+        result
+    }
+
+    fn finish(self, connection: ConnectionLike<'_>, duration: Duration) {
+        let duration_ms = duration.as_secs_f64() * 1000.0;
+
+        if self.ctx.debug.is_slow(duration) {
+            log::warn!(
+                query = self.query.to_string(),
+                duration_ms = duration_ms;
+                "slow statement"
+            );
+        }
+
+        let Some(statement_log) = self.ctx.statement_log else {
+            return;
+        };
+
+        let plan = self.explain.and_then(|explain| match fetch_plan(explain, connection) {
+            Ok(plan) => plan,
+            Err(error) => {
+                log::warn!("failed to explain the statement: {error}");
+                None
+            }
+        });
+
+        statement_log.push(StatementInfo {
+            sql: self.query.to_string(),
+            parameter_count: self.parameters.len(),
+            duration_ms,
+            plan,
+        });
+    }
+}
+
+fn fetch_plan(explain: Query, connection: ConnectionLike<'_>) -> Result<Option<serde_json::Value>, SdkError> {
+    let Some(mut row) = explain.fetch(connection)?.next() else {
+        return Ok(None);
+    };
+
+    match row.next() {
+        Some(column) => column?.as_json(),
+        None => Ok(None),
+    }
+}
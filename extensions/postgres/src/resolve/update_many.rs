@@ -1,22 +1,19 @@
 use grafbase_database_definition::TableId;
-use grafbase_sdk::{SdkError, host_io::logger::log, types::Data};
-use sql_ast::renderer;
+use grafbase_sdk::{SdkError, types::Data};
 
 use crate::context::Context;
 
-use super::query;
+use super::{dry_run::MutationConnection, query, statement::Statement};
 
 pub(crate) fn execute(ctx: Context<'_>, table_id: TableId) -> Result<Data, SdkError> {
     let table = ctx.database_definition.walk(table_id);
     let ast = query::update::build(&ctx, table, ctx.filter(table)?)?;
-    let query = renderer::postgres::render(ast);
+    let statement = Statement::new(ctx, ast);
 
-    log::debug!(query = query.to_string(); "executing query");
-
-    let connection = ctx.pool.acquire()?;
+    let connection = MutationConnection::acquire(ctx, table)?;
 
     if ctx.mutation_is_returning() {
-        let rows = query.fetch(&connection)?;
+        let rows = statement.fetch(connection.as_connection())?;
         let mut result = Vec::with_capacity(rows.size_hint().0);
 
         for mut row in rows {
@@ -32,14 +29,14 @@ pub(crate) fn execute(ctx: Context<'_>, table_id: TableId) -> Result<Data, SdkEr
             "rowCount": row_count,
         });
 
-        Ok(Data::Json(serde_json::to_vec(&data).unwrap()))
+        connection.finish(data)
     } else {
-        let row_count = query.execute(&connection)?;
+        let row_count = statement.execute(connection.as_connection())?;
 
         let data = serde_json::json!({
             "rowCount": row_count,
         });
 
-        Ok(Data::Json(serde_json::to_vec(&data).unwrap()))
+        connection.finish(data)
     }
 }
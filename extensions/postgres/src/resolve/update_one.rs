@@ -1,24 +1,19 @@
 use grafbase_database_definition::TableId;
-use grafbase_sdk::{SdkError, host_io::logger::log, types::Data};
-use sql_ast::renderer;
+use grafbase_sdk::{SdkError, types::Data};
 
 use crate::context::Context;
 
-use super::query;
+use super::{dry_run::MutationConnection, query, statement::Statement};
 
 pub(crate) fn execute(ctx: Context<'_>, table_id: TableId) -> Result<Data, SdkError> {
     let table = ctx.database_definition.walk(table_id);
     let ast = query::update::build(&ctx, table, ctx.unique_filter(table)?)?;
-    let query = renderer::postgres::render(ast);
+    let statement = Statement::new(ctx, ast);
 
-    log::debug!(query = query.to_string(); "executing query");
-
-    let connection = ctx.pool.acquire()?;
+    let connection = MutationConnection::acquire(ctx, table)?;
 
     if ctx.mutation_is_returning() {
-        let mut rows = query.fetch(&connection)?;
-
-        let result = match rows.next() {
+        let result = match statement.fetch(connection.as_connection())?.next() {
             Some(mut row) => match row.next() {
                 Some(Ok(col)) => col.as_json()?.unwrap_or(serde_json::Value::Null),
                 Some(Err(err)) => return Err(SdkError::from(format!("query error: {err}"))),
@@ -34,18 +29,14 @@ pub(crate) fn execute(ctx: Context<'_>, table_id: TableId) -> Result<Data, SdkEr
             "rowCount": row_count,
         });
 
-        let data = Data::Json(serde_json::to_vec(&data).unwrap());
-
-        Ok(data)
+        connection.finish(data)
     } else {
-        let row_count = query.execute(&connection)?;
+        let row_count = statement.execute(connection.as_connection())?;
 
         let data = serde_json::json!({
             "rowCount": row_count,
         });
 
-        let data = Data::Json(serde_json::to_vec(&data).unwrap());
-
-        Ok(data)
+        connection.finish(data)
     }
 }
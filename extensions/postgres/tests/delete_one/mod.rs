@@ -428,3 +428,212 @@ async fn enum_array() {
     }
     "#);
 }
+
+#[tokio::test]
+async fn dry_run_with_cascades() {
+    let api = PgTestApi::new("", |api| async move {
+        let user_table = indoc! {r#"
+            CREATE TABLE "User" (
+                id INT PRIMARY KEY,
+                name VARCHAR(255) NOT NULL
+            )
+        "#};
+
+        api.execute_sql(user_table).await;
+
+        let blog_table = indoc! {r#"
+            CREATE TABLE "Blog" (
+                id INT PRIMARY KEY,
+                user_id INT NOT NULL,
+                CONSTRAINT Blog_User_fkey FOREIGN KEY (user_id) REFERENCES "User" (id) ON DELETE CASCADE
+            )
+        "#};
+
+        api.execute_sql(blog_table).await;
+
+        let comment_table = indoc! {r#"
+            CREATE TABLE "Comment" (
+                id INT PRIMARY KEY,
+                blog_id INT NOT NULL,
+                CONSTRAINT Comment_Blog_fkey FOREIGN KEY (blog_id) REFERENCES "Blog" (id) ON DELETE CASCADE
+            )
+        "#};
+
+        api.execute_sql(comment_table).await;
+
+        api.execute_sql(r#"INSERT INTO "User" (id, name) VALUES (1, 'Musti'), (2, 'Naukio')"#)
+            .await;
+
+        api.execute_sql(r#"INSERT INTO "Blog" (id, user_id) VALUES (1, 1), (2, 1), (3, 2)"#)
+            .await;
+
+        api.execute_sql(r#"INSERT INTO "Comment" (id, blog_id) VALUES (1, 1), (2, 1), (3, 2), (4, 3)"#)
+            .await;
+    })
+    .await;
+
+    let runner = api.runner_spawn().await;
+
+    let mutation = indoc! {r"
+        mutation {
+          userDelete(lookup: { id: 1 }, dryRun: true) {
+            returning { id name }
+            rowCount
+            affectedTables { table deleted updated }
+          }
+        }
+    "};
+
+    let mutation_response = runner.query(mutation).send().await;
+
+    insta::assert_json_snapshot!(mutation_response, @r#"
+    {
+      "data": {
+        "userDelete": {
+          "returning": {
+            "id": 1,
+            "name": "Musti"
+          },
+          "rowCount": 1,
+          "affectedTables": [
+            {
+              "table": "User",
+              "deleted": 1,
+              "updated": 0
+            },
+            {
+              "table": "Blog",
+              "deleted": 2,
+              "updated": 0
+            },
+            {
+              "table": "Comment",
+              "deleted": 3,
+              "updated": 0
+            }
+          ]
+        }
+      }
+    }
+    "#);
+
+    let query = indoc! {r"
+        query {
+          user(lookup: { id: 1 }) { id blogs(first: 10) { edges { node { id } } } }
+        }
+    "};
+
+    let query_response = runner.query(query).send().await;
+
+    insta::assert_json_snapshot!(query_response, @r#"
+    {
+      "data": {
+        "user": {
+          "id": 1,
+          "blogs": {
+            "edges": [
+              {
+                "node": {
+                  "id": 1
+                }
+              },
+              {
+                "node": {
+                  "id": 2
+                }
+              }
+            ]
+          }
+        }
+      }
+    }
+    "#);
+}
+
+#[tokio::test]
+async fn dry_run_with_partitioned_table() {
+    let api = PgTestApi::new("", |api| async move {
+        let schema = indoc! {r#"
+            CREATE TABLE "sensors" (
+                id INT PRIMARY KEY
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let schema = indoc! {r#"
+            CREATE TABLE "measurements" (
+                id INT NOT NULL,
+                sensor_id INT NOT NULL REFERENCES "sensors" (id) ON DELETE CASCADE,
+                recorded_at DATE NOT NULL,
+                value INT NOT NULL,
+                PRIMARY KEY (id, recorded_at)
+            ) PARTITION BY RANGE (recorded_at)
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let schema = indoc! {r#"
+            CREATE TABLE "measurements_2024" PARTITION OF "measurements"
+            FOR VALUES FROM ('2024-01-01') TO ('2025-01-01')
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let schema = indoc! {r#"
+            CREATE TABLE "measurements_2025" PARTITION OF "measurements"
+            FOR VALUES FROM ('2025-01-01') TO ('2026-01-01')
+        "#};
+
+        api.execute_sql(schema).await;
+
+        api.execute_sql(r#"INSERT INTO "sensors" (id) VALUES (1), (2)"#).await;
+
+        let insert = indoc! {r#"
+            INSERT INTO "measurements" (id, sensor_id, recorded_at, value) VALUES
+                (1, 1, '2024-06-01', 10),
+                (2, 1, '2025-06-01', 20),
+                (3, 1, '2025-07-01', 30),
+                (4, 2, '2025-06-01', 40)
+        "#};
+
+        api.execute_sql(insert).await;
+    })
+    .await;
+
+    let runner = api.runner_spawn().await;
+
+    let mutation = indoc! {r"
+        mutation {
+          sensorDelete(lookup: { id: 1 }, dryRun: true) {
+            rowCount
+            affectedTables { table deleted updated }
+          }
+        }
+    "};
+
+    let mutation_response = runner.query(mutation).send().await;
+
+    // The deletes in both partitions are counted for the partitioned table.
+    insta::assert_json_snapshot!(mutation_response, @r#"
+    {
+      "data": {
+        "sensorDelete": {
+          "rowCount": 1,
+          "affectedTables": [
+            {
+              "table": "Sensor",
+              "deleted": 1,
+              "updated": 0
+            },
+            {
+              "table": "Measurement",
+              "deleted": 3,
+              "updated": 0
+            }
+          ]
+        }
+      }
+    }
+    "#);
+}
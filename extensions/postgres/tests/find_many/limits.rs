@@ -0,0 +1,291 @@
+use crate::PgTestApi;
+use indoc::indoc;
+
+async fn users_with_blogs(config: &str) -> PgTestApi {
+    PgTestApi::new(config, |api| async move {
+        let user_table = indoc! {r#"
+            CREATE TABLE "User" (
+                id INT PRIMARY KEY,
+                name VARCHAR(255) NOT NULL
+            );
+        "#};
+
+        api.execute_sql(user_table).await;
+
+        let blog_table = indoc! {r#"
+            CREATE TABLE "Blog" (
+                id INT PRIMARY KEY,
+                user_id INT NOT NULL,
+                title VARCHAR(255) NOT NULL,
+                CONSTRAINT Blog_User_fkey FOREIGN KEY (user_id) REFERENCES "User" (id)
+            )
+        "#};
+
+        api.execute_sql(blog_table).await;
+
+        let insert_users = indoc! {r#"
+            INSERT INTO "User" (id, name) VALUES
+              (1, 'Musti'),
+              (2, 'Naukio'),
+              (3, 'Pertti')
+        "#};
+
+        api.execute_sql(insert_users).await;
+
+        let insert_blogs = indoc! {r#"
+            INSERT INTO "Blog" (id, user_id, title) VALUES
+              (1, 1, 'Hello, world!'),
+              (2, 1, 'Sayonara...'),
+              (3, 2, 'Meow meow?')
+        "#};
+
+        api.execute_sql(insert_blogs).await;
+    })
+    .await
+}
+
+#[tokio::test]
+async fn first_over_max_page_size() {
+    let config = indoc! {r#"
+        [extensions.postgres.config.databases.limits]
+        max_page_size = 2
+    "#};
+
+    let api = users_with_blogs(config).await;
+    let runner = api.runner_spawn().await;
+
+    let query = indoc! {r"
+        query {
+          users(first: 3) {
+            edges { node { id name } }
+          }
+        }
+    "};
+
+    let response = runner.query(query).send().await;
+
+    insta::assert_json_snapshot!(response, @r#"
+    {
+      "data": null,
+      "errors": [
+        {
+          "message": "The first argument of 3 exceeds the maximum page size of 2 for type User",
+          "locations": [
+            {
+              "line": 2,
+              "column": 3
+            }
+          ],
+          "path": [
+            "users"
+          ],
+          "extensions": {
+            "code": "EXTENSION_ERROR"
+          }
+        }
+      ]
+    }
+    "#);
+}
+
+#[tokio::test]
+async fn nested_last_over_table_max_page_size() {
+    let config = indoc! {r#"
+        [extensions.postgres.config.databases.limits]
+        max_page_size = 10
+
+        [extensions.postgres.config.databases.limits.tables."public.Blog"]
+        max_page_size = 1
+    "#};
+
+    let api = users_with_blogs(config).await;
+    let runner = api.runner_spawn().await;
+
+    let query = indoc! {r"
+        query {
+          users(first: 10) {
+            edges { node { id blogs(last: 2) { edges { node { id } } } } }
+          }
+        }
+    "};
+
+    let response = runner.query(query).send().await;
+
+    insta::assert_json_snapshot!(response, @r#"
+    {
+      "data": null,
+      "errors": [
+        {
+          "message": "The last argument of 2 exceeds the maximum page size of 1 for type Blog",
+          "locations": [
+            {
+              "line": 2,
+              "column": 3
+            }
+          ],
+          "path": [
+            "users"
+          ],
+          "extensions": {
+            "code": "EXTENSION_ERROR"
+          }
+        }
+      ]
+    }
+    "#);
+}
+
+#[tokio::test]
+async fn default_page_size() {
+    let config = indoc! {r#"
+        [extensions.postgres.config.databases.limits]
+        max_page_size = 10
+        default_page_size = 2
+    "#};
+
+    let api = users_with_blogs(config).await;
+    let runner = api.runner_spawn().await;
+
+    let query = indoc! {r"
+        query {
+          users {
+            edges { node { id name } }
+            pageInfo { hasNextPage }
+          }
+        }
+    "};
+
+    let response = runner.query(query).send().await;
+
+    insta::assert_json_snapshot!(response, @r#"
+    {
+      "data": {
+        "users": {
+          "edges": [
+            {
+              "node": {
+                "id": 1,
+                "name": "Musti"
+              }
+            },
+            {
+              "node": {
+                "id": 2,
+                "name": "Naukio"
+              }
+            }
+          ],
+          "pageInfo": {
+            "hasNextPage": true
+          }
+        }
+      }
+    }
+    "#);
+}
+
+#[tokio::test]
+async fn nesting_over_max_depth() {
+    let config = indoc! {r#"
+        [extensions.postgres.config.databases.limits]
+        max_depth = 1
+    "#};
+
+    let api = users_with_blogs(config).await;
+    let runner = api.runner_spawn().await;
+
+    let query = indoc! {r"
+        query {
+          users(first: 10) {
+            edges { node { id blogs { edges { node { id user { id } } } } } }
+          }
+        }
+    "};
+
+    let response = runner.query(query).send().await;
+
+    insta::assert_json_snapshot!(response, @r#"
+    {
+      "data": null,
+      "errors": [
+        {
+          "message": "The query exceeds the maximum relation nesting depth of 1",
+          "locations": [
+            {
+              "line": 2,
+              "column": 3
+            }
+          ],
+          "path": [
+            "users"
+          ],
+          "extensions": {
+            "code": "EXTENSION_ERROR"
+          }
+        }
+      ]
+    }
+    "#);
+}
+
+#[tokio::test]
+async fn slow_statement_is_cancelled() {
+    let config = indoc! {r#"
+        [extensions.postgres.config.databases.limits]
+        statement_timeout_ms = 100
+    "#};
+
+    let api = users_with_blogs(config).await;
+
+    let introspection_config = indoc! {r#"
+        [schemas.public.tables.User.computed_fields.slowName]
+        type = "String!"
+        sql = "(SELECT name FROM pg_sleep(1))"
+    "#};
+
+    let runner = api.runner_spawn_with_config(introspection_config).await;
+
+    let query = indoc! {r"
+        query {
+          users(first: 1) {
+            edges { node { id slowName } }
+          }
+        }
+    "};
+
+    let response = serde_json::to_value(runner.query(query).send().await).unwrap();
+    let message = response["errors"][0]["message"].as_str().unwrap();
+
+    assert!(
+        message.contains("canceling statement due to statement timeout"),
+        "unexpected response: {response}"
+    );
+
+    // The timeout doesn't end the session, the next statements on pooled connections still work.
+    let query = indoc! {r"
+        query {
+          users(first: 1) {
+            edges { node { id name } }
+          }
+        }
+    "};
+
+    let response = runner.query(query).send().await;
+
+    insta::assert_json_snapshot!(response, @r#"
+    {
+      "data": {
+        "users": {
+          "edges": [
+            {
+              "node": {
+                "id": 1,
+                "name": "Musti"
+              }
+            }
+          ]
+        }
+      }
+    }
+    "#);
+}
@@ -1,3 +1,4 @@
+mod limits;
 mod pagination;
 
 use crate::PgTestApi;
@@ -2237,3 +2238,64 @@ async fn last_as_parameter() {
     }
     "#);
 }
+
+#[tokio::test]
+async fn computed_field() {
+    let api = PgTestApi::new("", |api| async move {
+        let schema = indoc! {r#"
+            CREATE TABLE "users" (
+                id INT PRIMARY KEY,
+                first_name VARCHAR(255) NOT NULL,
+                last_name VARCHAR(255) NOT NULL
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let insert = indoc! {r#"
+            INSERT INTO "users" (id, first_name, last_name) VALUES (1, 'Musti', 'Kissa'), (2, 'Naukio', 'Kissa')
+        "#};
+
+        api.execute_sql(insert).await;
+    })
+    .await;
+
+    let config = indoc! {r#"
+        [schemas.public.tables.users.computed_fields.fullName]
+        type = "String!"
+        sql = "first_name || ' ' || last_name"
+    "#};
+
+    let runner = api.runner_spawn_with_config(config).await;
+
+    let query = indoc! {r"
+        query {
+          users(first: 1, orderBy: [{ id: DESC }]) {
+            edges { node { id fullName } }
+            pageInfo { hasNextPage }
+          }
+        }
+    "};
+
+    let response = runner.query(query).send().await;
+
+    insta::assert_json_snapshot!(response, @r#"
+    {
+      "data": {
+        "users": {
+          "edges": [
+            {
+              "node": {
+                "id": 2,
+                "fullName": "Naukio Kissa"
+              }
+            }
+          ],
+          "pageInfo": {
+            "hasNextPage": true
+          }
+        }
+      }
+    }
+    "#);
+}
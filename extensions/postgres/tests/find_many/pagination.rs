@@ -1,5 +1,6 @@
 mod cursors;
 mod filters;
+mod sealed_cursors;
 
 use crate::PgTestApi;
 use indoc::indoc;
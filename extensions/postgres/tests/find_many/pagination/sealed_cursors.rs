@@ -0,0 +1,268 @@
+use crate::PgTestApi;
+use grafbase_sdk::test::TestGateway;
+use indoc::indoc;
+use serde_json::{Value, json};
+
+async fn users_with_sealed_cursors() -> PgTestApi {
+    let config = indoc! {r#"
+        [extensions.postgres.config.databases.cursors]
+        secret = "super-secret-cursor-key-for-the-tests"
+    "#};
+
+    PgTestApi::new(config, |api| async move {
+        let schema = indoc! {r#"
+            CREATE TABLE "User" (
+                id INT PRIMARY KEY,
+                name VARCHAR(255) NOT NULL
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let insert = indoc! {r#"
+            INSERT INTO "User" (id, name) VALUES (1, 'Musti'), (2, 'Naukio')
+        "#};
+
+        api.execute_sql(insert).await;
+    })
+    .await
+}
+
+/// Sealed cursors have a random nonce, so the snapshots show a placeholder instead.
+fn redact_cursors(response: impl serde::Serialize) -> Value {
+    fn redact(value: &mut Value) {
+        match value {
+            Value::Object(object) => {
+                for (key, value) in object {
+                    match value {
+                        Value::String(cursor) if key.ends_with("ursor") => *cursor = "[sealed]".to_owned(),
+                        _ => redact(value),
+                    }
+                }
+            }
+            Value::Array(values) => values.iter_mut().for_each(redact),
+            _ => (),
+        }
+    }
+
+    let mut value = serde_json::to_value(response).unwrap();
+    redact(&mut value);
+    value
+}
+
+/// The end cursor of the first page of users.
+async fn first_page_end_cursor(runner: &TestGateway) -> String {
+    let query = indoc! {r"
+        query {
+          users(first: 1) {
+            pageInfo { endCursor }
+          }
+        }
+    "};
+
+    let response: Value = runner.query(query).send().await.deserialize().unwrap();
+
+    response["data"]["users"]["pageInfo"]["endCursor"]
+        .as_str()
+        .unwrap()
+        .to_owned()
+}
+
+#[tokio::test]
+async fn paginate_with_sealed_cursors() {
+    let api = users_with_sealed_cursors().await;
+    let runner = api.runner_spawn().await;
+
+    let query = indoc! {r"
+        query Users($after: String) {
+          users(first: 1, after: $after) {
+            edges { node { name } cursor }
+            pageInfo { hasNextPage startCursor endCursor }
+          }
+        }
+    "};
+
+    let response: Value = runner.query(query).send().await.deserialize().unwrap();
+    let end_cursor = response["data"]["users"]["pageInfo"]["endCursor"].as_str().unwrap();
+
+    // The same cursor seals to a different string every time.
+    assert_ne!(
+        Some(end_cursor),
+        response["data"]["users"]["pageInfo"]["startCursor"].as_str()
+    );
+
+    insta::assert_json_snapshot!(redact_cursors(&response), @r#"
+    {
+      "data": {
+        "users": {
+          "edges": [
+            {
+              "node": {
+                "name": "Musti"
+              },
+              "cursor": "[sealed]"
+            }
+          ],
+          "pageInfo": {
+            "hasNextPage": true,
+            "startCursor": "[sealed]",
+            "endCursor": "[sealed]"
+          }
+        }
+      }
+    }
+    "#);
+
+    let response = runner
+        .query(query)
+        .variables(json!({ "after": end_cursor }))
+        .send()
+        .await;
+
+    insta::assert_json_snapshot!(redact_cursors(response), @r#"
+    {
+      "data": {
+        "users": {
+          "edges": [
+            {
+              "node": {
+                "name": "Naukio"
+              },
+              "cursor": "[sealed]"
+            }
+          ],
+          "pageInfo": {
+            "hasNextPage": false,
+            "startCursor": "[sealed]",
+            "endCursor": "[sealed]"
+          }
+        }
+      }
+    }
+    "#);
+}
+
+#[tokio::test]
+async fn tampered_cursor() {
+    let api = users_with_sealed_cursors().await;
+    let runner = api.runner_spawn().await;
+
+    // Flips a character in the middle of the encrypted payload.
+    let mut cursor = first_page_end_cursor(&runner).await.into_bytes();
+    let index = cursor.len() / 2 + 8;
+    cursor[index] = if cursor[index] == b'A' { b'B' } else { b'A' };
+    let cursor = String::from_utf8(cursor).unwrap();
+
+    let query = indoc! {r"
+        query Users($after: String) {
+          users(first: 1, after: $after) {
+            edges { node { name } }
+          }
+        }
+    "};
+
+    let response = runner.query(query).variables(json!({ "after": cursor })).send().await;
+
+    insta::assert_json_snapshot!(response, @r#"
+    {
+      "data": null,
+      "errors": [
+        {
+          "message": "Invalid cursor: the cursor signature is invalid",
+          "locations": [
+            {
+              "line": 2,
+              "column": 3
+            }
+          ],
+          "path": [
+            "users"
+          ],
+          "extensions": {
+            "code": "EXTENSION_ERROR"
+          }
+        }
+      ]
+    }
+    "#);
+}
+
+#[tokio::test]
+async fn cursor_for_another_ordering() {
+    let api = users_with_sealed_cursors().await;
+    let runner = api.runner_spawn().await;
+
+    let cursor = first_page_end_cursor(&runner).await;
+
+    let query = indoc! {r"
+        query Users($after: String) {
+          users(first: 1, orderBy: [{ name: DESC }], after: $after) {
+            edges { node { name } }
+          }
+        }
+    "};
+
+    let response = runner.query(query).variables(json!({ "after": cursor })).send().await;
+
+    insta::assert_json_snapshot!(response, @r#"
+    {
+      "data": null,
+      "errors": [
+        {
+          "message": "Invalid cursor: the cursor was created for ordering id:asc, the query orders by name:desc,id:asc",
+          "locations": [
+            {
+              "line": 2,
+              "column": 3
+            }
+          ],
+          "path": [
+            "users"
+          ],
+          "extensions": {
+            "code": "EXTENSION_ERROR"
+          }
+        }
+      ]
+    }
+    "#);
+}
+
+#[tokio::test]
+async fn unsealed_cursor() {
+    let api = users_with_sealed_cursors().await;
+    let runner = api.runner_spawn().await;
+
+    let query = indoc! {r#"
+        query {
+          users(first: 1, after: "WzFd") {
+            edges { node { name } }
+          }
+        }
+    "#};
+
+    let response = runner.query(query).send().await;
+
+    insta::assert_json_snapshot!(response, @r#"
+    {
+      "data": null,
+      "errors": [
+        {
+          "message": "Invalid cursor: cursor version 91 is not supported",
+          "locations": [
+            {
+              "line": 2,
+              "column": 3
+            }
+          ],
+          "path": [
+            "users"
+          ],
+          "extensions": {
+            "code": "EXTENSION_ERROR"
+          }
+        }
+      ]
+    }
+    "#);
+}
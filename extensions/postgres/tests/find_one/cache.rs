@@ -0,0 +1,143 @@
+use indoc::{formatdoc, indoc};
+
+use crate::PgTestApi;
+
+async fn cached_users(max_age_ms: u64) -> PgTestApi {
+    let config = formatdoc! {r#"
+        [extensions.postgres.config.databases.cache.tables."public.users"]
+        max_age_ms = {max_age_ms}
+    "#};
+
+    PgTestApi::new(&config, |api| async move {
+        let schema = indoc! {r#"
+            CREATE TABLE "users" (
+                id INT PRIMARY KEY,
+                name VARCHAR(255) NOT NULL
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let insert = indoc! {r#"
+            INSERT INTO "users" (id, name) VALUES (1, 'Musti'), (2, 'Naukio')
+        "#};
+
+        api.execute_sql(insert).await;
+    })
+    .await
+}
+
+#[tokio::test]
+async fn serves_cached_result() {
+    let api = cached_users(60000).await;
+    let runner = api.runner_spawn().await;
+
+    let query = indoc! {r"
+        query {
+          user(lookup: { id: 1 }) { id name }
+        }
+    "};
+
+    let response = runner.query(query).send().await;
+
+    insta::assert_json_snapshot!(response, @r#"
+    {
+      "data": {
+        "user": {
+          "id": 1,
+          "name": "Musti"
+        }
+      }
+    }
+    "#);
+
+    // Changes made outside of the extension are not seen until the result expires.
+    api.execute_sql(r#"UPDATE "users" SET name = 'Pertti' WHERE id = 1"#)
+        .await;
+
+    let response = runner.query(query).send().await;
+
+    insta::assert_json_snapshot!(response, @r#"
+    {
+      "data": {
+        "user": {
+          "id": 1,
+          "name": "Musti"
+        }
+      }
+    }
+    "#);
+}
+
+#[tokio::test]
+async fn mutations_invalidate_cached_results() {
+    let api = cached_users(60000).await;
+    let runner = api.runner_spawn().await;
+
+    let query = indoc! {r"
+        query {
+          users(first: 10) { edges { node { id name } } }
+        }
+    "};
+
+    let response = runner.query(query).send().await;
+
+    insta::assert_json_snapshot!(response, @r#"
+    {
+      "data": {
+        "users": {
+          "edges": [
+            {
+              "node": {
+                "id": 1,
+                "name": "Musti"
+              }
+            },
+            {
+              "node": {
+                "id": 2,
+                "name": "Naukio"
+              }
+            }
+          ]
+        }
+      }
+    }
+    "#);
+
+    let mutation = indoc! {r#"
+        mutation {
+          userUpdate(lookup: { id: 1 }, input: { name: { set: "Pertti" } }) {
+            rowCount
+          }
+        }
+    "#};
+
+    runner.query(mutation).send().await;
+
+    // The mutation bumps the generation of the table, so the next read runs the query again.
+    let response = runner.query(query).send().await;
+
+    insta::assert_json_snapshot!(response, @r#"
+    {
+      "data": {
+        "users": {
+          "edges": [
+            {
+              "node": {
+                "id": 1,
+                "name": "Pertti"
+              }
+            },
+            {
+              "node": {
+                "id": 2,
+                "name": "Naukio"
+              }
+            }
+          ]
+        }
+      }
+    }
+    "#);
+}
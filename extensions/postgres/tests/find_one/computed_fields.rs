@@ -0,0 +1,148 @@
+use crate::PgTestApi;
+use indoc::indoc;
+
+#[tokio::test]
+async fn expression_over_the_row() {
+    let api = PgTestApi::new("", |api| async move {
+        let schema = indoc! {r#"
+            CREATE TABLE "users" (
+                id INT PRIMARY KEY,
+                first_name VARCHAR(255) NOT NULL,
+                last_name VARCHAR(255) NOT NULL
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let insert = indoc! {r#"
+            INSERT INTO "users" (id, first_name, last_name) VALUES (1, 'Musti', 'Kissa'), (2, 'Naukio', 'Kissa')
+        "#};
+
+        api.execute_sql(insert).await;
+    })
+    .await;
+
+    let config = indoc! {r#"
+        [schemas.public.tables.users.computed_fields.fullName]
+        type = "String!"
+        sql = "first_name || ' ' || last_name"
+    "#};
+
+    let runner = api.runner_spawn_with_config(config).await;
+
+    let query = indoc! {r"
+        query {
+          user(lookup: { id: 1 }) { id fullName name: fullName }
+        }
+    "};
+
+    let response = runner.query(query).send().await;
+
+    insta::assert_json_snapshot!(response, @r#"
+    {
+      "data": {
+        "user": {
+          "id": 1,
+          "fullName": "Musti Kissa",
+          "name": "Musti Kissa"
+        }
+      }
+    }
+    "#);
+}
+
+#[tokio::test]
+async fn correlated_subquery_in_a_nested_relation() {
+    let api = PgTestApi::new("", |api| async move {
+        let schema = indoc! {r#"
+            CREATE TABLE "users" (
+                id INT PRIMARY KEY,
+                name VARCHAR(255) NOT NULL
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let schema = indoc! {r#"
+            CREATE TABLE "posts" (
+                id INT PRIMARY KEY,
+                user_id INT NOT NULL REFERENCES "users" (id),
+                title VARCHAR(255) NOT NULL
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let schema = indoc! {r#"
+            CREATE TABLE "comments" (
+                id INT PRIMARY KEY,
+                post_id INT NOT NULL REFERENCES "posts" (id)
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+
+        api.execute_sql(r#"INSERT INTO "users" (id, name) VALUES (1, 'Musti')"#)
+            .await;
+
+        let insert = indoc! {r#"
+            INSERT INTO "posts" (id, user_id, title) VALUES (1, 1, 'Meow'), (2, 1, 'Purr')
+        "#};
+
+        api.execute_sql(insert).await;
+
+        let insert = indoc! {r#"
+            INSERT INTO "comments" (id, post_id) VALUES (1, 1), (2, 1), (3, 2)
+        "#};
+
+        api.execute_sql(insert).await;
+    })
+    .await;
+
+    let config = indoc! {r#"
+        [schemas.public.tables.posts.computed_fields.commentCount]
+        type = "Int!"
+        sql = "(SELECT count(*)::int FROM comments WHERE comments.post_id = posts.id)"
+    "#};
+
+    let runner = api.runner_spawn_with_config(config).await;
+
+    let query = indoc! {r"
+        query {
+          user(lookup: { id: 1 }) {
+            name
+            posts(orderBy: [{ id: ASC }]) {
+              edges { node { title commentCount } }
+            }
+          }
+        }
+    "};
+
+    let response = runner.query(query).send().await;
+
+    insta::assert_json_snapshot!(response, @r#"
+    {
+      "data": {
+        "user": {
+          "name": "Musti",
+          "posts": {
+            "edges": [
+              {
+                "node": {
+                  "title": "Meow",
+                  "commentCount": 2
+                }
+              },
+              {
+                "node": {
+                  "title": "Purr",
+                  "commentCount": 1
+                }
+              }
+            ]
+          }
+        }
+      }
+    }
+    "#);
+}
@@ -0,0 +1,139 @@
+use indoc::indoc;
+
+use crate::PgTestApi;
+
+async fn debugged_users() -> PgTestApi {
+    let config = indoc! {r#"
+        [extensions.postgres.config.debug]
+        enabled = true
+        header = "x-postgres-debug"
+        explain = true
+
+        [[headers]]
+        rule = "forward"
+        name = "x-postgres-debug"
+    "#};
+
+    PgTestApi::new(config, |api| async move {
+        let schema = indoc! {r#"
+            CREATE TABLE "users" (
+                id INT PRIMARY KEY,
+                name VARCHAR(255) NOT NULL
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let insert = indoc! {r#"
+            INSERT INTO "users" (id, name) VALUES (1, 'Musti')
+        "#};
+
+        api.execute_sql(insert).await;
+    })
+    .await
+}
+
+const FAILING_COMPUTED_FIELD: &str = indoc! {r#"
+    [schemas.public.tables.users.computed_fields.broken]
+    type = "Int"
+    sql = "id / (id - id)"
+"#};
+
+const QUERY: &str = indoc! {r"
+    query {
+      user(lookup: { id: 1 }) { id name }
+    }
+"};
+
+const FAILING_QUERY: &str = indoc! {r"
+    query {
+      user(lookup: { id: 1 }) { id broken }
+    }
+"};
+
+#[tokio::test]
+async fn statements_in_successful_response_with_header() {
+    let api = debugged_users().await;
+    let runner = api.runner_spawn().await;
+
+    let response = runner.query(QUERY).header("x-postgres-debug", "true").send().await;
+
+    assert_eq!(response["data"]["user"]["name"], "Musti", "{response}");
+
+    // The data is kept, and the statements are in an extra error.
+    let errors = response.errors();
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0]["message"], "Postgres debug information");
+
+    let statements = errors[0]["extensions"]["postgres"]["statements"]
+        .as_array()
+        .expect("debug information in the error extensions");
+
+    assert_eq!(statements.len(), 1);
+
+    let statement = &statements[0];
+
+    assert!(statement["sql"].as_str().unwrap().starts_with("SELECT"));
+    assert!(statement["parameterCount"].as_u64().unwrap() >= 1);
+    assert!(statement["durationMs"].is_number());
+    assert!(statement["plan"][0]["Plan"].is_object(), "unexpected plan: {statement}");
+}
+
+#[tokio::test]
+async fn statements_in_error_extensions_with_header() {
+    let api = debugged_users().await;
+    let runner = api.runner_spawn_with_config(FAILING_COMPUTED_FIELD).await;
+
+    let response = runner
+        .query(FAILING_QUERY)
+        .header("x-postgres-debug", "true")
+        .send()
+        .await;
+    let errors = response.errors();
+
+    let statements = errors[0]["extensions"]["postgres"]["statements"]
+        .as_array()
+        .expect("debug information in the error extensions");
+
+    assert_eq!(statements.len(), 1);
+
+    let statement = &statements[0];
+
+    assert!(statement["sql"].as_str().unwrap().starts_with("SELECT"));
+    assert!(statement["parameterCount"].as_u64().unwrap() >= 1);
+    assert!(statement["durationMs"].is_number());
+
+    // `EXPLAIN (FORMAT JSON)` returns an array with one plan.
+    assert!(statement["plan"][0]["Plan"].is_object(), "unexpected plan: {statement}");
+
+    // Plain `EXPLAIN` doesn't execute the statement, so there are no actual timings.
+    assert!(statement["plan"][0]["Plan"].get("Actual Total Time").is_none());
+}
+
+#[tokio::test]
+async fn no_debug_information_without_opt_in() {
+    let api = debugged_users().await;
+    let runner = api.runner_spawn_with_config(FAILING_COMPUTED_FIELD).await;
+
+    for response in [
+        runner.query(QUERY).send().await,
+        runner.query(QUERY).header("x-postgres-debug", "false").send().await,
+    ] {
+        assert!(response.errors().is_empty(), "{response}");
+    }
+
+    for response in [
+        runner.query(FAILING_QUERY).send().await,
+        runner
+            .query(FAILING_QUERY)
+            .header("x-postgres-debug", "false")
+            .send()
+            .await,
+    ] {
+        let errors = response.errors();
+
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0]["extensions"].get("postgres").is_none());
+    }
+}
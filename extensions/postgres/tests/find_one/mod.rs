@@ -1,4 +1,8 @@
+mod cache;
+mod computed_fields;
+mod debug;
 mod joins;
+mod precomputed_definition;
 mod types;
 mod views;
 
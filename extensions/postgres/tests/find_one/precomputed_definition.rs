@@ -0,0 +1,97 @@
+use crate::PgTestApi;
+use indoc::indoc;
+
+#[tokio::test]
+async fn relations_and_computed_fields() {
+    let api = PgTestApi::new("", |api| async move {
+        let schema = indoc! {r#"
+            CREATE TABLE "users" (
+                id INT PRIMARY KEY,
+                first_name VARCHAR(255) NOT NULL,
+                last_name VARCHAR(255) NOT NULL
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let schema = indoc! {r#"
+            CREATE TABLE "posts" (
+                id INT PRIMARY KEY,
+                user_id INT NOT NULL REFERENCES "users" (id),
+                title VARCHAR(255) NOT NULL
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let insert = indoc! {r#"
+            INSERT INTO "users" (id, first_name, last_name) VALUES (1, 'Musti', 'Kissa')
+        "#};
+
+        api.execute_sql(insert).await;
+
+        let insert = indoc! {r#"
+            INSERT INTO "posts" (id, user_id, title) VALUES (1, 1, 'Meow'), (2, 1, 'Purr')
+        "#};
+
+        api.execute_sql(insert).await;
+    })
+    .await;
+
+    let config = indoc! {r#"
+        precompute_definition = true
+
+        [schemas.public.tables.users.computed_fields.fullName]
+        type = "String!"
+        sql = "first_name || ' ' || last_name"
+    "#};
+
+    let runner = api.runner_spawn_with_config(config).await;
+
+    let query = indoc! {r"
+        query {
+          post(lookup: { id: 2 }) {
+            title
+            user { fullName }
+          }
+          user(lookup: { id: 1 }) {
+            fullName
+            posts(orderBy: [{ id: ASC }]) {
+              edges { node { title } }
+            }
+          }
+        }
+    "};
+
+    let response = runner.query(query).send().await;
+
+    insta::assert_json_snapshot!(response, @r#"
+    {
+      "data": {
+        "post": {
+          "title": "Purr",
+          "user": {
+            "fullName": "Musti Kissa"
+          }
+        },
+        "user": {
+          "fullName": "Musti Kissa",
+          "posts": {
+            "edges": [
+              {
+                "node": {
+                  "title": "Meow"
+                }
+              },
+              {
+                "node": {
+                  "title": "Purr"
+                }
+              }
+            ]
+          }
+        }
+      }
+    }
+    "#);
+}
@@ -9,7 +9,7 @@ mod lookup_many;
 mod update_many;
 mod update_one;
 
-use std::{cell::RefCell, fmt::Display, path::Path, sync::Arc};
+use std::{cell::RefCell, collections::BTreeMap, fmt::Display, path::Path, sync::Arc};
 
 use grafbase_postgres_introspection::config::Config;
 use grafbase_sdk::{
@@ -260,6 +260,9 @@ impl PgTestApi {
             enable_queries: true,
             schema_allowlist: None,
             schema_denylist: Vec::new(),
+            validation_directive: None,
+            subgraphs: Default::default(),
+            precompute_definition: false,
         })
         .await
     }
@@ -276,6 +279,9 @@ impl PgTestApi {
             enable_queries: true,
             schema_allowlist: None,
             schema_denylist: Vec::new(),
+            validation_directive: None,
+            subgraphs: Default::default(),
+            precompute_definition: false,
         })
         .await
     }
@@ -288,6 +294,15 @@ impl PgTestApi {
             .unwrap()
     }
 
+    async fn introspect_subgraphs(&self, toml_str: &str) -> BTreeMap<String, String> {
+        let config = toml::from_str(toml_str).unwrap();
+        let mut conn = self.inner.pool.acquire().await.unwrap();
+
+        grafbase_postgres_introspection::introspect_subgraphs(&mut conn, config)
+            .await
+            .unwrap()
+    }
+
     async fn introspect_error(&self, toml_str: &str) -> String {
         let config = toml::from_str(toml_str).unwrap();
         let mut conn = self.inner.pool.acquire().await.unwrap();
@@ -0,0 +1,705 @@
+use crate::PgTestApi;
+use indoc::indoc;
+
+#[tokio::test]
+async fn computed_fields() {
+    let api = PgTestApi::new("", |api| async move {
+        let schema = indoc! {r#"
+            CREATE TABLE "users" (
+                id INT PRIMARY KEY,
+                first_name VARCHAR(255) NOT NULL,
+                last_name VARCHAR(255) NOT NULL
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+    })
+    .await;
+
+    let config = indoc! {r#"
+        extension_url = "https://grafbase.com/extensions/postgres/0.1.1"
+
+        [schemas.public.tables.users.computed_fields.fullName]
+        type = "String!"
+        sql = "first_name || ' ' || last_name"
+        description = "The first and last name of the user"
+
+        [schemas.public.tables.users.computed_fields.quotedName]
+        type = "String"
+        sql = """
+        format('"%s"', first_name)
+        """
+    "#};
+
+    let result = api.introspect_with_config(config).await;
+
+    insta::assert_snapshot!(&result, @r#"
+    extend schema
+      @link(
+        url: "https://grafbase.com/extensions/postgres/0.1.1",
+        import: [
+          "@pgDatabase",
+          "@pgTable",
+          "@pgColumn",
+          "@pgComputed",
+          "@pgEnum",
+          "@pgEnumVariant",
+          "@pgRelation",
+          "@pgKey",
+          "@pgLookup",
+          "@pgSelectOne",
+          "@pgSelectMany",
+          "@pgInsertOne",
+          "@pgInsertMany",
+          "@pgUpdateOne",
+          "@pgUpdateMany",
+          "@pgDeleteOne",
+          "@pgDeleteMany",
+          "@pgConnection",
+          "@pgMutation",
+          "@pgReturning",
+          "PgKeyType",
+          "PgColumnType",
+          "PgRelationType"
+        ]
+      )
+      @link(
+        url: "https://specs.grafbase.com/composite-schemas/v1",
+        import: [
+          "@lookup",
+          "@key",
+          "@derive",
+          "@is"
+        ]
+      )
+      @link(
+        url: "https://specs.apollo.dev/federation/v2.3",
+        import: [
+          "@shareable",
+          "@inaccessible"
+        ]
+      )
+      @pgDatabase(name: "default")
+
+    """
+    Specifies the direction for ordering results.
+    """
+    enum OrderDirection {
+      """
+      Specifies an ascending order for a given orderBy argument.
+      """
+      ASC
+      """
+      Specifies a descending order for a given orderBy argument.
+      """
+      DESC
+    }
+
+    """
+    Search filter input for Int type.
+    """
+    input IntFilterInput @oneOf {
+      """
+      The value is exactly the one given
+      """
+      eq: Int
+      """
+      The value is not the one given
+      """
+      ne: Int
+      """
+      The value is greater than the one given
+      """
+      gt: Int
+      """
+      The value is less than the one given
+      """
+      lt: Int
+      """
+      The value is greater than, or equal to the one given
+      """
+      gte: Int
+      """
+      The value is less than, or equal to the one given
+      """
+      lte: Int
+      """
+      The value is in the given array of values
+      """
+      in: [Int!]
+      """
+      The value is not in the given array of values
+      """
+      nin: [Int!]
+      """
+      A negation of the given filter
+      """
+      not: IntFilterInput
+    }
+
+    """
+    Update input for Int type.
+    """
+    input IntUpdateInput @oneOf {
+      """
+      Replaces the value of a field with the specified value.
+      """
+      set: Int
+      """
+      Increments the value of a field by the specified value.
+      """
+      increment: Int
+      """
+      Decrements the value of a field by the specified value.
+      """
+      decrement: Int
+      """
+      Multiplies the value of a field by the specified value.
+      """
+      multiply: Int
+      """
+      Divides the value of a field by the specified value.
+      """
+      divide: Int
+    }
+
+    """
+    Search filter input for String type.
+    """
+    input StringFilterInput @oneOf {
+      """
+      The value is exactly the one given
+      """
+      eq: String
+      """
+      The value is not the one given
+      """
+      ne: String
+      """
+      The value is greater than the one given
+      """
+      gt: String
+      """
+      The value is less than the one given
+      """
+      lt: String
+      """
+      The value is greater than, or equal to the one given
+      """
+      gte: String
+      """
+      The value is less than, or equal to the one given
+      """
+      lte: String
+      """
+      The given input is part of the column value
+      """
+      like: String
+      """
+      The value is in the given array of values
+      """
+      in: [String!]
+      """
+      The value is not in the given array of values
+      """
+      nin: [String!]
+      """
+      A negation of the given filter
+      """
+      not: StringFilterInput
+    }
+
+    """
+    Update input for String type.
+    """
+    input StringUpdateInput @oneOf {
+      """
+      Replaces the value of a field with the specified value.
+      """
+      set: String
+    }
+
+    """
+    Input for creating a new User
+    """
+    input UserCreateInput {
+      """
+      Set field value for id
+      """
+      id: Int!
+      """
+      Set field value for firstName
+      """
+      firstName: String!
+      """
+      Set field value for lastName
+      """
+      lastName: String!
+    }
+
+    """
+    Filter input type for User objects.
+    """
+    input UserFilterInput @oneOf {
+      """
+      Filter by the given id
+      """
+      id: IntFilterInput
+      """
+      Filter by the given firstName
+      """
+      firstName: StringFilterInput
+      """
+      Filter by the given lastName
+      """
+      lastName: StringFilterInput
+      """
+      All of the filters must match
+      """
+      ALL: [UserFilterInput]
+      """
+      None of the filters must match
+      """
+      NONE: [UserFilterInput]
+      """
+      At least one of the filters must match
+      """
+      ANY: [UserFilterInput]
+    }
+
+    """
+    Input type to select a unique User
+    """
+    input UserLookupInput @oneOf {
+      """
+      Select by the 'id' field
+      """
+      id: Int
+    }
+
+    """
+    Lookup input type for User objects for subgraph joins.
+    """
+    input UserManyLookupInput @oneOf @inaccessible {
+      """
+      Select by the 'id' field
+      """
+      id: [Int!] @inaccessible
+    }
+
+    """
+    Specifies the ordering for User results.
+    """
+    input UserOrderByInput @oneOf {
+      """
+      Order users by id
+      """
+      id: OrderDirection
+      """
+      Order users by firstName
+      """
+      firstName: OrderDirection
+      """
+      Order users by lastName
+      """
+      lastName: OrderDirection
+    }
+
+    """
+    Input for updating an existing User
+    """
+    input UserUpdateInput {
+      """
+      Update field value for id
+      """
+      id: IntUpdateInput
+      """
+      Update field value for firstName
+      """
+      firstName: StringUpdateInput
+      """
+      Update field value for lastName
+      """
+      lastName: StringUpdateInput
+    }
+
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
+    """
+    Information about pagination in a collection of objects
+    """
+    type PageInfo
+      @shareable
+    {
+      """
+      When paginating backwards, are there more items?
+      """
+      hasPreviousPage: Boolean! @shareable
+      """
+      When paginating forwards, are there more items?
+      """
+      hasNextPage: Boolean! @shareable
+      """
+      The cursor of the first item in the page
+      """
+      startCursor: String @shareable
+      """
+      The cursor of the last item in the page
+      """
+      endCursor: String @shareable
+    }
+
+    type User
+      @key(fields: "id")
+      @pgTable(name: "users")
+      @pgKey(fields: ["id"], type: PRIMARY)
+    {
+      id: Int! @pgColumn(name: "id", type: INT)
+      firstName: String! @pgColumn(name: "first_name", type: VARCHAR)
+      lastName: String! @pgColumn(name: "last_name", type: VARCHAR)
+      """
+      The first and last name of the user
+      """
+      fullName: String! @pgComputed(sql: "first_name || ' ' || last_name")
+      quotedName: String @pgComputed(sql: "format('\"%s\"', first_name)\n")
+    }
+
+    """
+    The connection type for User
+    """
+    type UserConnection
+      @pgConnection(type: "User")
+    {
+      """
+      A list of edges
+      """
+      edges: [UserEdge!]! @shareable
+      """
+      Information to aid in pagination
+      """
+      pageInfo: PageInfo! @shareable
+    }
+
+    """
+    Return type when creating many users
+    """
+    type UserCreateManyPayload
+      @pgMutation(type: "User")
+    {
+      """
+      Returned item(s) from the mutation
+      """
+      returning: [UserReturning]! @shareable
+      """
+      The number of rows mutated
+      """
+      rowCount: Int! @shareable
+    }
+
+    """
+    Return type when creating one User
+    """
+    type UserCreatePayload
+      @pgMutation(type: "User")
+    {
+      """
+      Returned item(s) from the mutation
+      """
+      returning: UserReturning @shareable
+      """
+      The number of rows mutated
+      """
+      rowCount: Int! @shareable
+    }
+
+    """
+    Return type when deleting many users
+    """
+    type UserDeleteManyPayload
+      @pgMutation(type: "User")
+    {
+      """
+      Returned item(s) from the mutation
+      """
+      returning: [UserReturning]! @shareable
+      """
+      The number of rows mutated
+      """
+      rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
+    }
+
+    """
+    Return type when deleting one User
+    """
+    type UserDeletePayload
+      @pgMutation(type: "User")
+    {
+      """
+      Returned item(s) from the mutation
+      """
+      returning: UserReturning @shareable
+      """
+      The number of rows mutated
+      """
+      rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
+    }
+
+    """
+    An edge in a connection. Contains the node and its cursor
+    """
+    type UserEdge {
+      """
+      The item at the end of the edge
+      """
+      node: User! @shareable
+      """
+      A cursor for use in pagination
+      """
+      cursor: String! @shareable
+    }
+
+    """
+    Return type containing fields of the mutated or created User object
+    """
+    type UserReturning
+      @pgReturning(type: "User")
+    {
+      """
+      The value of the id field
+      """
+      id: Int! @shareable
+      """
+      The value of the firstName field
+      """
+      firstName: String!
+      """
+      The value of the lastName field
+      """
+      lastName: String!
+    }
+
+    """
+    Return type when updating many users
+    """
+    type UserUpdateManyPayload
+      @pgMutation(type: "User")
+    {
+      """
+      Returned item(s) from the mutation
+      """
+      returning: [UserReturning]! @shareable
+      """
+      The number of rows mutated
+      """
+      rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
+    }
+
+    """
+    Return type when updating one User
+    """
+    type UserUpdatePayload
+      @pgMutation(type: "User")
+    {
+      """
+      Returned item(s) from the mutation
+      """
+      returning: UserReturning @shareable
+      """
+      The number of rows mutated
+      """
+      rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
+    }
+
+    type Query {
+      """
+      Query a unique User
+      """
+      user(
+        """
+        Input for unique User lookup
+        """
+        lookup: UserLookupInput!,
+      ): User @pgSelectOne
+      """
+      Query and paginate multiple users
+      """
+      users(
+        """
+        Filter for User
+        """
+        filter: UserFilterInput,
+        """
+        Limit the number of results, from the beginning
+        """
+        first: Int,
+        """
+        Limit the number of results, from the end
+        """
+        last: Int,
+        """
+        Cursor for pagination, select items before the cursor. Use together with `last`.
+        """
+        before: String,
+        """
+        Cursor for pagination, select items after the cursor. Use together with `first`.
+        """
+        after: String,
+        """
+        Order the results by selected fields
+        """
+        orderBy: [UserOrderByInput!],
+      ): UserConnection! @pgSelectMany
+      """
+      Lookup multiple users for subgraph joins
+      """
+      userLookup(
+        """
+        Filter users with an array of keys
+        """
+        lookup: UserManyLookupInput @inaccessible,
+      ): [User] @pgLookup @lookup @inaccessible
+    }
+
+    type Mutation {
+      """
+      Create a single User
+      """
+      userCreate(
+        """
+        Input for creating a single User
+        """
+        input: UserCreateInput!,
+      ): UserCreatePayload! @pgInsertOne
+      """
+      Create multiple users
+      """
+      userCreateMany(
+        """
+        Input for creating multiple User instances
+        """
+        input: [UserCreateInput!]!,
+      ): UserCreateManyPayload! @pgInsertMany
+      """
+      Update a unique User
+      """
+      userUpdate(
+        """
+        Lookup input for unique User update
+        """
+        lookup: UserLookupInput!,
+        """
+        Input for updating a User
+        """
+        input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
+      ): UserUpdatePayload! @pgUpdateOne
+      """
+      Update multiple users
+      """
+      userUpdateMany(
+        """
+        Filter for updating multiple User instances
+        """
+        filter: UserFilterInput,
+        """
+        Input for updating multiple User instances
+        """
+        input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
+      ): UserUpdateManyPayload! @pgUpdateMany
+      """
+      Delete a unique User
+      """
+      userDelete(
+        """
+        Lookup input for unique User deletion
+        """
+        lookup: UserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
+      ): UserDeletePayload! @pgDeleteOne
+      """
+      Delete multiple users
+      """
+      userDeleteMany(
+        """
+        Filter for User deletion
+        """
+        filter: UserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
+      ): UserDeleteManyPayload! @pgDeleteMany
+    }
+    "#);
+}
+
+#[tokio::test]
+async fn computed_field_clashing_with_a_column() {
+    let api = PgTestApi::new("", |api| async move {
+        let schema = indoc! {r#"
+            CREATE TABLE "users" (
+                id INT PRIMARY KEY,
+                first_name VARCHAR(255) NOT NULL
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+    })
+    .await;
+
+    let config = indoc! {r#"
+        extension_url = "https://grafbase.com/extensions/postgres/0.1.1"
+
+        [schemas.public.tables.users.computed_fields.firstName]
+        type = "String!"
+        sql = "upper(first_name)"
+    "#};
+
+    let error = api.introspect_error(config).await;
+
+    insta::assert_snapshot!(error, @"Computed field firstName clashes with a column in type User");
+}
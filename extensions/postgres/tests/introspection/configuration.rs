@@ -678,6 +678,26 @@ async fn globally_disabled_queries() {
       _: Boolean
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     type Loser
       @key(fields: "id")
       @pgTable(name: "losers", schema: "b")
@@ -732,6 +752,10 @@ async fn globally_disabled_queries() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -748,6 +772,10 @@ async fn globally_disabled_queries() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -776,6 +804,10 @@ async fn globally_disabled_queries() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -792,6 +824,10 @@ async fn globally_disabled_queries() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type User
@@ -848,6 +884,10 @@ async fn globally_disabled_queries() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -864,6 +904,10 @@ async fn globally_disabled_queries() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -892,6 +936,10 @@ async fn globally_disabled_queries() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -908,6 +956,10 @@ async fn globally_disabled_queries() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Mutation {
@@ -941,6 +993,10 @@ async fn globally_disabled_queries() {
         Input for updating a User
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdatePayload! @pgUpdateOne
       """
       Update multiple users
@@ -954,6 +1010,10 @@ async fn globally_disabled_queries() {
         Input for updating multiple User instances
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique User
@@ -963,6 +1023,10 @@ async fn globally_disabled_queries() {
         Lookup input for unique User deletion
         """
         lookup: UserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeletePayload! @pgDeleteOne
       """
       Delete multiple users
@@ -972,6 +1036,10 @@ async fn globally_disabled_queries() {
         Filter for User deletion
         """
         filter: UserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeleteManyPayload! @pgDeleteMany
       """
       Create a single Loser
@@ -1003,6 +1071,10 @@ async fn globally_disabled_queries() {
         Input for updating a Loser
         """
         input: LoserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserUpdatePayload! @pgUpdateOne
       """
       Update multiple losers
@@ -1016,6 +1088,10 @@ async fn globally_disabled_queries() {
         Input for updating multiple Loser instances
         """
         input: LoserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique Loser
@@ -1025,6 +1101,10 @@ async fn globally_disabled_queries() {
         Lookup input for unique Loser deletion
         """
         lookup: LoserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserDeletePayload! @pgDeleteOne
       """
       Delete multiple losers
@@ -1034,6 +1114,10 @@ async fn globally_disabled_queries() {
         Filter for Loser deletion
         """
         filter: LoserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -1293,6 +1377,26 @@ async fn disable_mutations_per_schema() {
       _: Boolean
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     type Loser
       @key(fields: "id")
       @pgTable(name: "losers", schema: "b")
@@ -1425,6 +1529,10 @@ async fn disable_mutations_per_schema() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -1441,6 +1549,10 @@ async fn disable_mutations_per_schema() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -1483,6 +1595,10 @@ async fn disable_mutations_per_schema() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -1499,6 +1615,10 @@ async fn disable_mutations_per_schema() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Query {
@@ -1629,6 +1749,10 @@ async fn disable_mutations_per_schema() {
         Input for updating a User
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdatePayload! @pgUpdateOne
       """
       Update multiple users
@@ -1642,6 +1766,10 @@ async fn disable_mutations_per_schema() {
         Input for updating multiple User instances
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique User
@@ -1651,6 +1779,10 @@ async fn disable_mutations_per_schema() {
         Lookup input for unique User deletion
         """
         lookup: UserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeletePayload! @pgDeleteOne
       """
       Delete multiple users
@@ -1660,6 +1792,10 @@ async fn disable_mutations_per_schema() {
         Filter for User deletion
         """
         filter: UserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -1913,6 +2049,26 @@ async fn disable_queries_per_schema() {
       _: Boolean
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     type Loser
       @key(fields: "id")
       @pgTable(name: "losers", schema: "b")
@@ -1967,6 +2123,10 @@ async fn disable_queries_per_schema() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -1983,6 +2143,10 @@ async fn disable_queries_per_schema() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -2011,6 +2175,10 @@ async fn disable_queries_per_schema() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -2027,6 +2195,10 @@ async fn disable_queries_per_schema() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -2123,6 +2295,10 @@ async fn disable_queries_per_schema() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -2139,6 +2315,10 @@ async fn disable_queries_per_schema() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -2181,6 +2361,10 @@ async fn disable_queries_per_schema() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -2197,6 +2381,10 @@ async fn disable_queries_per_schema() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Query {
@@ -2280,6 +2468,10 @@ async fn disable_queries_per_schema() {
         Input for updating a User
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdatePayload! @pgUpdateOne
       """
       Update multiple users
@@ -2293,6 +2485,10 @@ async fn disable_queries_per_schema() {
         Input for updating multiple User instances
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique User
@@ -2302,6 +2498,10 @@ async fn disable_queries_per_schema() {
         Lookup input for unique User deletion
         """
         lookup: UserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeletePayload! @pgDeleteOne
       """
       Delete multiple users
@@ -2311,6 +2511,10 @@ async fn disable_queries_per_schema() {
         Filter for User deletion
         """
         filter: UserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeleteManyPayload! @pgDeleteMany
       """
       Create a single Loser
@@ -2342,6 +2546,10 @@ async fn disable_queries_per_schema() {
         Input for updating a Loser
         """
         input: LoserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserUpdatePayload! @pgUpdateOne
       """
       Update multiple losers
@@ -2355,6 +2563,10 @@ async fn disable_queries_per_schema() {
         Input for updating multiple Loser instances
         """
         input: LoserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique Loser
@@ -2364,6 +2576,10 @@ async fn disable_queries_per_schema() {
         Lookup input for unique Loser deletion
         """
         lookup: LoserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserDeletePayload! @pgDeleteOne
       """
       Delete multiple losers
@@ -2373,6 +2589,10 @@ async fn disable_queries_per_schema() {
         Filter for Loser deletion
         """
         filter: LoserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -2706,6 +2926,26 @@ async fn disable_mutations_per_table() {
       _: Boolean
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     type Loser
       @key(fields: "id")
       @pgTable(name: "losers", schema: "b")
@@ -2838,6 +3078,10 @@ async fn disable_mutations_per_table() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -2854,6 +3098,10 @@ async fn disable_mutations_per_table() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -2896,6 +3144,10 @@ async fn disable_mutations_per_table() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -2912,6 +3164,10 @@ async fn disable_mutations_per_table() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Woof
@@ -2984,6 +3240,10 @@ async fn disable_mutations_per_table() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -3000,6 +3260,10 @@ async fn disable_mutations_per_table() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -3042,6 +3306,10 @@ async fn disable_mutations_per_table() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -3058,6 +3326,10 @@ async fn disable_mutations_per_table() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Query {
@@ -3235,6 +3507,10 @@ async fn disable_mutations_per_table() {
         Input for updating a User
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdatePayload! @pgUpdateOne
       """
       Update multiple users
@@ -3248,6 +3524,10 @@ async fn disable_mutations_per_table() {
         Input for updating multiple User instances
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique User
@@ -3257,6 +3537,10 @@ async fn disable_mutations_per_table() {
         Lookup input for unique User deletion
         """
         lookup: UserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeletePayload! @pgDeleteOne
       """
       Delete multiple users
@@ -3266,6 +3550,10 @@ async fn disable_mutations_per_table() {
         Filter for User deletion
         """
         filter: UserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeleteManyPayload! @pgDeleteMany
       """
       Create a single Woof
@@ -3297,6 +3585,10 @@ async fn disable_mutations_per_table() {
         Input for updating a Woof
         """
         input: WoofUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): WoofUpdatePayload! @pgUpdateOne
       """
       Update multiple woofs
@@ -3310,6 +3602,10 @@ async fn disable_mutations_per_table() {
         Input for updating multiple Woof instances
         """
         input: WoofUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): WoofUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique Woof
@@ -3319,6 +3615,10 @@ async fn disable_mutations_per_table() {
         Lookup input for unique Woof deletion
         """
         lookup: WoofLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): WoofDeletePayload! @pgDeleteOne
       """
       Delete multiple woofs
@@ -3328,6 +3628,10 @@ async fn disable_mutations_per_table() {
         Filter for Woof deletion
         """
         filter: WoofFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): WoofDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -3655,6 +3959,26 @@ async fn disable_queries_per_table() {
       _: Boolean
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     type Loser
       @key(fields: "id")
       @pgTable(name: "losers", schema: "b")
@@ -3709,6 +4033,10 @@ async fn disable_queries_per_table() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -3725,6 +4053,10 @@ async fn disable_queries_per_table() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -3753,6 +4085,10 @@ async fn disable_queries_per_table() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -3769,6 +4105,10 @@ async fn disable_queries_per_table() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -3865,6 +4205,10 @@ async fn disable_queries_per_table() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -3881,6 +4225,10 @@ async fn disable_queries_per_table() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -3923,6 +4271,10 @@ async fn disable_queries_per_table() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -3939,6 +4291,10 @@ async fn disable_queries_per_table() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Woof
@@ -4011,6 +4367,10 @@ async fn disable_queries_per_table() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -4027,6 +4387,10 @@ async fn disable_queries_per_table() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -4069,6 +4433,10 @@ async fn disable_queries_per_table() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -4085,6 +4453,10 @@ async fn disable_queries_per_table() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Query {
@@ -4215,6 +4587,10 @@ async fn disable_queries_per_table() {
         Input for updating a User
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdatePayload! @pgUpdateOne
       """
       Update multiple users
@@ -4228,6 +4604,10 @@ async fn disable_queries_per_table() {
         Input for updating multiple User instances
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique User
@@ -4237,6 +4617,10 @@ async fn disable_queries_per_table() {
         Lookup input for unique User deletion
         """
         lookup: UserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeletePayload! @pgDeleteOne
       """
       Delete multiple users
@@ -4246,6 +4630,10 @@ async fn disable_queries_per_table() {
         Filter for User deletion
         """
         filter: UserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeleteManyPayload! @pgDeleteMany
       """
       Create a single Loser
@@ -4277,6 +4665,10 @@ async fn disable_queries_per_table() {
         Input for updating a Loser
         """
         input: LoserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserUpdatePayload! @pgUpdateOne
       """
       Update multiple losers
@@ -4290,6 +4682,10 @@ async fn disable_queries_per_table() {
         Input for updating multiple Loser instances
         """
         input: LoserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique Loser
@@ -4299,6 +4695,10 @@ async fn disable_queries_per_table() {
         Lookup input for unique Loser deletion
         """
         lookup: LoserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserDeletePayload! @pgDeleteOne
       """
       Delete multiple losers
@@ -4308,6 +4708,10 @@ async fn disable_queries_per_table() {
         Filter for Loser deletion
         """
         filter: LoserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserDeleteManyPayload! @pgDeleteMany
       """
       Create a single Woof
@@ -4339,6 +4743,10 @@ async fn disable_queries_per_table() {
         Input for updating a Woof
         """
         input: WoofUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): WoofUpdatePayload! @pgUpdateOne
       """
       Update multiple woofs
@@ -4352,6 +4760,10 @@ async fn disable_queries_per_table() {
         Input for updating multiple Woof instances
         """
         input: WoofUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): WoofUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique Woof
@@ -4361,6 +4773,10 @@ async fn disable_queries_per_table() {
         Lookup input for unique Woof deletion
         """
         lookup: WoofLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): WoofDeletePayload! @pgDeleteOne
       """
       Delete multiple woofs
@@ -4370,6 +4786,10 @@ async fn disable_queries_per_table() {
         Filter for Woof deletion
         """
         filter: WoofFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): WoofDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -4631,6 +5051,26 @@ async fn schema_mutations_setting_takes_precedence_over_global_setting() {
       id: OrderDirection
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     type Loser
       @key(fields: "id")
       @pgTable(name: "losers", schema: "b")
@@ -4701,6 +5141,10 @@ async fn schema_mutations_setting_takes_precedence_over_global_setting() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -4717,6 +5161,10 @@ async fn schema_mutations_setting_takes_precedence_over_global_setting() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -4759,6 +5207,10 @@ async fn schema_mutations_setting_takes_precedence_over_global_setting() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -4775,6 +5227,10 @@ async fn schema_mutations_setting_takes_precedence_over_global_setting() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -4967,6 +5423,10 @@ async fn schema_mutations_setting_takes_precedence_over_global_setting() {
         Input for updating a Loser
         """
         input: LoserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserUpdatePayload! @pgUpdateOne
       """
       Update multiple losers
@@ -4980,6 +5440,10 @@ async fn schema_mutations_setting_takes_precedence_over_global_setting() {
         Input for updating multiple Loser instances
         """
         input: LoserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique Loser
@@ -4989,6 +5453,10 @@ async fn schema_mutations_setting_takes_precedence_over_global_setting() {
         Lookup input for unique Loser deletion
         """
         lookup: LoserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserDeletePayload! @pgDeleteOne
       """
       Delete multiple losers
@@ -4998,6 +5466,10 @@ async fn schema_mutations_setting_takes_precedence_over_global_setting() {
         Filter for Loser deletion
         """
         filter: LoserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -5253,14 +5725,34 @@ async fn schema_queries_setting_takes_precedence_over_global_setting() {
       _: Boolean
     }
 
-    type Loser
-      @key(fields: "id")
-      @pgTable(name: "losers", schema: "b")
-      @pgKey(fields: ["id"], type: PRIMARY)
-    {
-      id: Int! @pgColumn(name: "id", type: INT)
-    }
-
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
+    type Loser
+      @key(fields: "id")
+      @pgTable(name: "losers", schema: "b")
+      @pgKey(fields: ["id"], type: PRIMARY)
+    {
+      id: Int! @pgColumn(name: "id", type: INT)
+    }
+
     """
     The connection type for Loser
     """
@@ -5323,6 +5815,10 @@ async fn schema_queries_setting_takes_precedence_over_global_setting() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -5339,6 +5835,10 @@ async fn schema_queries_setting_takes_precedence_over_global_setting() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -5381,6 +5881,10 @@ async fn schema_queries_setting_takes_precedence_over_global_setting() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -5397,6 +5901,10 @@ async fn schema_queries_setting_takes_precedence_over_global_setting() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -5477,6 +5985,10 @@ async fn schema_queries_setting_takes_precedence_over_global_setting() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -5493,6 +6005,10 @@ async fn schema_queries_setting_takes_precedence_over_global_setting() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -5521,6 +6037,10 @@ async fn schema_queries_setting_takes_precedence_over_global_setting() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -5537,6 +6057,10 @@ async fn schema_queries_setting_takes_precedence_over_global_setting() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Query {
@@ -5620,6 +6144,10 @@ async fn schema_queries_setting_takes_precedence_over_global_setting() {
         Input for updating a User
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdatePayload! @pgUpdateOne
       """
       Update multiple users
@@ -5633,6 +6161,10 @@ async fn schema_queries_setting_takes_precedence_over_global_setting() {
         Input for updating multiple User instances
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique User
@@ -5642,6 +6174,10 @@ async fn schema_queries_setting_takes_precedence_over_global_setting() {
         Lookup input for unique User deletion
         """
         lookup: UserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeletePayload! @pgDeleteOne
       """
       Delete multiple users
@@ -5651,6 +6187,10 @@ async fn schema_queries_setting_takes_precedence_over_global_setting() {
         Filter for User deletion
         """
         filter: UserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeleteManyPayload! @pgDeleteMany
       """
       Create a single Loser
@@ -5682,6 +6222,10 @@ async fn schema_queries_setting_takes_precedence_over_global_setting() {
         Input for updating a Loser
         """
         input: LoserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserUpdatePayload! @pgUpdateOne
       """
       Update multiple losers
@@ -5695,6 +6239,10 @@ async fn schema_queries_setting_takes_precedence_over_global_setting() {
         Input for updating multiple Loser instances
         """
         input: LoserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique Loser
@@ -5704,6 +6252,10 @@ async fn schema_queries_setting_takes_precedence_over_global_setting() {
         Lookup input for unique Loser deletion
         """
         lookup: LoserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserDeletePayload! @pgDeleteOne
       """
       Delete multiple losers
@@ -5713,6 +6265,10 @@ async fn schema_queries_setting_takes_precedence_over_global_setting() {
         Filter for Loser deletion
         """
         filter: LoserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -5974,6 +6530,26 @@ async fn table_mutations_setting_takes_precedence_over_global_setting() {
       id: OrderDirection
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     type Loser
       @key(fields: "id")
       @pgTable(name: "losers", schema: "b")
@@ -6044,6 +6620,10 @@ async fn table_mutations_setting_takes_precedence_over_global_setting() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -6060,6 +6640,10 @@ async fn table_mutations_setting_takes_precedence_over_global_setting() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -6102,6 +6686,10 @@ async fn table_mutations_setting_takes_precedence_over_global_setting() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -6118,6 +6706,10 @@ async fn table_mutations_setting_takes_precedence_over_global_setting() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -6310,6 +6902,10 @@ async fn table_mutations_setting_takes_precedence_over_global_setting() {
         Input for updating a Loser
         """
         input: LoserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserUpdatePayload! @pgUpdateOne
       """
       Update multiple losers
@@ -6323,6 +6919,10 @@ async fn table_mutations_setting_takes_precedence_over_global_setting() {
         Input for updating multiple Loser instances
         """
         input: LoserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique Loser
@@ -6332,6 +6932,10 @@ async fn table_mutations_setting_takes_precedence_over_global_setting() {
         Lookup input for unique Loser deletion
         """
         lookup: LoserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserDeletePayload! @pgDeleteOne
       """
       Delete multiple losers
@@ -6341,6 +6945,10 @@ async fn table_mutations_setting_takes_precedence_over_global_setting() {
         Filter for Loser deletion
         """
         filter: LoserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -6596,6 +7204,26 @@ async fn table_queries_setting_takes_precedence_over_global_setting() {
       _: Boolean
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     type Loser
       @key(fields: "id")
       @pgTable(name: "losers", schema: "b")
@@ -6666,6 +7294,10 @@ async fn table_queries_setting_takes_precedence_over_global_setting() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -6682,6 +7314,10 @@ async fn table_queries_setting_takes_precedence_over_global_setting() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -6724,6 +7360,10 @@ async fn table_queries_setting_takes_precedence_over_global_setting() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -6740,6 +7380,10 @@ async fn table_queries_setting_takes_precedence_over_global_setting() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -6820,6 +7464,10 @@ async fn table_queries_setting_takes_precedence_over_global_setting() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -6836,6 +7484,10 @@ async fn table_queries_setting_takes_precedence_over_global_setting() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -6864,6 +7516,10 @@ async fn table_queries_setting_takes_precedence_over_global_setting() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -6880,6 +7536,10 @@ async fn table_queries_setting_takes_precedence_over_global_setting() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Query {
@@ -6963,6 +7623,10 @@ async fn table_queries_setting_takes_precedence_over_global_setting() {
         Input for updating a User
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdatePayload! @pgUpdateOne
       """
       Update multiple users
@@ -6976,6 +7640,10 @@ async fn table_queries_setting_takes_precedence_over_global_setting() {
         Input for updating multiple User instances
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique User
@@ -6985,6 +7653,10 @@ async fn table_queries_setting_takes_precedence_over_global_setting() {
         Lookup input for unique User deletion
         """
         lookup: UserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeletePayload! @pgDeleteOne
       """
       Delete multiple users
@@ -6994,6 +7666,10 @@ async fn table_queries_setting_takes_precedence_over_global_setting() {
         Filter for User deletion
         """
         filter: UserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeleteManyPayload! @pgDeleteMany
       """
       Create a single Loser
@@ -7025,6 +7701,10 @@ async fn table_queries_setting_takes_precedence_over_global_setting() {
         Input for updating a Loser
         """
         input: LoserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserUpdatePayload! @pgUpdateOne
       """
       Update multiple losers
@@ -7038,6 +7718,10 @@ async fn table_queries_setting_takes_precedence_over_global_setting() {
         Input for updating multiple Loser instances
         """
         input: LoserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique Loser
@@ -7047,6 +7731,10 @@ async fn table_queries_setting_takes_precedence_over_global_setting() {
         Lookup input for unique Loser deletion
         """
         lookup: LoserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserDeletePayload! @pgDeleteOne
       """
       Delete multiple losers
@@ -7056,6 +7744,10 @@ async fn table_queries_setting_takes_precedence_over_global_setting() {
         Filter for Loser deletion
         """
         filter: LoserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -7394,6 +8086,26 @@ async fn table_mutations_setting_takes_precedence_over_schema_setting() {
       id: OrderDirection
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     type Loser
       @key(fields: "id")
       @pgTable(name: "losers", schema: "b")
@@ -7464,6 +8176,10 @@ async fn table_mutations_setting_takes_precedence_over_schema_setting() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -7480,6 +8196,10 @@ async fn table_mutations_setting_takes_precedence_over_schema_setting() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -7522,6 +8242,10 @@ async fn table_mutations_setting_takes_precedence_over_schema_setting() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -7538,6 +8262,10 @@ async fn table_mutations_setting_takes_precedence_over_schema_setting() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -7634,6 +8362,10 @@ async fn table_mutations_setting_takes_precedence_over_schema_setting() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -7650,6 +8382,10 @@ async fn table_mutations_setting_takes_precedence_over_schema_setting() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -7692,6 +8428,10 @@ async fn table_mutations_setting_takes_precedence_over_schema_setting() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -7708,6 +8448,10 @@ async fn table_mutations_setting_takes_precedence_over_schema_setting() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Woof
@@ -7923,6 +8667,10 @@ async fn table_mutations_setting_takes_precedence_over_schema_setting() {
         Input for updating a User
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdatePayload! @pgUpdateOne
       """
       Update multiple users
@@ -7936,6 +8684,10 @@ async fn table_mutations_setting_takes_precedence_over_schema_setting() {
         Input for updating multiple User instances
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique User
@@ -7945,6 +8697,10 @@ async fn table_mutations_setting_takes_precedence_over_schema_setting() {
         Lookup input for unique User deletion
         """
         lookup: UserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeletePayload! @pgDeleteOne
       """
       Delete multiple users
@@ -7954,6 +8710,10 @@ async fn table_mutations_setting_takes_precedence_over_schema_setting() {
         Filter for User deletion
         """
         filter: UserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeleteManyPayload! @pgDeleteMany
       """
       Create a single Loser
@@ -7985,6 +8745,10 @@ async fn table_mutations_setting_takes_precedence_over_schema_setting() {
         Input for updating a Loser
         """
         input: LoserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserUpdatePayload! @pgUpdateOne
       """
       Update multiple losers
@@ -7998,6 +8762,10 @@ async fn table_mutations_setting_takes_precedence_over_schema_setting() {
         Input for updating multiple Loser instances
         """
         input: LoserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique Loser
@@ -8007,6 +8775,10 @@ async fn table_mutations_setting_takes_precedence_over_schema_setting() {
         Lookup input for unique Loser deletion
         """
         lookup: LoserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserDeletePayload! @pgDeleteOne
       """
       Delete multiple losers
@@ -8016,6 +8788,10 @@ async fn table_mutations_setting_takes_precedence_over_schema_setting() {
         Filter for Loser deletion
         """
         filter: LoserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -8348,6 +9124,26 @@ async fn table_queries_setting_takes_precedence_over_schema_setting() {
       _: Boolean
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     type Loser
       @key(fields: "id")
       @pgTable(name: "losers", schema: "b")
@@ -8418,6 +9214,10 @@ async fn table_queries_setting_takes_precedence_over_schema_setting() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -8434,6 +9234,10 @@ async fn table_queries_setting_takes_precedence_over_schema_setting() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -8476,6 +9280,10 @@ async fn table_queries_setting_takes_precedence_over_schema_setting() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -8492,6 +9300,10 @@ async fn table_queries_setting_takes_precedence_over_schema_setting() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -8588,6 +9400,10 @@ async fn table_queries_setting_takes_precedence_over_schema_setting() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -8604,6 +9420,10 @@ async fn table_queries_setting_takes_precedence_over_schema_setting() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -8646,6 +9466,10 @@ async fn table_queries_setting_takes_precedence_over_schema_setting() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -8662,6 +9486,10 @@ async fn table_queries_setting_takes_precedence_over_schema_setting() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Woof
@@ -8718,6 +9546,10 @@ async fn table_queries_setting_takes_precedence_over_schema_setting() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -8734,6 +9566,10 @@ async fn table_queries_setting_takes_precedence_over_schema_setting() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -8762,6 +9598,10 @@ async fn table_queries_setting_takes_precedence_over_schema_setting() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -8778,6 +9618,10 @@ async fn table_queries_setting_takes_precedence_over_schema_setting() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Query {
@@ -8908,6 +9752,10 @@ async fn table_queries_setting_takes_precedence_over_schema_setting() {
         Input for updating a User
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdatePayload! @pgUpdateOne
       """
       Update multiple users
@@ -8921,6 +9769,10 @@ async fn table_queries_setting_takes_precedence_over_schema_setting() {
         Input for updating multiple User instances
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique User
@@ -8930,6 +9782,10 @@ async fn table_queries_setting_takes_precedence_over_schema_setting() {
         Lookup input for unique User deletion
         """
         lookup: UserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeletePayload! @pgDeleteOne
       """
       Delete multiple users
@@ -8939,6 +9795,10 @@ async fn table_queries_setting_takes_precedence_over_schema_setting() {
         Filter for User deletion
         """
         filter: UserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeleteManyPayload! @pgDeleteMany
       """
       Create a single Loser
@@ -8970,6 +9830,10 @@ async fn table_queries_setting_takes_precedence_over_schema_setting() {
         Input for updating a Loser
         """
         input: LoserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserUpdatePayload! @pgUpdateOne
       """
       Update multiple losers
@@ -8983,6 +9847,10 @@ async fn table_queries_setting_takes_precedence_over_schema_setting() {
         Input for updating multiple Loser instances
         """
         input: LoserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique Loser
@@ -8992,6 +9860,10 @@ async fn table_queries_setting_takes_precedence_over_schema_setting() {
         Lookup input for unique Loser deletion
         """
         lookup: LoserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserDeletePayload! @pgDeleteOne
       """
       Delete multiple losers
@@ -9001,6 +9873,10 @@ async fn table_queries_setting_takes_precedence_over_schema_setting() {
         Filter for Loser deletion
         """
         filter: LoserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserDeleteManyPayload! @pgDeleteMany
       """
       Create a single Woof
@@ -9032,6 +9908,10 @@ async fn table_queries_setting_takes_precedence_over_schema_setting() {
         Input for updating a Woof
         """
         input: WoofUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): WoofUpdatePayload! @pgUpdateOne
       """
       Update multiple woofs
@@ -9045,6 +9925,10 @@ async fn table_queries_setting_takes_precedence_over_schema_setting() {
         Input for updating multiple Woof instances
         """
         input: WoofUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): WoofUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique Woof
@@ -9054,6 +9938,10 @@ async fn table_queries_setting_takes_precedence_over_schema_setting() {
         Lookup input for unique Woof deletion
         """
         lookup: WoofLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): WoofDeletePayload! @pgDeleteOne
       """
       Delete multiple woofs
@@ -9063,6 +9951,10 @@ async fn table_queries_setting_takes_precedence_over_schema_setting() {
         Filter for Woof deletion
         """
         filter: WoofFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): WoofDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -9300,6 +10192,26 @@ async fn disable_queries_globally_for_views() {
       _: Boolean
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     type Loser
       @key(fields: "id")
       @pgTable(name: "losers", schema: "b")
@@ -9354,6 +10266,10 @@ async fn disable_queries_globally_for_views() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -9370,6 +10286,10 @@ async fn disable_queries_globally_for_views() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -9398,6 +10318,10 @@ async fn disable_queries_globally_for_views() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -9414,6 +10338,10 @@ async fn disable_queries_globally_for_views() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Meow
@@ -9478,6 +10406,10 @@ async fn disable_queries_globally_for_views() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -9494,6 +10426,10 @@ async fn disable_queries_globally_for_views() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -9522,6 +10458,10 @@ async fn disable_queries_globally_for_views() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -9538,6 +10478,10 @@ async fn disable_queries_globally_for_views() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Woof
@@ -9579,6 +10523,10 @@ async fn disable_queries_globally_for_views() {
         Input for updating a User
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdatePayload! @pgUpdateOne
       """
       Update multiple users
@@ -9592,6 +10540,10 @@ async fn disable_queries_globally_for_views() {
         Input for updating multiple User instances
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique User
@@ -9601,6 +10553,10 @@ async fn disable_queries_globally_for_views() {
         Lookup input for unique User deletion
         """
         lookup: UserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeletePayload! @pgDeleteOne
       """
       Delete multiple users
@@ -9610,6 +10566,10 @@ async fn disable_queries_globally_for_views() {
         Filter for User deletion
         """
         filter: UserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeleteManyPayload! @pgDeleteMany
       """
       Create a single Loser
@@ -9641,6 +10601,10 @@ async fn disable_queries_globally_for_views() {
         Input for updating a Loser
         """
         input: LoserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserUpdatePayload! @pgUpdateOne
       """
       Update multiple losers
@@ -9654,6 +10618,10 @@ async fn disable_queries_globally_for_views() {
         Input for updating multiple Loser instances
         """
         input: LoserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique Loser
@@ -9663,6 +10631,10 @@ async fn disable_queries_globally_for_views() {
         Lookup input for unique Loser deletion
         """
         lookup: LoserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserDeletePayload! @pgDeleteOne
       """
       Delete multiple losers
@@ -9672,6 +10644,10 @@ async fn disable_queries_globally_for_views() {
         Filter for Loser deletion
         """
         filter: LoserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -10017,6 +10993,26 @@ async fn disable_queries_per_view() {
       _: Boolean
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     type Loser
       @key(fields: "id")
       @pgTable(name: "losers", schema: "b")
@@ -10087,6 +11083,10 @@ async fn disable_queries_per_view() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -10103,6 +11103,10 @@ async fn disable_queries_per_view() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -10145,6 +11149,10 @@ async fn disable_queries_per_view() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -10161,6 +11169,10 @@ async fn disable_queries_per_view() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Meow
@@ -10295,6 +11307,10 @@ async fn disable_queries_per_view() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -10311,6 +11327,10 @@ async fn disable_queries_per_view() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -10353,6 +11373,10 @@ async fn disable_queries_per_view() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -10369,6 +11393,10 @@ async fn disable_queries_per_view() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Woof
@@ -10554,6 +11582,10 @@ async fn disable_queries_per_view() {
         Input for updating a User
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdatePayload! @pgUpdateOne
       """
       Update multiple users
@@ -10567,6 +11599,10 @@ async fn disable_queries_per_view() {
         Input for updating multiple User instances
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique User
@@ -10576,6 +11612,10 @@ async fn disable_queries_per_view() {
         Lookup input for unique User deletion
         """
         lookup: UserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeletePayload! @pgDeleteOne
       """
       Delete multiple users
@@ -10585,6 +11625,10 @@ async fn disable_queries_per_view() {
         Filter for User deletion
         """
         filter: UserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeleteManyPayload! @pgDeleteMany
       """
       Create a single Loser
@@ -10616,6 +11660,10 @@ async fn disable_queries_per_view() {
         Input for updating a Loser
         """
         input: LoserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserUpdatePayload! @pgUpdateOne
       """
       Update multiple losers
@@ -10629,6 +11677,10 @@ async fn disable_queries_per_view() {
         Input for updating multiple Loser instances
         """
         input: LoserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique Loser
@@ -10638,6 +11690,10 @@ async fn disable_queries_per_view() {
         Lookup input for unique Loser deletion
         """
         lookup: LoserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserDeletePayload! @pgDeleteOne
       """
       Delete multiple losers
@@ -10647,6 +11703,10 @@ async fn disable_queries_per_view() {
         Filter for Loser deletion
         """
         filter: LoserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -10869,6 +11929,26 @@ async fn schema_allowlist() {
       _: Boolean
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     """
     Information about pagination in a collection of objects
     """
@@ -10963,6 +12043,10 @@ async fn schema_allowlist() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -10979,6 +12063,10 @@ async fn schema_allowlist() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -11021,6 +12109,10 @@ async fn schema_allowlist() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -11037,6 +12129,10 @@ async fn schema_allowlist() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Query {
@@ -11120,6 +12216,10 @@ async fn schema_allowlist() {
         Input for updating a User
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdatePayload! @pgUpdateOne
       """
       Update multiple users
@@ -11133,6 +12233,10 @@ async fn schema_allowlist() {
         Input for updating multiple User instances
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique User
@@ -11142,6 +12246,10 @@ async fn schema_allowlist() {
         Lookup input for unique User deletion
         """
         lookup: UserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeletePayload! @pgDeleteOne
       """
       Delete multiple users
@@ -11151,6 +12259,10 @@ async fn schema_allowlist() {
         Filter for User deletion
         """
         filter: UserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -11360,6 +12472,26 @@ async fn schema_allowlist_should_hide_relations() {
       _: Boolean
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     """
     Information about pagination in a collection of objects
     """
@@ -11454,6 +12586,10 @@ async fn schema_allowlist_should_hide_relations() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -11470,6 +12606,10 @@ async fn schema_allowlist_should_hide_relations() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -11512,6 +12652,10 @@ async fn schema_allowlist_should_hide_relations() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -11528,6 +12672,10 @@ async fn schema_allowlist_should_hide_relations() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Query {
@@ -11611,6 +12759,10 @@ async fn schema_allowlist_should_hide_relations() {
         Input for updating a User
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdatePayload! @pgUpdateOne
       """
       Update multiple users
@@ -11624,6 +12776,10 @@ async fn schema_allowlist_should_hide_relations() {
         Input for updating multiple User instances
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique User
@@ -11633,6 +12789,10 @@ async fn schema_allowlist_should_hide_relations() {
         Lookup input for unique User deletion
         """
         lookup: UserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeletePayload! @pgDeleteOne
       """
       Delete multiple users
@@ -11642,6 +12802,10 @@ async fn schema_allowlist_should_hide_relations() {
         Filter for User deletion
         """
         filter: UserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -11930,6 +13094,26 @@ async fn schema_denylist() {
       _: Boolean
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     type Loser
       @key(fields: "id")
       @pgTable(name: "losers", schema: "private")
@@ -12000,6 +13184,10 @@ async fn schema_denylist() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -12016,6 +13204,10 @@ async fn schema_denylist() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -12058,6 +13250,10 @@ async fn schema_denylist() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -12074,6 +13270,10 @@ async fn schema_denylist() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -12170,6 +13370,10 @@ async fn schema_denylist() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -12186,6 +13390,10 @@ async fn schema_denylist() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -12228,6 +13436,10 @@ async fn schema_denylist() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -12244,6 +13456,10 @@ async fn schema_denylist() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Query {
@@ -12374,6 +13590,10 @@ async fn schema_denylist() {
         Input for updating a Post
         """
         input: PostUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): PostUpdatePayload! @pgUpdateOne
       """
       Update multiple posts
@@ -12387,6 +13607,10 @@ async fn schema_denylist() {
         Input for updating multiple Post instances
         """
         input: PostUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): PostUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique Post
@@ -12396,6 +13620,10 @@ async fn schema_denylist() {
         Lookup input for unique Post deletion
         """
         lookup: PostLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): PostDeletePayload! @pgDeleteOne
       """
       Delete multiple posts
@@ -12405,6 +13633,10 @@ async fn schema_denylist() {
         Filter for Post deletion
         """
         filter: PostFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): PostDeleteManyPayload! @pgDeleteMany
       """
       Create a single Loser
@@ -12436,6 +13668,10 @@ async fn schema_denylist() {
         Input for updating a Loser
         """
         input: LoserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserUpdatePayload! @pgUpdateOne
       """
       Update multiple losers
@@ -12449,6 +13685,10 @@ async fn schema_denylist() {
         Input for updating multiple Loser instances
         """
         input: LoserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique Loser
@@ -12458,6 +13698,10 @@ async fn schema_denylist() {
         Lookup input for unique Loser deletion
         """
         lookup: LoserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserDeletePayload! @pgDeleteOne
       """
       Delete multiple losers
@@ -12467,6 +13711,10 @@ async fn schema_denylist() {
         Filter for Loser deletion
         """
         filter: LoserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -12693,6 +13941,26 @@ async fn schema_allowlist_and_denylist_precedence() {
       _: Boolean
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     """
     Information about pagination in a collection of objects
     """
@@ -12787,6 +14055,10 @@ async fn schema_allowlist_and_denylist_precedence() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -12803,6 +14075,10 @@ async fn schema_allowlist_and_denylist_precedence() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -12845,6 +14121,10 @@ async fn schema_allowlist_and_denylist_precedence() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -12861,6 +14141,10 @@ async fn schema_allowlist_and_denylist_precedence() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Query {
@@ -12944,6 +14228,10 @@ async fn schema_allowlist_and_denylist_precedence() {
         Input for updating a User
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdatePayload! @pgUpdateOne
       """
       Update multiple users
@@ -12957,6 +14245,10 @@ async fn schema_allowlist_and_denylist_precedence() {
         Input for updating multiple User instances
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique User
@@ -12966,6 +14258,10 @@ async fn schema_allowlist_and_denylist_precedence() {
         Lookup input for unique User deletion
         """
         lookup: UserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeletePayload! @pgDeleteOne
       """
       Delete multiple users
@@ -12975,6 +14271,10 @@ async fn schema_allowlist_and_denylist_precedence() {
         Filter for User deletion
         """
         filter: UserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -13364,6 +14664,26 @@ async fn schema_multiple_allowlists() {
       _: Boolean
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     type Loser
       @key(fields: "id")
       @pgTable(name: "losers", schema: "private")
@@ -13434,6 +14754,10 @@ async fn schema_multiple_allowlists() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -13450,6 +14774,10 @@ async fn schema_multiple_allowlists() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -13492,6 +14820,10 @@ async fn schema_multiple_allowlists() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -13508,6 +14840,10 @@ async fn schema_multiple_allowlists() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -13604,6 +14940,10 @@ async fn schema_multiple_allowlists() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -13620,6 +14960,10 @@ async fn schema_multiple_allowlists() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -13662,6 +15006,10 @@ async fn schema_multiple_allowlists() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -13678,6 +15026,10 @@ async fn schema_multiple_allowlists() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Query {
@@ -13808,6 +15160,10 @@ async fn schema_multiple_allowlists() {
         Input for updating a Post
         """
         input: PostUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): PostUpdatePayload! @pgUpdateOne
       """
       Update multiple posts
@@ -13821,6 +15177,10 @@ async fn schema_multiple_allowlists() {
         Input for updating multiple Post instances
         """
         input: PostUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): PostUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique Post
@@ -13830,6 +15190,10 @@ async fn schema_multiple_allowlists() {
         Lookup input for unique Post deletion
         """
         lookup: PostLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): PostDeletePayload! @pgDeleteOne
       """
       Delete multiple posts
@@ -13839,6 +15203,10 @@ async fn schema_multiple_allowlists() {
         Filter for Post deletion
         """
         filter: PostFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): PostDeleteManyPayload! @pgDeleteMany
       """
       Create a single Loser
@@ -13870,6 +15238,10 @@ async fn schema_multiple_allowlists() {
         Input for updating a Loser
         """
         input: LoserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserUpdatePayload! @pgUpdateOne
       """
       Update multiple losers
@@ -13883,6 +15255,10 @@ async fn schema_multiple_allowlists() {
         Input for updating multiple Loser instances
         """
         input: LoserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique Loser
@@ -13892,6 +15268,10 @@ async fn schema_multiple_allowlists() {
         Lookup input for unique Loser deletion
         """
         lookup: LoserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserDeletePayload! @pgDeleteOne
       """
       Delete multiple losers
@@ -13901,6 +15281,10 @@ async fn schema_multiple_allowlists() {
         Filter for Loser deletion
         """
         filter: LoserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -14195,14 +15579,34 @@ async fn schema_multiple_denylists() {
       """
       Order users by id
       """
-      id: OrderDirection
-    }
-
-    """
-    Input for updating an existing User
-    """
-    input UserUpdateInput {
-      _: Boolean
+      id: OrderDirection
+    }
+
+    """
+    Input for updating an existing User
+    """
+    input UserUpdateInput {
+      _: Boolean
+    }
+
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
     }
 
     """
@@ -14299,6 +15703,10 @@ async fn schema_multiple_denylists() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -14315,6 +15723,10 @@ async fn schema_multiple_denylists() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -14357,6 +15769,10 @@ async fn schema_multiple_denylists() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -14373,6 +15789,10 @@ async fn schema_multiple_denylists() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type User
@@ -14445,6 +15865,10 @@ async fn schema_multiple_denylists() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -14461,6 +15885,10 @@ async fn schema_multiple_denylists() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -14503,6 +15931,10 @@ async fn schema_multiple_denylists() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -14519,6 +15951,10 @@ async fn schema_multiple_denylists() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Query {
@@ -14649,6 +16085,10 @@ async fn schema_multiple_denylists() {
         Input for updating a Setting
         """
         input: SettingUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): SettingUpdatePayload! @pgUpdateOne
       """
       Update multiple settings
@@ -14662,6 +16102,10 @@ async fn schema_multiple_denylists() {
         Input for updating multiple Setting instances
         """
         input: SettingUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): SettingUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique Setting
@@ -14671,6 +16115,10 @@ async fn schema_multiple_denylists() {
         Lookup input for unique Setting deletion
         """
         lookup: SettingLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): SettingDeletePayload! @pgDeleteOne
       """
       Delete multiple settings
@@ -14680,6 +16128,10 @@ async fn schema_multiple_denylists() {
         Filter for Setting deletion
         """
         filter: SettingFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): SettingDeleteManyPayload! @pgDeleteMany
       """
       Create a single User
@@ -14711,6 +16163,10 @@ async fn schema_multiple_denylists() {
         Input for updating a User
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdatePayload! @pgUpdateOne
       """
       Update multiple users
@@ -14724,6 +16180,10 @@ async fn schema_multiple_denylists() {
         Input for updating multiple User instances
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique User
@@ -14733,6 +16193,10 @@ async fn schema_multiple_denylists() {
         Lookup input for unique User deletion
         """
         lookup: UserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeletePayload! @pgDeleteOne
       """
       Delete multiple users
@@ -14742,6 +16206,10 @@ async fn schema_multiple_denylists() {
         Filter for User deletion
         """
         filter: UserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -14953,6 +16421,26 @@ async fn schema_denylist_should_hide_relations() {
       _: Boolean
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     type Loser
       @key(fields: "id")
       @pgTable(name: "losers", schema: "private")
@@ -15023,6 +16511,10 @@ async fn schema_denylist_should_hide_relations() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -15039,6 +16531,10 @@ async fn schema_denylist_should_hide_relations() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -15081,6 +16577,10 @@ async fn schema_denylist_should_hide_relations() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -15097,6 +16597,10 @@ async fn schema_denylist_should_hide_relations() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -15204,6 +16708,10 @@ async fn schema_denylist_should_hide_relations() {
         Input for updating a Loser
         """
         input: LoserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserUpdatePayload! @pgUpdateOne
       """
       Update multiple losers
@@ -15217,6 +16725,10 @@ async fn schema_denylist_should_hide_relations() {
         Input for updating multiple Loser instances
         """
         input: LoserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique Loser
@@ -15226,6 +16738,10 @@ async fn schema_denylist_should_hide_relations() {
         Lookup input for unique Loser deletion
         """
         lookup: LoserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserDeletePayload! @pgDeleteOne
       """
       Delete multiple losers
@@ -15235,6 +16751,10 @@ async fn schema_denylist_should_hide_relations() {
         Filter for Loser deletion
         """
         filter: LoserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -15447,6 +16967,26 @@ async fn table_allowlist() {
       _: Boolean
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     type Loser
       @key(fields: "id")
       @pgTable(name: "losers")
@@ -15517,6 +17057,10 @@ async fn table_allowlist() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -15533,6 +17077,10 @@ async fn table_allowlist() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -15575,6 +17123,10 @@ async fn table_allowlist() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -15591,6 +17143,10 @@ async fn table_allowlist() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -15698,6 +17254,10 @@ async fn table_allowlist() {
         Input for updating a Loser
         """
         input: LoserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserUpdatePayload! @pgUpdateOne
       """
       Update multiple losers
@@ -15711,6 +17271,10 @@ async fn table_allowlist() {
         Input for updating multiple Loser instances
         """
         input: LoserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique Loser
@@ -15720,6 +17284,10 @@ async fn table_allowlist() {
         Lookup input for unique Loser deletion
         """
         lookup: LoserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserDeletePayload! @pgDeleteOne
       """
       Delete multiple losers
@@ -15729,6 +17297,10 @@ async fn table_allowlist() {
         Filter for Loser deletion
         """
         filter: LoserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): LoserDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -16007,6 +17579,26 @@ async fn table_denylist() {
       _: Boolean
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     """
     Information about pagination in a collection of objects
     """
@@ -16101,6 +17693,10 @@ async fn table_denylist() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -16117,6 +17713,10 @@ async fn table_denylist() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -16159,6 +17759,10 @@ async fn table_denylist() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -16175,6 +17779,10 @@ async fn table_denylist() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type User
@@ -16247,6 +17855,10 @@ async fn table_denylist() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -16263,6 +17875,10 @@ async fn table_denylist() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -16305,6 +17921,10 @@ async fn table_denylist() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -16321,6 +17941,10 @@ async fn table_denylist() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Query {
@@ -16451,6 +18075,10 @@ async fn table_denylist() {
         Input for updating a Post
         """
         input: PostUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): PostUpdatePayload! @pgUpdateOne
       """
       Update multiple posts
@@ -16464,6 +18092,10 @@ async fn table_denylist() {
         Input for updating multiple Post instances
         """
         input: PostUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): PostUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique Post
@@ -16473,6 +18105,10 @@ async fn table_denylist() {
         Lookup input for unique Post deletion
         """
         lookup: PostLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): PostDeletePayload! @pgDeleteOne
       """
       Delete multiple posts
@@ -16482,6 +18118,10 @@ async fn table_denylist() {
         Filter for Post deletion
         """
         filter: PostFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): PostDeleteManyPayload! @pgDeleteMany
       """
       Create a single User
@@ -16513,6 +18153,10 @@ async fn table_denylist() {
         Input for updating a User
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdatePayload! @pgUpdateOne
       """
       Update multiple users
@@ -16526,6 +18170,10 @@ async fn table_denylist() {
         Input for updating multiple User instances
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique User
@@ -16535,6 +18183,10 @@ async fn table_denylist() {
         Lookup input for unique User deletion
         """
         lookup: UserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeletePayload! @pgDeleteOne
       """
       Delete multiple users
@@ -16544,6 +18196,10 @@ async fn table_denylist() {
         Filter for User deletion
         """
         filter: UserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -16757,6 +18413,26 @@ async fn allowlist_denylist_precedence() {
       _: Boolean
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     """
     Information about pagination in a collection of objects
     """
@@ -16851,6 +18527,10 @@ async fn allowlist_denylist_precedence() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -16867,6 +18547,10 @@ async fn allowlist_denylist_precedence() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -16909,6 +18593,10 @@ async fn allowlist_denylist_precedence() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -16925,6 +18613,10 @@ async fn allowlist_denylist_precedence() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Query {
@@ -17008,6 +18700,10 @@ async fn allowlist_denylist_precedence() {
         Input for updating a User
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdatePayload! @pgUpdateOne
       """
       Update multiple users
@@ -17021,6 +18717,10 @@ async fn allowlist_denylist_precedence() {
         Input for updating multiple User instances
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique User
@@ -17030,6 +18730,10 @@ async fn allowlist_denylist_precedence() {
         Lookup input for unique User deletion
         """
         lookup: UserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeletePayload! @pgDeleteOne
       """
       Delete multiple users
@@ -17039,6 +18743,10 @@ async fn allowlist_denylist_precedence() {
         Filter for User deletion
         """
         filter: UserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -17361,6 +19069,26 @@ async fn allowlist_with_foreign_key_relationships() {
       _: Boolean
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     type Comment
       @key(fields: "id")
       @pgTable(name: "comments")
@@ -17432,6 +19160,10 @@ async fn allowlist_with_foreign_key_relationships() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -17448,6 +19180,10 @@ async fn allowlist_with_foreign_key_relationships() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -17494,6 +19230,10 @@ async fn allowlist_with_foreign_key_relationships() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -17510,6 +19250,10 @@ async fn allowlist_with_foreign_key_relationships() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -17606,6 +19350,10 @@ async fn allowlist_with_foreign_key_relationships() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -17622,6 +19370,10 @@ async fn allowlist_with_foreign_key_relationships() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -17664,6 +19416,10 @@ async fn allowlist_with_foreign_key_relationships() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -17680,6 +19436,10 @@ async fn allowlist_with_foreign_key_relationships() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Query {
@@ -17810,6 +19570,10 @@ async fn allowlist_with_foreign_key_relationships() {
         Input for updating a Comment
         """
         input: CommentUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): CommentUpdatePayload! @pgUpdateOne
       """
       Update multiple comments
@@ -17823,6 +19587,10 @@ async fn allowlist_with_foreign_key_relationships() {
         Input for updating multiple Comment instances
         """
         input: CommentUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): CommentUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique Comment
@@ -17832,6 +19600,10 @@ async fn allowlist_with_foreign_key_relationships() {
         Lookup input for unique Comment deletion
         """
         lookup: CommentLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): CommentDeletePayload! @pgDeleteOne
       """
       Delete multiple comments
@@ -17841,6 +19613,10 @@ async fn allowlist_with_foreign_key_relationships() {
         Filter for Comment deletion
         """
         filter: CommentFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): CommentDeleteManyPayload! @pgDeleteMany
       """
       Create a single User
@@ -17872,6 +19648,10 @@ async fn allowlist_with_foreign_key_relationships() {
         Input for updating a User
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdatePayload! @pgUpdateOne
       """
       Update multiple users
@@ -17885,6 +19665,10 @@ async fn allowlist_with_foreign_key_relationships() {
         Input for updating multiple User instances
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique User
@@ -17894,6 +19678,10 @@ async fn allowlist_with_foreign_key_relationships() {
         Lookup input for unique User deletion
         """
         lookup: UserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeletePayload! @pgDeleteOne
       """
       Delete multiple users
@@ -17903,6 +19691,10 @@ async fn allowlist_with_foreign_key_relationships() {
         Filter for User deletion
         """
         filter: UserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -18204,6 +19996,26 @@ async fn multiple_schema_support() {
       _: Boolean
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     type Comment
       @key(fields: "id")
       @pgTable(name: "comments", schema: "schema2")
@@ -18274,6 +20086,10 @@ async fn multiple_schema_support() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -18290,6 +20106,10 @@ async fn multiple_schema_support() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -18332,6 +20152,10 @@ async fn multiple_schema_support() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -18348,6 +20172,10 @@ async fn multiple_schema_support() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -18444,6 +20272,10 @@ async fn multiple_schema_support() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -18460,6 +20292,10 @@ async fn multiple_schema_support() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -18502,6 +20338,10 @@ async fn multiple_schema_support() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -18518,6 +20358,10 @@ async fn multiple_schema_support() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Query {
@@ -18648,6 +20492,10 @@ async fn multiple_schema_support() {
         Input for updating a User
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdatePayload! @pgUpdateOne
       """
       Update multiple users
@@ -18661,6 +20509,10 @@ async fn multiple_schema_support() {
         Input for updating multiple User instances
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique User
@@ -18670,6 +20522,10 @@ async fn multiple_schema_support() {
         Lookup input for unique User deletion
         """
         lookup: UserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeletePayload! @pgDeleteOne
       """
       Delete multiple users
@@ -18679,6 +20535,10 @@ async fn multiple_schema_support() {
         Filter for User deletion
         """
         filter: UserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeleteManyPayload! @pgDeleteMany
       """
       Create a single Comment
@@ -18710,6 +20570,10 @@ async fn multiple_schema_support() {
         Input for updating a Comment
         """
         input: CommentUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): CommentUpdatePayload! @pgUpdateOne
       """
       Update multiple comments
@@ -18723,6 +20587,10 @@ async fn multiple_schema_support() {
         Input for updating multiple Comment instances
         """
         input: CommentUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): CommentUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique Comment
@@ -18732,6 +20600,10 @@ async fn multiple_schema_support() {
         Lookup input for unique Comment deletion
         """
         lookup: CommentLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): CommentDeletePayload! @pgDeleteOne
       """
       Delete multiple comments
@@ -18741,6 +20613,10 @@ async fn multiple_schema_support() {
         Filter for Comment deletion
         """
         filter: CommentFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): CommentDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -242,6 +242,26 @@ async fn one_column_required() {
       authorId: IntUpdateInput
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     """
     Information about pagination in a collection of objects
     """
@@ -340,6 +360,10 @@ async fn one_column_required() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -356,6 +380,10 @@ async fn one_column_required() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -402,6 +430,10 @@ async fn one_column_required() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -418,6 +450,10 @@ async fn one_column_required() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type User
@@ -507,6 +543,10 @@ async fn one_column_required() {
         Input for updating a Post
         """
         input: PostUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): PostUpdatePayload! @pgUpdateOne
       """
       Update multiple posts
@@ -520,6 +560,10 @@ async fn one_column_required() {
         Input for updating multiple Post instances
         """
         input: PostUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): PostUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique Post
@@ -529,6 +573,10 @@ async fn one_column_required() {
         Lookup input for unique Post deletion
         """
         lookup: PostLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): PostDeletePayload! @pgDeleteOne
       """
       Delete multiple posts
@@ -538,6 +586,10 @@ async fn one_column_required() {
         Filter for Post deletion
         """
         filter: PostFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): PostDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -846,6 +898,26 @@ async fn composite_required() {
       set: String
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     """
     Information about pagination in a collection of objects
     """
@@ -945,6 +1017,10 @@ async fn composite_required() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -961,6 +1037,10 @@ async fn composite_required() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -1011,6 +1091,10 @@ async fn composite_required() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -1027,6 +1111,10 @@ async fn composite_required() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type User
@@ -1117,6 +1205,10 @@ async fn composite_required() {
         Input for updating a Post
         """
         input: PostUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): PostUpdatePayload! @pgUpdateOne
       """
       Update multiple posts
@@ -1130,6 +1222,10 @@ async fn composite_required() {
         Input for updating multiple Post instances
         """
         input: PostUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): PostUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique Post
@@ -1139,6 +1235,10 @@ async fn composite_required() {
         Lookup input for unique Post deletion
         """
         lookup: PostLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): PostDeletePayload! @pgDeleteOne
       """
       Delete multiple posts
@@ -1148,6 +1248,10 @@ async fn composite_required() {
         Filter for Post deletion
         """
         filter: PostFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): PostDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -1454,6 +1558,26 @@ async fn only_column_nullable() {
       authorId: IntUpdateInput
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     """
     Information about pagination in a collection of objects
     """
@@ -1552,6 +1676,10 @@ async fn only_column_nullable() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -1568,6 +1696,10 @@ async fn only_column_nullable() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -1614,6 +1746,10 @@ async fn only_column_nullable() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -1630,6 +1766,10 @@ async fn only_column_nullable() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type User
@@ -1719,6 +1859,10 @@ async fn only_column_nullable() {
         Input for updating a Post
         """
         input: PostUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): PostUpdatePayload! @pgUpdateOne
       """
       Update multiple posts
@@ -1732,6 +1876,10 @@ async fn only_column_nullable() {
         Input for updating multiple Post instances
         """
         input: PostUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): PostUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique Post
@@ -1741,6 +1889,10 @@ async fn only_column_nullable() {
         Lookup input for unique Post deletion
         """
         lookup: PostLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): PostDeletePayload! @pgDeleteOne
       """
       Delete multiple posts
@@ -1750,6 +1902,10 @@ async fn only_column_nullable() {
         Filter for Post deletion
         """
         filter: PostFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): PostDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -2058,6 +2214,26 @@ async fn composite_one_nullable() {
       set: String
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     """
     Information about pagination in a collection of objects
     """
@@ -2157,6 +2333,10 @@ async fn composite_one_nullable() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -2173,6 +2353,10 @@ async fn composite_one_nullable() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -2223,6 +2407,10 @@ async fn composite_one_nullable() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -2239,6 +2427,10 @@ async fn composite_one_nullable() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type User
@@ -2329,6 +2521,10 @@ async fn composite_one_nullable() {
         Input for updating a Post
         """
         input: PostUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): PostUpdatePayload! @pgUpdateOne
       """
       Update multiple posts
@@ -2342,6 +2538,10 @@ async fn composite_one_nullable() {
         Input for updating multiple Post instances
         """
         input: PostUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): PostUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique Post
@@ -2351,6 +2551,10 @@ async fn composite_one_nullable() {
         Lookup input for unique Post deletion
         """
         lookup: PostLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): PostDeletePayload! @pgDeleteOne
       """
       Delete multiple posts
@@ -2360,6 +2564,10 @@ async fn composite_one_nullable() {
         Filter for Post deletion
         """
         filter: PostFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): PostDeleteManyPayload! @pgDeleteMany
     }
     "#);
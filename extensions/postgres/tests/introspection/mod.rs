@@ -1,5 +1,10 @@
+mod computed_fields;
 mod configuration;
 mod derive_is;
+mod partitions;
+mod precomputed_definition;
+mod subgraphs;
+mod validation;
 mod views;
 
 use crate::PgTestApi;
@@ -189,6 +194,26 @@ async fn table_with_generated_always_identity_primary_key() {
       _: Boolean
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     """
     Information about pagination in a collection of objects
     """
@@ -283,6 +308,10 @@ async fn table_with_generated_always_identity_primary_key() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -299,6 +328,10 @@ async fn table_with_generated_always_identity_primary_key() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -341,6 +374,10 @@ async fn table_with_generated_always_identity_primary_key() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -357,6 +394,10 @@ async fn table_with_generated_always_identity_primary_key() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Query {
@@ -440,6 +481,10 @@ async fn table_with_generated_always_identity_primary_key() {
         Input for updating a User
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdatePayload! @pgUpdateOne
       """
       Update multiple users
@@ -453,6 +498,10 @@ async fn table_with_generated_always_identity_primary_key() {
         Input for updating multiple User instances
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique User
@@ -462,6 +511,10 @@ async fn table_with_generated_always_identity_primary_key() {
         Lookup input for unique User deletion
         """
         lookup: UserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeletePayload! @pgDeleteOne
       """
       Delete multiple users
@@ -471,6 +524,10 @@ async fn table_with_generated_always_identity_primary_key() {
         Filter for User deletion
         """
         filter: UserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -692,6 +749,26 @@ async fn table_with_generated_by_default_identity_primary_key() {
       id: IntUpdateInput
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     """
     Information about pagination in a collection of objects
     """
@@ -786,6 +863,10 @@ async fn table_with_generated_by_default_identity_primary_key() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -802,6 +883,10 @@ async fn table_with_generated_by_default_identity_primary_key() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -844,6 +929,10 @@ async fn table_with_generated_by_default_identity_primary_key() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -860,6 +949,10 @@ async fn table_with_generated_by_default_identity_primary_key() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Query {
@@ -943,6 +1036,10 @@ async fn table_with_generated_by_default_identity_primary_key() {
         Input for updating a User
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdatePayload! @pgUpdateOne
       """
       Update multiple users
@@ -956,6 +1053,10 @@ async fn table_with_generated_by_default_identity_primary_key() {
         Input for updating multiple User instances
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique User
@@ -965,6 +1066,10 @@ async fn table_with_generated_by_default_identity_primary_key() {
         Lookup input for unique User deletion
         """
         lookup: UserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeletePayload! @pgDeleteOne
       """
       Delete multiple users
@@ -974,17 +1079,24 @@ async fn table_with_generated_by_default_identity_primary_key() {
         Filter for User deletion
         """
         filter: UserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeleteManyPayload! @pgDeleteMany
     }
     "#);
 }
 
 #[tokio::test]
-async fn table_with_serial_primary_key() {
+async fn table_with_generated_and_default_columns() {
     let api = PgTestApi::new("", |api| async move {
         let schema = indoc! {r#"
-            CREATE TABLE "User" (
-                id SERIAL PRIMARY KEY
+            CREATE TABLE "line_items" (
+                id INT PRIMARY KEY GENERATED ALWAYS AS IDENTITY,
+                price INT NOT NULL,
+                quantity INT NOT NULL DEFAULT 1,
+                total INT GENERATED ALWAYS AS (price * quantity) STORED
             )
         "#};
 
@@ -1124,41 +1236,57 @@ async fn table_with_serial_primary_key() {
     }
 
     """
-    Input for creating a new User
+    Input for creating a new LineItem
     """
-    input UserCreateInput {
+    input LineItemCreateInput {
       """
-      Set field value for id
+      Set field value for price
       """
-      id: Int
+      price: Int!
+      """
+      Set field value for quantity
+      """
+      quantity: Int
     }
 
     """
-    Filter input type for User objects.
+    Filter input type for LineItem objects.
     """
-    input UserFilterInput @oneOf {
+    input LineItemFilterInput @oneOf {
       """
       Filter by the given id
       """
       id: IntFilterInput
       """
+      Filter by the given price
+      """
+      price: IntFilterInput
+      """
+      Filter by the given quantity
+      """
+      quantity: IntFilterInput
+      """
+      Filter by the given total
+      """
+      total: IntFilterInput
+      """
       All of the filters must match
       """
-      ALL: [UserFilterInput]
+      ALL: [LineItemFilterInput]
       """
       None of the filters must match
       """
-      NONE: [UserFilterInput]
+      NONE: [LineItemFilterInput]
       """
       At least one of the filters must match
       """
-      ANY: [UserFilterInput]
+      ANY: [LineItemFilterInput]
     }
 
     """
-    Input type to select a unique User
+    Input type to select a unique LineItem
     """
-    input UserLookupInput @oneOf {
+    input LineItemLookupInput @oneOf {
       """
       Select by the 'id' field
       """
@@ -1166,9 +1294,9 @@ async fn table_with_serial_primary_key() {
     }
 
     """
-    Lookup input type for User objects for subgraph joins.
+    Lookup input type for LineItem objects for subgraph joins.
     """
-    input UserManyLookupInput @oneOf @inaccessible {
+    input LineItemManyLookupInput @oneOf @inaccessible {
       """
       Select by the 'id' field
       """
@@ -1176,67 +1304,82 @@ async fn table_with_serial_primary_key() {
     }
 
     """
-    Specifies the ordering for User results.
+    Specifies the ordering for LineItem results.
     """
-    input UserOrderByInput @oneOf {
+    input LineItemOrderByInput @oneOf {
       """
-      Order users by id
+      Order lineItems by id
       """
       id: OrderDirection
+      """
+      Order lineItems by price
+      """
+      price: OrderDirection
+      """
+      Order lineItems by quantity
+      """
+      quantity: OrderDirection
+      """
+      Order lineItems by total
+      """
+      total: OrderDirection
     }
 
     """
-    Input for updating an existing User
+    Input for updating an existing LineItem
     """
-    input UserUpdateInput {
+    input LineItemUpdateInput {
       """
-      Update field value for id
+      Update field value for price
       """
-      id: IntUpdateInput
+      price: IntUpdateInput
+      """
+      Update field value for quantity
+      """
+      quantity: IntUpdateInput
     }
 
     """
-    Information about pagination in a collection of objects
+    The rows a dry run of a mutation would change in one table
     """
-    type PageInfo
+    type AffectedTable
       @shareable
     {
       """
-      When paginating backwards, are there more items?
-      """
-      hasPreviousPage: Boolean! @shareable
-      """
-      When paginating forwards, are there more items?
+      The name of the type of the table
       """
-      hasNextPage: Boolean! @shareable
+      table: String! @shareable
       """
-      The cursor of the first item in the page
+      The number of rows updated in the table
       """
-      startCursor: String @shareable
+      updated: Int! @shareable
       """
-      The cursor of the last item in the page
+      The number of rows deleted from the table
       """
-      endCursor: String @shareable
+      deleted: Int! @shareable
     }
 
-    type User
+    type LineItem
       @key(fields: "id")
-      @pgTable(name: "User")
+      @pgTable(name: "line_items")
       @pgKey(fields: ["id"], type: PRIMARY)
     {
       id: Int! @pgColumn(name: "id", type: INT)
+      price: Int! @pgColumn(name: "price", type: INT)
+      quantity: Int! @pgColumn(name: "quantity", type: INT)
+      total: Int @pgColumn(name: "total", type: INT)
     }
 
     """
-    The connection type for User
+    The connection type for LineItem
     """
-    type UserConnection
-      @pgConnection(type: "User")
+    type LineItemConnection
+      @pgConnection(type: "LineItem")
     {
       """
       A list of edges
       """
-      edges: [UserEdge!]! @shareable
+      edges: [LineItemEdge!]! @shareable
       """
       Information to aid in pagination
       """
@@ -1244,15 +1387,15 @@ async fn table_with_serial_primary_key() {
     }
 
     """
-    Return type when creating many users
+    Return type when creating many lineItems
     """
-    type UserCreateManyPayload
-      @pgMutation(type: "User")
+    type LineItemCreateManyPayload
+      @pgMutation(type: "LineItem")
     {
       """
       Returned item(s) from the mutation
       """
-      returning: [UserReturning]! @shareable
+      returning: [LineItemReturning]! @shareable
       """
       The number of rows mutated
       """
@@ -1260,15 +1403,15 @@ async fn table_with_serial_primary_key() {
     }
 
     """
-    Return type when creating one User
+    Return type when creating one LineItem
     """
-    type UserCreatePayload
-      @pgMutation(type: "User")
+    type LineItemCreatePayload
+      @pgMutation(type: "LineItem")
     {
       """
       Returned item(s) from the mutation
       """
-      returning: UserReturning @shareable
+      returning: LineItemReturning @shareable
       """
       The number of rows mutated
       """
@@ -1276,45 +1419,53 @@ async fn table_with_serial_primary_key() {
     }
 
     """
-    Return type when deleting many users
+    Return type when deleting many lineItems
     """
-    type UserDeleteManyPayload
-      @pgMutation(type: "User")
+    type LineItemDeleteManyPayload
+      @pgMutation(type: "LineItem")
     {
       """
       Returned item(s) from the mutation
       """
-      returning: [UserReturning]! @shareable
+      returning: [LineItemReturning]! @shareable
       """
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
-    Return type when deleting one User
+    Return type when deleting one LineItem
     """
-    type UserDeletePayload
-      @pgMutation(type: "User")
+    type LineItemDeletePayload
+      @pgMutation(type: "LineItem")
     {
       """
       Returned item(s) from the mutation
       """
-      returning: UserReturning @shareable
+      returning: LineItemReturning @shareable
       """
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
     An edge in a connection. Contains the node and its cursor
     """
-    type UserEdge {
+    type LineItemEdge {
       """
       The item at the end of the edge
       """
-      node: User! @shareable
+      node: LineItem! @shareable
       """
       A cursor for use in pagination
       """
@@ -1322,67 +1473,111 @@ async fn table_with_serial_primary_key() {
     }
 
     """
-    Return type containing fields of the mutated or created User object
+    Return type containing fields of the mutated or created LineItem object
     """
-    type UserReturning
-      @pgReturning(type: "User")
+    type LineItemReturning
+      @pgReturning(type: "LineItem")
     {
       """
       The value of the id field
       """
       id: Int! @shareable
+      """
+      The value of the price field
+      """
+      price: Int!
+      """
+      The value of the quantity field
+      """
+      quantity: Int!
+      """
+      The value of the total field
+      """
+      total: Int
     }
 
     """
-    Return type when updating many users
+    Return type when updating many lineItems
     """
-    type UserUpdateManyPayload
-      @pgMutation(type: "User")
+    type LineItemUpdateManyPayload
+      @pgMutation(type: "LineItem")
     {
       """
       Returned item(s) from the mutation
       """
-      returning: [UserReturning]! @shareable
+      returning: [LineItemReturning]! @shareable
       """
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
-    Return type when updating one User
+    Return type when updating one LineItem
     """
-    type UserUpdatePayload
-      @pgMutation(type: "User")
+    type LineItemUpdatePayload
+      @pgMutation(type: "LineItem")
     {
       """
       Returned item(s) from the mutation
       """
-      returning: UserReturning @shareable
+      returning: LineItemReturning @shareable
       """
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
+    }
+
+    """
+    Information about pagination in a collection of objects
+    """
+    type PageInfo
+      @shareable
+    {
+      """
+      When paginating backwards, are there more items?
+      """
+      hasPreviousPage: Boolean! @shareable
+      """
+      When paginating forwards, are there more items?
+      """
+      hasNextPage: Boolean! @shareable
+      """
+      The cursor of the first item in the page
+      """
+      startCursor: String @shareable
+      """
+      The cursor of the last item in the page
+      """
+      endCursor: String @shareable
     }
 
     type Query {
       """
-      Query a unique User
+      Query a unique LineItem
       """
-      user(
+      lineItem(
         """
-        Input for unique User lookup
+        Input for unique LineItem lookup
         """
-        lookup: UserLookupInput!,
-      ): User @pgSelectOne
+        lookup: LineItemLookupInput!,
+      ): LineItem @pgSelectOne
       """
-      Query and paginate multiple users
+      Query and paginate multiple lineItems
       """
-      users(
+      lineItems(
         """
-        Filter for User
+        Filter for LineItem
         """
-        filter: UserFilterInput,
+        filter: LineItemFilterInput,
         """
         Limit the number of results, from the beginning
         """
@@ -1402,81 +1597,652 @@ async fn table_with_serial_primary_key() {
         """
         Order the results by selected fields
         """
-        orderBy: [UserOrderByInput!],
-      ): UserConnection! @pgSelectMany
+        orderBy: [LineItemOrderByInput!],
+      ): LineItemConnection! @pgSelectMany
       """
-      Lookup multiple users for subgraph joins
+      Lookup multiple lineItems for subgraph joins
       """
-      userLookup(
+      lineItemLookup(
         """
-        Filter users with an array of keys
+        Filter lineItems with an array of keys
         """
-        lookup: UserManyLookupInput @inaccessible,
-      ): [User] @pgLookup @lookup @inaccessible
+        lookup: LineItemManyLookupInput @inaccessible,
+      ): [LineItem] @pgLookup @lookup @inaccessible
     }
 
     type Mutation {
       """
-      Create a single User
+      Create a single LineItem
       """
-      userCreate(
+      lineItemCreate(
         """
-        Input for creating a single User
+        Input for creating a single LineItem
         """
-        input: UserCreateInput!,
-      ): UserCreatePayload! @pgInsertOne
+        input: LineItemCreateInput!,
+      ): LineItemCreatePayload! @pgInsertOne
       """
-      Create multiple users
+      Create multiple lineItems
       """
-      userCreateMany(
+      lineItemCreateMany(
         """
-        Input for creating multiple User instances
+        Input for creating multiple LineItem instances
         """
-        input: [UserCreateInput!]!,
-      ): UserCreateManyPayload! @pgInsertMany
+        input: [LineItemCreateInput!]!,
+      ): LineItemCreateManyPayload! @pgInsertMany
       """
-      Update a unique User
+      Update a unique LineItem
       """
-      userUpdate(
+      lineItemUpdate(
         """
-        Lookup input for unique User update
+        Lookup input for unique LineItem update
         """
-        lookup: UserLookupInput!,
+        lookup: LineItemLookupInput!,
         """
-        Input for updating a User
+        Input for updating a LineItem
         """
-        input: UserUpdateInput!,
-      ): UserUpdatePayload! @pgUpdateOne
+        input: LineItemUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
+      ): LineItemUpdatePayload! @pgUpdateOne
       """
-      Update multiple users
+      Update multiple lineItems
       """
-      userUpdateMany(
+      lineItemUpdateMany(
         """
-        Filter for updating multiple User instances
+        Filter for updating multiple LineItem instances
         """
-        filter: UserFilterInput,
+        filter: LineItemFilterInput,
         """
-        Input for updating multiple User instances
+        Input for updating multiple LineItem instances
         """
-        input: UserUpdateInput!,
-      ): UserUpdateManyPayload! @pgUpdateMany
+        input: LineItemUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
+      ): LineItemUpdateManyPayload! @pgUpdateMany
       """
-      Delete a unique User
+      Delete a unique LineItem
       """
-      userDelete(
+      lineItemDelete(
         """
-        Lookup input for unique User deletion
+        Lookup input for unique LineItem deletion
         """
-        lookup: UserLookupInput!,
-      ): UserDeletePayload! @pgDeleteOne
+        lookup: LineItemLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
+      ): LineItemDeletePayload! @pgDeleteOne
       """
-      Delete multiple users
+      Delete multiple lineItems
       """
-      userDeleteMany(
+      lineItemDeleteMany(
         """
-        Filter for User deletion
+        Filter for LineItem deletion
         """
-        filter: UserFilterInput,
+        filter: LineItemFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
+      ): LineItemDeleteManyPayload! @pgDeleteMany
+    }
+    "#);
+}
+
+#[tokio::test]
+async fn table_with_serial_primary_key() {
+    let api = PgTestApi::new("", |api| async move {
+        let schema = indoc! {r#"
+            CREATE TABLE "User" (
+                id SERIAL PRIMARY KEY
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+    })
+    .await;
+
+    let result = api.introspect().await;
+
+    insta::assert_snapshot!(&result, @r#"
+    extend schema
+      @link(
+        url: "https://grafbase.com/extensions/postgres/0.1.1",
+        import: [
+          "@pgDatabase",
+          "@pgTable",
+          "@pgColumn",
+          "@pgEnum",
+          "@pgEnumVariant",
+          "@pgRelation",
+          "@pgKey",
+          "@pgLookup",
+          "@pgSelectOne",
+          "@pgSelectMany",
+          "@pgInsertOne",
+          "@pgInsertMany",
+          "@pgUpdateOne",
+          "@pgUpdateMany",
+          "@pgDeleteOne",
+          "@pgDeleteMany",
+          "@pgConnection",
+          "@pgMutation",
+          "@pgReturning",
+          "PgKeyType",
+          "PgColumnType",
+          "PgRelationType"
+        ]
+      )
+      @link(
+        url: "https://specs.grafbase.com/composite-schemas/v1",
+        import: [
+          "@lookup",
+          "@key",
+          "@derive",
+          "@is"
+        ]
+      )
+      @link(
+        url: "https://specs.apollo.dev/federation/v2.3",
+        import: [
+          "@shareable",
+          "@inaccessible"
+        ]
+      )
+      @pgDatabase(name: "default")
+
+    """
+    Specifies the direction for ordering results.
+    """
+    enum OrderDirection {
+      """
+      Specifies an ascending order for a given orderBy argument.
+      """
+      ASC
+      """
+      Specifies a descending order for a given orderBy argument.
+      """
+      DESC
+    }
+
+    """
+    Search filter input for Int type.
+    """
+    input IntFilterInput @oneOf {
+      """
+      The value is exactly the one given
+      """
+      eq: Int
+      """
+      The value is not the one given
+      """
+      ne: Int
+      """
+      The value is greater than the one given
+      """
+      gt: Int
+      """
+      The value is less than the one given
+      """
+      lt: Int
+      """
+      The value is greater than, or equal to the one given
+      """
+      gte: Int
+      """
+      The value is less than, or equal to the one given
+      """
+      lte: Int
+      """
+      The value is in the given array of values
+      """
+      in: [Int!]
+      """
+      The value is not in the given array of values
+      """
+      nin: [Int!]
+      """
+      A negation of the given filter
+      """
+      not: IntFilterInput
+    }
+
+    """
+    Update input for Int type.
+    """
+    input IntUpdateInput @oneOf {
+      """
+      Replaces the value of a field with the specified value.
+      """
+      set: Int
+      """
+      Increments the value of a field by the specified value.
+      """
+      increment: Int
+      """
+      Decrements the value of a field by the specified value.
+      """
+      decrement: Int
+      """
+      Multiplies the value of a field by the specified value.
+      """
+      multiply: Int
+      """
+      Divides the value of a field by the specified value.
+      """
+      divide: Int
+    }
+
+    """
+    Input for creating a new User
+    """
+    input UserCreateInput {
+      """
+      Set field value for id
+      """
+      id: Int
+    }
+
+    """
+    Filter input type for User objects.
+    """
+    input UserFilterInput @oneOf {
+      """
+      Filter by the given id
+      """
+      id: IntFilterInput
+      """
+      All of the filters must match
+      """
+      ALL: [UserFilterInput]
+      """
+      None of the filters must match
+      """
+      NONE: [UserFilterInput]
+      """
+      At least one of the filters must match
+      """
+      ANY: [UserFilterInput]
+    }
+
+    """
+    Input type to select a unique User
+    """
+    input UserLookupInput @oneOf {
+      """
+      Select by the 'id' field
+      """
+      id: Int
+    }
+
+    """
+    Lookup input type for User objects for subgraph joins.
+    """
+    input UserManyLookupInput @oneOf @inaccessible {
+      """
+      Select by the 'id' field
+      """
+      id: [Int!] @inaccessible
+    }
+
+    """
+    Specifies the ordering for User results.
+    """
+    input UserOrderByInput @oneOf {
+      """
+      Order users by id
+      """
+      id: OrderDirection
+    }
+
+    """
+    Input for updating an existing User
+    """
+    input UserUpdateInput {
+      """
+      Update field value for id
+      """
+      id: IntUpdateInput
+    }
+
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
+    """
+    Information about pagination in a collection of objects
+    """
+    type PageInfo
+      @shareable
+    {
+      """
+      When paginating backwards, are there more items?
+      """
+      hasPreviousPage: Boolean! @shareable
+      """
+      When paginating forwards, are there more items?
+      """
+      hasNextPage: Boolean! @shareable
+      """
+      The cursor of the first item in the page
+      """
+      startCursor: String @shareable
+      """
+      The cursor of the last item in the page
+      """
+      endCursor: String @shareable
+    }
+
+    type User
+      @key(fields: "id")
+      @pgTable(name: "User")
+      @pgKey(fields: ["id"], type: PRIMARY)
+    {
+      id: Int! @pgColumn(name: "id", type: INT)
+    }
+
+    """
+    The connection type for User
+    """
+    type UserConnection
+      @pgConnection(type: "User")
+    {
+      """
+      A list of edges
+      """
+      edges: [UserEdge!]! @shareable
+      """
+      Information to aid in pagination
+      """
+      pageInfo: PageInfo! @shareable
+    }
+
+    """
+    Return type when creating many users
+    """
+    type UserCreateManyPayload
+      @pgMutation(type: "User")
+    {
+      """
+      Returned item(s) from the mutation
+      """
+      returning: [UserReturning]! @shareable
+      """
+      The number of rows mutated
+      """
+      rowCount: Int! @shareable
+    }
+
+    """
+    Return type when creating one User
+    """
+    type UserCreatePayload
+      @pgMutation(type: "User")
+    {
+      """
+      Returned item(s) from the mutation
+      """
+      returning: UserReturning @shareable
+      """
+      The number of rows mutated
+      """
+      rowCount: Int! @shareable
+    }
+
+    """
+    Return type when deleting many users
+    """
+    type UserDeleteManyPayload
+      @pgMutation(type: "User")
+    {
+      """
+      Returned item(s) from the mutation
+      """
+      returning: [UserReturning]! @shareable
+      """
+      The number of rows mutated
+      """
+      rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
+    }
+
+    """
+    Return type when deleting one User
+    """
+    type UserDeletePayload
+      @pgMutation(type: "User")
+    {
+      """
+      Returned item(s) from the mutation
+      """
+      returning: UserReturning @shareable
+      """
+      The number of rows mutated
+      """
+      rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
+    }
+
+    """
+    An edge in a connection. Contains the node and its cursor
+    """
+    type UserEdge {
+      """
+      The item at the end of the edge
+      """
+      node: User! @shareable
+      """
+      A cursor for use in pagination
+      """
+      cursor: String! @shareable
+    }
+
+    """
+    Return type containing fields of the mutated or created User object
+    """
+    type UserReturning
+      @pgReturning(type: "User")
+    {
+      """
+      The value of the id field
+      """
+      id: Int! @shareable
+    }
+
+    """
+    Return type when updating many users
+    """
+    type UserUpdateManyPayload
+      @pgMutation(type: "User")
+    {
+      """
+      Returned item(s) from the mutation
+      """
+      returning: [UserReturning]! @shareable
+      """
+      The number of rows mutated
+      """
+      rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
+    }
+
+    """
+    Return type when updating one User
+    """
+    type UserUpdatePayload
+      @pgMutation(type: "User")
+    {
+      """
+      Returned item(s) from the mutation
+      """
+      returning: UserReturning @shareable
+      """
+      The number of rows mutated
+      """
+      rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
+    }
+
+    type Query {
+      """
+      Query a unique User
+      """
+      user(
+        """
+        Input for unique User lookup
+        """
+        lookup: UserLookupInput!,
+      ): User @pgSelectOne
+      """
+      Query and paginate multiple users
+      """
+      users(
+        """
+        Filter for User
+        """
+        filter: UserFilterInput,
+        """
+        Limit the number of results, from the beginning
+        """
+        first: Int,
+        """
+        Limit the number of results, from the end
+        """
+        last: Int,
+        """
+        Cursor for pagination, select items before the cursor. Use together with `last`.
+        """
+        before: String,
+        """
+        Cursor for pagination, select items after the cursor. Use together with `first`.
+        """
+        after: String,
+        """
+        Order the results by selected fields
+        """
+        orderBy: [UserOrderByInput!],
+      ): UserConnection! @pgSelectMany
+      """
+      Lookup multiple users for subgraph joins
+      """
+      userLookup(
+        """
+        Filter users with an array of keys
+        """
+        lookup: UserManyLookupInput @inaccessible,
+      ): [User] @pgLookup @lookup @inaccessible
+    }
+
+    type Mutation {
+      """
+      Create a single User
+      """
+      userCreate(
+        """
+        Input for creating a single User
+        """
+        input: UserCreateInput!,
+      ): UserCreatePayload! @pgInsertOne
+      """
+      Create multiple users
+      """
+      userCreateMany(
+        """
+        Input for creating multiple User instances
+        """
+        input: [UserCreateInput!]!,
+      ): UserCreateManyPayload! @pgInsertMany
+      """
+      Update a unique User
+      """
+      userUpdate(
+        """
+        Lookup input for unique User update
+        """
+        lookup: UserLookupInput!,
+        """
+        Input for updating a User
+        """
+        input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
+      ): UserUpdatePayload! @pgUpdateOne
+      """
+      Update multiple users
+      """
+      userUpdateMany(
+        """
+        Filter for updating multiple User instances
+        """
+        filter: UserFilterInput,
+        """
+        Input for updating multiple User instances
+        """
+        input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
+      ): UserUpdateManyPayload! @pgUpdateMany
+      """
+      Delete a unique User
+      """
+      userDelete(
+        """
+        Lookup input for unique User deletion
+        """
+        lookup: UserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
+      ): UserDeletePayload! @pgDeleteOne
+      """
+      Delete multiple users
+      """
+      userDeleteMany(
+        """
+        Filter for User deletion
+        """
+        filter: UserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -1850,6 +2616,10 @@ async fn table_with_enum_field() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -1866,6 +2636,10 @@ async fn table_with_enum_field() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -1912,6 +2686,10 @@ async fn table_with_enum_field() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -1928,6 +2706,30 @@ async fn table_with_enum_field() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
+    }
+
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
     }
 
     """
@@ -2035,6 +2837,10 @@ async fn table_with_enum_field() {
         Input for updating a A
         """
         input: AUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): AUpdatePayload! @pgUpdateOne
       """
       Update multiple as
@@ -2048,6 +2854,10 @@ async fn table_with_enum_field() {
         Input for updating multiple A instances
         """
         input: AUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): AUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique A
@@ -2057,6 +2867,10 @@ async fn table_with_enum_field() {
         Lookup input for unique A deletion
         """
         lookup: ALookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): ADeletePayload! @pgDeleteOne
       """
       Delete multiple as
@@ -2066,6 +2880,10 @@ async fn table_with_enum_field() {
         Filter for A deletion
         """
         filter: AFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): ADeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -2287,6 +3105,26 @@ async fn table_with_int_primary_key() {
       id: IntUpdateInput
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     """
     Information about pagination in a collection of objects
     """
@@ -2381,6 +3219,10 @@ async fn table_with_int_primary_key() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -2397,6 +3239,10 @@ async fn table_with_int_primary_key() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -2439,6 +3285,10 @@ async fn table_with_int_primary_key() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -2455,6 +3305,10 @@ async fn table_with_int_primary_key() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Query {
@@ -2538,6 +3392,10 @@ async fn table_with_int_primary_key() {
         Input for updating a User
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdatePayload! @pgUpdateOne
       """
       Update multiple users
@@ -2551,6 +3409,10 @@ async fn table_with_int_primary_key() {
         Input for updating multiple User instances
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique User
@@ -2560,6 +3422,10 @@ async fn table_with_int_primary_key() {
         Lookup input for unique User deletion
         """
         lookup: UserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeletePayload! @pgDeleteOne
       """
       Delete multiple users
@@ -2569,6 +3435,10 @@ async fn table_with_int_primary_key() {
         Filter for User deletion
         """
         filter: UserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -2790,6 +3660,26 @@ async fn table_with_int_unique() {
       id: IntUpdateInput
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     """
     Information about pagination in a collection of objects
     """
@@ -2884,6 +3774,10 @@ async fn table_with_int_unique() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -2900,6 +3794,10 @@ async fn table_with_int_unique() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -2942,6 +3840,10 @@ async fn table_with_int_unique() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -2958,6 +3860,10 @@ async fn table_with_int_unique() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Query {
@@ -3041,6 +3947,10 @@ async fn table_with_int_unique() {
         Input for updating a User
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdatePayload! @pgUpdateOne
       """
       Update multiple users
@@ -3054,6 +3964,10 @@ async fn table_with_int_unique() {
         Input for updating multiple User instances
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique User
@@ -3063,6 +3977,10 @@ async fn table_with_int_unique() {
         Lookup input for unique User deletion
         """
         lookup: UserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeletePayload! @pgDeleteOne
       """
       Delete multiple users
@@ -3072,6 +3990,10 @@ async fn table_with_int_unique() {
         Filter for User deletion
         """
         filter: UserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -3374,6 +4296,26 @@ async fn table_with_serial_primary_key_string_unique() {
       email: StringUpdateInput
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     """
     Information about pagination in a collection of objects
     """
@@ -3471,6 +4413,10 @@ async fn table_with_serial_primary_key_string_unique() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -3487,6 +4433,10 @@ async fn table_with_serial_primary_key_string_unique() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -3533,6 +4483,10 @@ async fn table_with_serial_primary_key_string_unique() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -3549,6 +4503,10 @@ async fn table_with_serial_primary_key_string_unique() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Query {
@@ -3632,6 +4590,10 @@ async fn table_with_serial_primary_key_string_unique() {
         Input for updating a User
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdatePayload! @pgUpdateOne
       """
       Update multiple users
@@ -3645,6 +4607,10 @@ async fn table_with_serial_primary_key_string_unique() {
         Input for updating multiple User instances
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique User
@@ -3654,6 +4620,10 @@ async fn table_with_serial_primary_key_string_unique() {
         Lookup input for unique User deletion
         """
         lookup: UserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeletePayload! @pgDeleteOne
       """
       Delete multiple users
@@ -3663,6 +4633,10 @@ async fn table_with_serial_primary_key_string_unique() {
         Filter for User deletion
         """
         filter: UserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -3904,6 +4878,26 @@ async fn table_with_composite_primary_key() {
       email: StringUpdateInput
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     """
     Information about pagination in a collection of objects
     """
@@ -3999,6 +4993,10 @@ async fn table_with_composite_primary_key() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -4015,6 +5013,10 @@ async fn table_with_composite_primary_key() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -4061,6 +5063,10 @@ async fn table_with_composite_primary_key() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -4077,6 +5083,10 @@ async fn table_with_composite_primary_key() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Query {
@@ -4160,6 +5170,10 @@ async fn table_with_composite_primary_key() {
         Input for updating a User
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdatePayload! @pgUpdateOne
       """
       Update multiple users
@@ -4173,6 +5187,10 @@ async fn table_with_composite_primary_key() {
         Input for updating multiple User instances
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique User
@@ -4182,6 +5200,10 @@ async fn table_with_composite_primary_key() {
         Lookup input for unique User deletion
         """
         lookup: UserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeletePayload! @pgDeleteOne
       """
       Delete multiple users
@@ -4191,6 +5213,10 @@ async fn table_with_composite_primary_key() {
         Filter for User deletion
         """
         filter: UserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -4494,6 +5520,26 @@ async fn two_schemas_same_table_name() {
       id: IntUpdateInput
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     """
     Information about pagination in a collection of objects
     """
@@ -4588,6 +5634,10 @@ async fn two_schemas_same_table_name() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -4604,6 +5654,10 @@ async fn two_schemas_same_table_name() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -4646,6 +5700,10 @@ async fn two_schemas_same_table_name() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -4662,6 +5720,10 @@ async fn two_schemas_same_table_name() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type PublicUser
@@ -4734,6 +5796,10 @@ async fn two_schemas_same_table_name() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -4750,6 +5816,10 @@ async fn two_schemas_same_table_name() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -4792,6 +5862,10 @@ async fn two_schemas_same_table_name() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -4808,6 +5882,10 @@ async fn two_schemas_same_table_name() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Query {
@@ -4938,6 +6016,10 @@ async fn two_schemas_same_table_name() {
         Input for updating a PrivateUser
         """
         input: PrivateUserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): PrivateUserUpdatePayload! @pgUpdateOne
       """
       Update multiple privateUsers
@@ -4951,6 +6033,10 @@ async fn two_schemas_same_table_name() {
         Input for updating multiple PrivateUser instances
         """
         input: PrivateUserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): PrivateUserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique PrivateUser
@@ -4960,6 +6046,10 @@ async fn two_schemas_same_table_name() {
         Lookup input for unique PrivateUser deletion
         """
         lookup: PrivateUserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): PrivateUserDeletePayload! @pgDeleteOne
       """
       Delete multiple privateUsers
@@ -4969,6 +6059,10 @@ async fn two_schemas_same_table_name() {
         Filter for PrivateUser deletion
         """
         filter: PrivateUserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): PrivateUserDeleteManyPayload! @pgDeleteMany
       """
       Create a single PublicUser
@@ -5000,6 +6094,10 @@ async fn two_schemas_same_table_name() {
         Input for updating a PublicUser
         """
         input: PublicUserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): PublicUserUpdatePayload! @pgUpdateOne
       """
       Update multiple publicUsers
@@ -5013,6 +6111,10 @@ async fn two_schemas_same_table_name() {
         Input for updating multiple PublicUser instances
         """
         input: PublicUserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): PublicUserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique PublicUser
@@ -5022,6 +6124,10 @@ async fn two_schemas_same_table_name() {
         Lookup input for unique PublicUser deletion
         """
         lookup: PublicUserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): PublicUserDeletePayload! @pgDeleteOne
       """
       Delete multiple publicUsers
@@ -5031,6 +6137,10 @@ async fn two_schemas_same_table_name() {
         Filter for PublicUser deletion
         """
         filter: PublicUserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): PublicUserDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -5341,6 +6451,26 @@ async fn table_with_an_array_column() {
       name: IntArrayUpdateInput
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     """
     Information about pagination in a collection of objects
     """
@@ -5436,6 +6566,10 @@ async fn table_with_an_array_column() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -5452,6 +6586,10 @@ async fn table_with_an_array_column() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -5498,6 +6636,10 @@ async fn table_with_an_array_column() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -5514,6 +6656,10 @@ async fn table_with_an_array_column() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Query {
@@ -5597,6 +6743,10 @@ async fn table_with_an_array_column() {
         Input for updating a User
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdatePayload! @pgUpdateOne
       """
       Update multiple users
@@ -5610,6 +6760,10 @@ async fn table_with_an_array_column() {
         Input for updating multiple User instances
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique User
@@ -5619,6 +6773,10 @@ async fn table_with_an_array_column() {
         Lookup input for unique User deletion
         """
         lookup: UserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeletePayload! @pgDeleteOne
       """
       Delete multiple users
@@ -5628,6 +6786,10 @@ async fn table_with_an_array_column() {
         Filter for User deletion
         """
         filter: UserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -5943,6 +7105,26 @@ async fn table_with_jsonb_column() {
       name: JSONUpdateInput
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     """
     Information about pagination in a collection of objects
     """
@@ -6038,6 +7220,10 @@ async fn table_with_jsonb_column() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -6054,6 +7240,10 @@ async fn table_with_jsonb_column() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -6100,6 +7290,10 @@ async fn table_with_jsonb_column() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -6116,6 +7310,10 @@ async fn table_with_jsonb_column() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Query {
@@ -6199,6 +7397,10 @@ async fn table_with_jsonb_column() {
         Input for updating a User
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdatePayload! @pgUpdateOne
       """
       Update multiple users
@@ -6212,6 +7414,10 @@ async fn table_with_jsonb_column() {
         Input for updating multiple User instances
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique User
@@ -6221,6 +7427,10 @@ async fn table_with_jsonb_column() {
         Lookup input for unique User deletion
         """
         lookup: UserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeletePayload! @pgDeleteOne
       """
       Delete multiple users
@@ -6230,6 +7440,10 @@ async fn table_with_jsonb_column() {
         Filter for User deletion
         """
         filter: UserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -6545,6 +7759,26 @@ async fn table_with_json_column() {
       name: JSONUpdateInput
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     """
     Information about pagination in a collection of objects
     """
@@ -6640,6 +7874,10 @@ async fn table_with_json_column() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -6656,6 +7894,10 @@ async fn table_with_json_column() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -6702,6 +7944,10 @@ async fn table_with_json_column() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -6718,6 +7964,10 @@ async fn table_with_json_column() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Query {
@@ -6801,6 +8051,10 @@ async fn table_with_json_column() {
         Input for updating a User
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdatePayload! @pgUpdateOne
       """
       Update multiple users
@@ -6814,6 +8068,10 @@ async fn table_with_json_column() {
         Input for updating multiple User instances
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique User
@@ -6823,6 +8081,10 @@ async fn table_with_json_column() {
         Lookup input for unique User deletion
         """
         lookup: UserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeletePayload! @pgDeleteOne
       """
       Delete multiple users
@@ -6832,6 +8094,10 @@ async fn table_with_json_column() {
         Filter for User deletion
         """
         filter: UserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -7280,6 +8546,26 @@ async fn two_tables_with_single_column_foreign_key() {
       name: StringUpdateInput
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     type Blog
       @key(fields: "id")
       @pgTable(name: "Blog")
@@ -7354,6 +8640,10 @@ async fn two_tables_with_single_column_foreign_key() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -7370,6 +8660,10 @@ async fn two_tables_with_single_column_foreign_key() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -7424,6 +8718,10 @@ async fn two_tables_with_single_column_foreign_key() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -7440,6 +8738,10 @@ async fn two_tables_with_single_column_foreign_key() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -7563,6 +8865,10 @@ async fn two_tables_with_single_column_foreign_key() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -7579,6 +8885,10 @@ async fn two_tables_with_single_column_foreign_key() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -7625,6 +8935,10 @@ async fn two_tables_with_single_column_foreign_key() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -7641,6 +8955,10 @@ async fn two_tables_with_single_column_foreign_key() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Query {
@@ -7771,6 +9089,10 @@ async fn two_tables_with_single_column_foreign_key() {
         Input for updating a Blog
         """
         input: BlogUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): BlogUpdatePayload! @pgUpdateOne
       """
       Update multiple blogs
@@ -7784,6 +9106,10 @@ async fn two_tables_with_single_column_foreign_key() {
         Input for updating multiple Blog instances
         """
         input: BlogUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): BlogUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique Blog
@@ -7793,6 +9119,10 @@ async fn two_tables_with_single_column_foreign_key() {
         Lookup input for unique Blog deletion
         """
         lookup: BlogLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): BlogDeletePayload! @pgDeleteOne
       """
       Delete multiple blogs
@@ -7802,6 +9132,10 @@ async fn two_tables_with_single_column_foreign_key() {
         Filter for Blog deletion
         """
         filter: BlogFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): BlogDeleteManyPayload! @pgDeleteMany
       """
       Create a single User
@@ -7833,6 +9167,10 @@ async fn two_tables_with_single_column_foreign_key() {
         Input for updating a User
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdatePayload! @pgUpdateOne
       """
       Update multiple users
@@ -7846,6 +9184,10 @@ async fn two_tables_with_single_column_foreign_key() {
         Input for updating multiple User instances
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique User
@@ -7855,6 +9197,10 @@ async fn two_tables_with_single_column_foreign_key() {
         Lookup input for unique User deletion
         """
         lookup: UserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeletePayload! @pgDeleteOne
       """
       Delete multiple users
@@ -7864,6 +9210,10 @@ async fn two_tables_with_single_column_foreign_key() {
         Filter for User deletion
         """
         filter: UserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -8071,6 +9421,26 @@ async fn foreign_key_to_a_table_without_a_key_should_not_create_a_relation() {
       id: StringUpdateInput
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     """
     Information about pagination in a collection of objects
     """
@@ -8165,6 +9535,10 @@ async fn foreign_key_to_a_table_without_a_key_should_not_create_a_relation() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -8181,6 +9555,10 @@ async fn foreign_key_to_a_table_without_a_key_should_not_create_a_relation() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -8223,6 +9601,10 @@ async fn foreign_key_to_a_table_without_a_key_should_not_create_a_relation() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -8239,6 +9621,10 @@ async fn foreign_key_to_a_table_without_a_key_should_not_create_a_relation() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Query {
@@ -8322,6 +9708,10 @@ async fn foreign_key_to_a_table_without_a_key_should_not_create_a_relation() {
         Input for updating a VisibleTable
         """
         input: VisibleTableUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): VisibleTableUpdatePayload! @pgUpdateOne
       """
       Update multiple visibleTables
@@ -8335,6 +9725,10 @@ async fn foreign_key_to_a_table_without_a_key_should_not_create_a_relation() {
         Input for updating multiple VisibleTable instances
         """
         input: VisibleTableUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): VisibleTableUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique VisibleTable
@@ -8344,6 +9738,10 @@ async fn foreign_key_to_a_table_without_a_key_should_not_create_a_relation() {
         Lookup input for unique VisibleTable deletion
         """
         lookup: VisibleTableLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): VisibleTableDeletePayload! @pgDeleteOne
       """
       Delete multiple visibleTables
@@ -8353,6 +9751,10 @@ async fn foreign_key_to_a_table_without_a_key_should_not_create_a_relation() {
         Filter for VisibleTable deletion
         """
         filter: VisibleTableFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): VisibleTableDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -8855,6 +10257,26 @@ async fn issue_november_2023() {
       networkId: IntUpdateInput
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     type Network
       @key(fields: "id")
       @pgTable(name: "networks")
@@ -8951,6 +10373,10 @@ async fn issue_november_2023() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -8967,6 +10393,10 @@ async fn issue_november_2023() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -9009,6 +10439,10 @@ async fn issue_november_2023() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -9025,6 +10459,10 @@ async fn issue_november_2023() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -9125,6 +10563,10 @@ async fn issue_november_2023() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -9141,6 +10583,10 @@ async fn issue_november_2023() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -9195,6 +10641,10 @@ async fn issue_november_2023() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -9211,6 +10661,10 @@ async fn issue_november_2023() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Query {
@@ -9341,6 +10795,10 @@ async fn issue_november_2023() {
         Input for updating a Network
         """
         input: NetworkUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): NetworkUpdatePayload! @pgUpdateOne
       """
       Update multiple networks
@@ -9354,6 +10812,10 @@ async fn issue_november_2023() {
         Input for updating multiple Network instances
         """
         input: NetworkUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): NetworkUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique Network
@@ -9363,6 +10825,10 @@ async fn issue_november_2023() {
         Lookup input for unique Network deletion
         """
         lookup: NetworkLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): NetworkDeletePayload! @pgDeleteOne
       """
       Delete multiple networks
@@ -9372,6 +10838,10 @@ async fn issue_november_2023() {
         Filter for Network deletion
         """
         filter: NetworkFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): NetworkDeleteManyPayload! @pgDeleteMany
       """
       Create a single Project
@@ -9403,6 +10873,10 @@ async fn issue_november_2023() {
         Input for updating a Project
         """
         input: ProjectUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): ProjectUpdatePayload! @pgUpdateOne
       """
       Update multiple projects
@@ -9416,6 +10890,10 @@ async fn issue_november_2023() {
         Input for updating multiple Project instances
         """
         input: ProjectUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): ProjectUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique Project
@@ -9425,6 +10903,10 @@ async fn issue_november_2023() {
         Lookup input for unique Project deletion
         """
         lookup: ProjectLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): ProjectDeletePayload! @pgDeleteOne
       """
       Delete multiple projects
@@ -9434,6 +10916,10 @@ async fn issue_november_2023() {
         Filter for Project deletion
         """
         filter: ProjectFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): ProjectDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -9661,6 +11147,26 @@ async fn table_with_comment() {
       divide: Int
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     """
     This is a table comment.
     """
@@ -9734,6 +11240,10 @@ async fn table_with_comment() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -9750,6 +11260,10 @@ async fn table_with_comment() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -9792,6 +11306,10 @@ async fn table_with_comment() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -9808,6 +11326,10 @@ async fn table_with_comment() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -9915,6 +11437,10 @@ async fn table_with_comment() {
         Input for updating a CommentedTable
         """
         input: CommentedTableUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): CommentedTableUpdatePayload! @pgUpdateOne
       """
       Update multiple commentedTables
@@ -9928,6 +11454,10 @@ async fn table_with_comment() {
         Input for updating multiple CommentedTable instances
         """
         input: CommentedTableUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): CommentedTableUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique CommentedTable
@@ -9937,6 +11467,10 @@ async fn table_with_comment() {
         Lookup input for unique CommentedTable deletion
         """
         lookup: CommentedTableLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): CommentedTableDeletePayload! @pgDeleteOne
       """
       Delete multiple commentedTables
@@ -9946,6 +11480,10 @@ async fn table_with_comment() {
         Filter for CommentedTable deletion
         """
         filter: CommentedTableFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): CommentedTableDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -10246,6 +11784,26 @@ async fn table_with_commented_column() {
       set: String
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     type CommentedColumnTable
       @key(fields: "id")
       @pgTable(name: "commented_column_table")
@@ -10320,6 +11878,10 @@ async fn table_with_commented_column() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -10336,6 +11898,10 @@ async fn table_with_commented_column() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -10382,6 +11948,10 @@ async fn table_with_commented_column() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -10398,6 +11968,10 @@ async fn table_with_commented_column() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -10505,6 +12079,10 @@ async fn table_with_commented_column() {
         Input for updating a CommentedColumnTable
         """
         input: CommentedColumnTableUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): CommentedColumnTableUpdatePayload! @pgUpdateOne
       """
       Update multiple commentedColumnTables
@@ -10518,6 +12096,10 @@ async fn table_with_commented_column() {
         Input for updating multiple CommentedColumnTable instances
         """
         input: CommentedColumnTableUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): CommentedColumnTableUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique CommentedColumnTable
@@ -10527,6 +12109,10 @@ async fn table_with_commented_column() {
         Lookup input for unique CommentedColumnTable deletion
         """
         lookup: CommentedColumnTableLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): CommentedColumnTableDeletePayload! @pgDeleteOne
       """
       Delete multiple commentedColumnTables
@@ -10536,6 +12122,10 @@ async fn table_with_commented_column() {
         Filter for CommentedColumnTable deletion
         """
         filter: CommentedColumnTableFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): CommentedColumnTableDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -10846,6 +12436,26 @@ async fn enum_with_comment() {
       val: CommentedEnumUpdateInput
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     """
     Information about pagination in a collection of objects
     """
@@ -10941,6 +12551,10 @@ async fn enum_with_comment() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -10957,6 +12571,10 @@ async fn enum_with_comment() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -11003,6 +12621,10 @@ async fn enum_with_comment() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -11019,6 +12641,10 @@ async fn enum_with_comment() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Query {
@@ -11102,6 +12728,10 @@ async fn enum_with_comment() {
         Input for updating a UsesCommentedEnum
         """
         input: UsesCommentedEnumUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UsesCommentedEnumUpdatePayload! @pgUpdateOne
       """
       Update multiple usesCommentedEnums
@@ -11115,6 +12745,10 @@ async fn enum_with_comment() {
         Input for updating multiple UsesCommentedEnum instances
         """
         input: UsesCommentedEnumUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UsesCommentedEnumUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique UsesCommentedEnum
@@ -11124,6 +12758,10 @@ async fn enum_with_comment() {
         Lookup input for unique UsesCommentedEnum deletion
         """
         lookup: UsesCommentedEnumLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UsesCommentedEnumDeletePayload! @pgDeleteOne
       """
       Delete multiple usesCommentedEnums
@@ -11133,6 +12771,10 @@ async fn enum_with_comment() {
         Filter for UsesCommentedEnum deletion
         """
         filter: UsesCommentedEnumFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UsesCommentedEnumDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -11482,6 +13124,26 @@ async fn table_with_commented_foreign_key() {
       id: IntUpdateInput
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     """
     Information about pagination in a collection of objects
     """
@@ -11581,6 +13243,10 @@ async fn table_with_commented_foreign_key() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -11597,6 +13263,10 @@ async fn table_with_commented_foreign_key() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -11643,6 +13313,10 @@ async fn table_with_commented_foreign_key() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -11659,6 +13333,10 @@ async fn table_with_commented_foreign_key() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type UserFkComment
@@ -11760,6 +13438,10 @@ async fn table_with_commented_foreign_key() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -11776,6 +13458,10 @@ async fn table_with_commented_foreign_key() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -11818,6 +13504,10 @@ async fn table_with_commented_foreign_key() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -11834,6 +13524,10 @@ async fn table_with_commented_foreign_key() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Query {
@@ -11964,6 +13658,10 @@ async fn table_with_commented_foreign_key() {
         Input for updating a PostFkComment
         """
         input: PostFkCommentUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): PostFkCommentUpdatePayload! @pgUpdateOne
       """
       Update multiple postFkComments
@@ -11977,6 +13675,10 @@ async fn table_with_commented_foreign_key() {
         Input for updating multiple PostFkComment instances
         """
         input: PostFkCommentUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): PostFkCommentUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique PostFkComment
@@ -11986,6 +13688,10 @@ async fn table_with_commented_foreign_key() {
         Lookup input for unique PostFkComment deletion
         """
         lookup: PostFkCommentLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): PostFkCommentDeletePayload! @pgDeleteOne
       """
       Delete multiple postFkComments
@@ -11995,6 +13701,10 @@ async fn table_with_commented_foreign_key() {
         Filter for PostFkComment deletion
         """
         filter: PostFkCommentFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): PostFkCommentDeleteManyPayload! @pgDeleteMany
       """
       Create a single UserFkComment
@@ -12026,6 +13736,10 @@ async fn table_with_commented_foreign_key() {
         Input for updating a UserFkComment
         """
         input: UserFkCommentUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserFkCommentUpdatePayload! @pgUpdateOne
       """
       Update multiple userFkComments
@@ -12039,6 +13753,10 @@ async fn table_with_commented_foreign_key() {
         Input for updating multiple UserFkComment instances
         """
         input: UserFkCommentUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserFkCommentUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique UserFkComment
@@ -12048,6 +13766,10 @@ async fn table_with_commented_foreign_key() {
         Lookup input for unique UserFkComment deletion
         """
         lookup: UserFkCommentLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserFkCommentDeletePayload! @pgDeleteOne
       """
       Delete multiple userFkComments
@@ -12057,6 +13779,10 @@ async fn table_with_commented_foreign_key() {
         Filter for UserFkComment deletion
         """
         filter: UserFkCommentFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserFkCommentDeleteManyPayload! @pgDeleteMany
     }
     "#);
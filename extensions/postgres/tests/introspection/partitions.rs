@@ -0,0 +1,1125 @@
+use crate::PgTestApi;
+use indoc::indoc;
+
+#[tokio::test]
+async fn partitioned_table_with_foreign_key() {
+    let api = PgTestApi::new("", |api| async move {
+        let schema = indoc! {r#"
+            CREATE TABLE "sensors" (
+                id INT PRIMARY KEY
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let schema = indoc! {r#"
+            CREATE TABLE "measurements" (
+                id INT NOT NULL,
+                sensor_id INT NOT NULL REFERENCES "sensors" (id),
+                recorded_at DATE NOT NULL,
+                value INT NOT NULL,
+                PRIMARY KEY (id, recorded_at)
+            ) PARTITION BY RANGE (recorded_at)
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let schema = indoc! {r#"
+            CREATE TABLE "measurements_2024" PARTITION OF "measurements"
+            FOR VALUES FROM ('2024-01-01') TO ('2025-01-01')
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let schema = indoc! {r#"
+            CREATE TABLE "measurements_2025" PARTITION OF "measurements"
+            FOR VALUES FROM ('2025-01-01') TO ('2026-01-01')
+        "#};
+
+        api.execute_sql(schema).await;
+    })
+    .await;
+
+    let result = api.introspect().await;
+
+    insta::assert_snapshot!(&result, @r#"
+    extend schema
+      @link(
+        url: "https://grafbase.com/extensions/postgres/0.1.1",
+        import: [
+          "@pgDatabase",
+          "@pgTable",
+          "@pgColumn",
+          "@pgEnum",
+          "@pgEnumVariant",
+          "@pgRelation",
+          "@pgKey",
+          "@pgLookup",
+          "@pgSelectOne",
+          "@pgSelectMany",
+          "@pgInsertOne",
+          "@pgInsertMany",
+          "@pgUpdateOne",
+          "@pgUpdateMany",
+          "@pgDeleteOne",
+          "@pgDeleteMany",
+          "@pgConnection",
+          "@pgMutation",
+          "@pgReturning",
+          "PgKeyType",
+          "PgColumnType",
+          "PgRelationType"
+        ]
+      )
+      @link(
+        url: "https://specs.grafbase.com/composite-schemas/v1",
+        import: [
+          "@lookup",
+          "@key",
+          "@derive",
+          "@is"
+        ]
+      )
+      @link(
+        url: "https://specs.apollo.dev/federation/v2.3",
+        import: [
+          "@shareable",
+          "@inaccessible"
+        ]
+      )
+      @pgDatabase(name: "default")
+
+    """
+    Date data type represented as a string in ISO 8601 format (YYYY-MM-DD)
+    """
+    scalar Date
+
+    """
+    Specifies the direction for ordering results.
+    """
+    enum OrderDirection {
+      """
+      Specifies an ascending order for a given orderBy argument.
+      """
+      ASC
+      """
+      Specifies a descending order for a given orderBy argument.
+      """
+      DESC
+    }
+
+    """
+    Search filter input for Date type.
+    """
+    input DateFilterInput @oneOf {
+      """
+      The value is exactly the one given
+      """
+      eq: Date
+      """
+      The value is not the one given
+      """
+      ne: Date
+      """
+      The value is greater than the one given
+      """
+      gt: Date
+      """
+      The value is less than the one given
+      """
+      lt: Date
+      """
+      The value is greater than, or equal to the one given
+      """
+      gte: Date
+      """
+      The value is less than, or equal to the one given
+      """
+      lte: Date
+      """
+      The value is in the given array of values
+      """
+      in: [Date!]
+      """
+      The value is not in the given array of values
+      """
+      nin: [Date!]
+      """
+      A negation of the given filter
+      """
+      not: DateFilterInput
+    }
+
+    """
+    Update input for Date type.
+    """
+    input DateUpdateInput @oneOf {
+      """
+      Replaces the value of a field with the specified value.
+      """
+      set: Date
+    }
+
+    """
+    Search filter input for Int type.
+    """
+    input IntFilterInput @oneOf {
+      """
+      The value is exactly the one given
+      """
+      eq: Int
+      """
+      The value is not the one given
+      """
+      ne: Int
+      """
+      The value is greater than the one given
+      """
+      gt: Int
+      """
+      The value is less than the one given
+      """
+      lt: Int
+      """
+      The value is greater than, or equal to the one given
+      """
+      gte: Int
+      """
+      The value is less than, or equal to the one given
+      """
+      lte: Int
+      """
+      The value is in the given array of values
+      """
+      in: [Int!]
+      """
+      The value is not in the given array of values
+      """
+      nin: [Int!]
+      """
+      A negation of the given filter
+      """
+      not: IntFilterInput
+    }
+
+    """
+    Update input for Int type.
+    """
+    input IntUpdateInput @oneOf {
+      """
+      Replaces the value of a field with the specified value.
+      """
+      set: Int
+      """
+      Increments the value of a field by the specified value.
+      """
+      increment: Int
+      """
+      Decrements the value of a field by the specified value.
+      """
+      decrement: Int
+      """
+      Multiplies the value of a field by the specified value.
+      """
+      multiply: Int
+      """
+      Divides the value of a field by the specified value.
+      """
+      divide: Int
+    }
+
+    """
+    Filter input type for Measurement collections
+    """
+    input MeasurementCollectionFilterInput {
+      """
+      The object is related to an object with the given fields
+      """
+      contains: MeasurementFilterInput
+    }
+
+    """
+    Input for creating a new Measurement
+    """
+    input MeasurementCreateInput {
+      """
+      Set field value for id
+      """
+      id: Int!
+      """
+      Set field value for sensorId
+      """
+      sensorId: Int!
+      """
+      Set field value for recordedAt
+      """
+      recordedAt: Date!
+      """
+      Set field value for value
+      """
+      value: Int!
+    }
+
+    """
+    Filter input type for Measurement objects.
+    """
+    input MeasurementFilterInput @oneOf {
+      """
+      Filter by the given id
+      """
+      id: IntFilterInput
+      """
+      Filter by the given sensorId
+      """
+      sensorId: IntFilterInput
+      """
+      Filter by the given recordedAt
+      """
+      recordedAt: DateFilterInput
+      """
+      Filter by the given value
+      """
+      value: IntFilterInput
+      """
+      Filter by the related Sensor object
+      """
+      sensor: SensorFilterInput
+      """
+      All of the filters must match
+      """
+      ALL: [MeasurementFilterInput]
+      """
+      None of the filters must match
+      """
+      NONE: [MeasurementFilterInput]
+      """
+      At least one of the filters must match
+      """
+      ANY: [MeasurementFilterInput]
+    }
+
+    """
+    Input type to select a unique Measurement with multiple fields
+    """
+    input MeasurementIdRecordedAtInput {
+      """
+      Select by the 'id' field
+      """
+      id: Int!
+      """
+      Select by the 'recordedAt' field
+      """
+      recordedAt: Date!
+    }
+
+    """
+    Input type to select a unique Measurement
+    """
+    input MeasurementLookupInput @oneOf {
+      """
+      Select Measurement by composite columns 'id, recordedAt'
+      """
+      idRecordedAt: MeasurementIdRecordedAtInput
+    }
+
+    """
+    Lookup input type for Measurement objects for subgraph joins.
+    """
+    input MeasurementManyLookupInput @oneOf @inaccessible {
+      """
+      Select Measurement by composite columns 'id, recordedAt'
+      """
+      idRecordedAt: [MeasurementIdRecordedAtInput!] @inaccessible
+    }
+
+    """
+    Specifies the ordering for Measurement results.
+    """
+    input MeasurementOrderByInput @oneOf {
+      """
+      Order measurements by id
+      """
+      id: OrderDirection
+      """
+      Order measurements by sensorId
+      """
+      sensorId: OrderDirection
+      """
+      Order measurements by recordedAt
+      """
+      recordedAt: OrderDirection
+      """
+      Order measurements by value
+      """
+      value: OrderDirection
+      """
+      Order Measurement results by Sensor fields
+      """
+      sensor: SensorOrderByInput
+    }
+
+    """
+    Input for updating an existing Measurement
+    """
+    input MeasurementUpdateInput {
+      """
+      Update field value for id
+      """
+      id: IntUpdateInput
+      """
+      Update field value for sensorId
+      """
+      sensorId: IntUpdateInput
+      """
+      Update field value for recordedAt
+      """
+      recordedAt: DateUpdateInput
+      """
+      Update field value for value
+      """
+      value: IntUpdateInput
+    }
+
+    """
+    Input for creating a new Sensor
+    """
+    input SensorCreateInput {
+      """
+      Set field value for id
+      """
+      id: Int!
+    }
+
+    """
+    Filter input type for Sensor objects.
+    """
+    input SensorFilterInput @oneOf {
+      """
+      Filter by the given id
+      """
+      id: IntFilterInput
+      """
+      Filter by the related Measurement objects
+      """
+      measurements: MeasurementCollectionFilterInput
+      """
+      All of the filters must match
+      """
+      ALL: [SensorFilterInput]
+      """
+      None of the filters must match
+      """
+      NONE: [SensorFilterInput]
+      """
+      At least one of the filters must match
+      """
+      ANY: [SensorFilterInput]
+    }
+
+    """
+    Input type to select a unique Sensor
+    """
+    input SensorLookupInput @oneOf {
+      """
+      Select by the 'id' field
+      """
+      id: Int
+    }
+
+    """
+    Lookup input type for Sensor objects for subgraph joins.
+    """
+    input SensorManyLookupInput @oneOf @inaccessible {
+      """
+      Select by the 'id' field
+      """
+      id: [Int!] @inaccessible
+    }
+
+    """
+    Specifies the ordering for Sensor results.
+    """
+    input SensorOrderByInput @oneOf {
+      """
+      Order sensors by id
+      """
+      id: OrderDirection
+    }
+
+    """
+    Input for updating an existing Sensor
+    """
+    input SensorUpdateInput {
+      """
+      Update field value for id
+      """
+      id: IntUpdateInput
+    }
+
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
+    type Measurement
+      @key(fields: "id recordedAt")
+      @pgTable(name: "measurements")
+      @pgKey(fields: ["id", "recordedAt"], type: PRIMARY)
+    {
+      id: Int! @pgColumn(name: "id", type: INT)
+      sensorId: Int! @pgColumn(name: "sensor_id", type: INT)
+      recordedAt: Date! @pgColumn(name: "recorded_at", type: DATE)
+      value: Int! @pgColumn(name: "value", type: INT)
+      sensor: Sensor! @pgRelation(name: "measurements_sensor_id_fkey", fields: ["sensorId"], references: ["id"])
+    }
+
+    """
+    The connection type for Measurement
+    """
+    type MeasurementConnection
+      @pgConnection(type: "Measurement")
+    {
+      """
+      A list of edges
+      """
+      edges: [MeasurementEdge!]! @shareable
+      """
+      Information to aid in pagination
+      """
+      pageInfo: PageInfo! @shareable
+    }
+
+    """
+    Return type when creating many measurements
+    """
+    type MeasurementCreateManyPayload
+      @pgMutation(type: "Measurement")
+    {
+      """
+      Returned item(s) from the mutation
+      """
+      returning: [MeasurementReturning]! @shareable
+      """
+      The number of rows mutated
+      """
+      rowCount: Int! @shareable
+    }
+
+    """
+    Return type when creating one Measurement
+    """
+    type MeasurementCreatePayload
+      @pgMutation(type: "Measurement")
+    {
+      """
+      Returned item(s) from the mutation
+      """
+      returning: MeasurementReturning @shareable
+      """
+      The number of rows mutated
+      """
+      rowCount: Int! @shareable
+    }
+
+    """
+    Return type when deleting many measurements
+    """
+    type MeasurementDeleteManyPayload
+      @pgMutation(type: "Measurement")
+    {
+      """
+      Returned item(s) from the mutation
+      """
+      returning: [MeasurementReturning]! @shareable
+      """
+      The number of rows mutated
+      """
+      rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
+    }
+
+    """
+    Return type when deleting one Measurement
+    """
+    type MeasurementDeletePayload
+      @pgMutation(type: "Measurement")
+    {
+      """
+      Returned item(s) from the mutation
+      """
+      returning: MeasurementReturning @shareable
+      """
+      The number of rows mutated
+      """
+      rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
+    }
+
+    """
+    An edge in a connection. Contains the node and its cursor
+    """
+    type MeasurementEdge {
+      """
+      The item at the end of the edge
+      """
+      node: Measurement! @shareable
+      """
+      A cursor for use in pagination
+      """
+      cursor: String! @shareable
+    }
+
+    """
+    Return type containing fields of the mutated or created Measurement object
+    """
+    type MeasurementReturning
+      @pgReturning(type: "Measurement")
+    {
+      """
+      The value of the id field
+      """
+      id: Int! @shareable
+      """
+      The value of the sensorId field
+      """
+      sensorId: Int!
+      """
+      The value of the recordedAt field
+      """
+      recordedAt: Date! @shareable
+      """
+      The value of the value field
+      """
+      value: Int!
+    }
+
+    """
+    Return type when updating many measurements
+    """
+    type MeasurementUpdateManyPayload
+      @pgMutation(type: "Measurement")
+    {
+      """
+      Returned item(s) from the mutation
+      """
+      returning: [MeasurementReturning]! @shareable
+      """
+      The number of rows mutated
+      """
+      rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
+    }
+
+    """
+    Return type when updating one Measurement
+    """
+    type MeasurementUpdatePayload
+      @pgMutation(type: "Measurement")
+    {
+      """
+      Returned item(s) from the mutation
+      """
+      returning: MeasurementReturning @shareable
+      """
+      The number of rows mutated
+      """
+      rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
+    }
+
+    """
+    Information about pagination in a collection of objects
+    """
+    type PageInfo
+      @shareable
+    {
+      """
+      When paginating backwards, are there more items?
+      """
+      hasPreviousPage: Boolean! @shareable
+      """
+      When paginating forwards, are there more items?
+      """
+      hasNextPage: Boolean! @shareable
+      """
+      The cursor of the first item in the page
+      """
+      startCursor: String @shareable
+      """
+      The cursor of the last item in the page
+      """
+      endCursor: String @shareable
+    }
+
+    type Sensor
+      @key(fields: "id")
+      @pgTable(name: "sensors")
+      @pgKey(fields: ["id"], type: PRIMARY)
+    {
+      id: Int! @pgColumn(name: "id", type: INT)
+      measurements(
+        """
+        Filter the related Measurement instances
+        """
+        filter: MeasurementFilterInput,
+        """
+        Select the first Measurement instances
+        """
+        first: Int,
+        """
+        Select the last Measurement instances
+        """
+        last: Int,
+        """
+        Select the Measurement instances before the given cursor
+        """
+        before: String,
+        """
+        Select the Measurement instances after the given cursor
+        """
+        after: String,
+        """
+        Order the Measurement instances by the given fields
+        """
+        orderBy: [MeasurementOrderByInput!],
+      ): MeasurementConnection! @pgRelation(name: "measurements_sensor_id_fkey")
+    }
+
+    """
+    The connection type for Sensor
+    """
+    type SensorConnection
+      @pgConnection(type: "Sensor")
+    {
+      """
+      A list of edges
+      """
+      edges: [SensorEdge!]! @shareable
+      """
+      Information to aid in pagination
+      """
+      pageInfo: PageInfo! @shareable
+    }
+
+    """
+    Return type when creating many sensors
+    """
+    type SensorCreateManyPayload
+      @pgMutation(type: "Sensor")
+    {
+      """
+      Returned item(s) from the mutation
+      """
+      returning: [SensorReturning]! @shareable
+      """
+      The number of rows mutated
+      """
+      rowCount: Int! @shareable
+    }
+
+    """
+    Return type when creating one Sensor
+    """
+    type SensorCreatePayload
+      @pgMutation(type: "Sensor")
+    {
+      """
+      Returned item(s) from the mutation
+      """
+      returning: SensorReturning @shareable
+      """
+      The number of rows mutated
+      """
+      rowCount: Int! @shareable
+    }
+
+    """
+    Return type when deleting many sensors
+    """
+    type SensorDeleteManyPayload
+      @pgMutation(type: "Sensor")
+    {
+      """
+      Returned item(s) from the mutation
+      """
+      returning: [SensorReturning]! @shareable
+      """
+      The number of rows mutated
+      """
+      rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
+    }
+
+    """
+    Return type when deleting one Sensor
+    """
+    type SensorDeletePayload
+      @pgMutation(type: "Sensor")
+    {
+      """
+      Returned item(s) from the mutation
+      """
+      returning: SensorReturning @shareable
+      """
+      The number of rows mutated
+      """
+      rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
+    }
+
+    """
+    An edge in a connection. Contains the node and its cursor
+    """
+    type SensorEdge {
+      """
+      The item at the end of the edge
+      """
+      node: Sensor! @shareable
+      """
+      A cursor for use in pagination
+      """
+      cursor: String! @shareable
+    }
+
+    """
+    Return type containing fields of the mutated or created Sensor object
+    """
+    type SensorReturning
+      @pgReturning(type: "Sensor")
+    {
+      """
+      The value of the id field
+      """
+      id: Int! @shareable
+    }
+
+    """
+    Return type when updating many sensors
+    """
+    type SensorUpdateManyPayload
+      @pgMutation(type: "Sensor")
+    {
+      """
+      Returned item(s) from the mutation
+      """
+      returning: [SensorReturning]! @shareable
+      """
+      The number of rows mutated
+      """
+      rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
+    }
+
+    """
+    Return type when updating one Sensor
+    """
+    type SensorUpdatePayload
+      @pgMutation(type: "Sensor")
+    {
+      """
+      Returned item(s) from the mutation
+      """
+      returning: SensorReturning @shareable
+      """
+      The number of rows mutated
+      """
+      rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
+    }
+
+    type Query {
+      """
+      Query a unique Measurement
+      """
+      measurement(
+        """
+        Input for unique Measurement lookup
+        """
+        lookup: MeasurementLookupInput!,
+      ): Measurement @pgSelectOne
+      """
+      Query and paginate multiple measurements
+      """
+      measurements(
+        """
+        Filter for Measurement
+        """
+        filter: MeasurementFilterInput,
+        """
+        Limit the number of results, from the beginning
+        """
+        first: Int,
+        """
+        Limit the number of results, from the end
+        """
+        last: Int,
+        """
+        Cursor for pagination, select items before the cursor. Use together with `last`.
+        """
+        before: String,
+        """
+        Cursor for pagination, select items after the cursor. Use together with `first`.
+        """
+        after: String,
+        """
+        Order the results by selected fields
+        """
+        orderBy: [MeasurementOrderByInput!],
+      ): MeasurementConnection! @pgSelectMany
+      """
+      Lookup multiple measurements for subgraph joins
+      """
+      measurementLookup(
+        """
+        Filter measurements with an array of keys
+        """
+        lookup: MeasurementManyLookupInput @inaccessible,
+      ): [Measurement] @pgLookup @lookup @inaccessible
+      """
+      Query a unique Sensor
+      """
+      sensor(
+        """
+        Input for unique Sensor lookup
+        """
+        lookup: SensorLookupInput!,
+      ): Sensor @pgSelectOne
+      """
+      Query and paginate multiple sensors
+      """
+      sensors(
+        """
+        Filter for Sensor
+        """
+        filter: SensorFilterInput,
+        """
+        Limit the number of results, from the beginning
+        """
+        first: Int,
+        """
+        Limit the number of results, from the end
+        """
+        last: Int,
+        """
+        Cursor for pagination, select items before the cursor. Use together with `last`.
+        """
+        before: String,
+        """
+        Cursor for pagination, select items after the cursor. Use together with `first`.
+        """
+        after: String,
+        """
+        Order the results by selected fields
+        """
+        orderBy: [SensorOrderByInput!],
+      ): SensorConnection! @pgSelectMany
+      """
+      Lookup multiple sensors for subgraph joins
+      """
+      sensorLookup(
+        """
+        Filter sensors with an array of keys
+        """
+        lookup: SensorManyLookupInput @inaccessible,
+      ): [Sensor] @pgLookup @lookup @inaccessible
+    }
+
+    type Mutation {
+      """
+      Create a single Measurement
+      """
+      measurementCreate(
+        """
+        Input for creating a single Measurement
+        """
+        input: MeasurementCreateInput!,
+      ): MeasurementCreatePayload! @pgInsertOne
+      """
+      Create multiple measurements
+      """
+      measurementCreateMany(
+        """
+        Input for creating multiple Measurement instances
+        """
+        input: [MeasurementCreateInput!]!,
+      ): MeasurementCreateManyPayload! @pgInsertMany
+      """
+      Update a unique Measurement
+      """
+      measurementUpdate(
+        """
+        Lookup input for unique Measurement update
+        """
+        lookup: MeasurementLookupInput!,
+        """
+        Input for updating a Measurement
+        """
+        input: MeasurementUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
+      ): MeasurementUpdatePayload! @pgUpdateOne
+      """
+      Update multiple measurements
+      """
+      measurementUpdateMany(
+        """
+        Filter for updating multiple Measurement instances
+        """
+        filter: MeasurementFilterInput,
+        """
+        Input for updating multiple Measurement instances
+        """
+        input: MeasurementUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
+      ): MeasurementUpdateManyPayload! @pgUpdateMany
+      """
+      Delete a unique Measurement
+      """
+      measurementDelete(
+        """
+        Lookup input for unique Measurement deletion
+        """
+        lookup: MeasurementLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
+      ): MeasurementDeletePayload! @pgDeleteOne
+      """
+      Delete multiple measurements
+      """
+      measurementDeleteMany(
+        """
+        Filter for Measurement deletion
+        """
+        filter: MeasurementFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
+      ): MeasurementDeleteManyPayload! @pgDeleteMany
+      """
+      Create a single Sensor
+      """
+      sensorCreate(
+        """
+        Input for creating a single Sensor
+        """
+        input: SensorCreateInput!,
+      ): SensorCreatePayload! @pgInsertOne
+      """
+      Create multiple sensors
+      """
+      sensorCreateMany(
+        """
+        Input for creating multiple Sensor instances
+        """
+        input: [SensorCreateInput!]!,
+      ): SensorCreateManyPayload! @pgInsertMany
+      """
+      Update a unique Sensor
+      """
+      sensorUpdate(
+        """
+        Lookup input for unique Sensor update
+        """
+        lookup: SensorLookupInput!,
+        """
+        Input for updating a Sensor
+        """
+        input: SensorUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
+      ): SensorUpdatePayload! @pgUpdateOne
+      """
+      Update multiple sensors
+      """
+      sensorUpdateMany(
+        """
+        Filter for updating multiple Sensor instances
+        """
+        filter: SensorFilterInput,
+        """
+        Input for updating multiple Sensor instances
+        """
+        input: SensorUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
+      ): SensorUpdateManyPayload! @pgUpdateMany
+      """
+      Delete a unique Sensor
+      """
+      sensorDelete(
+        """
+        Lookup input for unique Sensor deletion
+        """
+        lookup: SensorLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
+      ): SensorDeletePayload! @pgDeleteOne
+      """
+      Delete multiple sensors
+      """
+      sensorDeleteMany(
+        """
+        Filter for Sensor deletion
+        """
+        filter: SensorFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
+      ): SensorDeleteManyPayload! @pgDeleteMany
+    }
+    "#);
+}
@@ -0,0 +1,112 @@
+use std::fmt::Write;
+
+use base64::{Engine, prelude::BASE64_STANDARD};
+use grafbase_database_definition::{DatabaseDefinition, DeserializeError};
+use indoc::indoc;
+
+use crate::PgTestApi;
+
+#[tokio::test]
+async fn definition_roundtrip() {
+    let api = PgTestApi::new("", |api| async move {
+        let schema = indoc! {r#"
+            CREATE TABLE "users" (
+                id INT PRIMARY KEY,
+                email TEXT NOT NULL UNIQUE,
+                first_name TEXT NOT NULL,
+                last_name TEXT NOT NULL
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let schema = indoc! {r#"
+            CREATE TABLE "posts" (
+                id INT PRIMARY KEY,
+                user_id INT NOT NULL REFERENCES "users" (id),
+                title TEXT NOT NULL,
+                UNIQUE (user_id, title)
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+    })
+    .await;
+
+    let config = indoc! {r#"
+        extension_url = "https://grafbase.com/extensions/postgres/0.6.1"
+        precompute_definition = true
+
+        [schemas.public.tables.users.computed_fields.fullName]
+        type = "String!"
+        sql = "first_name || ' ' || last_name"
+    "#};
+
+    let result = api.introspect_with_config(config).await;
+
+    let (_, definition) = result
+        .split_once(r#"@pgDatabase(name: "default", definition: ""#)
+        .expect("the definition is embedded into the directive");
+
+    let (definition, _) = definition.split_once('"').unwrap();
+    let bytes = BASE64_STANDARD.decode(definition).unwrap();
+    let database_definition = DatabaseDefinition::from_bytes(&bytes).unwrap();
+
+    let mut summary = String::new();
+
+    for table in database_definition.tables() {
+        writeln!(summary, "{} ({})", table.client_name(), table.database_name()).unwrap();
+
+        for column in table.columns() {
+            writeln!(summary, "  column {}", column.client_name()).unwrap();
+        }
+
+        for relation in table.relations() {
+            writeln!(summary, "  relation {}", relation.client_field_name()).unwrap();
+        }
+
+        for field in ["id", "email", "userIdTitle", "fullName"] {
+            if let Some(key) = database_definition.find_unique_constraint_for_client_field(field, table.id()) {
+                let columns: Vec<_> = key.columns().map(|c| c.table_column().client_name()).collect();
+                writeln!(summary, "  key {field}: {}", columns.join(", ")).unwrap();
+            }
+
+            if database_definition
+                .get_computed_field_id_for_field(table.id(), field)
+                .is_some()
+            {
+                writeln!(summary, "  computed field {field}").unwrap();
+            }
+        }
+    }
+
+    insta::assert_snapshot!(summary, @"
+    Post (posts)
+      column id
+      column userId
+      column title
+      relation user
+      key id: id
+      key userIdTitle: userId, title
+    User (users)
+      column id
+      column email
+      column firstName
+      column lastName
+      relation post
+      key id: id
+      key email: email
+      computed field fullName
+    ");
+
+    // The same introspection gives the same bytes.
+    assert_eq!(result, api.introspect_with_config(config).await);
+
+    let mut bytes = bytes;
+    bytes[4] += 1;
+
+    let error = DatabaseDefinition::from_bytes(&bytes).unwrap_err();
+    assert!(matches!(error, DeserializeError::VersionMismatch { .. }));
+
+    insta::assert_snapshot!(error, @"the definition has format version 2, expected 1");
+}
@@ -0,0 +1,682 @@
+use crate::PgTestApi;
+use indoc::indoc;
+
+#[tokio::test]
+async fn foreign_key_between_subgraphs() {
+    let api = PgTestApi::new("", |api| async move {
+        api.execute_sql("CREATE SCHEMA users").await;
+        api.execute_sql("CREATE SCHEMA orders").await;
+
+        let schema = indoc! {r#"
+            CREATE TABLE users.users (
+                id INT PRIMARY KEY,
+                name TEXT NOT NULL
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let schema = indoc! {r#"
+            CREATE TABLE orders.orders (
+                id INT PRIMARY KEY,
+                user_id INT NOT NULL REFERENCES users.users(id)
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+    })
+    .await;
+
+    let config = indoc! {r#"
+        extension_url = "https://grafbase.com/extensions/postgres/0.1.1"
+        enable_mutations = false
+
+        [subgraphs.users]
+        schemas = ["users"]
+
+        [subgraphs.orders]
+        schemas = ["orders"]
+    "#};
+
+    let result = api.introspect_subgraphs(config).await;
+
+    insta::assert_snapshot!(&result["orders"], @r#"
+    extend schema
+      @link(
+        url: "https://grafbase.com/extensions/postgres/0.1.1",
+        import: [
+          "@pgDatabase",
+          "@pgTable",
+          "@pgColumn",
+          "@pgEnum",
+          "@pgEnumVariant",
+          "@pgRelation",
+          "@pgKey",
+          "@pgLookup",
+          "@pgSelectOne",
+          "@pgSelectMany",
+          "@pgInsertOne",
+          "@pgInsertMany",
+          "@pgUpdateOne",
+          "@pgUpdateMany",
+          "@pgDeleteOne",
+          "@pgDeleteMany",
+          "@pgConnection",
+          "@pgMutation",
+          "@pgReturning",
+          "PgKeyType",
+          "PgColumnType",
+          "PgRelationType"
+        ]
+      )
+      @link(
+        url: "https://specs.grafbase.com/composite-schemas/v1",
+        import: [
+          "@lookup",
+          "@key",
+          "@derive",
+          "@is"
+        ]
+      )
+      @link(
+        url: "https://specs.apollo.dev/federation/v2.3",
+        import: [
+          "@shareable",
+          "@inaccessible"
+        ]
+      )
+      @pgDatabase(name: "default")
+
+    """
+    Specifies the direction for ordering results.
+    """
+    enum OrderDirection {
+      """
+      Specifies an ascending order for a given orderBy argument.
+      """
+      ASC
+      """
+      Specifies a descending order for a given orderBy argument.
+      """
+      DESC
+    }
+
+    """
+    Search filter input for Int type.
+    """
+    input IntFilterInput @oneOf {
+      """
+      The value is exactly the one given
+      """
+      eq: Int
+      """
+      The value is not the one given
+      """
+      ne: Int
+      """
+      The value is greater than the one given
+      """
+      gt: Int
+      """
+      The value is less than the one given
+      """
+      lt: Int
+      """
+      The value is greater than, or equal to the one given
+      """
+      gte: Int
+      """
+      The value is less than, or equal to the one given
+      """
+      lte: Int
+      """
+      The value is in the given array of values
+      """
+      in: [Int!]
+      """
+      The value is not in the given array of values
+      """
+      nin: [Int!]
+      """
+      A negation of the given filter
+      """
+      not: IntFilterInput
+    }
+
+    """
+    Filter input type for Order objects.
+    """
+    input OrderFilterInput @oneOf {
+      """
+      Filter by the given id
+      """
+      id: IntFilterInput
+      """
+      Filter by the given userId
+      """
+      userId: IntFilterInput
+      """
+      All of the filters must match
+      """
+      ALL: [OrderFilterInput]
+      """
+      None of the filters must match
+      """
+      NONE: [OrderFilterInput]
+      """
+      At least one of the filters must match
+      """
+      ANY: [OrderFilterInput]
+    }
+
+    """
+    Input type to select a unique Order
+    """
+    input OrderLookupInput @oneOf {
+      """
+      Select by the 'id' field
+      """
+      id: Int
+    }
+
+    """
+    Lookup input type for Order objects for subgraph joins.
+    """
+    input OrderManyLookupInput @oneOf @inaccessible {
+      """
+      Select by the 'id' field
+      """
+      id: [Int!] @inaccessible
+    }
+
+    """
+    Specifies the ordering for Order results.
+    """
+    input OrderOrderByInput @oneOf {
+      """
+      Order orders by id
+      """
+      id: OrderDirection
+      """
+      Order orders by userId
+      """
+      userId: OrderDirection
+    }
+
+    type Order
+      @key(fields: "id")
+      @pgTable(name: "orders", schema: "orders")
+      @pgKey(fields: ["id"], type: PRIMARY)
+    {
+      id: Int! @pgColumn(name: "id", type: INT)
+      userId: Int! @pgColumn(name: "user_id", type: INT)
+      user: User! @derive @is(field: "{ userId: id }")
+    }
+
+    """
+    The connection type for Order
+    """
+    type OrderConnection
+      @pgConnection(type: "Order")
+    {
+      """
+      A list of edges
+      """
+      edges: [OrderEdge!]! @shareable
+      """
+      Information to aid in pagination
+      """
+      pageInfo: PageInfo! @shareable
+    }
+
+    """
+    An edge in a connection. Contains the node and its cursor
+    """
+    type OrderEdge {
+      """
+      The item at the end of the edge
+      """
+      node: Order! @shareable
+      """
+      A cursor for use in pagination
+      """
+      cursor: String! @shareable
+    }
+
+    """
+    Information about pagination in a collection of objects
+    """
+    type PageInfo
+      @shareable
+    {
+      """
+      When paginating backwards, are there more items?
+      """
+      hasPreviousPage: Boolean! @shareable
+      """
+      When paginating forwards, are there more items?
+      """
+      hasNextPage: Boolean! @shareable
+      """
+      The cursor of the first item in the page
+      """
+      startCursor: String @shareable
+      """
+      The cursor of the last item in the page
+      """
+      endCursor: String @shareable
+    }
+
+    type User
+      @key(fields: "id")
+    {
+      id: Int!
+    }
+
+    type Query {
+      """
+      Query a unique Order
+      """
+      order(
+        """
+        Input for unique Order lookup
+        """
+        lookup: OrderLookupInput!,
+      ): Order @pgSelectOne
+      """
+      Query and paginate multiple orders
+      """
+      orders(
+        """
+        Filter for Order
+        """
+        filter: OrderFilterInput,
+        """
+        Limit the number of results, from the beginning
+        """
+        first: Int,
+        """
+        Limit the number of results, from the end
+        """
+        last: Int,
+        """
+        Cursor for pagination, select items before the cursor. Use together with `last`.
+        """
+        before: String,
+        """
+        Cursor for pagination, select items after the cursor. Use together with `first`.
+        """
+        after: String,
+        """
+        Order the results by selected fields
+        """
+        orderBy: [OrderOrderByInput!],
+      ): OrderConnection! @pgSelectMany
+      """
+      Lookup multiple orders for subgraph joins
+      """
+      orderLookup(
+        """
+        Filter orders with an array of keys
+        """
+        lookup: OrderManyLookupInput @inaccessible,
+      ): [Order] @pgLookup @lookup @inaccessible
+    }
+    "#);
+    insta::assert_snapshot!(&result["users"], @r#"
+    extend schema
+      @link(
+        url: "https://grafbase.com/extensions/postgres/0.1.1",
+        import: [
+          "@pgDatabase",
+          "@pgTable",
+          "@pgColumn",
+          "@pgEnum",
+          "@pgEnumVariant",
+          "@pgRelation",
+          "@pgKey",
+          "@pgLookup",
+          "@pgSelectOne",
+          "@pgSelectMany",
+          "@pgInsertOne",
+          "@pgInsertMany",
+          "@pgUpdateOne",
+          "@pgUpdateMany",
+          "@pgDeleteOne",
+          "@pgDeleteMany",
+          "@pgConnection",
+          "@pgMutation",
+          "@pgReturning",
+          "PgKeyType",
+          "PgColumnType",
+          "PgRelationType"
+        ]
+      )
+      @link(
+        url: "https://specs.grafbase.com/composite-schemas/v1",
+        import: [
+          "@lookup",
+          "@key",
+          "@derive",
+          "@is"
+        ]
+      )
+      @link(
+        url: "https://specs.apollo.dev/federation/v2.3",
+        import: [
+          "@shareable",
+          "@inaccessible"
+        ]
+      )
+      @pgDatabase(name: "default")
+
+    """
+    Specifies the direction for ordering results.
+    """
+    enum OrderDirection {
+      """
+      Specifies an ascending order for a given orderBy argument.
+      """
+      ASC
+      """
+      Specifies a descending order for a given orderBy argument.
+      """
+      DESC
+    }
+
+    """
+    Search filter input for Int type.
+    """
+    input IntFilterInput @oneOf {
+      """
+      The value is exactly the one given
+      """
+      eq: Int
+      """
+      The value is not the one given
+      """
+      ne: Int
+      """
+      The value is greater than the one given
+      """
+      gt: Int
+      """
+      The value is less than the one given
+      """
+      lt: Int
+      """
+      The value is greater than, or equal to the one given
+      """
+      gte: Int
+      """
+      The value is less than, or equal to the one given
+      """
+      lte: Int
+      """
+      The value is in the given array of values
+      """
+      in: [Int!]
+      """
+      The value is not in the given array of values
+      """
+      nin: [Int!]
+      """
+      A negation of the given filter
+      """
+      not: IntFilterInput
+    }
+
+    """
+    Search filter input for String type.
+    """
+    input StringFilterInput @oneOf {
+      """
+      The value is exactly the one given
+      """
+      eq: String
+      """
+      The value is not the one given
+      """
+      ne: String
+      """
+      The value is greater than the one given
+      """
+      gt: String
+      """
+      The value is less than the one given
+      """
+      lt: String
+      """
+      The value is greater than, or equal to the one given
+      """
+      gte: String
+      """
+      The value is less than, or equal to the one given
+      """
+      lte: String
+      """
+      The given input is part of the column value
+      """
+      like: String
+      """
+      The value is in the given array of values
+      """
+      in: [String!]
+      """
+      The value is not in the given array of values
+      """
+      nin: [String!]
+      """
+      A negation of the given filter
+      """
+      not: StringFilterInput
+    }
+
+    """
+    Filter input type for User objects.
+    """
+    input UserFilterInput @oneOf {
+      """
+      Filter by the given id
+      """
+      id: IntFilterInput
+      """
+      Filter by the given name
+      """
+      name: StringFilterInput
+      """
+      All of the filters must match
+      """
+      ALL: [UserFilterInput]
+      """
+      None of the filters must match
+      """
+      NONE: [UserFilterInput]
+      """
+      At least one of the filters must match
+      """
+      ANY: [UserFilterInput]
+    }
+
+    """
+    Input type to select a unique User
+    """
+    input UserLookupInput @oneOf {
+      """
+      Select by the 'id' field
+      """
+      id: Int
+    }
+
+    """
+    Lookup input type for User objects for subgraph joins.
+    """
+    input UserManyLookupInput @oneOf @inaccessible {
+      """
+      Select by the 'id' field
+      """
+      id: [Int!] @inaccessible
+    }
+
+    """
+    Specifies the ordering for User results.
+    """
+    input UserOrderByInput @oneOf {
+      """
+      Order users by id
+      """
+      id: OrderDirection
+      """
+      Order users by name
+      """
+      name: OrderDirection
+    }
+
+    """
+    Information about pagination in a collection of objects
+    """
+    type PageInfo
+      @shareable
+    {
+      """
+      When paginating backwards, are there more items?
+      """
+      hasPreviousPage: Boolean! @shareable
+      """
+      When paginating forwards, are there more items?
+      """
+      hasNextPage: Boolean! @shareable
+      """
+      The cursor of the first item in the page
+      """
+      startCursor: String @shareable
+      """
+      The cursor of the last item in the page
+      """
+      endCursor: String @shareable
+    }
+
+    type User
+      @key(fields: "id")
+      @pgTable(name: "users", schema: "users")
+      @pgKey(fields: ["id"], type: PRIMARY)
+    {
+      id: Int! @pgColumn(name: "id", type: INT)
+      name: String! @pgColumn(name: "name", type: TEXT)
+    }
+
+    """
+    The connection type for User
+    """
+    type UserConnection
+      @pgConnection(type: "User")
+    {
+      """
+      A list of edges
+      """
+      edges: [UserEdge!]! @shareable
+      """
+      Information to aid in pagination
+      """
+      pageInfo: PageInfo! @shareable
+    }
+
+    """
+    An edge in a connection. Contains the node and its cursor
+    """
+    type UserEdge {
+      """
+      The item at the end of the edge
+      """
+      node: User! @shareable
+      """
+      A cursor for use in pagination
+      """
+      cursor: String! @shareable
+    }
+
+    type Query {
+      """
+      Query a unique User
+      """
+      user(
+        """
+        Input for unique User lookup
+        """
+        lookup: UserLookupInput!,
+      ): User @pgSelectOne
+      """
+      Query and paginate multiple users
+      """
+      users(
+        """
+        Filter for User
+        """
+        filter: UserFilterInput,
+        """
+        Limit the number of results, from the beginning
+        """
+        first: Int,
+        """
+        Limit the number of results, from the end
+        """
+        last: Int,
+        """
+        Cursor for pagination, select items before the cursor. Use together with `last`.
+        """
+        before: String,
+        """
+        Cursor for pagination, select items after the cursor. Use together with `first`.
+        """
+        after: String,
+        """
+        Order the results by selected fields
+        """
+        orderBy: [UserOrderByInput!],
+      ): UserConnection! @pgSelectMany
+      """
+      Lookup multiple users for subgraph joins
+      """
+      userLookup(
+        """
+        Filter users with an array of keys
+        """
+        lookup: UserManyLookupInput @inaccessible,
+      ): [User] @pgLookup @lookup @inaccessible
+    }
+    "#);
+}
+
+#[tokio::test]
+async fn table_in_two_subgraphs() {
+    let api = PgTestApi::new("", |api| async move {
+        let schema = indoc! {r#"
+            CREATE TABLE "users" (
+                id INT PRIMARY KEY
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+    })
+    .await;
+
+    let config = indoc! {r#"
+        extension_url = "https://grafbase.com/extensions/postgres/0.1.1"
+
+        [subgraphs.a]
+        schemas = ["public"]
+
+        [subgraphs.b]
+        tables = ["public.users"]
+    "#};
+
+    let mut conn = api.inner.pool.acquire().await.unwrap();
+    let config = toml::from_str(config).unwrap();
+
+    let error = grafbase_postgres_introspection::introspect_subgraphs(&mut conn, config)
+        .await
+        .unwrap_err()
+        .to_string();
+
+    insta::assert_snapshot!(&error, @"Table public.users is in subgraphs a and b. A table can be in only one subgraph.");
+}
@@ -0,0 +1,1544 @@
+use crate::PgTestApi;
+use indoc::indoc;
+
+#[tokio::test]
+async fn check_constraints_and_lengths() {
+    let api = PgTestApi::new("", |api| async move {
+        let schema = indoc! {r#"
+            CREATE TABLE "products" (
+                id INT PRIMARY KEY GENERATED ALWAYS AS IDENTITY,
+                name VARCHAR(64) NOT NULL CHECK (char_length(name) >= 3),
+                code CHAR(8) NOT NULL,
+                price NUMERIC NOT NULL CHECK (price > 0),
+                stock INT NOT NULL CHECK (stock >= 0 AND stock <= 1000),
+                rating INT CHECK (rating > 0) CHECK (rating < 6),
+                status TEXT NOT NULL CHECK (status IN ('draft', 'published', 'it''s "archived"')),
+                description TEXT CHECK (description ~ '^[A-Z]')
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+    })
+    .await;
+
+    let config = indoc! {r#"
+        extension_url = "https://grafbase.com/extensions/postgres/0.1.1"
+
+        [validation_directive]
+        url = "https://example.com/validation/v1"
+    "#};
+
+    let result = api.introspect_with_config(config).await;
+
+    insta::assert_snapshot!(&result, @r#"
+    extend schema
+      @link(
+        url: "https://grafbase.com/extensions/postgres/0.1.1",
+        import: [
+          "@pgDatabase",
+          "@pgTable",
+          "@pgColumn",
+          "@pgEnum",
+          "@pgEnumVariant",
+          "@pgRelation",
+          "@pgKey",
+          "@pgLookup",
+          "@pgSelectOne",
+          "@pgSelectMany",
+          "@pgInsertOne",
+          "@pgInsertMany",
+          "@pgUpdateOne",
+          "@pgUpdateMany",
+          "@pgDeleteOne",
+          "@pgDeleteMany",
+          "@pgConnection",
+          "@pgMutation",
+          "@pgReturning",
+          "PgKeyType",
+          "PgColumnType",
+          "PgRelationType"
+        ]
+      )
+      @link(
+        url: "https://specs.grafbase.com/composite-schemas/v1",
+        import: [
+          "@lookup",
+          "@key",
+          "@derive",
+          "@is"
+        ]
+      )
+      @link(
+        url: "https://specs.apollo.dev/federation/v2.3",
+        import: [
+          "@shareable",
+          "@inaccessible"
+        ]
+      )
+      @link(
+        url: "https://example.com/validation/v1",
+        import: ["@constraint"]
+      )
+      @pgDatabase(name: "default")
+
+    """
+    Decimal data type with arbitrary precision, represented as a string containing a numeric value
+    """
+    scalar Decimal
+
+    """
+    Specifies the direction for ordering results.
+    """
+    enum OrderDirection {
+      """
+      Specifies an ascending order for a given orderBy argument.
+      """
+      ASC
+      """
+      Specifies a descending order for a given orderBy argument.
+      """
+      DESC
+    }
+
+    """
+    Search filter input for Decimal type.
+    """
+    input DecimalFilterInput @oneOf {
+      """
+      The value is exactly the one given
+      """
+      eq: Decimal
+      """
+      The value is not the one given
+      """
+      ne: Decimal
+      """
+      The value is greater than the one given
+      """
+      gt: Decimal
+      """
+      The value is less than the one given
+      """
+      lt: Decimal
+      """
+      The value is greater than, or equal to the one given
+      """
+      gte: Decimal
+      """
+      The value is less than, or equal to the one given
+      """
+      lte: Decimal
+      """
+      The value is in the given array of values
+      """
+      in: [Decimal!]
+      """
+      The value is not in the given array of values
+      """
+      nin: [Decimal!]
+      """
+      A negation of the given filter
+      """
+      not: DecimalFilterInput
+    }
+
+    """
+    Search filter input for Int type.
+    """
+    input IntFilterInput @oneOf {
+      """
+      The value is exactly the one given
+      """
+      eq: Int
+      """
+      The value is not the one given
+      """
+      ne: Int
+      """
+      The value is greater than the one given
+      """
+      gt: Int
+      """
+      The value is less than the one given
+      """
+      lt: Int
+      """
+      The value is greater than, or equal to the one given
+      """
+      gte: Int
+      """
+      The value is less than, or equal to the one given
+      """
+      lte: Int
+      """
+      The value is in the given array of values
+      """
+      in: [Int!]
+      """
+      The value is not in the given array of values
+      """
+      nin: [Int!]
+      """
+      A negation of the given filter
+      """
+      not: IntFilterInput
+    }
+
+    """
+    Update input for the code field of Product.
+    """
+    input ProductCodeFieldUpdateInput @oneOf {
+      """
+      Replaces the value of a field with the specified value.
+      """
+      set: String @constraint(maxLength: 8)
+    }
+
+    """
+    Input for creating a new Product
+    """
+    input ProductCreateInput {
+      """
+      Set field value for name
+      """
+      name: String! @constraint(minLength: 3, maxLength: 64)
+      """
+      Set field value for code
+      """
+      code: String! @constraint(maxLength: 8)
+      """
+      Set field value for price
+      """
+      price: Decimal! @constraint(exclusiveMin: 0)
+      """
+      Set field value for stock
+      """
+      stock: Int! @constraint(min: 0, max: 1000)
+      """
+      Set field value for rating
+      """
+      rating: Int @constraint(min: 1, max: 5)
+      """
+      Set field value for status
+      """
+      status: String! @constraint(oneOf: ["draft", "published", "it's \"archived\""])
+      """
+      Set field value for description
+      """
+      description: String
+    }
+
+    """
+    Filter input type for Product objects.
+    """
+    input ProductFilterInput @oneOf {
+      """
+      Filter by the given id
+      """
+      id: IntFilterInput
+      """
+      Filter by the given name
+      """
+      name: StringFilterInput
+      """
+      Filter by the given code
+      """
+      code: StringFilterInput
+      """
+      Filter by the given price
+      """
+      price: DecimalFilterInput
+      """
+      Filter by the given stock
+      """
+      stock: IntFilterInput
+      """
+      Filter by the given rating
+      """
+      rating: IntFilterInput
+      """
+      Filter by the given status
+      """
+      status: StringFilterInput
+      """
+      Filter by the given description
+      """
+      description: StringFilterInput
+      """
+      All of the filters must match
+      """
+      ALL: [ProductFilterInput]
+      """
+      None of the filters must match
+      """
+      NONE: [ProductFilterInput]
+      """
+      At least one of the filters must match
+      """
+      ANY: [ProductFilterInput]
+    }
+
+    """
+    Input type to select a unique Product
+    """
+    input ProductLookupInput @oneOf {
+      """
+      Select by the 'id' field
+      """
+      id: Int
+    }
+
+    """
+    Lookup input type for Product objects for subgraph joins.
+    """
+    input ProductManyLookupInput @oneOf @inaccessible {
+      """
+      Select by the 'id' field
+      """
+      id: [Int!] @inaccessible
+    }
+
+    """
+    Update input for the name field of Product.
+    """
+    input ProductNameFieldUpdateInput @oneOf {
+      """
+      Replaces the value of a field with the specified value.
+      """
+      set: String @constraint(minLength: 3, maxLength: 64)
+    }
+
+    """
+    Specifies the ordering for Product results.
+    """
+    input ProductOrderByInput @oneOf {
+      """
+      Order products by id
+      """
+      id: OrderDirection
+      """
+      Order products by name
+      """
+      name: OrderDirection
+      """
+      Order products by code
+      """
+      code: OrderDirection
+      """
+      Order products by price
+      """
+      price: OrderDirection
+      """
+      Order products by stock
+      """
+      stock: OrderDirection
+      """
+      Order products by rating
+      """
+      rating: OrderDirection
+      """
+      Order products by status
+      """
+      status: OrderDirection
+      """
+      Order products by description
+      """
+      description: OrderDirection
+    }
+
+    """
+    Update input for the price field of Product.
+    """
+    input ProductPriceFieldUpdateInput @oneOf {
+      """
+      Replaces the value of a field with the specified value.
+      """
+      set: Decimal @constraint(exclusiveMin: 0)
+      """
+      Increments the value of a field by the specified value.
+      """
+      increment: Decimal
+      """
+      Decrements the value of a field by the specified value.
+      """
+      decrement: Decimal
+      """
+      Multiplies the value of a field by the specified value.
+      """
+      multiply: Decimal
+      """
+      Divides the value of a field by the specified value.
+      """
+      divide: Decimal
+    }
+
+    """
+    Update input for the rating field of Product.
+    """
+    input ProductRatingFieldUpdateInput @oneOf {
+      """
+      Replaces the value of a field with the specified value.
+      """
+      set: Int @constraint(min: 1, max: 5)
+      """
+      Increments the value of a field by the specified value.
+      """
+      increment: Int
+      """
+      Decrements the value of a field by the specified value.
+      """
+      decrement: Int
+      """
+      Multiplies the value of a field by the specified value.
+      """
+      multiply: Int
+      """
+      Divides the value of a field by the specified value.
+      """
+      divide: Int
+    }
+
+    """
+    Update input for the status field of Product.
+    """
+    input ProductStatusFieldUpdateInput @oneOf {
+      """
+      Replaces the value of a field with the specified value.
+      """
+      set: String @constraint(oneOf: ["draft", "published", "it's \"archived\""])
+    }
+
+    """
+    Update input for the stock field of Product.
+    """
+    input ProductStockFieldUpdateInput @oneOf {
+      """
+      Replaces the value of a field with the specified value.
+      """
+      set: Int @constraint(min: 0, max: 1000)
+      """
+      Increments the value of a field by the specified value.
+      """
+      increment: Int
+      """
+      Decrements the value of a field by the specified value.
+      """
+      decrement: Int
+      """
+      Multiplies the value of a field by the specified value.
+      """
+      multiply: Int
+      """
+      Divides the value of a field by the specified value.
+      """
+      divide: Int
+    }
+
+    """
+    Input for updating an existing Product
+    """
+    input ProductUpdateInput {
+      """
+      Update field value for name
+      """
+      name: ProductNameFieldUpdateInput
+      """
+      Update field value for code
+      """
+      code: ProductCodeFieldUpdateInput
+      """
+      Update field value for price
+      """
+      price: ProductPriceFieldUpdateInput
+      """
+      Update field value for stock
+      """
+      stock: ProductStockFieldUpdateInput
+      """
+      Update field value for rating
+      """
+      rating: ProductRatingFieldUpdateInput
+      """
+      Update field value for status
+      """
+      status: ProductStatusFieldUpdateInput
+      """
+      Update field value for description
+      """
+      description: StringUpdateInput
+    }
+
+    """
+    Search filter input for String type.
+    """
+    input StringFilterInput @oneOf {
+      """
+      The value is exactly the one given
+      """
+      eq: String
+      """
+      The value is not the one given
+      """
+      ne: String
+      """
+      The value is greater than the one given
+      """
+      gt: String
+      """
+      The value is less than the one given
+      """
+      lt: String
+      """
+      The value is greater than, or equal to the one given
+      """
+      gte: String
+      """
+      The value is less than, or equal to the one given
+      """
+      lte: String
+      """
+      The given input is part of the column value
+      """
+      like: String
+      """
+      The value is in the given array of values
+      """
+      in: [String!]
+      """
+      The value is not in the given array of values
+      """
+      nin: [String!]
+      """
+      A negation of the given filter
+      """
+      not: StringFilterInput
+    }
+
+    """
+    Update input for String type.
+    """
+    input StringUpdateInput @oneOf {
+      """
+      Replaces the value of a field with the specified value.
+      """
+      set: String
+    }
+
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
+    """
+    Information about pagination in a collection of objects
+    """
+    type PageInfo
+      @shareable
+    {
+      """
+      When paginating backwards, are there more items?
+      """
+      hasPreviousPage: Boolean! @shareable
+      """
+      When paginating forwards, are there more items?
+      """
+      hasNextPage: Boolean! @shareable
+      """
+      The cursor of the first item in the page
+      """
+      startCursor: String @shareable
+      """
+      The cursor of the last item in the page
+      """
+      endCursor: String @shareable
+    }
+
+    type Product
+      @key(fields: "id")
+      @pgTable(name: "products")
+      @pgKey(fields: ["id"], type: PRIMARY)
+    {
+      id: Int! @pgColumn(name: "id", type: INT)
+      name: String! @pgColumn(name: "name", type: VARCHAR)
+      code: String! @pgColumn(name: "code", type: CHAR)
+      price: Decimal! @pgColumn(name: "price", type: NUMERIC)
+      stock: Int! @pgColumn(name: "stock", type: INT)
+      rating: Int @pgColumn(name: "rating", type: INT)
+      status: String! @pgColumn(name: "status", type: TEXT)
+      description: String @pgColumn(name: "description", type: TEXT)
+    }
+
+    """
+    The connection type for Product
+    """
+    type ProductConnection
+      @pgConnection(type: "Product")
+    {
+      """
+      A list of edges
+      """
+      edges: [ProductEdge!]! @shareable
+      """
+      Information to aid in pagination
+      """
+      pageInfo: PageInfo! @shareable
+    }
+
+    """
+    Return type when creating many products
+    """
+    type ProductCreateManyPayload
+      @pgMutation(type: "Product")
+    {
+      """
+      Returned item(s) from the mutation
+      """
+      returning: [ProductReturning]! @shareable
+      """
+      The number of rows mutated
+      """
+      rowCount: Int! @shareable
+    }
+
+    """
+    Return type when creating one Product
+    """
+    type ProductCreatePayload
+      @pgMutation(type: "Product")
+    {
+      """
+      Returned item(s) from the mutation
+      """
+      returning: ProductReturning @shareable
+      """
+      The number of rows mutated
+      """
+      rowCount: Int! @shareable
+    }
+
+    """
+    Return type when deleting many products
+    """
+    type ProductDeleteManyPayload
+      @pgMutation(type: "Product")
+    {
+      """
+      Returned item(s) from the mutation
+      """
+      returning: [ProductReturning]! @shareable
+      """
+      The number of rows mutated
+      """
+      rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
+    }
+
+    """
+    Return type when deleting one Product
+    """
+    type ProductDeletePayload
+      @pgMutation(type: "Product")
+    {
+      """
+      Returned item(s) from the mutation
+      """
+      returning: ProductReturning @shareable
+      """
+      The number of rows mutated
+      """
+      rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
+    }
+
+    """
+    An edge in a connection. Contains the node and its cursor
+    """
+    type ProductEdge {
+      """
+      The item at the end of the edge
+      """
+      node: Product! @shareable
+      """
+      A cursor for use in pagination
+      """
+      cursor: String! @shareable
+    }
+
+    """
+    Return type containing fields of the mutated or created Product object
+    """
+    type ProductReturning
+      @pgReturning(type: "Product")
+    {
+      """
+      The value of the id field
+      """
+      id: Int! @shareable
+      """
+      The value of the name field
+      """
+      name: String!
+      """
+      The value of the code field
+      """
+      code: String!
+      """
+      The value of the price field
+      """
+      price: Decimal!
+      """
+      The value of the stock field
+      """
+      stock: Int!
+      """
+      The value of the rating field
+      """
+      rating: Int
+      """
+      The value of the status field
+      """
+      status: String!
+      """
+      The value of the description field
+      """
+      description: String
+    }
+
+    """
+    Return type when updating many products
+    """
+    type ProductUpdateManyPayload
+      @pgMutation(type: "Product")
+    {
+      """
+      Returned item(s) from the mutation
+      """
+      returning: [ProductReturning]! @shareable
+      """
+      The number of rows mutated
+      """
+      rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
+    }
+
+    """
+    Return type when updating one Product
+    """
+    type ProductUpdatePayload
+      @pgMutation(type: "Product")
+    {
+      """
+      Returned item(s) from the mutation
+      """
+      returning: ProductReturning @shareable
+      """
+      The number of rows mutated
+      """
+      rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
+    }
+
+    type Query {
+      """
+      Query a unique Product
+      """
+      product(
+        """
+        Input for unique Product lookup
+        """
+        lookup: ProductLookupInput!,
+      ): Product @pgSelectOne
+      """
+      Query and paginate multiple products
+      """
+      products(
+        """
+        Filter for Product
+        """
+        filter: ProductFilterInput,
+        """
+        Limit the number of results, from the beginning
+        """
+        first: Int,
+        """
+        Limit the number of results, from the end
+        """
+        last: Int,
+        """
+        Cursor for pagination, select items before the cursor. Use together with `last`.
+        """
+        before: String,
+        """
+        Cursor for pagination, select items after the cursor. Use together with `first`.
+        """
+        after: String,
+        """
+        Order the results by selected fields
+        """
+        orderBy: [ProductOrderByInput!],
+      ): ProductConnection! @pgSelectMany
+      """
+      Lookup multiple products for subgraph joins
+      """
+      productLookup(
+        """
+        Filter products with an array of keys
+        """
+        lookup: ProductManyLookupInput @inaccessible,
+      ): [Product] @pgLookup @lookup @inaccessible
+    }
+
+    type Mutation {
+      """
+      Create a single Product
+      """
+      productCreate(
+        """
+        Input for creating a single Product
+        """
+        input: ProductCreateInput!,
+      ): ProductCreatePayload! @pgInsertOne
+      """
+      Create multiple products
+      """
+      productCreateMany(
+        """
+        Input for creating multiple Product instances
+        """
+        input: [ProductCreateInput!]!,
+      ): ProductCreateManyPayload! @pgInsertMany
+      """
+      Update a unique Product
+      """
+      productUpdate(
+        """
+        Lookup input for unique Product update
+        """
+        lookup: ProductLookupInput!,
+        """
+        Input for updating a Product
+        """
+        input: ProductUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
+      ): ProductUpdatePayload! @pgUpdateOne
+      """
+      Update multiple products
+      """
+      productUpdateMany(
+        """
+        Filter for updating multiple Product instances
+        """
+        filter: ProductFilterInput,
+        """
+        Input for updating multiple Product instances
+        """
+        input: ProductUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
+      ): ProductUpdateManyPayload! @pgUpdateMany
+      """
+      Delete a unique Product
+      """
+      productDelete(
+        """
+        Lookup input for unique Product deletion
+        """
+        lookup: ProductLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
+      ): ProductDeletePayload! @pgDeleteOne
+      """
+      Delete multiple products
+      """
+      productDeleteMany(
+        """
+        Filter for Product deletion
+        """
+        filter: ProductFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
+      ): ProductDeleteManyPayload! @pgDeleteMany
+    }
+    "#);
+}
+
+#[tokio::test]
+async fn custom_validation_directive_name() {
+    let api = PgTestApi::new("", |api| async move {
+        let schema = indoc! {r#"
+            CREATE TABLE "products" (
+                id INT PRIMARY KEY,
+                name VARCHAR(64) NOT NULL CHECK (char_length(name) >= 3)
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+    })
+    .await;
+
+    let config = indoc! {r#"
+        extension_url = "https://grafbase.com/extensions/postgres/0.1.1"
+
+        [validation_directive]
+        name = "validate"
+        url = "https://example.com/validation/v1"
+    "#};
+
+    let result = api.introspect_with_config(config).await;
+
+    insta::assert_snapshot!(&result, @r#"
+    extend schema
+      @link(
+        url: "https://grafbase.com/extensions/postgres/0.1.1",
+        import: [
+          "@pgDatabase",
+          "@pgTable",
+          "@pgColumn",
+          "@pgEnum",
+          "@pgEnumVariant",
+          "@pgRelation",
+          "@pgKey",
+          "@pgLookup",
+          "@pgSelectOne",
+          "@pgSelectMany",
+          "@pgInsertOne",
+          "@pgInsertMany",
+          "@pgUpdateOne",
+          "@pgUpdateMany",
+          "@pgDeleteOne",
+          "@pgDeleteMany",
+          "@pgConnection",
+          "@pgMutation",
+          "@pgReturning",
+          "PgKeyType",
+          "PgColumnType",
+          "PgRelationType"
+        ]
+      )
+      @link(
+        url: "https://specs.grafbase.com/composite-schemas/v1",
+        import: [
+          "@lookup",
+          "@key",
+          "@derive",
+          "@is"
+        ]
+      )
+      @link(
+        url: "https://specs.apollo.dev/federation/v2.3",
+        import: [
+          "@shareable",
+          "@inaccessible"
+        ]
+      )
+      @link(
+        url: "https://example.com/validation/v1",
+        import: ["@validate"]
+      )
+      @pgDatabase(name: "default")
+
+    """
+    Specifies the direction for ordering results.
+    """
+    enum OrderDirection {
+      """
+      Specifies an ascending order for a given orderBy argument.
+      """
+      ASC
+      """
+      Specifies a descending order for a given orderBy argument.
+      """
+      DESC
+    }
+
+    """
+    Search filter input for Int type.
+    """
+    input IntFilterInput @oneOf {
+      """
+      The value is exactly the one given
+      """
+      eq: Int
+      """
+      The value is not the one given
+      """
+      ne: Int
+      """
+      The value is greater than the one given
+      """
+      gt: Int
+      """
+      The value is less than the one given
+      """
+      lt: Int
+      """
+      The value is greater than, or equal to the one given
+      """
+      gte: Int
+      """
+      The value is less than, or equal to the one given
+      """
+      lte: Int
+      """
+      The value is in the given array of values
+      """
+      in: [Int!]
+      """
+      The value is not in the given array of values
+      """
+      nin: [Int!]
+      """
+      A negation of the given filter
+      """
+      not: IntFilterInput
+    }
+
+    """
+    Update input for Int type.
+    """
+    input IntUpdateInput @oneOf {
+      """
+      Replaces the value of a field with the specified value.
+      """
+      set: Int
+      """
+      Increments the value of a field by the specified value.
+      """
+      increment: Int
+      """
+      Decrements the value of a field by the specified value.
+      """
+      decrement: Int
+      """
+      Multiplies the value of a field by the specified value.
+      """
+      multiply: Int
+      """
+      Divides the value of a field by the specified value.
+      """
+      divide: Int
+    }
+
+    """
+    Input for creating a new Product
+    """
+    input ProductCreateInput {
+      """
+      Set field value for id
+      """
+      id: Int!
+      """
+      Set field value for name
+      """
+      name: String! @validate(minLength: 3, maxLength: 64)
+    }
+
+    """
+    Filter input type for Product objects.
+    """
+    input ProductFilterInput @oneOf {
+      """
+      Filter by the given id
+      """
+      id: IntFilterInput
+      """
+      Filter by the given name
+      """
+      name: StringFilterInput
+      """
+      All of the filters must match
+      """
+      ALL: [ProductFilterInput]
+      """
+      None of the filters must match
+      """
+      NONE: [ProductFilterInput]
+      """
+      At least one of the filters must match
+      """
+      ANY: [ProductFilterInput]
+    }
+
+    """
+    Input type to select a unique Product
+    """
+    input ProductLookupInput @oneOf {
+      """
+      Select by the 'id' field
+      """
+      id: Int
+    }
+
+    """
+    Lookup input type for Product objects for subgraph joins.
+    """
+    input ProductManyLookupInput @oneOf @inaccessible {
+      """
+      Select by the 'id' field
+      """
+      id: [Int!] @inaccessible
+    }
+
+    """
+    Update input for the name field of Product.
+    """
+    input ProductNameFieldUpdateInput @oneOf {
+      """
+      Replaces the value of a field with the specified value.
+      """
+      set: String @validate(minLength: 3, maxLength: 64)
+    }
+
+    """
+    Specifies the ordering for Product results.
+    """
+    input ProductOrderByInput @oneOf {
+      """
+      Order products by id
+      """
+      id: OrderDirection
+      """
+      Order products by name
+      """
+      name: OrderDirection
+    }
+
+    """
+    Input for updating an existing Product
+    """
+    input ProductUpdateInput {
+      """
+      Update field value for id
+      """
+      id: IntUpdateInput
+      """
+      Update field value for name
+      """
+      name: ProductNameFieldUpdateInput
+    }
+
+    """
+    Search filter input for String type.
+    """
+    input StringFilterInput @oneOf {
+      """
+      The value is exactly the one given
+      """
+      eq: String
+      """
+      The value is not the one given
+      """
+      ne: String
+      """
+      The value is greater than the one given
+      """
+      gt: String
+      """
+      The value is less than the one given
+      """
+      lt: String
+      """
+      The value is greater than, or equal to the one given
+      """
+      gte: String
+      """
+      The value is less than, or equal to the one given
+      """
+      lte: String
+      """
+      The given input is part of the column value
+      """
+      like: String
+      """
+      The value is in the given array of values
+      """
+      in: [String!]
+      """
+      The value is not in the given array of values
+      """
+      nin: [String!]
+      """
+      A negation of the given filter
+      """
+      not: StringFilterInput
+    }
+
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
+    """
+    Information about pagination in a collection of objects
+    """
+    type PageInfo
+      @shareable
+    {
+      """
+      When paginating backwards, are there more items?
+      """
+      hasPreviousPage: Boolean! @shareable
+      """
+      When paginating forwards, are there more items?
+      """
+      hasNextPage: Boolean! @shareable
+      """
+      The cursor of the first item in the page
+      """
+      startCursor: String @shareable
+      """
+      The cursor of the last item in the page
+      """
+      endCursor: String @shareable
+    }
+
+    type Product
+      @key(fields: "id")
+      @pgTable(name: "products")
+      @pgKey(fields: ["id"], type: PRIMARY)
+    {
+      id: Int! @pgColumn(name: "id", type: INT)
+      name: String! @pgColumn(name: "name", type: VARCHAR)
+    }
+
+    """
+    The connection type for Product
+    """
+    type ProductConnection
+      @pgConnection(type: "Product")
+    {
+      """
+      A list of edges
+      """
+      edges: [ProductEdge!]! @shareable
+      """
+      Information to aid in pagination
+      """
+      pageInfo: PageInfo! @shareable
+    }
+
+    """
+    Return type when creating many products
+    """
+    type ProductCreateManyPayload
+      @pgMutation(type: "Product")
+    {
+      """
+      Returned item(s) from the mutation
+      """
+      returning: [ProductReturning]! @shareable
+      """
+      The number of rows mutated
+      """
+      rowCount: Int! @shareable
+    }
+
+    """
+    Return type when creating one Product
+    """
+    type ProductCreatePayload
+      @pgMutation(type: "Product")
+    {
+      """
+      Returned item(s) from the mutation
+      """
+      returning: ProductReturning @shareable
+      """
+      The number of rows mutated
+      """
+      rowCount: Int! @shareable
+    }
+
+    """
+    Return type when deleting many products
+    """
+    type ProductDeleteManyPayload
+      @pgMutation(type: "Product")
+    {
+      """
+      Returned item(s) from the mutation
+      """
+      returning: [ProductReturning]! @shareable
+      """
+      The number of rows mutated
+      """
+      rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
+    }
+
+    """
+    Return type when deleting one Product
+    """
+    type ProductDeletePayload
+      @pgMutation(type: "Product")
+    {
+      """
+      Returned item(s) from the mutation
+      """
+      returning: ProductReturning @shareable
+      """
+      The number of rows mutated
+      """
+      rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
+    }
+
+    """
+    An edge in a connection. Contains the node and its cursor
+    """
+    type ProductEdge {
+      """
+      The item at the end of the edge
+      """
+      node: Product! @shareable
+      """
+      A cursor for use in pagination
+      """
+      cursor: String! @shareable
+    }
+
+    """
+    Return type containing fields of the mutated or created Product object
+    """
+    type ProductReturning
+      @pgReturning(type: "Product")
+    {
+      """
+      The value of the id field
+      """
+      id: Int! @shareable
+      """
+      The value of the name field
+      """
+      name: String!
+    }
+
+    """
+    Return type when updating many products
+    """
+    type ProductUpdateManyPayload
+      @pgMutation(type: "Product")
+    {
+      """
+      Returned item(s) from the mutation
+      """
+      returning: [ProductReturning]! @shareable
+      """
+      The number of rows mutated
+      """
+      rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
+    }
+
+    """
+    Return type when updating one Product
+    """
+    type ProductUpdatePayload
+      @pgMutation(type: "Product")
+    {
+      """
+      Returned item(s) from the mutation
+      """
+      returning: ProductReturning @shareable
+      """
+      The number of rows mutated
+      """
+      rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
+    }
+
+    type Query {
+      """
+      Query a unique Product
+      """
+      product(
+        """
+        Input for unique Product lookup
+        """
+        lookup: ProductLookupInput!,
+      ): Product @pgSelectOne
+      """
+      Query and paginate multiple products
+      """
+      products(
+        """
+        Filter for Product
+        """
+        filter: ProductFilterInput,
+        """
+        Limit the number of results, from the beginning
+        """
+        first: Int,
+        """
+        Limit the number of results, from the end
+        """
+        last: Int,
+        """
+        Cursor for pagination, select items before the cursor. Use together with `last`.
+        """
+        before: String,
+        """
+        Cursor for pagination, select items after the cursor. Use together with `first`.
+        """
+        after: String,
+        """
+        Order the results by selected fields
+        """
+        orderBy: [ProductOrderByInput!],
+      ): ProductConnection! @pgSelectMany
+      """
+      Lookup multiple products for subgraph joins
+      """
+      productLookup(
+        """
+        Filter products with an array of keys
+        """
+        lookup: ProductManyLookupInput @inaccessible,
+      ): [Product] @pgLookup @lookup @inaccessible
+    }
+
+    type Mutation {
+      """
+      Create a single Product
+      """
+      productCreate(
+        """
+        Input for creating a single Product
+        """
+        input: ProductCreateInput!,
+      ): ProductCreatePayload! @pgInsertOne
+      """
+      Create multiple products
+      """
+      productCreateMany(
+        """
+        Input for creating multiple Product instances
+        """
+        input: [ProductCreateInput!]!,
+      ): ProductCreateManyPayload! @pgInsertMany
+      """
+      Update a unique Product
+      """
+      productUpdate(
+        """
+        Lookup input for unique Product update
+        """
+        lookup: ProductLookupInput!,
+        """
+        Input for updating a Product
+        """
+        input: ProductUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
+      ): ProductUpdatePayload! @pgUpdateOne
+      """
+      Update multiple products
+      """
+      productUpdateMany(
+        """
+        Filter for updating multiple Product instances
+        """
+        filter: ProductFilterInput,
+        """
+        Input for updating multiple Product instances
+        """
+        input: ProductUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
+      ): ProductUpdateManyPayload! @pgUpdateMany
+      """
+      Delete a unique Product
+      """
+      productDelete(
+        """
+        Lookup input for unique Product deletion
+        """
+        lookup: ProductLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
+      ): ProductDeletePayload! @pgDeleteOne
+      """
+      Delete multiple products
+      """
+      productDeleteMany(
+        """
+        Filter for Product deletion
+        """
+        filter: ProductFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
+      ): ProductDeleteManyPayload! @pgDeleteMany
+    }
+    "#);
+}
@@ -284,6 +284,26 @@ async fn view_with_int_unique() {
       id: IntUpdateInput
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     type FilteredUser
       @key(fields: "id")
       @pgTable(name: "filtered_users", kind: VIEW)
@@ -416,6 +436,10 @@ async fn view_with_int_unique() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -432,6 +456,10 @@ async fn view_with_int_unique() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -474,6 +502,10 @@ async fn view_with_int_unique() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -490,6 +522,10 @@ async fn view_with_int_unique() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Query {
@@ -620,6 +656,10 @@ async fn view_with_int_unique() {
         Input for updating a User
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdatePayload! @pgUpdateOne
       """
       Update multiple users
@@ -633,6 +673,10 @@ async fn view_with_int_unique() {
         Input for updating multiple User instances
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique User
@@ -642,6 +686,10 @@ async fn view_with_int_unique() {
         Lookup input for unique User deletion
         """
         lookup: UserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeletePayload! @pgDeleteOne
       """
       Delete multiple users
@@ -651,6 +699,10 @@ async fn view_with_int_unique() {
         Filter for User deletion
         """
         filter: UserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -939,6 +991,26 @@ async fn materialized_view_with_int_unique() {
       id: IntUpdateInput
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     type FilteredUser
       @key(fields: "id")
       @pgTable(name: "filtered_users", kind: MATERIALIZED_VIEW)
@@ -1071,6 +1143,10 @@ async fn materialized_view_with_int_unique() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -1087,6 +1163,10 @@ async fn materialized_view_with_int_unique() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -1129,6 +1209,10 @@ async fn materialized_view_with_int_unique() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -1145,6 +1229,10 @@ async fn materialized_view_with_int_unique() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Query {
@@ -1275,6 +1363,10 @@ async fn materialized_view_with_int_unique() {
         Input for updating a User
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdatePayload! @pgUpdateOne
       """
       Update multiple users
@@ -1288,6 +1380,10 @@ async fn materialized_view_with_int_unique() {
         Input for updating multiple User instances
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique User
@@ -1297,6 +1393,10 @@ async fn materialized_view_with_int_unique() {
         Lookup input for unique User deletion
         """
         lookup: UserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeletePayload! @pgDeleteOne
       """
       Delete multiple users
@@ -1306,6 +1406,10 @@ async fn materialized_view_with_int_unique() {
         Filter for User deletion
         """
         filter: UserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -1643,6 +1747,26 @@ async fn view_with_composite_key() {
       emailAddress: StringUpdateInput
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     type FilteredUser
       @key(fields: "name emailAddress")
       @pgTable(name: "filtered_users", kind: VIEW)
@@ -1777,6 +1901,10 @@ async fn view_with_composite_key() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -1793,6 +1921,10 @@ async fn view_with_composite_key() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -1839,6 +1971,10 @@ async fn view_with_composite_key() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -1855,6 +1991,10 @@ async fn view_with_composite_key() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Query {
@@ -1985,6 +2125,10 @@ async fn view_with_composite_key() {
         Input for updating a User
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdatePayload! @pgUpdateOne
       """
       Update multiple users
@@ -1998,6 +2142,10 @@ async fn view_with_composite_key() {
         Input for updating multiple User instances
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique User
@@ -2007,6 +2155,10 @@ async fn view_with_composite_key() {
         Lookup input for unique User deletion
         """
         lookup: UserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeletePayload! @pgDeleteOne
       """
       Delete multiple users
@@ -2016,6 +2168,10 @@ async fn view_with_composite_key() {
         Filter for User deletion
         """
         filter: UserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -2428,6 +2584,26 @@ async fn view_with_relation_from_view_to_table() {
       id: IntUpdateInput
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     type Blog
       @key(fields: "id")
       @pgTable(name: "Blog")
@@ -2500,6 +2676,10 @@ async fn view_with_relation_from_view_to_table() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -2516,6 +2696,10 @@ async fn view_with_relation_from_view_to_table() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -2562,6 +2746,10 @@ async fn view_with_relation_from_view_to_table() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -2578,6 +2766,10 @@ async fn view_with_relation_from_view_to_table() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type FilteredUser
@@ -2738,6 +2930,10 @@ async fn view_with_relation_from_view_to_table() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -2754,6 +2950,10 @@ async fn view_with_relation_from_view_to_table() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -2796,6 +2996,10 @@ async fn view_with_relation_from_view_to_table() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -2812,6 +3016,10 @@ async fn view_with_relation_from_view_to_table() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Query {
@@ -2989,6 +3197,10 @@ async fn view_with_relation_from_view_to_table() {
         Input for updating a Blog
         """
         input: BlogUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): BlogUpdatePayload! @pgUpdateOne
       """
       Update multiple blogs
@@ -3002,6 +3214,10 @@ async fn view_with_relation_from_view_to_table() {
         Input for updating multiple Blog instances
         """
         input: BlogUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): BlogUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique Blog
@@ -3011,6 +3227,10 @@ async fn view_with_relation_from_view_to_table() {
         Lookup input for unique Blog deletion
         """
         lookup: BlogLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): BlogDeletePayload! @pgDeleteOne
       """
       Delete multiple blogs
@@ -3020,6 +3240,10 @@ async fn view_with_relation_from_view_to_table() {
         Filter for Blog deletion
         """
         filter: BlogFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): BlogDeleteManyPayload! @pgDeleteMany
       """
       Create a single User
@@ -3051,6 +3275,10 @@ async fn view_with_relation_from_view_to_table() {
         Input for updating a User
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdatePayload! @pgUpdateOne
       """
       Update multiple users
@@ -3064,6 +3292,10 @@ async fn view_with_relation_from_view_to_table() {
         Input for updating multiple User instances
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique User
@@ -3073,6 +3305,10 @@ async fn view_with_relation_from_view_to_table() {
         Lookup input for unique User deletion
         """
         lookup: UserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeletePayload! @pgDeleteOne
       """
       Delete multiple users
@@ -3082,6 +3318,10 @@ async fn view_with_relation_from_view_to_table() {
         Filter for User deletion
         """
         filter: UserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeleteManyPayload! @pgDeleteMany
     }
     "#);
@@ -3502,6 +3742,26 @@ async fn view_with_relation_from_table_to_view() {
       id: IntUpdateInput
     }
 
+    """
+    The rows a dry run of a mutation would change in one table
+    """
+    type AffectedTable
+      @shareable
+    {
+      """
+      The name of the type of the table
+      """
+      table: String! @shareable
+      """
+      The number of rows updated in the table
+      """
+      updated: Int! @shareable
+      """
+      The number of rows deleted from the table
+      """
+      deleted: Int! @shareable
+    }
+
     type Blog
       @key(fields: "id")
       @pgTable(name: "Blog")
@@ -3574,6 +3834,10 @@ async fn view_with_relation_from_table_to_view() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -3590,6 +3854,10 @@ async fn view_with_relation_from_table_to_view() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -3636,6 +3904,10 @@ async fn view_with_relation_from_table_to_view() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -3652,6 +3924,10 @@ async fn view_with_relation_from_table_to_view() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type FilteredBlog
@@ -3813,6 +4089,10 @@ async fn view_with_relation_from_table_to_view() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -3829,6 +4109,10 @@ async fn view_with_relation_from_table_to_view() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -3871,6 +4155,10 @@ async fn view_with_relation_from_table_to_view() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     """
@@ -3887,6 +4175,10 @@ async fn view_with_relation_from_table_to_view() {
       The number of rows mutated
       """
       rowCount: Int! @shareable
+      """
+      The rows a dry run would change per table, including the changes by foreign key actions
+      """
+      affectedTables: [AffectedTable!] @shareable
     }
 
     type Query {
@@ -4064,6 +4356,10 @@ async fn view_with_relation_from_table_to_view() {
         Input for updating a Blog
         """
         input: BlogUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): BlogUpdatePayload! @pgUpdateOne
       """
       Update multiple blogs
@@ -4077,6 +4373,10 @@ async fn view_with_relation_from_table_to_view() {
         Input for updating multiple Blog instances
         """
         input: BlogUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): BlogUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique Blog
@@ -4086,6 +4386,10 @@ async fn view_with_relation_from_table_to_view() {
         Lookup input for unique Blog deletion
         """
         lookup: BlogLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): BlogDeletePayload! @pgDeleteOne
       """
       Delete multiple blogs
@@ -4095,6 +4399,10 @@ async fn view_with_relation_from_table_to_view() {
         Filter for Blog deletion
         """
         filter: BlogFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): BlogDeleteManyPayload! @pgDeleteMany
       """
       Create a single User
@@ -4126,6 +4434,10 @@ async fn view_with_relation_from_table_to_view() {
         Input for updating a User
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdatePayload! @pgUpdateOne
       """
       Update multiple users
@@ -4139,6 +4451,10 @@ async fn view_with_relation_from_table_to_view() {
         Input for updating multiple User instances
         """
         input: UserUpdateInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserUpdateManyPayload! @pgUpdateMany
       """
       Delete a unique User
@@ -4148,6 +4464,10 @@ async fn view_with_relation_from_table_to_view() {
         Lookup input for unique User deletion
         """
         lookup: UserLookupInput!,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeletePayload! @pgDeleteOne
       """
       Delete multiple users
@@ -4157,7 +4477,370 @@ async fn view_with_relation_from_table_to_view() {
         Filter for User deletion
         """
         filter: UserFilterInput,
+        """
+        Runs the mutation in a transaction that is rolled back, returning the rows it would change per table
+        """
+        dryRun: Boolean,
       ): UserDeleteManyPayload! @pgDeleteMany
     }
     "#);
 }
+
+#[tokio::test]
+async fn foreign_table_with_unique_key() {
+    let api = PgTestApi::new("", |api| async move {
+        api.execute_sql("CREATE EXTENSION postgres_fdw").await;
+
+        let create_server = indoc! {r#"
+            CREATE SERVER "remote" FOREIGN DATA WRAPPER postgres_fdw
+            OPTIONS (host 'localhost', dbname 'remote')
+        "#};
+
+        api.execute_sql(create_server).await;
+
+        let create_table = indoc! {r#"
+            CREATE FOREIGN TABLE "remote_users" (
+                id INT,
+                name TEXT NOT NULL
+            ) SERVER "remote"
+        "#};
+
+        api.execute_sql(create_table).await;
+    })
+    .await;
+
+    let config = indoc! {r#"
+        extension_url = "https://grafbase.com/extensions/postgres/0.3.0"
+
+        [schemas.public.foreign_tables.remote_users]
+        unique_keys = [["id"]]
+
+        [schemas.public.foreign_tables.remote_users.columns.id]
+        nullable = false
+    "#};
+
+    let result = api.introspect_with_config(config).await;
+
+    insta::assert_snapshot!(&result, @r#"
+    extend schema
+      @link(
+        url: "https://grafbase.com/extensions/postgres/0.3.0",
+        import: [
+          "@pgDatabase",
+          "@pgTable",
+          "@pgColumn",
+          "@pgEnum",
+          "@pgEnumVariant",
+          "@pgRelation",
+          "@pgKey",
+          "@pgLookup",
+          "@pgSelectOne",
+          "@pgSelectMany",
+          "@pgInsertOne",
+          "@pgInsertMany",
+          "@pgUpdateOne",
+          "@pgUpdateMany",
+          "@pgDeleteOne",
+          "@pgDeleteMany",
+          "@pgConnection",
+          "@pgMutation",
+          "@pgReturning",
+          "PgKeyType",
+          "PgColumnType",
+          "PgRelationType"
+        ]
+      )
+      @link(
+        url: "https://specs.grafbase.com/composite-schemas/v1",
+        import: [
+          "@lookup",
+          "@key",
+          "@derive",
+          "@is"
+        ]
+      )
+      @link(
+        url: "https://specs.apollo.dev/federation/v2.3",
+        import: [
+          "@shareable",
+          "@inaccessible"
+        ]
+      )
+      @pgDatabase(name: "default")
+
+    """
+    Specifies the direction for ordering results.
+    """
+    enum OrderDirection {
+      """
+      Specifies an ascending order for a given orderBy argument.
+      """
+      ASC
+      """
+      Specifies a descending order for a given orderBy argument.
+      """
+      DESC
+    }
+
+    """
+    Search filter input for Int type.
+    """
+    input IntFilterInput @oneOf {
+      """
+      The value is exactly the one given
+      """
+      eq: Int
+      """
+      The value is not the one given
+      """
+      ne: Int
+      """
+      The value is greater than the one given
+      """
+      gt: Int
+      """
+      The value is less than the one given
+      """
+      lt: Int
+      """
+      The value is greater than, or equal to the one given
+      """
+      gte: Int
+      """
+      The value is less than, or equal to the one given
+      """
+      lte: Int
+      """
+      The value is in the given array of values
+      """
+      in: [Int!]
+      """
+      The value is not in the given array of values
+      """
+      nin: [Int!]
+      """
+      A negation of the given filter
+      """
+      not: IntFilterInput
+    }
+
+    """
+    Filter input type for RemoteUser objects.
+    """
+    input RemoteUserFilterInput @oneOf {
+      """
+      Filter by the given id
+      """
+      id: IntFilterInput
+      """
+      Filter by the given name
+      """
+      name: StringFilterInput
+      """
+      All of the filters must match
+      """
+      ALL: [RemoteUserFilterInput]
+      """
+      None of the filters must match
+      """
+      NONE: [RemoteUserFilterInput]
+      """
+      At least one of the filters must match
+      """
+      ANY: [RemoteUserFilterInput]
+    }
+
+    """
+    Input type to select a unique RemoteUser
+    """
+    input RemoteUserLookupInput @oneOf {
+      """
+      Select by the 'id' field
+      """
+      id: Int
+    }
+
+    """
+    Lookup input type for RemoteUser objects for subgraph joins.
+    """
+    input RemoteUserManyLookupInput @oneOf @inaccessible {
+      """
+      Select by the 'id' field
+      """
+      id: [Int!] @inaccessible
+    }
+
+    """
+    Specifies the ordering for RemoteUser results.
+    """
+    input RemoteUserOrderByInput @oneOf {
+      """
+      Order remoteUsers by id
+      """
+      id: OrderDirection
+      """
+      Order remoteUsers by name
+      """
+      name: OrderDirection
+    }
+
+    """
+    Search filter input for String type.
+    """
+    input StringFilterInput @oneOf {
+      """
+      The value is exactly the one given
+      """
+      eq: String
+      """
+      The value is not the one given
+      """
+      ne: String
+      """
+      The value is greater than the one given
+      """
+      gt: String
+      """
+      The value is less than the one given
+      """
+      lt: String
+      """
+      The value is greater than, or equal to the one given
+      """
+      gte: String
+      """
+      The value is less than, or equal to the one given
+      """
+      lte: String
+      """
+      The given input is part of the column value
+      """
+      like: String
+      """
+      The value is in the given array of values
+      """
+      in: [String!]
+      """
+      The value is not in the given array of values
+      """
+      nin: [String!]
+      """
+      A negation of the given filter
+      """
+      not: StringFilterInput
+    }
+
+    """
+    Information about pagination in a collection of objects
+    """
+    type PageInfo
+      @shareable
+    {
+      """
+      When paginating backwards, are there more items?
+      """
+      hasPreviousPage: Boolean! @shareable
+      """
+      When paginating forwards, are there more items?
+      """
+      hasNextPage: Boolean! @shareable
+      """
+      The cursor of the first item in the page
+      """
+      startCursor: String @shareable
+      """
+      The cursor of the last item in the page
+      """
+      endCursor: String @shareable
+    }
+
+    type RemoteUser
+      @key(fields: "id")
+      @pgTable(name: "remote_users", kind: FOREIGN_TABLE)
+      @pgKey(fields: ["id"], type: UNIQUE)
+    {
+      id: Int! @pgColumn(name: "id", type: INT)
+      name: String! @pgColumn(name: "name", type: TEXT)
+    }
+
+    """
+    The connection type for RemoteUser
+    """
+    type RemoteUserConnection
+      @pgConnection(type: "RemoteUser")
+    {
+      """
+      A list of edges
+      """
+      edges: [RemoteUserEdge!]! @shareable
+      """
+      Information to aid in pagination
+      """
+      pageInfo: PageInfo! @shareable
+    }
+
+    """
+    An edge in a connection. Contains the node and its cursor
+    """
+    type RemoteUserEdge {
+      """
+      The item at the end of the edge
+      """
+      node: RemoteUser! @shareable
+      """
+      A cursor for use in pagination
+      """
+      cursor: String! @shareable
+    }
+
+    type Query {
+      """
+      Query a unique RemoteUser
+      """
+      remoteUser(
+        """
+        Input for unique RemoteUser lookup
+        """
+        lookup: RemoteUserLookupInput!,
+      ): RemoteUser @pgSelectOne
+      """
+      Query and paginate multiple remoteUsers
+      """
+      remoteUsers(
+        """
+        Filter for RemoteUser
+        """
+        filter: RemoteUserFilterInput,
+        """
+        Limit the number of results, from the beginning
+        """
+        first: Int,
+        """
+        Limit the number of results, from the end
+        """
+        last: Int,
+        """
+        Cursor for pagination, select items before the cursor. Use together with `last`.
+        """
+        before: String,
+        """
+        Cursor for pagination, select items after the cursor. Use together with `first`.
+        """
+        after: String,
+        """
+        Order the results by selected fields
+        """
+        orderBy: [RemoteUserOrderByInput!],
+      ): RemoteUserConnection! @pgSelectMany
+      """
+      Lookup multiple remoteUsers for subgraph joins
+      """
+      remoteUserLookup(
+        """
+        Filter remoteUsers with an array of keys
+        """
+        lookup: RemoteUserManyLookupInput @inaccessible,
+      ): [RemoteUser] @pgLookup @lookup @inaccessible
+    }
+    "#);
+}
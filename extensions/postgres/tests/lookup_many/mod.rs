@@ -682,3 +682,126 @@ async fn lookup_rename() {
     }
     "#);
 }
+
+#[tokio::test]
+async fn with_composite_enum_key_and_misses() {
+    let mock_sdl = indoc! {r#"
+        extend schema
+            @link(url: "https://specs.grafbase.com/composite-schemas/v1", import: ["@lookup", "@key"])
+
+        enum Role {
+          ADMIN
+          MEMBER
+        }
+
+        type Membership @key(fields: "orgId role") {
+          orgId: Int!
+          role: Role!
+          since: Int!
+        }
+
+        type Query {
+          memberships: [Membership!]!
+        }
+    "#};
+
+    let response = json!([
+        {
+            "orgId": 1,
+            "role": "MEMBER",
+            "since": 2020
+        },
+        {
+            "orgId": 2,
+            "role": "ADMIN",
+            "since": 2021
+        },
+        {
+            "orgId": 1,
+            "role": "ADMIN",
+            "since": 2022
+        },
+        {
+            "orgId": 1,
+            "role": "MEMBER",
+            "since": 2023
+        }
+    ]);
+
+    let subgraph = GraphqlSubgraph::with_schema(mock_sdl)
+        .with_resolver("Query", "memberships", response)
+        .with_name("mock")
+        .build();
+
+    let api = PgTestApi::new_with_subgraphs("", vec![subgraph], |api| async move {
+        api.execute_sql("CREATE TYPE role AS ENUM ('admin', 'member')").await;
+
+        let schema = indoc! {r#"
+            CREATE TABLE "Membership" (
+                org_id INT NOT NULL,
+                role role NOT NULL,
+                title VARCHAR(255) NULL,
+                PRIMARY KEY (org_id, role)
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let insert = indoc! {r#"
+            INSERT INTO "Membership" (org_id, role, title) VALUES
+              (1, 'admin', 'Owner'),
+              (1, 'member', 'Developer')
+        "#};
+
+        api.execute_sql(insert).await;
+    })
+    .await;
+
+    let runner = api.runner_spawn().await;
+
+    let query = indoc! {r"
+        query {
+          memberships {
+            orgId
+            role
+            since
+            title
+          }
+        }
+    "};
+
+    let response = runner.query(query).send().await;
+
+    insta::assert_json_snapshot!(response, @r#"
+    {
+      "data": {
+        "memberships": [
+          {
+            "orgId": 1,
+            "role": "MEMBER",
+            "since": 2020,
+            "title": "Developer"
+          },
+          {
+            "orgId": 2,
+            "role": "ADMIN",
+            "since": 2021,
+            "title": null
+          },
+          {
+            "orgId": 1,
+            "role": "ADMIN",
+            "since": 2022,
+            "title": "Owner"
+          },
+          {
+            "orgId": 1,
+            "role": "MEMBER",
+            "since": 2023,
+            "title": "Developer"
+          }
+        ]
+      }
+    }
+    "#);
+}
@@ -268,3 +268,162 @@ async fn no_returning() {
     }
     "#);
 }
+
+#[tokio::test]
+async fn dry_run() {
+    let api = PgTestApi::new("", |api| async move {
+        let schema = indoc! {r#"
+            CREATE TABLE "User" (
+                id INT PRIMARY KEY,
+                name VARCHAR(255) NOT NULL
+            )
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let insert = indoc! {r#"
+            INSERT INTO "User" (id, name) VALUES (1, 'Musti'), (2, 'Naukio'), (3, 'Pertti')
+        "#};
+
+        api.execute_sql(insert).await;
+    })
+    .await;
+
+    let runner = api.runner_spawn().await;
+
+    let mutation = indoc! {r#"
+        mutation {
+          userUpdateMany(filter: { id: { lt: 3 } }, input: { name: { set: "Kalle" } }, dryRun: true) {
+            rowCount
+            affectedTables { table updated deleted }
+          }
+        }
+    "#};
+
+    let mutation_response = runner.query(mutation).send().await;
+
+    insta::assert_json_snapshot!(mutation_response, @r#"
+    {
+      "data": {
+        "userUpdateMany": {
+          "rowCount": 2,
+          "affectedTables": [
+            {
+              "table": "User",
+              "updated": 2,
+              "deleted": 0
+            }
+          ]
+        }
+      }
+    }
+    "#);
+
+    let query = indoc! {r"
+        query {
+          users(first: 10) { edges { node { id name } } }
+        }
+    "};
+
+    let query_response = runner.query(query).send().await;
+
+    insta::assert_json_snapshot!(query_response, @r#"
+    {
+      "data": {
+        "users": {
+          "edges": [
+            {
+              "node": {
+                "id": 1,
+                "name": "Musti"
+              }
+            },
+            {
+              "node": {
+                "id": 2,
+                "name": "Naukio"
+              }
+            },
+            {
+              "node": {
+                "id": 3,
+                "name": "Pertti"
+              }
+            }
+          ]
+        }
+      }
+    }
+    "#);
+}
+
+#[tokio::test]
+async fn dry_run_on_partitioned_table() {
+    let api = PgTestApi::new("", |api| async move {
+        let schema = indoc! {r#"
+            CREATE TABLE "measurements" (
+                id INT NOT NULL,
+                recorded_at DATE NOT NULL,
+                value INT NOT NULL,
+                PRIMARY KEY (id, recorded_at)
+            ) PARTITION BY RANGE (recorded_at)
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let schema = indoc! {r#"
+            CREATE TABLE "measurements_2024" PARTITION OF "measurements"
+            FOR VALUES FROM ('2024-01-01') TO ('2025-01-01')
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let schema = indoc! {r#"
+            CREATE TABLE "measurements_2025" PARTITION OF "measurements"
+            FOR VALUES FROM ('2025-01-01') TO ('2026-01-01')
+        "#};
+
+        api.execute_sql(schema).await;
+
+        let insert = indoc! {r#"
+            INSERT INTO "measurements" (id, recorded_at, value) VALUES
+                (1, '2024-06-01', 10),
+                (2, '2024-12-31', 20),
+                (3, '2025-06-01', 30)
+        "#};
+
+        api.execute_sql(insert).await;
+    })
+    .await;
+
+    let runner = api.runner_spawn().await;
+
+    // Moves the second measurement to the other partition.
+    let mutation = indoc! {r#"
+        mutation {
+          measurementUpdateMany(filter: { id: { gt: 1 } }, input: { recordedAt: { set: "2025-01-01" } }, dryRun: true) {
+            rowCount
+            affectedTables { table updated deleted }
+          }
+        }
+    "#};
+
+    let mutation_response = runner.query(mutation).send().await;
+
+    insta::assert_json_snapshot!(mutation_response, @r#"
+    {
+      "data": {
+        "measurementUpdateMany": {
+          "rowCount": 2,
+          "affectedTables": [
+            {
+              "table": "Measurement",
+              "updated": 2,
+              "deleted": 0
+            }
+          ]
+        }
+      }
+    }
+    "#);
+}